@@ -1,3 +1,5 @@
+use napi::bindgen_prelude::Buffer;
+
 use crate::radio::config::RadioConfig;
 
 pub mod radio;
@@ -17,3 +19,49 @@ pub mod services;
 pub fn ble_config() -> RadioConfig {
     RadioConfig::from_inner(rf24ble::ble_config())
 }
+
+/// Returns a {@link RadioConfig} object tailored for passively sniffing
+/// real BLE advertisements (as opposed to {@link bleConfig}'s
+/// FakeBle-to-FakeBle link).
+///
+/// > [!NOTE]
+/// > This configuration complies with inherent
+/// > [Limitations](https://docs.rs/rf24ble-rs/latest/rf24ble/index.html#limitations).
+#[napi]
+#[allow(
+    dead_code,
+    reason = "function is exported publicly in generated binding"
+)]
+pub fn sniffer_config() -> RadioConfig {
+    RadioConfig::from_inner(rf24ble::sniffer_config())
+}
+
+/// Whiten (or de-whiten) `data` as it would be transmitted/received on the given BLE `channel`.
+///
+/// This is a convenience function for users building custom/proprietary PDUs
+/// (eg. the `0xFF` manufacturer-specific path documented on {@link FakeBle.send}).
+#[napi]
+#[allow(
+    dead_code,
+    reason = "function is exported publicly in generated binding"
+)]
+pub fn whiten(data: Buffer, channel: u8) -> Buffer {
+    let mut data = data.to_vec();
+    rf24ble::whiten(&mut data, channel);
+    Buffer::from(data)
+}
+
+/// Calculate a 24 bit CRC checksum for `data`, as used by the BLE specification.
+///
+/// This is a convenience function for users building custom/proprietary PDUs
+/// (eg. the `0xFF` manufacturer-specific path documented on {@link FakeBle.send}).
+/// The returned bytes shall be appended to the transmitted payload *before* applying
+/// {@link whiten}.
+#[napi]
+#[allow(
+    dead_code,
+    reason = "function is exported publicly in generated binding"
+)]
+pub fn crc24(data: Buffer) -> Buffer {
+    Buffer::from(rf24ble::crc24(&data).to_vec())
+}
@@ -7,7 +7,17 @@ use napi::{
 };
 use rf24ble::BleChannels;
 
-use super::services::BlePayload;
+use super::services::{AdStructure, BlePayload};
+
+/// The result of {@link FakeBle.readAll}: every AD structure in a received advertisement,
+/// verbatim, plus the advertiser's MAC address.
+#[napi(object)]
+pub struct DecodedAdStructures {
+    /// The advertiser's MAC address.
+    pub mac_address: Buffer,
+    /// Every AD structure found in the advertisement, in order.
+    pub structures: Vec<AdStructure>,
+}
 
 /// A class to use the nRF24L01 as a Fake BLE beacon.
 ///
@@ -145,6 +155,9 @@ impl FakeBle {
     /// - advertise a Battery's remaining change level: {@link BatteryService}
     /// - advertise a Temperature measurement: {@link TemperatureService}
     /// - advertise a URL: {@link UrlService}
+    /// - advertise an Eddystone-UID beacon identity: {@link EddystoneUidService}
+    /// - advertise an Eddystone-TLM telemetry frame: {@link EddystoneTlmService}
+    /// - advertise an Apple iBeacon: {@link IBeaconService}
     ///
     /// For a custom/proprietary BLE service, the given `buf` must adopt compliance with BLE specifications.
     /// For example, a buffer of `n` bytes shall be formed as follows:
@@ -191,4 +204,36 @@ impl FakeBle {
         let channel = self.radio.get_channel()?;
         Ok(BlePayload::from_bytes(&mut buf, channel))
     }
+
+    /// Read the first available payload from the radio's RX FIFO and decode it into the
+    /// advertiser's MAC address plus its raw sequence of GAP AD structures.
+    ///
+    /// Unlike {@link FakeBle.read} (which only recognizes this crate's own built-in
+    /// services), this reports every AD structure in the payload verbatim, so third-party
+    /// advertisers (manufacturer data, flags, service UUID lists, etc) aren't silently
+    /// dropped.
+    ///
+    /// > [!WARNING]
+    /// > The payload must be decoded while the radio is on
+    /// > the same channel that it received the data.
+    /// > Otherwise, the decoding process will fail.
+    ///
+    /// If the payload was somehow malformed or incomplete,
+    /// then this function returns an undefined value.
+    #[napi]
+    pub fn read_all(&mut self) -> Result<Option<DecodedAdStructures>> {
+        let mut buf = self.radio.read(Some(32))?;
+        let channel = self.radio.get_channel()?;
+        Ok(
+            rf24ble::services::BlePayload::decode_ad_structures(&mut buf, channel).map(
+                |(mac_address, structures)| DecodedAdStructures {
+                    mac_address: Buffer::from(mac_address.to_vec()),
+                    structures: structures
+                        .into_iter()
+                        .map(|inner| AdStructure { inner })
+                        .collect(),
+                },
+            ),
+        )
+    }
 }
@@ -1,5 +1,5 @@
 #![allow(clippy::new_without_default)]
-use napi::bindgen_prelude::Buffer;
+use napi::{bindgen_prelude::Buffer, Error, Result, Status};
 use rf24ble::services::prelude::*;
 
 /// A BLE data service for broadcasting a battery's remaining charge (as a percentage).
@@ -124,6 +124,258 @@ impl UrlService {
         self.inner.set_data(&value);
     }
 
+    /// Like {@link UrlService.data}'s setter, but throws instead of silently broadcasting a
+    /// URL that would exceed `ble`'s 18-byte advertisement budget (see
+    /// {@link FakeBle.lenAvailable}).
+    #[napi]
+    pub fn set_data_for(&mut self, ble: &super::radio::FakeBle, value: String) -> Result<()> {
+        let mut candidate = self.inner;
+        candidate.set_data(&value);
+        if ble.len_available(candidate.buffer()) < 0 {
+            return Err(Error::new(
+                Status::GenericFailure,
+                "value exceeds FakeBle's advertisement budget",
+            ));
+        }
+        self.inner = candidate;
+        Ok(())
+    }
+
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    #[napi(getter)]
+    pub fn buffer(&mut self) -> Buffer {
+        Buffer::from(self.inner.buffer())
+    }
+}
+
+/// A BLE data service for broadcasting an Eddystone-UID beacon identity.
+///
+/// Conforms to the UID frame specified by [Google's EddyStone][eddystone] data format.
+///
+/// [eddystone]: https://github.com/google/eddystone
+///
+/// @group BLE Service Data Classes
+#[napi]
+#[derive(Debug, Clone, Copy)]
+pub struct EddystoneUidService {
+    inner: rf24ble::services::EddystoneUidService,
+}
+
+#[napi]
+impl EddystoneUidService {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: rf24ble::services::EddystoneUidService::default(),
+        }
+    }
+
+    #[napi(getter)]
+    pub fn pa_level(&self) -> i8 {
+        self.inner.pa_level()
+    }
+
+    /// The predicted PA (Power Amplitude) level at 1 meter radius.
+    #[napi(setter, js_name = "paLevel")]
+    pub fn set_pa_level(&mut self, value: i8) {
+        self.inner.set_pa_level(value);
+    }
+
+    #[napi(getter)]
+    pub fn namespace(&self) -> Buffer {
+        Buffer::from(self.inner.namespace().to_vec())
+    }
+
+    /// The 10-byte namespace ID.
+    #[napi(setter, js_name = "namespace")]
+    pub fn set_namespace(&mut self, value: Buffer) {
+        let mut namespace = [0u8; 10];
+        let len = value.len().min(10);
+        namespace[0..len].copy_from_slice(&value[0..len]);
+        self.inner.set_namespace(namespace);
+    }
+
+    #[napi(getter)]
+    pub fn instance(&self) -> Buffer {
+        Buffer::from(self.inner.instance().to_vec())
+    }
+
+    /// The 6-byte instance ID.
+    #[napi(setter, js_name = "instance")]
+    pub fn set_instance(&mut self, value: Buffer) {
+        let mut instance = [0u8; 6];
+        let len = value.len().min(6);
+        instance[0..len].copy_from_slice(&value[0..len]);
+        self.inner.set_instance(instance);
+    }
+
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    #[napi(getter)]
+    pub fn buffer(&mut self) -> Buffer {
+        Buffer::from(self.inner.buffer())
+    }
+}
+
+/// A BLE data service for broadcasting an Eddystone-TLM telemetry frame.
+///
+/// Conforms to the unencrypted TLM frame specified by [Google's EddyStone][eddystone] data
+/// format.
+///
+/// [eddystone]: https://github.com/google/eddystone
+///
+/// @group BLE Service Data Classes
+#[napi]
+#[derive(Debug, Clone, Copy)]
+pub struct EddystoneTlmService {
+    inner: rf24ble::services::EddystoneTlmService,
+}
+
+#[napi]
+impl EddystoneTlmService {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: rf24ble::services::EddystoneTlmService::default(),
+        }
+    }
+
+    /// The TLM frame version. Always `0`, the only version currently defined by the
+    /// Eddystone-TLM spec.
+    #[napi(getter)]
+    pub fn version(&self) -> u8 {
+        self.inner.version()
+    }
+
+    #[napi(getter)]
+    pub fn battery_voltage(&self) -> u16 {
+        self.inner.battery_voltage()
+    }
+
+    /// The battery voltage (in mV). `0` means "unsupported".
+    #[napi(setter, js_name = "batteryVoltage")]
+    pub fn set_battery_voltage(&mut self, value: u16) {
+        self.inner.set_battery_voltage(value);
+    }
+
+    /// Does this frame report a battery voltage, or is it the "unsupported" sentinel?
+    #[napi(getter, js_name = "hasBatteryVoltage")]
+    pub fn has_battery_voltage(&self) -> bool {
+        self.inner.has_battery_voltage()
+    }
+
+    #[napi(getter)]
+    pub fn temperature(&self) -> f64 {
+        self.inner.temperature() as f64
+    }
+
+    /// The beacon's temperature (in Celsius). `-128.0` means "unsupported".
+    #[napi(setter, js_name = "temperature")]
+    pub fn set_temperature(&mut self, value: f64) {
+        self.inner.set_temperature(value as f32);
+    }
+
+    /// Does this frame report a temperature, or is it the "unsupported" sentinel?
+    #[napi(getter, js_name = "hasTemperature")]
+    pub fn has_temperature(&self) -> bool {
+        self.inner.has_temperature()
+    }
+
+    #[napi(getter)]
+    pub fn pdu_count(&self) -> u32 {
+        self.inner.pdu_count()
+    }
+
+    /// The count of advertising PDUs sent since power-up (or reboot).
+    #[napi(setter, js_name = "pduCount")]
+    pub fn set_pdu_count(&mut self, value: u32) {
+        self.inner.set_pdu_count(value);
+    }
+
+    #[napi(getter)]
+    pub fn uptime(&self) -> u32 {
+        self.inner.uptime()
+    }
+
+    /// The time since power-up (or reboot), in 0.1 second units.
+    #[napi(setter, js_name = "uptime")]
+    pub fn set_uptime(&mut self, value: u32) {
+        self.inner.set_uptime(value);
+    }
+
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    #[napi(getter)]
+    pub fn buffer(&mut self) -> Buffer {
+        Buffer::from(self.inner.buffer())
+    }
+}
+
+/// A BLE data service for broadcasting an Apple iBeacon.
+///
+/// Conforms to the iBeacon advertising format.
+///
+/// @group BLE Service Data Classes
+#[napi]
+#[derive(Debug, Clone, Copy)]
+pub struct IBeaconService {
+    inner: rf24ble::services::IBeaconService,
+}
+
+#[napi]
+impl IBeaconService {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: rf24ble::services::IBeaconService::default(),
+        }
+    }
+
+    #[napi(getter)]
+    pub fn uuid(&self) -> Buffer {
+        Buffer::from(self.inner.uuid().to_vec())
+    }
+
+    /// The 16-byte proximity UUID.
+    #[napi(setter, js_name = "uuid")]
+    pub fn set_uuid(&mut self, value: Buffer) {
+        let mut uuid = [0u8; 16];
+        let len = value.len().min(16);
+        uuid[0..len].copy_from_slice(&value[0..len]);
+        self.inner.set_uuid(uuid);
+    }
+
+    #[napi(getter)]
+    pub fn major(&self) -> u16 {
+        self.inner.major()
+    }
+
+    /// The major value.
+    #[napi(setter, js_name = "major")]
+    pub fn set_major(&mut self, value: u16) {
+        self.inner.set_major(value);
+    }
+
+    #[napi(getter)]
+    pub fn minor(&self) -> u16 {
+        self.inner.minor()
+    }
+
+    /// The minor value.
+    #[napi(setter, js_name = "minor")]
+    pub fn set_minor(&mut self, value: u16) {
+        self.inner.set_minor(value);
+    }
+
+    #[napi(getter)]
+    pub fn measured_power(&self) -> i8 {
+        self.inner.measured_power()
+    }
+
+    /// The measured power (in dBm) at 1 meter.
+    #[napi(setter, js_name = "measuredPower")]
+    pub fn set_measured_power(&mut self, value: i8) {
+        self.inner.set_measured_power(value);
+    }
+
     /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
     #[napi(getter)]
     pub fn buffer(&mut self) -> Buffer {
@@ -131,6 +383,119 @@ impl UrlService {
     }
 }
 
+/// A BLE data service for broadcasting arbitrary manufacturer-specific data.
+///
+/// Conforms to the Manufacturer Specific Data AD structure (type `0xFF`): a 2-byte
+/// little-endian company identifier followed by an arbitrary payload.
+///
+/// @group BLE Service Data Classes
+#[napi]
+#[derive(Debug, Clone, Copy)]
+pub struct ManufacturerDataService {
+    inner: rf24ble::services::ManufacturerDataService,
+}
+
+#[napi]
+impl ManufacturerDataService {
+    /// @param companyId - The 2-byte company identifier (e.g. `0x004C` for Apple).
+    #[napi(constructor)]
+    pub fn new(company_id: u16) -> Self {
+        Self {
+            inner: rf24ble::services::ManufacturerDataService::new(company_id),
+        }
+    }
+
+    /// The 2-byte company identifier.
+    #[napi(getter, js_name = "companyId")]
+    pub fn company_id(&self) -> u16 {
+        self.inner.company_id()
+    }
+
+    #[napi(getter)]
+    pub fn data(&self) -> Buffer {
+        Buffer::from(self.inner.data())
+    }
+
+    /// The payload that follows {@link ManufacturerDataService.companyId}.
+    #[napi(setter, js_name = "data")]
+    pub fn set_data(&mut self, payload: Buffer) {
+        self.inner.set_data(&payload);
+    }
+
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    #[napi(getter)]
+    pub fn buffer(&mut self) -> Buffer {
+        Buffer::from(self.inner.buffer())
+    }
+}
+
+/// An unrecognized AD structure, captured verbatim from a received advertisement.
+///
+/// Covers both Service Data (AD type `0x16`, {@link CustomService.serviceId} being the
+/// GATT service UUID) and Manufacturer Specific Data (AD type `0xFF`,
+/// {@link CustomService.serviceId} being the company identifier) structures that don't
+/// match one of the built-in services above.
+///
+/// @group BLE Service Data Classes
+#[napi]
+#[derive(Debug, Clone, Copy)]
+pub struct CustomService {
+    inner: rf24ble::services::RawAdStructure,
+}
+
+#[napi]
+impl CustomService {
+    /// The AD structure's type byte (`0x16` for Service Data, `0xFF` for Manufacturer
+    /// Specific Data).
+    #[napi(getter)]
+    pub fn ad_type(&self) -> u8 {
+        self.inner.ad_type
+    }
+
+    /// The 16-bit service-data UUID or manufacturer company identifier leading this
+    /// structure's payload, if the payload is long enough to contain one.
+    #[napi(getter)]
+    pub fn service_id(&self) -> Option<u16> {
+        self.inner.service_id()
+    }
+
+    /// The raw payload following {@link CustomService.serviceId}.
+    #[napi(getter)]
+    pub fn data(&mut self) -> Buffer {
+        Buffer::from(self.inner.payload())
+    }
+}
+
+/// A single GAP Advertising Data structure, captured verbatim from a received
+/// advertisement, with no interpretation of its contents.
+///
+/// Returned by {@link FakeBle.readAll}, which (unlike {@link FakeBle.read}) reports every
+/// AD structure in a payload, not just the handful of built-in services this crate
+/// recognizes. Useful for sniffing third-party advertisers (flags, appearance, service
+/// UUID lists, manufacturer data, etc).
+///
+/// @group BLE Service Data Classes
+#[napi]
+#[derive(Debug, Clone, Copy)]
+pub struct AdStructure {
+    inner: rf24ble::services::RawAdStructure,
+}
+
+#[napi]
+impl AdStructure {
+    /// The AD structure's type byte.
+    #[napi(getter, js_name = "adType")]
+    pub fn ad_type(&self) -> u8 {
+        self.inner.ad_type
+    }
+
+    /// The raw data following {@link AdStructure.adType}, with no further interpretation.
+    #[napi(getter)]
+    pub fn data(&mut self) -> Buffer {
+        Buffer::from(self.inner.data())
+    }
+}
+
 /// A structure to represent received BLE data.
 #[napi]
 pub struct BlePayload {
@@ -140,6 +505,10 @@ pub struct BlePayload {
     battery_charge: Option<BatteryService>,
     url: Option<UrlService>,
     temperature: Option<TemperatureService>,
+    eddystone_uid: Option<EddystoneUidService>,
+    eddystone_tlm: Option<EddystoneTlmService>,
+    ibeacon: Option<IBeaconService>,
+    unsupported: Vec<CustomService>,
 }
 
 impl BlePayload {
@@ -160,6 +529,18 @@ impl BlePayload {
                     .map(|batt| BatteryService { inner: batt }),
                 url: payload.url.map(|u| UrlService { inner: u }),
                 temperature: payload.temperature.map(|t| TemperatureService { inner: t }),
+                eddystone_uid: payload
+                    .eddystone_uid
+                    .map(|uid| EddystoneUidService { inner: uid }),
+                eddystone_tlm: payload
+                    .eddystone_tlm
+                    .map(|tlm| EddystoneTlmService { inner: tlm }),
+                ibeacon: payload.ibeacon.map(|b| IBeaconService { inner: b }),
+                unsupported: payload
+                    .unsupported
+                    .into_iter()
+                    .map(|raw| CustomService { inner: raw })
+                    .collect(),
             });
         }
         None
@@ -203,4 +584,29 @@ impl BlePayload {
     pub fn url(&self) -> Option<UrlService> {
         self.url
     }
+
+    /// The transmitting device's advertised Eddystone-UID beacon identity.
+    #[napi(getter)]
+    pub fn eddystone_uid(&self) -> Option<EddystoneUidService> {
+        self.eddystone_uid
+    }
+
+    /// The transmitting device's advertised Eddystone-TLM telemetry.
+    #[napi(getter)]
+    pub fn eddystone_tlm(&self) -> Option<EddystoneTlmService> {
+        self.eddystone_tlm
+    }
+
+    /// The transmitting device's advertised iBeacon.
+    #[napi(getter)]
+    pub fn ibeacon(&self) -> Option<IBeaconService> {
+        self.ibeacon
+    }
+
+    /// Any AD structures that aren't one of the built-in services above, captured
+    /// verbatim.
+    #[napi(getter)]
+    pub fn unsupported(&self) -> Vec<CustomService> {
+        self.unsupported.clone()
+    }
 }
@@ -0,0 +1,5 @@
+#![cfg(target_os = "linux")]
+pub mod addressing;
+pub mod node;
+
+pub use node::NetworkNode;
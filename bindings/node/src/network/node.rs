@@ -0,0 +1,235 @@
+#![cfg(target_os = "linux")]
+
+//! A logical-addressing network layer built directly on a dedicated radio, modeled on the
+//! RF24Network ecosystem that many of the ported drivers depend on.
+//!
+//! Each [`NetworkNode`] is identified by a 15-bit octal logical address (see
+//! [`super::addressing`]) instead of a raw 5-byte pipe address. Frames not addressed to the
+//! local node are automatically forwarded one hop toward their destination, either down to
+//! the correct child or up to the parent.
+//!
+//! Like [`AsyncRF24`](super::super::radio::async_radio::AsyncRF24), this owns its radio
+//! directly (constructed from `cePin`/`csPin`/`hardwareConfig`) rather than wrapping an
+//! existing {@link RF24} instance, since only one object may drive the underlying hardware.
+
+use std::collections::VecDeque;
+
+use linux_embedded_hal::{CdevPin, SpidevDevice};
+use napi::{bindgen_prelude::Buffer, Error, Result, Status};
+use rf24::radio::prelude::*;
+
+use super::addressing::{child_address, child_index, is_descendant, link_address, parent_address};
+use crate::radio::interface::{open_hardware, Delay};
+use crate::radio::types::HardwareConfig;
+
+/// `from_node`(2) + `to_node`(2) + `id`(2) + `type`(1) + reserved(1), matching RF24Network's
+/// on-air frame header.
+const HEADER_LEN: usize = 8;
+/// The largest payload that fits a single 32-byte radio payload alongside [`HEADER_LEN`].
+const MAX_PAYLOAD_LEN: usize = 32 - HEADER_LEN;
+
+fn radio_err<E: core::fmt::Debug>(e: E) -> Error {
+    Error::new(Status::GenericFailure, format!("{e:?}"))
+}
+
+/// A frame received by [`NetworkNode.read`][NetworkNode::read].
+#[napi(object)]
+pub struct NetworkFrame {
+    /// The logical address of the node that originally sent this frame.
+    pub from_node: u16,
+    /// The application-defined value given to the sender's `write()` call.
+    pub header_type: u8,
+    /// The frame's payload (up to 24 bytes).
+    pub payload: Buffer,
+}
+
+/// A node in a logical-addressing, multi-hop network built on top of the nRF24L01.
+///
+/// Every node is identified by a 15-bit logical address arranged in an octal tree: the
+/// master is address `0`, and each node may have up to 5 children (`01`..`05`, in octal),
+/// their children (`011`..`051`), and so on. A node's parent address is obtained by
+/// dropping the most-significant octal digit.
+///
+/// @example
+/// ```js
+/// const { NetworkNode } = require("@rf24/rf24");
+///
+/// const node = new NetworkNode(22, 0);
+/// node.begin(0); // this node is the master
+///
+/// while (true) {
+///   node.update();
+///   if (node.available()) {
+///     const frame = node.read();
+///   }
+/// }
+/// ```
+#[napi(js_name = "NetworkNode")]
+pub struct NetworkNode {
+    radio: rf24::radio::RF24<SpidevDevice, CdevPin, Delay>,
+    node_address: u16,
+    next_id: u16,
+    frame_queue: VecDeque<(u16, u8, Vec<u8>)>,
+}
+
+#[napi]
+impl NetworkNode {
+    /// Construct a node, opening the same hardware that {@link RF24}'s constructor would.
+    ///
+    /// @param cePin - The GPIO pin number connected to the radio's CE pin.
+    /// @param csPin - The identifying number for the SPI bus' CS pin.
+    /// @param hardwareConfig - Optional parameters to fine tune hardware configuration.
+    #[napi(constructor)]
+    pub fn new(ce_pin: u32, cs_pin: u8, hardware_config: Option<HardwareConfig>) -> Result<Self> {
+        let (ce_pin, spi, _hw_config) = open_hardware(ce_pin, cs_pin, hardware_config)?;
+        Ok(Self {
+            radio: rf24::radio::RF24::new(ce_pin, spi, Delay),
+            node_address: 0,
+            next_id: 0,
+            frame_queue: VecDeque::new(),
+        })
+    }
+
+    /// Initialize the radio and join the network as `nodeAddress`.
+    ///
+    /// This derives this node's physical pipe addresses from `nodeAddress` (see
+    /// {@link NetworkNode.childAddress}) and configures them automatically: pipe 0 for the
+    /// uplink to its parent (if any), and pipes 1 - 5 for up to 5 children.
+    #[napi]
+    pub fn begin(&mut self, node_address: u16) -> Result<()> {
+        self.radio.init().map_err(radio_err)?;
+        self.radio.set_address_length(5).map_err(radio_err)?;
+        if node_address != 0 {
+            let parent = parent_address(node_address);
+            let my_slot = child_index(parent, node_address);
+            self.radio
+                .open_rx_pipe(0, &link_address(parent, my_slot))
+                .map_err(radio_err)?;
+        }
+        for child in 1u8..=5 {
+            self.radio
+                .open_rx_pipe(child, &link_address(node_address, child))
+                .map_err(radio_err)?;
+        }
+        self.radio.as_rx().map_err(radio_err)?;
+        self.node_address = node_address;
+        Ok(())
+    }
+
+    /// This node's logical address, as given to {@link NetworkNode.begin}.
+    #[napi(getter)]
+    pub fn node_address(&self) -> u16 {
+        self.node_address
+    }
+
+    /// Process any payloads waiting in the radio's RX FIFO.
+    ///
+    /// Frames addressed to this node are queued for {@link NetworkNode.read}. Frames
+    /// addressed elsewhere are forwarded toward their destination.
+    #[napi]
+    pub fn update(&mut self) -> Result<()> {
+        let mut buf = [0u8; 32];
+        while self.radio.available().map_err(radio_err)? {
+            let len = self.radio.read(&mut buf, None).map_err(radio_err)? as usize;
+            if len < HEADER_LEN {
+                continue;
+            }
+            let from = u16::from_le_bytes([buf[0], buf[1]]);
+            let to = u16::from_le_bytes([buf[2], buf[3]]);
+            let header_type = buf[6];
+            if to == self.node_address {
+                let payload = buf[HEADER_LEN..len].to_vec();
+                self.frame_queue.push_back((from, header_type, payload));
+            } else {
+                Self::forward(self.node_address, &mut self.radio, to, &buf[..len])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Is there a received frame waiting in the local queue?
+    #[napi]
+    pub fn available(&self) -> bool {
+        !self.frame_queue.is_empty()
+    }
+
+    /// Pop the next locally addressed frame from the queue.
+    ///
+    /// Returns `null` if {@link NetworkNode.available} is `false`.
+    #[napi]
+    pub fn read(&mut self) -> Option<NetworkFrame> {
+        self.frame_queue
+            .pop_front()
+            .map(|(from_node, header_type, payload)| NetworkFrame {
+                from_node,
+                header_type,
+                payload: Buffer::from(payload),
+            })
+    }
+
+    /// Send `buf` to the node at logical address `toNode`.
+    ///
+    /// @param toNode - The logical address of the destination node.
+    /// @param headerType - An application-defined value carried alongside the payload.
+    /// This is returned as-is via {@link NetworkFrame.headerType}.
+    /// @param buf - The payload to send (up to 24 bytes).
+    #[napi]
+    pub fn write(&mut self, to_node: u16, header_type: u8, buf: Buffer) -> Result<bool> {
+        if buf.len() > MAX_PAYLOAD_LEN {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("payload exceeds the {MAX_PAYLOAD_LEN} byte maximum for a single frame"),
+            ));
+        }
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        let mut frame = Vec::with_capacity(HEADER_LEN + buf.len());
+        frame.extend_from_slice(&self.node_address.to_le_bytes());
+        frame.extend_from_slice(&to_node.to_le_bytes());
+        frame.extend_from_slice(&id.to_le_bytes());
+        frame.push(header_type);
+        frame.push(0); // reserved, matching RF24Network's on-air layout
+        frame.extend_from_slice(&buf);
+        Self::forward(self.node_address, &mut self.radio, to_node, &frame)?;
+        Ok(true)
+    }
+
+    /// The address of `parent`'s child at `childIndex` (`1..=5`).
+    #[napi]
+    pub fn child_address(parent: u16, child_index: u8) -> u16 {
+        child_address(parent, child_index)
+    }
+
+    /// The address of `address`'s parent node.
+    #[napi]
+    pub fn parent_address(address: u16) -> u16 {
+        parent_address(address)
+    }
+}
+
+impl NetworkNode {
+    /// Forward a raw (already header-prefixed) `frame` toward `to`, one hop at a time.
+    fn forward(
+        node_address: u16,
+        radio: &mut rf24::radio::RF24<SpidevDevice, CdevPin, Delay>,
+        to: u16,
+        frame: &[u8],
+    ) -> Result<()> {
+        let next_hop = if is_descendant(node_address, to) {
+            link_address(node_address, child_index(node_address, to))
+        } else if node_address == 0 {
+            return Err(Error::new(
+                Status::GenericFailure,
+                format!("no route to node {to}: it is not a descendant of this master node"),
+            ));
+        } else {
+            let parent = parent_address(node_address);
+            link_address(parent, child_index(parent, node_address))
+        };
+        radio.as_tx().map_err(radio_err)?;
+        radio.open_tx_pipe(&next_hop).map_err(radio_err)?;
+        radio.send(frame, false).map_err(radio_err)?;
+        radio.as_rx().map_err(radio_err)?;
+        Ok(())
+    }
+}
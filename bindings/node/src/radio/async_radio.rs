@@ -0,0 +1,492 @@
+#![cfg(target_os = "linux")]
+
+//! A non-blocking counterpart to [`RF24`](super::interface::RF24) for Node.js callers that
+//! cannot afford to stall the event loop while the radio spins on TX_DS/MAX_RT or polls the
+//! RX FIFO.
+//!
+//! Because the inner `rf24::radio::RF24` is not `Send`, it cannot be moved onto libuv's
+//! threadpool for the duration of a single call. Instead, [`AsyncRF24`] owns a dedicated
+//! worker thread that constructs (and keeps) the radio locally, and talks to it over a
+//! command channel. Each async method only ever sends a [`Command`] and waits for its
+//! reply, so the `napi::Task` that actually runs on the threadpool only ever touches
+//! `Send` data.
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::interface::{open_hardware, Delay};
+use super::types::{HardwareConfig, RadioDetails, WriteConfig};
+use napi::{
+    bindgen_prelude::{AsyncTask, Buffer},
+    Env, Error, Result, Status, Task,
+};
+use rf24::radio::prelude::*;
+
+enum Command {
+    Begin {
+        reply: mpsc::Sender<Result<()>>,
+    },
+    Send {
+        buf: Vec<u8>,
+        ask_no_ack: bool,
+        reply: mpsc::Sender<Result<bool>>,
+    },
+    Write {
+        buf: Vec<u8>,
+        ask_no_ack: bool,
+        start_tx: bool,
+        reply: mpsc::Sender<Result<bool>>,
+    },
+    Read {
+        len: Option<u8>,
+        reply: mpsc::Sender<Result<Vec<u8>>>,
+    },
+    Resend {
+        send_only: bool,
+        reply: mpsc::Sender<Result<bool>>,
+    },
+    AwaitPayload {
+        timeout_ms: u32,
+        reply: mpsc::Sender<Result<Option<Vec<u8>>>>,
+    },
+    GetDetails {
+        reply: mpsc::Sender<Result<rf24::RadioDetails>>,
+    },
+}
+
+fn radio_error<E: core::fmt::Debug>(e: E) -> Error {
+    Error::new(Status::GenericFailure, format!("{e:?}"))
+}
+
+fn disconnected() -> Error {
+    Error::new(
+        Status::GenericFailure,
+        "the radio's worker thread is no longer running",
+    )
+}
+
+/// Run on a dedicated thread for the lifetime of an [`AsyncRF24`] instance, servicing
+/// [`Command`]s against a locally owned radio until the command channel is dropped.
+fn run_worker(
+    ce_pin: u32,
+    cs_pin: u8,
+    hardware_config: Option<HardwareConfig>,
+    commands: mpsc::Receiver<Command>,
+) {
+    let radio = open_hardware(ce_pin, cs_pin, hardware_config)
+        .map(|(ce_pin, spi, _hw_config)| rf24::radio::RF24::new(ce_pin, spi, Delay));
+    let mut radio = match radio {
+        Ok(radio) => radio,
+        Err(e) => {
+            // The hardware could not be opened. Keep draining commands so callers get a
+            // meaningful error instead of `disconnected()` when the channel closes early.
+            for command in commands {
+                let err = Error::new(e.status, e.reason.clone());
+                match command {
+                    Command::Begin { reply } => drop(reply.send(Err(err))),
+                    Command::Send { reply, .. } => drop(reply.send(Err(err))),
+                    Command::Write { reply, .. } => drop(reply.send(Err(err))),
+                    Command::Read { reply, .. } => drop(reply.send(Err(err))),
+                    Command::Resend { reply, .. } => drop(reply.send(Err(err))),
+                    Command::AwaitPayload { reply, .. } => drop(reply.send(Err(err))),
+                    Command::GetDetails { reply } => drop(reply.send(Err(err))),
+                }
+            }
+            return;
+        }
+    };
+    let mut read_buf = [0u8; 32];
+
+    for command in commands {
+        match command {
+            Command::Begin { reply } => {
+                let result = radio.init().map_err(radio_error);
+                drop(reply.send(result));
+            }
+            Command::Send {
+                buf,
+                ask_no_ack,
+                reply,
+            } => {
+                let result = radio.send(&buf, ask_no_ack).map_err(radio_error);
+                drop(reply.send(result));
+            }
+            Command::Write {
+                buf,
+                ask_no_ack,
+                start_tx,
+                reply,
+            } => {
+                let result = radio.write(&buf, ask_no_ack, start_tx).map_err(radio_error);
+                drop(reply.send(result));
+            }
+            Command::Read { len, reply } => {
+                let result = radio
+                    .read(&mut read_buf, len)
+                    .map(|len| read_buf[0..len as usize].to_vec())
+                    .map_err(radio_error);
+                drop(reply.send(result));
+            }
+            Command::Resend { send_only, reply } => {
+                let result = radio.resend(send_only).map_err(radio_error);
+                drop(reply.send(result));
+            }
+            Command::AwaitPayload { timeout_ms, reply } => {
+                let deadline = Instant::now() + Duration::from_millis(timeout_ms as u64);
+                let result = 'poll: loop {
+                    match radio.available() {
+                        Ok(true) => {
+                            break 'poll radio.read(&mut read_buf, None).map(|len| {
+                                Some(read_buf[0..len as usize].to_vec())
+                            }).map_err(radio_error);
+                        }
+                        Ok(false) => {
+                            if Instant::now() >= deadline {
+                                break 'poll Ok(None);
+                            }
+                            thread::sleep(Duration::from_millis(1));
+                        }
+                        Err(e) => break 'poll Err(radio_error(e)),
+                    }
+                };
+                drop(reply.send(result));
+            }
+            Command::GetDetails { reply } => {
+                let result = radio.get_details().map_err(radio_error);
+                drop(reply.send(result));
+            }
+        }
+    }
+}
+
+/// An asynchronous counterpart to {@link RF24} whose send/resend/receive operations
+/// return Promises instead of blocking the event loop.
+///
+/// Internally, this owns a dedicated worker thread that holds the actual radio; the
+/// Promise-returning methods only exchange short-lived messages with that thread. This
+/// means an {@link AsyncRF24} instance does not share state with (and cannot be
+/// constructed from) a {@link RF24} instance.
+///
+/// ```ts
+/// import { AsyncRF24 } from "@rf24/rf24";
+///
+/// const radio = new AsyncRF24(22, 0);
+/// await radio.begin();
+/// await radio.sendAsync(Buffer.from("hello"));
+/// ```
+#[napi(js_name = "AsyncRF24")]
+pub struct AsyncRF24 {
+    commands: mpsc::Sender<Command>,
+}
+
+#[napi]
+impl AsyncRF24 {
+    /// Construct an object to control the radio asynchronously.
+    ///
+    /// This spawns the worker thread immediately; the radio's hardware is opened on
+    /// that thread before any queued command is serviced.
+    ///
+    /// @param cePin - The GPIO pin number connected to the radio's CE pin.
+    /// @param csPin - The identifying number for the SPI bus' CS pin;
+    /// also labeled as "CEx" (where "x" is this parameter's value) on many
+    /// Raspberry Pi pin diagrams. See {@link HardwareConfig.devSpiBus} for more detail.
+    /// @param hardwareConfig - Optional parameters to fine tune hardware configuration
+    /// (like SPI bus number and GPIO chip number).
+    ///
+    /// @group Async
+    #[napi(constructor)]
+    pub fn new(ce_pin: u32, cs_pin: u8, hardware_config: Option<HardwareConfig>) -> Self {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || run_worker(ce_pin, cs_pin, hardware_config, rx));
+        Self { commands: tx }
+    }
+
+    /// Initialize the radio on the configured hardware (as specified to {@link AsyncRF24}
+    /// constructor).
+    ///
+    /// @throws A Generic Error if a hardware failure caused problems
+    /// (includes a message to describe what problem was detected).
+    ///
+    /// @group Async
+    #[napi]
+    pub fn begin(&self) -> AsyncTask<BeginTask> {
+        AsyncTask::new(BeginTask {
+            commands: self.commands.clone(),
+        })
+    }
+
+    /// Asynchronously load a given `buf` into the TX FIFO and wait for a response
+    /// (if auto-ack is enabled), resolving with a Boolean describing success.
+    ///
+    /// This is the non-blocking (Promise-returning) counterpart to {@link RF24.send}.
+    ///
+    /// @param buf - The buffer of bytes to transmit.
+    /// @param askNoAck - A flag to disable the auto-ack feature for the given payload in `buf`.
+    ///
+    /// @group Async
+    #[napi]
+    pub fn send_async(&self, buf: Buffer, ask_no_ack: Option<bool>) -> AsyncTask<SendTask> {
+        AsyncTask::new(SendTask {
+            commands: self.commands.clone(),
+            buf: buf.to_vec(),
+            ask_no_ack: ask_no_ack.unwrap_or_default(),
+        })
+    }
+
+    /// Asynchronously upload a given `buf` to the radio's TX FIFO without waiting for the
+    /// auto-ACK response.
+    ///
+    /// This is the non-blocking (Promise-returning) counterpart to {@link RF24.write}.
+    ///
+    /// @group Async
+    #[napi]
+    pub fn write_async(&self, buf: Buffer, write_config: Option<WriteConfig>) -> AsyncTask<WriteTask> {
+        let options = write_config.unwrap_or_default();
+        AsyncTask::new(WriteTask {
+            commands: self.commands.clone(),
+            buf: buf.to_vec(),
+            ask_no_ack: options.ask_no_ack.or(options.multicast).unwrap_or_default(),
+            start_tx: options.start_tx.unwrap_or(true),
+        })
+    }
+
+    /// Asynchronously read the next available payload from the RX FIFO.
+    ///
+    /// This is the non-blocking (Promise-returning) counterpart to {@link RF24.read}.
+    ///
+    /// @param len - An optional number of bytes to read from the FIFO. This is capped at `32`.
+    ///
+    /// @group Async
+    #[napi]
+    pub fn read_async(&self, len: Option<u8>) -> AsyncTask<ReadTask> {
+        AsyncTask::new(ReadTask {
+            commands: self.commands.clone(),
+            len,
+        })
+    }
+
+    /// Asynchronously resend a failed payload still held in the TX FIFO.
+    ///
+    /// This is the non-blocking (Promise-returning) counterpart to {@link RF24.resend}.
+    ///
+    /// @param sendOnly - A flag to leave any ACK payload sitting in the RX FIFO
+    /// instead of flushing it after a successful resend. Defaults to `false`.
+    ///
+    /// @group Async
+    #[napi]
+    pub fn resend_async(&self, send_only: Option<bool>) -> AsyncTask<ResendTask> {
+        AsyncTask::new(ResendTask {
+            commands: self.commands.clone(),
+            send_only: send_only.unwrap_or_default(),
+        })
+    }
+
+    /// Wait (without blocking the event loop) until a payload is received or `timeoutMs`
+    /// elapses, whichever comes first.
+    ///
+    /// @param timeoutMs - The maximum number of milliseconds to wait for a payload.
+    ///
+    /// @returns The received payload, or `null` if `timeoutMs` elapsed with nothing received.
+    ///
+    /// @group Async
+    #[napi]
+    pub fn await_payload(&self, timeout_ms: u32) -> AsyncTask<AwaitPayloadTask> {
+        AsyncTask::new(AwaitPayloadTask {
+            commands: self.commands.clone(),
+            timeout_ms,
+        })
+    }
+
+    /// Asynchronously get the radio's current configuration as a structured object.
+    ///
+    /// This is the non-blocking (Promise-returning) counterpart to {@link RF24.getDetails}.
+    ///
+    /// @group Async
+    #[napi]
+    pub fn get_details(&self) -> AsyncTask<GetDetailsTask> {
+        AsyncTask::new(GetDetailsTask {
+            commands: self.commands.clone(),
+        })
+    }
+}
+
+/// The [`Task`] behind {@link AsyncRF24.begin}.
+pub struct BeginTask {
+    commands: mpsc::Sender<Command>,
+}
+
+impl Task for BeginTask {
+    type Output = ();
+    type JsValue = ();
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let (reply, response) = mpsc::channel();
+        self.commands
+            .send(Command::Begin { reply })
+            .map_err(|_| disconnected())?;
+        response.recv().map_err(|_| disconnected())?
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// The [`Task`] behind {@link AsyncRF24.sendAsync}.
+pub struct SendTask {
+    commands: mpsc::Sender<Command>,
+    buf: Vec<u8>,
+    ask_no_ack: bool,
+}
+
+impl Task for SendTask {
+    type Output = bool;
+    type JsValue = bool;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let (reply, response) = mpsc::channel();
+        self.commands
+            .send(Command::Send {
+                buf: std::mem::take(&mut self.buf),
+                ask_no_ack: self.ask_no_ack,
+                reply,
+            })
+            .map_err(|_| disconnected())?;
+        response.recv().map_err(|_| disconnected())?
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// The [`Task`] behind {@link AsyncRF24.writeAsync}.
+pub struct WriteTask {
+    commands: mpsc::Sender<Command>,
+    buf: Vec<u8>,
+    ask_no_ack: bool,
+    start_tx: bool,
+}
+
+impl Task for WriteTask {
+    type Output = bool;
+    type JsValue = bool;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let (reply, response) = mpsc::channel();
+        self.commands
+            .send(Command::Write {
+                buf: std::mem::take(&mut self.buf),
+                ask_no_ack: self.ask_no_ack,
+                start_tx: self.start_tx,
+                reply,
+            })
+            .map_err(|_| disconnected())?;
+        response.recv().map_err(|_| disconnected())?
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// The [`Task`] behind {@link AsyncRF24.readAsync}.
+pub struct ReadTask {
+    commands: mpsc::Sender<Command>,
+    len: Option<u8>,
+}
+
+impl Task for ReadTask {
+    type Output = Vec<u8>;
+    type JsValue = Buffer;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let (reply, response) = mpsc::channel();
+        self.commands
+            .send(Command::Read {
+                len: self.len,
+                reply,
+            })
+            .map_err(|_| disconnected())?;
+        response.recv().map_err(|_| disconnected())?
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(Buffer::from(output))
+    }
+}
+
+/// The [`Task`] behind {@link AsyncRF24.resendAsync}.
+pub struct ResendTask {
+    commands: mpsc::Sender<Command>,
+    send_only: bool,
+}
+
+impl Task for ResendTask {
+    type Output = bool;
+    type JsValue = bool;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let (reply, response) = mpsc::channel();
+        self.commands
+            .send(Command::Resend {
+                send_only: self.send_only,
+                reply,
+            })
+            .map_err(|_| disconnected())?;
+        response.recv().map_err(|_| disconnected())?
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// The [`Task`] behind {@link AsyncRF24.awaitPayload}.
+pub struct AwaitPayloadTask {
+    commands: mpsc::Sender<Command>,
+    timeout_ms: u32,
+}
+
+impl Task for AwaitPayloadTask {
+    type Output = Option<Vec<u8>>;
+    type JsValue = Option<Buffer>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let (reply, response) = mpsc::channel();
+        self.commands
+            .send(Command::AwaitPayload {
+                timeout_ms: self.timeout_ms,
+                reply,
+            })
+            .map_err(|_| disconnected())?;
+        response.recv().map_err(|_| disconnected())?
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output.map(Buffer::from))
+    }
+}
+
+/// The [`Task`] behind {@link AsyncRF24.getDetails}.
+pub struct GetDetailsTask {
+    commands: mpsc::Sender<Command>,
+}
+
+impl Task for GetDetailsTask {
+    type Output = rf24::RadioDetails;
+    type JsValue = RadioDetails;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let (reply, response) = mpsc::channel();
+        self.commands
+            .send(Command::GetDetails { reply })
+            .map_err(|_| disconnected())?;
+        response.recv().map_err(|_| disconnected())?
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(RadioDetails::from_inner(output))
+    }
+}
@@ -224,6 +224,21 @@ impl RadioConfig {
         Ok(())
     }
 
+    #[napi(getter, js_name = "dynamicPayloadsBin")]
+    pub fn get_dynamic_payloads_bin(&self) -> u8 {
+        self.inner.dynamic_payloads_bin()
+    }
+
+    /// Enable or disable dynamically sized payloads on a per-pipe basis.
+    ///
+    /// The given value (in binary form) is used to control the feature for each pipe,
+    /// mirroring {@link RadioConfig.autoAck}'s bitmask convention: bit 0 controls pipe 0,
+    /// bit 1 controls pipe 1, and so on.
+    #[napi(setter, js_name = "dynamicPayloadsBin")]
+    pub fn set_dynamic_payloads_bin(&mut self, value: u8) {
+        self.inner = self.inner.with_dynamic_payloads_bin(value);
+    }
+
     #[napi(getter, js_name = "ackPayloads")]
     pub fn get_ack_payloads(&self) -> bool {
         self.inner.ack_payloads()
@@ -245,6 +260,25 @@ impl RadioConfig {
         Ok(())
     }
 
+    #[napi(getter, js_name = "ackPayloadsBin")]
+    pub fn get_ack_payloads_bin(&self) -> u8 {
+        self.inner.ack_payloads_bin()
+    }
+
+    /// Enable or disable custom ACK payloads on a per-pipe basis.
+    ///
+    /// The given value (in binary form) is used to control the feature for each pipe,
+    /// mirroring {@link RadioConfig.autoAck}'s bitmask convention: bit 0 controls pipe 0,
+    /// bit 1 controls pipe 1, and so on.
+    ///
+    /// Any pipe enabled here also has {@link RadioConfig.autoAck} and
+    /// {@link RadioConfig.dynamicPayloadsBin} enabled for that pipe, since ACK payloads
+    /// require both.
+    #[napi(setter, js_name = "ackPayloadsBin")]
+    pub fn set_ack_payloads_bin(&mut self, value: u8) {
+        self.inner = self.inner.with_ack_payloads_bin(value);
+    }
+
     #[napi(getter, js_name = "askNoAck")]
     pub fn get_ask_no_ack(&self) -> bool {
         self.inner.ask_no_ack()
@@ -348,6 +382,21 @@ impl RadioConfig {
         Buffer::from(self.addr_buf.to_vec())
     }
 
+    /// Get the static payload length that a specified RX `pipe` (0 - 5) will use,
+    /// falling back to {@link RadioConfig.payloadLength} if `pipe` has no value of its
+    /// own set via {@link RadioConfig.setPipePayloadLength}.
+    #[napi]
+    pub fn get_pipe_payload_length(&self, pipe: u8) -> u8 {
+        self.inner.pipe_payload_length(pipe)
+    }
+
+    /// Set a static payload length for a specified RX `pipe` (0 - 5), overriding
+    /// {@link RadioConfig.payloadLength} on that pipe only.
+    #[napi]
+    pub fn set_pipe_payload_length(&mut self, pipe: u8, value: u8) {
+        self.inner = self.inner.with_pipe_payload_length(pipe, value);
+    }
+
     /// Set the TX address.
     ///
     /// Only pipe 0 can be used for TX operations (including auto-ACK packets during RX operations).
@@ -371,6 +420,17 @@ impl RadioConfig {
     pub fn close_rx_pipe(&mut self, pipe: u8) {
         self.inner = self.inner.close_rx_pipe(pipe);
     }
+
+    /// Check this configuration for illegal combinations that this class's setters do
+    /// not themselves reject, before pushing it to hardware.
+    ///
+    /// Throws an error describing the conflict if one is found.
+    #[napi]
+    pub fn validate(&self) -> Result<()> {
+        self.inner
+            .validate()
+            .map_err(|err| napi::Error::from_reason(format!("{err:?}")))
+    }
 }
 
 impl RadioConfig {
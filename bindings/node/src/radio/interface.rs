@@ -1,9 +1,13 @@
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use super::config::RadioConfig;
+use super::irq::{self, EventCallbacks, IrqEvent};
+use super::shared_bus::SpiBackend;
 use super::types::{
-    coerce_to_bool, AvailablePipe, CrcLength, DataRate, FifoState, HardwareConfig, PaLevel,
-    StatusFlags, WriteConfig,
+    coerce_to_bool, AutoRetryConfig, AvailablePipe, CrcLength, DataRate, FallbackMode, FifoState,
+    HardwareConfig, PaLevel, RadioDetails, RadioState, RxFrame, SpiTransaction, StatusFlags,
+    WriteConfig,
 };
 
 use embedded_hal::{delay::DelayNs, digital::OutputPin};
@@ -15,11 +19,15 @@ use linux_embedded_hal::{
 use nix::sys::time::TimeSpec;
 use nix::time::{clock_nanosleep, ClockId, ClockNanosleepFlags};
 
-use napi::{bindgen_prelude::Buffer, Error, JsNumber, Result, Status};
+use napi::{
+    bindgen_prelude::Buffer,
+    threadsafe_function::{ErrorStrategy, ThreadsafeFunction},
+    Error, JsNumber, Result, Status,
+};
 
 use rf24::radio::prelude::*;
 
-struct Delay;
+pub(crate) struct Delay;
 
 impl DelayNs for Delay {
     fn delay_ns(&mut self, ns: u32) {
@@ -32,11 +40,196 @@ impl DelayNs for Delay {
     }
 }
 
+/// Open the `/dev/gpiochip{dev_gpio_chip}` device.
+pub(crate) fn open_gpio_chip(dev_gpio_chip: u8) -> Result<linux_embedded_hal::gpio_cdev::Chip> {
+    chips()
+        .map_err(|_| {
+            Error::new(
+                Status::GenericFailure,
+                "Failed to get list of GPIO chips for the system",
+            )
+        })?
+        .find(|chip| {
+            if let Ok(chip) = chip {
+                if chip
+                    .path()
+                    .to_string_lossy()
+                    .ends_with(&dev_gpio_chip.to_string())
+                {
+                    return true;
+                }
+            }
+            false
+        })
+        .ok_or(Error::new(
+            Status::InvalidArg,
+            format!("Could not find specified dev/gpiochip{dev_gpio_chip} for this system."),
+        ))?
+        .map_err(|e| {
+            Error::new(
+                Status::InvalidArg,
+                format!("Could not open GPIO chip dev/gpiochip{dev_gpio_chip}: {e:?}"),
+            )
+        })
+}
+
+/// Open the SPI device for the given bus/CS pin numbers.
+///
+/// This is shared between [`RF24::new`] and `AsyncRF24::new` (and the IRQ worker thread
+/// spawned by [`RF24::new`] when a `irqPin` is configured) so that the hardware
+/// acquisition logic (and its error messages) only needs to be maintained in one place.
+pub(crate) fn open_spi(cs_pin: u8, dev_spi_bus: u8, spi_speed: u32) -> Result<SpidevDevice> {
+    let mut spi = SpidevDevice::open(format!("/dev/spidev{dev_spi_bus}.{cs_pin}")).map_err(
+        |_| {
+            Error::new(Status::InvalidArg, format!(
+                "SPI bus {dev_spi_bus} with CS pin option {cs_pin} is not available in this system"
+            )
+        )
+        },
+    )?;
+    let config = SpidevOptions::new()
+        .max_speed_hz(spi_speed)
+        .mode(SpiModeFlags::SPI_MODE_0)
+        .bits_per_word(8)
+        .build();
+    spi.configure(&config)
+        .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+    Ok(spi)
+}
+
+/// Request `line` from `dev_gpio` as an output pin initialized to `default_value`.
+///
+/// This is shared between [`open_hardware`] and [`SpiBus::open`](super::shared_bus::SpiBus::open)
+/// since both need to turn a raw GPIO line number into a usable [`CdevPin`].
+pub(crate) fn request_output_pin(
+    dev_gpio: &mut linux_embedded_hal::gpio_cdev::Chip,
+    line: u32,
+    default_value: u8,
+) -> Result<CdevPin> {
+    let gpio_line = dev_gpio.get_line(line).map_err(|e| {
+        Error::new(
+            Status::InvalidArg,
+            format!("GPIO{line} is unavailable: {e:?}"),
+        )
+    })?;
+    let gpio_line_handle = gpio_line
+        .request(LineRequestFlags::OUTPUT, default_value, "rf24-rs")
+        .map_err(|e| {
+            Error::new(
+                Status::InvalidArg,
+                format!("GPIO{line} is already in use: {e:?}"),
+            )
+        })?;
+    CdevPin::new(gpio_line_handle).map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+}
+
+/// Open the GPIO line and SPI device for the given pin/bus numbers.
+///
+/// Returns the opened CE pin and SPI device, along with the `hardware_config` resolved
+/// to its effective defaults (so callers can also act on fields like `irqPin`).
+pub(crate) fn open_hardware(
+    ce_pin: u32,
+    cs_pin: u8,
+    hardware_config: Option<HardwareConfig>,
+) -> Result<(CdevPin, SpidevDevice, HardwareConfig)> {
+    // convert optional arg to default values
+    let hw_config = hardware_config.unwrap_or_default();
+    let spi_speed = hw_config.spi_speed.unwrap_or(10_000_000);
+    let dev_gpio_chip = hw_config.dev_gpio_chip.unwrap_or_default();
+    let dev_spi_bus = hw_config.dev_spi_bus.unwrap_or_default();
+
+    let mut dev_gpio = open_gpio_chip(dev_gpio_chip)?;
+    let ce_pin = request_output_pin(&mut dev_gpio, ce_pin, 0)?;
+
+    let spi = open_spi(cs_pin, dev_spi_bus, spi_speed)?;
+
+    Ok((
+        ce_pin,
+        spi,
+        HardwareConfig {
+            dev_gpio_chip: Some(dev_gpio_chip),
+            dev_spi_bus: Some(dev_spi_bus),
+            spi_speed: Some(spi_speed),
+            irq_pin: hw_config.irq_pin,
+        },
+    ))
+}
+
+/// The number of frames (and therefore `MAX_MESSAGE_FRAMES * `[`rf24::transport::MAX_FRAME_DATA`]
+/// bytes) a single {@link RF24.sendMessage}/{@link RF24.recvMessage} message can span.
+const MAX_MESSAGE_FRAMES: usize = 64;
+
+/// How long a partially received {@link RF24.recvMessage} message may sit unfinished
+/// before it is discarded, so a lost final fragment (with no retransmission and no
+/// unrelated message arriving afterward to reset it) cannot hold the reassembler's
+/// buffer in a half-filled state forever.
+const MAX_REASSEMBLY_AGE: Duration = Duration::from_secs(5);
+
+/// How many received payloads {@link RF24.drainFifo} buffers before it starts dropping
+/// the oldest one to make room for the newest.
+const RX_RING_CAPACITY: usize = 32;
+
+/// A fixed-capacity, drop-oldest ring of payloads buffered by {@link RF24.drainFifo} and
+/// popped by {@link RF24.readFrames}.
+///
+/// This decouples draining the radio's hardware RX FIFO (3 levels deep) from the rate at
+/// which JS actually consumes payloads, so a burst of traffic doesn't force one napi call
+/// per payload.
+pub(crate) struct RxRing {
+    frames: [(u8, [u8; 32], u8); RX_RING_CAPACITY],
+    head: usize,
+    len: usize,
+}
+
+impl Default for RxRing {
+    fn default() -> Self {
+        Self {
+            frames: [(0u8, [0u8; 32], 0u8); RX_RING_CAPACITY],
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl RxRing {
+    fn push(&mut self, pipe: u8, buf: &[u8]) {
+        let tail = (self.head + self.len) % RX_RING_CAPACITY;
+        let len = buf.len().min(32);
+        self.frames[tail] = (pipe, [0u8; 32], len as u8);
+        self.frames[tail].1[..len].copy_from_slice(&buf[..len]);
+        if self.len < RX_RING_CAPACITY {
+            self.len += 1;
+        } else {
+            // drop the oldest frame to make room for this one
+            self.head = (self.head + 1) % RX_RING_CAPACITY;
+        }
+    }
+
+    fn pop(&mut self) -> Option<(u8, [u8; 32], u8)> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = self.frames[self.head];
+        self.head = (self.head + 1) % RX_RING_CAPACITY;
+        self.len -= 1;
+        Some(item)
+    }
+}
+
 /// This class provides the user facing API to interact with a nRF24L01 transceiver.
 #[napi(js_name = "RF24")]
 pub struct RF24 {
-    inner: rf24::radio::RF24<SpidevDevice, CdevPin, Delay>,
-    read_buf: [u8; 32],
+    pub(crate) inner: rf24::radio::RF24<SpiBackend, CdevPin, Delay>,
+    pub(crate) read_buf: [u8; 32],
+    pub(crate) events: Arc<Mutex<EventCallbacks>>,
+    pub(crate) next_msg_id: u8,
+    pub(crate) msg_reassembler: rf24::transport::Reassembler<MAX_MESSAGE_FRAMES>,
+    pub(crate) msg_reassembly_started_at: Option<Instant>,
+    /// The RX/TX mode captured by {@link RF24.saveCeState}, consumed by
+    /// {@link RF24.restoreCeState}.
+    pub(crate) saved_ce_state: Option<rf24::RadioState>,
+    /// Payloads buffered by {@link RF24.drainFifo}, popped by {@link RF24.readFrames}.
+    pub(crate) rx_ring: RxRing,
 }
 
 #[napi]
@@ -48,85 +241,110 @@ impl RF24 {
     /// also labeled as "CEx" (where "x" is this parameter's value) on many
     /// Raspberry Pi pin diagrams. See {@link HardwareConfig.devSpiBus} for more detail.
     /// @param hardwareConfig - Optional parameters to fine tune hardware configuration
-    /// (like SPI bus number and GPIO chip number).
+    /// (like SPI bus number and GPIO chip number). If {@link HardwareConfig.irqPin} is
+    /// specified, a background thread is started to dispatch events registered via
+    /// {@link RF24.on}.
     ///
     /// @group Basic
     #[napi(constructor)]
     pub fn new(ce_pin: u32, cs_pin: u8, hardware_config: Option<HardwareConfig>) -> Result<Self> {
-        // convert optional arg to default values
-        let hw_config = hardware_config.unwrap_or_default();
-        let spi_speed = hw_config.spi_speed.unwrap_or(10_000_000);
-        let dev_gpio_chip = hw_config.dev_gpio_chip.unwrap_or_default();
-        let dev_spi_bus = hw_config.dev_spi_bus.unwrap_or_default();
-
-        // get the desired "/dev/gpiochip{dev_gpio_chip}"
-        let mut dev_gpio = chips()
-            .map_err(|_| {
-                Error::new(
-                    Status::GenericFailure,
-                    "Failed to get list of GPIO chips for the system",
-                )
-            })?
-            .find(|chip| {
-                if let Ok(chip) = chip {
-                    if chip
-                        .path()
-                        .to_string_lossy()
-                        .ends_with(&dev_gpio_chip.to_string())
-                    {
-                        return true;
-                    }
-                }
-                false
-            })
-            .ok_or(Error::new(
-                Status::InvalidArg,
-                format!("Could not find specified dev/gpiochip{dev_gpio_chip} for this system."),
-            ))?
-            .map_err(|e| {
-                Error::new(
-                    Status::InvalidArg,
-                    format!("Could not open GPIO chip dev/gpiochip{dev_gpio_chip}: {e:?}"),
-                )
-            })?;
-        let ce_line = dev_gpio.get_line(ce_pin).map_err(|e| {
-            Error::new(
-                Status::InvalidArg,
-                format!("GPIO{ce_pin} is unavailable: {e:?}"),
-            )
-        })?;
-        let ce_line_handle = ce_line
-            .request(LineRequestFlags::OUTPUT, 0, "rf24-rs")
-            .map_err(|e| {
-                Error::new(
-                    Status::InvalidArg,
-                    format!("GPIO{ce_pin} is already in use: {e:?}"),
-                )
-            })?;
-        let ce_pin = CdevPin::new(ce_line_handle)
-            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
-
-        let mut spi =
-            SpidevDevice::open(format!("/dev/spidev{dev_spi_bus}.{cs_pin}")).map_err(|_| {
-                Error::new(Status::InvalidArg, format!(
-                    "SPI bus {dev_spi_bus} with CS pin option {cs_pin} is not available in this system"
-                )
-            )
-            })?;
-        let config = SpidevOptions::new()
-            .max_speed_hz(spi_speed)
-            .mode(SpiModeFlags::SPI_MODE_0)
-            .bits_per_word(8)
-            .build();
-        spi.configure(&config)
-            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
-
+        let (ce_pin, spi, hw_config) = open_hardware(ce_pin, cs_pin, hardware_config)?;
+        let events = Arc::<Mutex<EventCallbacks>>::default();
+        if let Some(irq_pin) = hw_config.irq_pin {
+            irq::spawn(irq_pin, cs_pin, &hw_config, events.clone())?;
+        }
         Ok(Self {
-            inner: rf24::radio::RF24::new(ce_pin, spi, Delay),
+            inner: rf24::radio::RF24::new(ce_pin, SpiBackend::Dedicated(spi), Delay),
             read_buf: [0u8; 32],
+            events,
+            next_msg_id: 0,
+            msg_reassembler: rf24::transport::Reassembler::new(),
+            msg_reassembly_started_at: None,
+            saved_ce_state: None,
+            rx_ring: RxRing::default(),
         })
     }
 
+    /// Register a `callback` to be invoked whenever the specified `event` occurs.
+    ///
+    /// This requires {@link HardwareConfig.irqPin} to have been specified when
+    /// constructing this {@link RF24} instance; otherwise, registered callbacks are
+    /// never invoked.
+    ///
+    /// @param event - One of `"dataReady"`, `"dataSent"`, or `"dataFail"`.
+    /// @param callback - Invoked with an {@link IrqEvent} describing the event.
+    /// `pipe` is only set for `"dataReady"`; `arc` is only set for `"dataFail"`.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn on(
+        &mut self,
+        event: String,
+        callback: ThreadsafeFunction<IrqEvent, ErrorStrategy::Fatal>,
+    ) -> Result<()> {
+        let mut events = self
+            .events
+            .lock()
+            .map_err(|_| Error::new(Status::GenericFailure, "IRQ event state was poisoned"))?;
+        match event.as_str() {
+            "dataReady" => events.data_ready = Some(callback),
+            "dataSent" => events.data_sent = Some(callback),
+            "dataFail" => events.data_fail = Some(callback),
+            _ => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "Unknown event {event:?}. Expected \"dataReady\", \"dataSent\", or \"dataFail\"."
+                    ),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove a previously registered callback for `event`, if any.
+    ///
+    /// This is the inverse of {@link RF24.on}. Calling it for an `event` with no
+    /// registered callback is a no-op.
+    ///
+    /// @param event - One of `"dataReady"`, `"dataSent"`, or `"dataFail"`.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn off(&mut self, event: String) -> Result<()> {
+        let mut events = self
+            .events
+            .lock()
+            .map_err(|_| Error::new(Status::GenericFailure, "IRQ event state was poisoned"))?;
+        match event.as_str() {
+            "dataReady" => events.data_ready = None,
+            "dataSent" => events.data_sent = None,
+            "dataFail" => events.data_fail = None,
+            _ => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "Unknown event {event:?}. Expected \"dataReady\", \"dataSent\", or \"dataFail\"."
+                    ),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove every callback registered via {@link RF24.on}, regardless of event.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn remove_all_listeners(&mut self) -> Result<()> {
+        let mut events = self
+            .events
+            .lock()
+            .map_err(|_| Error::new(Status::GenericFailure, "IRQ event state was poisoned"))?;
+        *events = EventCallbacks::default();
+        Ok(())
+    }
+
     /// Initialize the radio on the configured hardware (as specified to {@link RF24} constructor).
     ///
     /// @throws A Generic Error if a hardware failure caused problems
@@ -146,6 +364,21 @@ impl RF24 {
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Verify the radio is actually responding on the SPI bus.
+    ///
+    /// This writes a probe pattern to the `SETUP_AW` register and reads it back,
+    /// restoring the register's original value afterward. Unlike {@link RF24.begin},
+    /// this does not reconfigure the radio, so it is safe to call at any time (e.g.
+    /// from a watchdog) to confirm the transceiver is still wired and powered.
+    ///
+    /// @group Basic
+    #[napi]
+    pub fn is_chip_connected(&mut self) -> Result<bool> {
+        self.inner
+            .is_chip_connected()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
     /// Reconfigure the radio with the specified `config`.
     ///
     /// > [!WARNING]
@@ -163,6 +396,22 @@ impl RF24 {
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Reconstruct a {@link RadioConfig} from the radio's current register state.
+    ///
+    /// This is the inverse of {@link RF24.withConfig} and is useful for verifying
+    /// that a prior {@link RF24.withConfig} call actually took effect, detecting SPI
+    /// wiring faults (a returned config of all `0`s or all `0xFF`s), or snapshotting a
+    /// pre-configured radio's settings to reapply later.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn get_config(&mut self) -> Result<RadioConfig> {
+        self.inner
+            .get_config()
+            .map(RadioConfig::from_inner)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
     /// Set the radio's CE pin HIGH (`true`) or LOW (`false`).
     ///
     /// This is only exposed for advanced use of TX FIFO during
@@ -266,7 +515,8 @@ impl RF24 {
     /// {@link RF24.getStatusFlags}
     /// to determine if transmission was successful.
     ///
-    /// @param buf - The buffer of bytes to load into the TX FIFO.
+    /// @param buf - The buffer of bytes to load into the TX FIFO. Ignored if
+    /// {@link WriteConfig.reuseTx} is `true`.
     ///
     /// @returns A Boolean that describes if the given `buf` was successfully loaded
     /// into the TX FIFO. Remember, the TX FIFO has only 3 levels ("slots").
@@ -274,14 +524,20 @@ impl RF24 {
     /// @group Advanced
     #[napi]
     pub fn write(&mut self, buf: Buffer, write_config: Option<WriteConfig>) -> Result<bool> {
-        let buf = buf.to_vec();
         let options = write_config.unwrap_or_default();
+        if options.reuse_tx.unwrap_or_default() {
+            return self
+                .inner
+                .rewrite()
+                .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")));
+        }
+        let buf = buf.to_vec();
+        let ask_no_ack = options
+            .ask_no_ack
+            .or(options.multicast)
+            .unwrap_or_default();
         self.inner
-            .write(
-                &buf,
-                options.ask_no_ack.unwrap_or_default(),
-                options.start_tx.unwrap_or(true),
-            )
+            .write(&buf, ask_no_ack, options.start_tx.unwrap_or(true))
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
@@ -312,11 +568,19 @@ impl RF24 {
     /// This is similar to {@link RF24.send} but specifically for
     /// failed transmissions.
     ///
+    /// @param sendOnly - A flag to leave any ACK payload sitting in the RX FIFO
+    /// instead of flushing it after a successful resend. Use {@link RF24.read}
+    /// to fetch that ACK payload. Defaults to `false`.
+    ///
     /// @group Basic
     #[napi]
-    pub fn resend(&mut self) -> Result<bool> {
+    pub fn resend(
+        &mut self,
+        #[napi(ts_arg_type = "boolean | number")] send_only: Option<JsNumber>,
+    ) -> Result<bool> {
+        let send_only = coerce_to_bool(send_only, false)?;
         self.inner
-            .resend()
+            .resend(send_only)
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
@@ -327,14 +591,30 @@ impl RF24 {
     /// {@link RF24.getStatusFlags} to determine if
     /// retransmission was successful.
     ///
+    /// @returns `false` (without doing anything else) if the TX FIFO is empty,
+    /// since there is no payload to reuse in that case.
+    ///
     /// @group Advanced
     #[napi]
-    pub fn rewrite(&mut self) -> Result<()> {
+    pub fn rewrite(&mut self) -> Result<bool> {
         self.inner
             .rewrite()
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Issue the `REUSE_TX_PL` command directly, without waiting for the result.
+    ///
+    /// This is an alias of {@link RF24.rewrite} (named after the underlying SPI command)
+    /// for callers coming from the reference C++ driver's API. The reused payload stays
+    /// at the top of the TX FIFO until {@link RF24.flushTx} is called or a normal
+    /// {@link RF24.send}/{@link RF24.write} overwrites it.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn reuse_tx_payload(&mut self) -> Result<bool> {
+        self.rewrite()
+    }
+
     /// Get the Automatic Retry Count (ARC) of attempts made during the last transmission.
     ///
     /// This resets with every new transmission. The returned value is meaningless if the
@@ -351,6 +631,45 @@ impl RF24 {
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Get the count of lost packets (PLOS) since the last time the radio's channel was set.
+    ///
+    /// This counter is saturated at 15; it does not overflow/reset on its own. Setting the
+    /// channel (via {@link RF24.channel}) resets it back to `0`, so this value is only
+    /// meaningful relative to the currently configured channel.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn get_lost_packets(&mut self) -> Result<u8> {
+        self.inner
+            .get_lost_packets()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
+    /// Nudge the auto-retry delay and count (see {@link RF24.setAutoRetries}) based on how
+    /// the most recent transmission actually went, instead of committing to one static
+    /// retry profile for the whole session.
+    ///
+    /// Call this after a {@link RF24.send} or {@link RF24.write} attempt.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn adapt_auto_retries(&mut self) -> Result<()> {
+        self.inner
+            .adapt_auto_retries()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
+    /// Get the radio's coarse operating state, as tracked by this instance.
+    ///
+    /// This reflects the local cache of the `CONFIG` register and the CE pin's last
+    /// known level; it does not perform any SPI transactions.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn get_state(&self) -> RadioState {
+        RadioState::from_inner(self.inner.get_state())
+    }
+
     /// Is this radio a nRF24L01+ variant?
     ///
     /// The bool that this attribute returns is only valid _after_ calling
@@ -423,6 +742,196 @@ impl RF24 {
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Alias of {@link RF24.startCarrierWave}, named after the feature it exercises
+    /// (the `CONT_WAVE` bit) for callers coming from the reference C++ driver's API.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn start_constant_carrier(&mut self, level: PaLevel, channel: u8) -> Result<()> {
+        self.start_carrier_wave(level, channel)
+    }
+
+    /// Alias of {@link RF24.stopCarrierWave}.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn stop_constant_carrier(&mut self) -> Result<()> {
+        self.stop_carrier_wave()
+    }
+
+    /// Survey the given channel range for ambient RF activity.
+    ///
+    /// For each channel in `[startChannel, endChannel]` (inclusive), the radio is tuned to
+    /// that channel and put into RX mode, then the Received Power Detector is sampled
+    /// `samplesPerChannel` times (see {@link RF24.rpd}). The radio's prior channel and
+    /// RX/TX mode are restored before returning.
+    ///
+    /// @param startChannel - The first channel (inclusive) to survey.
+    /// @param endChannel - The last channel (inclusive) to survey.
+    /// @param samplesPerChannel - The number of times to sample the RPD flag per channel.
+    ///
+    /// @returns A buffer of hit counts, one per channel, indexed by the channel's offset
+    /// from `startChannel`.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn scan_channels(
+        &mut self,
+        start_channel: u8,
+        end_channel: u8,
+        samples_per_channel: u8,
+    ) -> Result<Buffer> {
+        if start_channel > end_channel {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!(
+                    "startChannel ({start_channel}) must not be greater than endChannel ({end_channel})"
+                ),
+            ));
+        }
+        let prior_channel = self
+            .inner
+            .get_channel()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+        let mut hits = Vec::with_capacity(end_channel as usize - start_channel as usize + 1);
+        for channel in start_channel..=end_channel {
+            let [count] = self
+                .inner
+                .scan_channels(&[channel], samples_per_channel)
+                .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+            hits.push(count);
+        }
+        self.inner
+            .set_channel(prior_channel)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+        Ok(Buffer::from(hits))
+    }
+
+    /// Survey an explicit (not necessarily contiguous) list of channels for ambient
+    /// RF activity.
+    ///
+    /// This is the same survey {@link RF24.scanChannels} performs, but for an arbitrary
+    /// `channels` list instead of a contiguous range — useful for re-checking only the
+    /// channels a prior scan flagged as busy.
+    ///
+    /// @param channels - The channels to survey, in the order they should be sampled.
+    /// @param samplesPerChannel - The number of times to sample the RPD flag per channel.
+    ///
+    /// @returns A buffer of hit counts, one per channel, in the same order as `channels`.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn scan_channel_list(
+        &mut self,
+        channels: Vec<u8>,
+        samples_per_channel: u8,
+    ) -> Result<Buffer> {
+        let mut hits = Vec::with_capacity(channels.len());
+        for channel in channels {
+            let [count] = self
+                .inner
+                .scan_channels(&[channel], samples_per_channel)
+                .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+            hits.push(count);
+        }
+        Ok(Buffer::from(hits))
+    }
+
+    /// Find the quietest channel among `channels`, i.e. whichever has the fewest RPD
+    /// hits over `samplesPerChannel` samples.
+    ///
+    /// This is a convenience wrapper around {@link RF24.scanChannelList} for picking a
+    /// low-noise operating frequency at startup instead of hand-rolling the scan and
+    /// comparing its histogram. If multiple channels tie for the fewest hits, the
+    /// first (lowest-indexed) one is returned.
+    ///
+    /// @param channels - The channels to survey, in the order they should be sampled.
+    /// @param samplesPerChannel - The number of times to sample the RPD flag per channel.
+    ///
+    /// @returns The quietest channel found.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn find_clear_channel(&mut self, channels: Vec<u8>, samples_per_channel: u8) -> Result<u8> {
+        let mut quietest = (channels.first().copied().unwrap_or(0), u8::MAX);
+        for channel in channels {
+            let [count] = self
+                .inner
+                .scan_channels(&[channel], samples_per_channel)
+                .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+            if count < quietest.1 {
+                quietest = (channel, count);
+            }
+        }
+        Ok(quietest.0)
+    }
+
+    /// Read `len` bytes from a register, bypassing the driver's cached shadow state.
+    ///
+    /// This is a low-level diagnostic primitive: it performs a single SPI transaction
+    /// and does not consult or update any of this driver's cached shadow state. Useful
+    /// for dumping the full register map for a bug report or driving undocumented
+    /// clone-chip features the typed API does not cover.
+    ///
+    /// @param address - The register's address.
+    /// @param len - The number of bytes to read (at most 32).
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn read_register(&mut self, address: u8, len: u8) -> Result<SpiTransaction> {
+        let mut buf = vec![0u8; len as usize];
+        let status = self
+            .inner
+            .read_register(address, &mut buf)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+        Ok(SpiTransaction {
+            status,
+            buf: Buffer::from(buf),
+        })
+    }
+
+    /// Write `buf` to a register, bypassing the driver's cached shadow state.
+    ///
+    /// Unlike the typed setters elsewhere in this class, this does not keep any of this
+    /// class' cached state in sync with the register written. See {@link RF24.readRegister}
+    /// for the rationale behind exposing this.
+    ///
+    /// @param address - The register's address.
+    /// @param buf - The bytes to write.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn write_register(&mut self, address: u8, buf: Buffer) -> Result<u8> {
+        self.inner
+            .write_register(address, &buf)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
+    /// Perform a single raw SPI transaction: write `command` followed by `buf`, then
+    /// return the bytes shifted back in over MISO.
+    ///
+    /// Unlike {@link RF24.readRegister} and {@link RF24.writeRegister}, `command` is
+    /// sent as-is (it is not combined with `W_REGISTER`/`R_REGISTER`), so this can drive
+    /// any SPI command the nRF24L01 (or a clone chip) supports, documented or not.
+    ///
+    /// @param command - The command byte to send.
+    /// @param buf - The bytes to send after `command`. Pass an empty buffer for
+    /// commands (like `NOP`) that take no arguments.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn spi_command(&mut self, command: u8, buf: Buffer) -> Result<SpiTransaction> {
+        let mut buf = buf.to_vec();
+        let status = self
+            .inner
+            .spi_command(command, &mut buf)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+        Ok(SpiTransaction {
+            status,
+            buf: Buffer::from(buf),
+        })
+    }
+
     /// Enable or disable the LNA feature.
     ///
     /// This is enabled by default (regardless of chip variant).
@@ -496,6 +1005,23 @@ impl RF24 {
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Set the auto-ack feature for all pipes (0-5) in a single SPI transaction.
+    ///
+    /// > [!NOTE]
+    /// > This feature requires CRC to be enabled.
+    /// > See {@link RF24.crcLength} for more detail.
+    ///
+    /// @param mask - A bitmask in which bits 0-5 map to pipes 0-5; a set bit enables
+    /// auto-ack for that pipe.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn set_auto_ack_bin(&mut self, mask: u8) -> Result<()> {
+        self.inner
+            .set_auto_ack_bin(mask)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
     /// Allow disabling the auto-ack feature for individual payloads.
     ///
     /// @param enable - Setting this to `true` will allow the `askNoAck` parameter to
@@ -540,20 +1066,52 @@ impl RF24 {
     /// This feature is part of the auto-ack feature, thus the auto-ack feature is
     /// required for this function to have any effect.
     ///
-    /// @param delay - This value is clamped to the range [0, 15]. This value is
-    /// translated to microseconds with the formula `250 + (delay * 250) = microseconds`.
-    /// Meaning, the effective range of `delay` is [250, 4000].
-    /// @param count - The number of attempt to retransmit when no ACK packet was
-    /// received (after transmitting). This value is clamped to the range [0, 15].
+    /// {@link AutoRetryConfig.delay} is validated against the currently configured
+    /// {@link RF24.dataRate} and {@link RF24.crcLength}/{@link RF24.payloadLength}:
+    /// if the requested delay is too short to receive an ACK packet (e.g. at 250 kbps
+    /// with auto-ACK and a full 32-byte ACK payload, which needs at least 1500 us), it
+    /// is clamped upward to the smallest delay that is safe for the current configuration.
     ///
     /// @group Configuration
     #[napi]
-    pub fn set_auto_retries(&mut self, delay: u8, count: u8) -> Result<()> {
+    pub fn set_auto_retry(&mut self, config: AutoRetryConfig) -> Result<()> {
+        let data_rate = self
+            .inner
+            .get_data_rate()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+        let crc_length = self
+            .inner
+            .get_crc_length()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+        let payload_length = self
+            .inner
+            .get_payload_length()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+        let min_delay_us = min_auto_retry_delay_us(data_rate, crc_length, payload_length);
+        let requested_us = 250u32 + config.delay.min(15) as u32 * 250;
+        let delay = if requested_us < min_delay_us {
+            (min_delay_us.saturating_sub(250)).div_ceil(250).min(15) as u8
+        } else {
+            config.delay.min(15)
+        };
         self.inner
-            .set_auto_retries(delay, count)
+            .set_auto_retries(delay, config.count)
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Get the currently configured auto-retry `delay` and `count` (see
+    /// {@link RF24.setAutoRetry}).
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn get_auto_retry(&mut self) -> Result<AutoRetryConfig> {
+        let (delay, count) = self
+            .inner
+            .get_auto_retries()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+        Ok(AutoRetryConfig { delay, count })
+    }
+
     /// Set the channel (frequency) that the radio uses to transmit and receive.
     ///
     /// @param channel - This value is clamped to the range [0, 125].
@@ -639,6 +1197,10 @@ impl RF24 {
     /// Similar to {@link RF24.available} but also returns the
     /// pipe that received the next available payload.
     ///
+    /// The radio's `STATUS` register uses a pipe number of 7 as a sentinel for "RX FIFO
+    /// empty". `available` is `false` (and `pipe` is meaningless) whenever that sentinel
+    /// is observed, even if an earlier register read suggested a payload was waiting.
+    ///
     /// @group Basic
     #[napi]
     pub fn available_pipe(&mut self) -> Result<AvailablePipe> {
@@ -653,6 +1215,60 @@ impl RF24 {
         })
     }
 
+    /// Drain every payload currently in the radio's RX FIFO into an internal ring
+    /// buffer, tagging each with the pipe it arrived on.
+    ///
+    /// This lets a single napi call collect a whole burst of traffic instead of
+    /// crossing the napi boundary once per payload with {@link RF24.read}. Call this
+    /// from a `"dataReady"` callback registered via {@link RF24.on} (or just poll it
+    /// periodically), then retrieve the buffered payloads with {@link RF24.readFrames}.
+    ///
+    /// The ring buffer holds at most 32 payloads; if it is already full, the oldest
+    /// buffered payload is dropped to make room for the newest one drained here.
+    ///
+    /// Returns the number of payloads drained from the RX FIFO (which may exceed the
+    /// number actually retained, if the ring buffer overflowed).
+    ///
+    /// @group Basic
+    #[napi]
+    pub fn drain_fifo(&mut self) -> Result<u32> {
+        let mut drained = 0u32;
+        while self
+            .inner
+            .available()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?
+        {
+            let (len, pipe) = self
+                .inner
+                .read_with_pipe(&mut self.read_buf, None)
+                .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+            self.rx_ring.push(pipe, &self.read_buf[0..len as usize]);
+            drained += 1;
+        }
+        Ok(drained)
+    }
+
+    /// Pop up to `max` payloads buffered by {@link RF24.drainFifo}, oldest first.
+    ///
+    /// If `max` is not specified, every currently buffered payload is returned.
+    ///
+    /// @group Basic
+    #[napi]
+    pub fn read_frames(&mut self, max: Option<u32>) -> Vec<RxFrame> {
+        let max = max.unwrap_or(u32::MAX);
+        let mut frames = Vec::new();
+        while (frames.len() as u32) < max {
+            let Some((pipe, buf, len)) = self.rx_ring.pop() else {
+                break;
+            };
+            frames.push(RxFrame {
+                pipe,
+                payload: Buffer::from(buf[0..len as usize].to_vec()),
+            });
+        }
+        frames
+    }
+
     /// Discard all 3 levels of the radio's RX FIFO.
     ///
     /// @group Advanced
@@ -715,6 +1331,37 @@ impl RF24 {
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Whether the Si24R1 clone LNA (Low Noise Amplifier) gain bit (`RF_SETUP` bit 0)
+    /// is asserted.
+    ///
+    /// On genuine nRF24L01(+) silicon this bit is reserved and has no effect.
+    ///
+    /// @group Configuration
+    #[napi(getter, js_name = "lnaEnabled")]
+    pub fn get_lna_enabled(&mut self) -> Result<bool> {
+        self.inner
+            .get_pa_level_lna()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+            .map(|(_, lna)| lna)
+    }
+
+    /// Set the PA level alongside the Si24R1 clone LNA (Low Noise Amplifier) gain bit.
+    ///
+    /// On genuine nRF24L01(+) silicon the LNA bit is reserved and `lnaEnable` has no
+    /// effect; on Si24R1 clones, disabling it shifts the actual dBm output at every
+    /// PA step, so set it to match the module actually in use.
+    ///
+    /// @param paLevel - The {@link PaLevel} to use.
+    /// @param lnaEnable - Whether to assert the Si24R1 LNA gain bit.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn set_pa_level_lna(&mut self, pa_level: PaLevel, lna_enable: bool) -> Result<()> {
+        self.inner
+            .set_pa_level_lna(pa_level.into_inner(), lna_enable)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
     /// Get/set the statically sized payload length.
     ///
     /// This configuration is not used if dynamic payloads are enabled.
@@ -760,6 +1407,42 @@ impl RF24 {
         self.inner.get_dynamic_payloads()
     }
 
+    /// Enable or disable the dynamically sized payloads feature for a single pipe,
+    /// leaving the other pipes' settings untouched.
+    ///
+    /// Unlike {@link RF24.dynamicPayloads}, this allows mixing a dynamic-length pipe
+    /// with statically sized pipes on the same radio.
+    ///
+    /// @param enable - If set to `true`, `pipe` uses dynamically sized payloads.
+    /// @param pipe - The pipe number to configure. This must be in range [0, 5],
+    /// otherwise this function does nothing.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn set_dynamic_payload_pipe(
+        &mut self,
+        #[napi(ts_arg_type = "boolean | number")] enable: JsNumber,
+        pipe: u8,
+    ) -> Result<()> {
+        self.inner
+            .set_dynamic_payload_pipe(coerce_to_bool(Some(enable), false)?, pipe)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
+    /// Set the dynamically sized payloads feature for all pipes (0-5) in a single SPI
+    /// transaction.
+    ///
+    /// @param mask - A bitmask in which bits 0-5 map to pipes 0-5; a set bit enables
+    /// dynamically sized payloads for that pipe.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn set_dynamic_payloads_bin(&mut self, mask: u8) -> Result<()> {
+        self.inner
+            .set_dynamic_payloads_bin(mask)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
     /// Get the length of the next available payload in the RX FIFO.
     ///
     /// If dynamically sized payloads are not enabled (via
@@ -880,6 +1563,82 @@ impl RF24 {
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Capture the radio's current RX/TX mode for later restoration.
+    ///
+    /// Call this before dropping the CE pin low (eg. via {@link RF24.cePin}) and
+    /// {@link RF24.powerDown} ahead of a host/microcontroller sleep cycle. Pairs with
+    /// {@link RF24.restoreCeState}, which resumes exactly this mode after waking,
+    /// without re-running {@link RF24.asRx} or {@link RF24.asTx}.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn save_ce_state(&mut self) -> RadioState {
+        let state = self.inner.get_state();
+        self.saved_ce_state = Some(state);
+        RadioState::from_inner(state)
+    }
+
+    /// Restore the RX/TX mode captured by {@link RF24.saveCeState}.
+    ///
+    /// If {@link RF24.saveCeState} was never called (or its captured state was already
+    /// consumed by a prior call to this function), this does nothing.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn restore_ce_state(&mut self) -> Result<()> {
+        let Some(state) = self.saved_ce_state.take() else {
+            return Ok(());
+        };
+        match state {
+            rf24::RadioState::RxMode => self.as_rx(),
+            rf24::RadioState::TxMode => self.as_standby_ii(),
+            _ => self.as_standby_i(),
+        }
+    }
+
+    /// Explicitly settle the radio in Standby-I (CE inactive).
+    ///
+    /// This is the lowest standby current draw available while still powered up.
+    /// Re-entering TX or RX mode from here pays the usual CE settling time.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn as_standby_i(&mut self) -> Result<()> {
+        self.inner
+            .as_standby_i()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
+    /// Explicitly settle the radio in Standby-II (CE active, TX FIFO empty).
+    ///
+    /// This allows sub-millisecond re-transmit latency, at a slightly higher standby
+    /// current draw than Standby-I.
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn as_standby_ii(&mut self) -> Result<()> {
+        self.inner
+            .as_standby_ii()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
+    /// The idle state that {@link RF24.send} settles the radio into after a
+    /// transmission completes.
+    ///
+    /// @defaultValue {@link FallbackMode.StandbyI}
+    ///
+    /// @group Configuration
+    #[napi(setter, js_name = "fallbackMode")]
+    pub fn set_fallback_mode(&mut self, mode: FallbackMode) {
+        self.inner.set_fallback_mode(mode.into_inner());
+    }
+
+    /// @group Configuration
+    #[napi(getter, js_name = "fallbackMode")]
+    pub fn get_fallback_mode(&self) -> FallbackMode {
+        FallbackMode::from_inner(self.inner.get_fallback_mode())
+    }
+
     /// @group Configuration
     #[napi(getter, js_name = "txDelay")]
     pub fn get_tx_delay(&self) -> u32 {
@@ -927,6 +1686,40 @@ impl RF24 {
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
 
+    /// Configure the IRQ pin to reflect the given events, using the same positional
+    /// parameters as TMRh20's RF24 library.
+    ///
+    /// This is an alias of {@link RF24.setStatusFlags} for users more familiar with the
+    /// original C++ API's naming.
+    ///
+    /// @param txOk - Whether a successful transmission (`"dataSent"`) asserts the IRQ pin.
+    /// @param txFail - Whether a failed transmission (`"dataFail"`) asserts the IRQ pin.
+    /// @param rxReady - Whether a received payload (`"dataReady"`) asserts the IRQ pin.
+    ///
+    /// @group Configuration
+    #[napi(js_name = "maskIRQ")]
+    pub fn mask_irq(&mut self, tx_ok: bool, tx_fail: bool, rx_ready: bool) -> Result<()> {
+        self.set_status_flags(Some(StatusFlags {
+            rx_dr: Some(rx_ready),
+            tx_ds: Some(tx_ok),
+            tx_df: Some(tx_fail),
+        }))
+    }
+
+    /// Get the {@link StatusFlags} that are currently configured to assert the IRQ pin.
+    ///
+    /// This is the inverse of {@link RF24.setStatusFlags}: a `true` member of the
+    /// returned flags means that event is enabled and will trigger the IRQ pin.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn get_masked_flags(&mut self) -> Result<StatusFlags> {
+        self.inner
+            .get_masked_flags()
+            .map(StatusFlags::from_inner)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
     /// Reset the specified {@link StatusFlags}.
     ///
     /// @param flags - If no value is given, then all flags are reset.
@@ -982,4 +1775,144 @@ impl RF24 {
             .print_details()
             .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
     }
+
+    /// Get the radio's current configuration as a structured object.
+    ///
+    /// Unlike {@link RF24.printDetails}, this does not print anything; it is meant
+    /// for logging, GUIs, or automated diagnostics (e.g. asserting radio configuration
+    /// in tests or serializing to JSON).
+    ///
+    /// @group Configuration
+    #[napi]
+    pub fn get_details(&mut self) -> Result<RadioDetails> {
+        self.inner
+            .get_details()
+            .map(RadioDetails::from_inner)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))
+    }
+
+    /// Send an arbitrarily sized `buf`, transparently splitting it into multiple
+    /// payloads and reassembling it on the receiving end with {@link RF24.recvMessage}.
+    ///
+    /// Each frame is sent with {@link RF24.send} (retrying once via
+    /// {@link RF24.resend} on a failed ACK); sending stops at the first frame that
+    /// still fails afterward, so a `false` result means the peer received a partial
+    /// message and should discard it.
+    ///
+    /// @param buf - The message to send. This is capped at `64 * 29` (1856) bytes.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn send_message(&mut self, buf: Buffer) -> Result<bool> {
+        let msg_id = self.next_msg_id;
+        self.next_msg_id = self.next_msg_id.wrapping_add(1);
+        let fragmenter = rf24::transport::Fragmenter::<MAX_MESSAGE_FRAMES>::new(msg_id, &buf)
+            .ok_or_else(|| {
+                Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "Message of {} bytes exceeds the {} byte limit",
+                        buf.len(),
+                        MAX_MESSAGE_FRAMES * rf24::transport::MAX_FRAME_DATA
+                    ),
+                )
+            })?;
+        for (frame, len) in fragmenter {
+            let mut ok = self
+                .inner
+                .send(&frame[..len], false)
+                .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+            if !ok {
+                ok = self
+                    .inner
+                    .resend(true)
+                    .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+            }
+            if !ok {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Block (up to `timeoutMs`) until a full message sent via {@link RF24.sendMessage}
+    /// has been reassembled, or the timeout elapses.
+    ///
+    /// Reassembly state persists across calls, so frames that trickle in after a
+    /// timeout are still counted when this is called again. However, a message that sits
+    /// unfinished for longer than [`MAX_REASSEMBLY_AGE`] is discarded (e.g. because its
+    /// final fragment was lost and never retransmitted), so a stalled sender cannot wedge
+    /// future calls to this method.
+    ///
+    /// @param timeoutMs - The maximum number of milliseconds to wait for a complete
+    /// message.
+    ///
+    /// @returns The reassembled message, or `null` if `timeoutMs` elapsed first.
+    ///
+    /// @group Advanced
+    #[napi]
+    pub fn recv_message(&mut self, timeout_ms: u32) -> Result<Option<Buffer>> {
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms as u64);
+        let mut out = [0u8; MAX_MESSAGE_FRAMES * rf24::transport::MAX_FRAME_DATA];
+        loop {
+            if self
+                .inner
+                .available()
+                .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?
+            {
+                if self.msg_reassembler.is_empty() {
+                    self.msg_reassembly_started_at = Some(Instant::now());
+                }
+                let len = self
+                    .inner
+                    .read(&mut self.read_buf, None)
+                    .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+                if let Some(msg_len) = self
+                    .msg_reassembler
+                    .receive_frame(&self.read_buf[..len as usize], &mut out)
+                {
+                    self.msg_reassembly_started_at = None;
+                    return Ok(Some(Buffer::from(&out[..msg_len])));
+                }
+                if self
+                    .msg_reassembly_started_at
+                    .is_some_and(|started| started.elapsed() > MAX_REASSEMBLY_AGE)
+                {
+                    self.msg_reassembler = rf24::transport::Reassembler::new();
+                    self.msg_reassembly_started_at = None;
+                }
+            } else if Instant::now() >= deadline {
+                return Ok(None);
+            } else {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+}
+
+/// Estimate the minimum safe auto-retry delay (in microseconds) for the given
+/// [`rf24::DataRate`], [`rf24::CrcLength`], and payload length.
+///
+/// This accounts for the air time of an address + packet-control-field + payload + CRC,
+/// plus a fixed turnaround overhead, per the nRF24L01+ datasheet's guidance that a
+/// 250 kbps link with 16-bit CRC and a full 32-byte ACK payload needs at least 1500 us
+/// between retries.
+fn min_auto_retry_delay_us(
+    data_rate: rf24::DataRate,
+    crc_length: rf24::CrcLength,
+    payload_length: u8,
+) -> u32 {
+    let crc_bytes: u32 = match crc_length {
+        rf24::CrcLength::Disabled => 0,
+        rf24::CrcLength::Bit8 => 1,
+        rf24::CrcLength::Bit16 => 2,
+    };
+    let packet_bytes = 5 + 2 + payload_length as u32 + crc_bytes;
+    let bits = packet_bytes * 8;
+    let air_us = match data_rate {
+        rf24::DataRate::Kbps250 => bits * 4,
+        rf24::DataRate::Mbps1 => bits,
+        rf24::DataRate::Mbps2 => bits / 2,
+    };
+    (air_us + 250).min(4000)
 }
@@ -0,0 +1,143 @@
+#![cfg(target_os = "linux")]
+
+//! Event-driven dispatch of the radio's IRQ pin, used by {@link RF24.on}.
+//!
+//! When a `irqPin` is configured (see [`HardwareConfig.irqPin`](super::types::HardwareConfig)),
+//! [`spawn`] requests that GPIO line as a falling-edge event line and blocks a dedicated
+//! thread on it. Each edge, the thread reads and clears the STATUS register and invokes
+//! whichever JS callback(s) registered (via [`EventCallbacks`]) match the flags that fired.
+//!
+//! The worker thread re-opens its own SPI device handle rather than sharing the one owned
+//! by the main-thread [`RF24`](super::interface::RF24) instance, since `rf24::radio::RF24`
+//! is not `Send`. This mirrors how `AsyncRF24`'s worker thread (see [`super::async_radio`])
+//! owns its own radio instance instead of receiving one via move.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use embedded_hal::digital::{ErrorType, OutputPin};
+use linux_embedded_hal::gpio_cdev::{EventRequestFlags, LineRequestFlags};
+use napi::{
+    bindgen_prelude::Result,
+    threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode},
+};
+use rf24::radio::prelude::*;
+
+use super::interface::{open_gpio_chip, open_spi, Delay};
+use super::types::HardwareConfig;
+
+/// An event dispatched to a JS callback registered via {@link RF24.on}.
+#[napi(object)]
+#[derive(Default, Clone, Copy)]
+pub struct IrqEvent {
+    /// The pipe that received a payload. Only set for the `"dataReady"` event.
+    pub pipe: Option<u8>,
+    /// The Automatic Retry Count reached when the transmission failed.
+    /// Only set for the `"dataFail"` event.
+    pub arc: Option<u8>,
+}
+
+/// The JS callbacks registered via {@link RF24.on}, shared between the main thread and
+/// the IRQ worker thread spawned by [`spawn`].
+#[derive(Default)]
+pub(crate) struct EventCallbacks {
+    pub data_ready: Option<ThreadsafeFunction<IrqEvent, ErrorStrategy::Fatal>>,
+    pub data_sent: Option<ThreadsafeFunction<IrqEvent, ErrorStrategy::Fatal>>,
+    pub data_fail: Option<ThreadsafeFunction<IrqEvent, ErrorStrategy::Fatal>>,
+}
+
+/// A no-op CE pin, used so the IRQ worker thread's own `rf24::radio::RF24` instance can
+/// use [`EsbStatus`] methods without requesting (and contending for) the CE line that the
+/// main-thread radio already holds. None of [`EsbStatus::update`],
+/// [`EsbStatus::clear_status_flags`], nor `get_last_arc()` touch the CE pin.
+struct NoopPin;
+
+impl ErrorType for NoopPin {
+    type Error = core::convert::Infallible;
+}
+
+impl OutputPin for NoopPin {
+    fn set_low(&mut self) -> core::result::Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> core::result::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Spawn the dedicated IRQ-handling thread for `irq_pin`, reusing the already-resolved
+/// `dev_gpio_chip`/`dev_spi_bus`/`spi_speed` from `hardware_config`.
+pub(crate) fn spawn(
+    irq_pin: u32,
+    cs_pin: u8,
+    hardware_config: &HardwareConfig,
+    callbacks: Arc<Mutex<EventCallbacks>>,
+) -> Result<()> {
+    let dev_gpio_chip = hardware_config.dev_gpio_chip.unwrap_or_default();
+    let dev_spi_bus = hardware_config.dev_spi_bus.unwrap_or_default();
+    let spi_speed = hardware_config.spi_speed.unwrap_or(10_000_000);
+
+    let mut chip = open_gpio_chip(dev_gpio_chip)?;
+    let line = chip
+        .get_line(irq_pin)
+        .map_err(|e| napi::Error::from_reason(format!("GPIO{irq_pin} is unavailable: {e:?}")))?;
+    let mut events = line
+        .events(
+            LineRequestFlags::INPUT,
+            EventRequestFlags::FALLING_EDGE,
+            "rf24-rs-irq",
+        )
+        .map_err(|e| {
+            napi::Error::from_reason(format!("GPIO{irq_pin} is already in use: {e:?}"))
+        })?;
+
+    let spi = open_spi(cs_pin, dev_spi_bus, spi_speed)?;
+    let mut radio = rf24::radio::RF24::new(NoopPin, spi, Delay);
+
+    thread::spawn(move || {
+        // `events` blocks on each iteration until the IRQ pin falls LOW.
+        while events.next().is_some() {
+            if radio.update().is_err() {
+                continue;
+            }
+            let mut flags = rf24::StatusFlags::default();
+            radio.get_status_flags(&mut flags);
+            if flags.rx_dr() {
+                let event = IrqEvent {
+                    pipe: Some(flags.pipe()),
+                    arc: None,
+                };
+                if let Ok(callbacks) = callbacks.lock() {
+                    if let Some(callback) = &callbacks.data_ready {
+                        callback.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+                    }
+                }
+            }
+            if flags.tx_ds() {
+                if let Ok(callbacks) = callbacks.lock() {
+                    if let Some(callback) = &callbacks.data_sent {
+                        callback.call(
+                            IrqEvent::default(),
+                            ThreadsafeFunctionCallMode::NonBlocking,
+                        );
+                    }
+                }
+            }
+            if flags.tx_df() {
+                let arc = radio.get_last_arc().ok();
+                if let Ok(callbacks) = callbacks.lock() {
+                    if let Some(callback) = &callbacks.data_fail {
+                        callback.call(
+                            IrqEvent { pipe: None, arc },
+                            ThreadsafeFunctionCallMode::NonBlocking,
+                        );
+                    }
+                }
+            }
+            let _ = radio.clear_status_flags(flags);
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+pub mod async_radio;
+pub mod config;
+pub mod interface;
+pub mod irq;
+pub mod multiceiver;
+pub mod shared_bus;
+pub mod types;
+
+pub use async_radio::AsyncRF24;
+pub use interface::RF24;
+pub use multiceiver::Multiceiver;
+pub use shared_bus::SpiBus;
@@ -0,0 +1,144 @@
+#![cfg(target_os = "linux")]
+
+//! A high-level helper for the "one central node, many senders" topology: a single
+//! receiver listening across all 6 RX pipes at once, demultiplexing payloads by the
+//! pipe they arrived on.
+//!
+//! Like [`NetworkNode`](crate::network::NetworkNode), this owns its radio
+//! directly (constructed from `cePin`/`csPin`/`hardwareConfig`) rather than wrapping an
+//! existing {@link RF24} instance, since only one object may drive the underlying
+//! hardware.
+
+use linux_embedded_hal::{CdevPin, SpidevDevice};
+use napi::{bindgen_prelude::Buffer, Error, Result, Status};
+use rf24::radio::prelude::*;
+
+use super::interface::{open_hardware, Delay};
+use super::types::HardwareConfig;
+
+/// The maximum number of RX pipes a radio supports.
+const MAX_PIPES: u8 = 6;
+
+fn radio_err<E: core::fmt::Debug>(e: E) -> Error {
+    Error::new(Status::GenericFailure, format!("{e:?}"))
+}
+
+fn check_pipe(pipe: u8) -> Result<()> {
+    if pipe >= MAX_PIPES {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("pipe must be in range [0, {}], got {pipe}", MAX_PIPES - 1),
+        ));
+    }
+    Ok(())
+}
+
+/// A payload received by {@link Multiceiver.poll}, tagged with the pipe it arrived on.
+#[napi(object)]
+pub struct MulticeiverFrame {
+    /// Which RX pipe (`0` - `5`) received `payload`.
+    pub pipe: u8,
+    /// The received payload.
+    pub payload: Buffer,
+}
+
+/// Listens across all 6 RX pipes at once and demultiplexes incoming payloads by pipe,
+/// for the common topology of one receiver serving many independent senders.
+///
+/// > [!NOTE]
+/// > As with {@link RF24.openRxPipe}, only pipes 0 and 1 use the full 5-byte address
+/// > given to {@link Multiceiver.register} (or the constructor); pipes 2 - 5 only use
+/// > the address's first byte, sharing the remaining 4 bytes with pipe 1.
+///
+/// @example
+/// ```js
+/// const { Multiceiver } = require("@rf24/rf24");
+///
+/// const receiver = new Multiceiver(22, 0, [
+///   Buffer.from([0xD0, 0xD0, 0xD0, 0xD0, 0xD0]),
+///   Buffer.from([0xF1, 0xD0, 0xD0, 0xD0, 0xD0]),
+/// ]);
+/// for (const { pipe, payload } of receiver.poll()) {
+///   console.log(`pipe ${pipe}:`, payload);
+/// }
+/// ```
+#[napi(js_name = "Multiceiver")]
+pub struct Multiceiver {
+    radio: rf24::radio::RF24<SpidevDevice, CdevPin, Delay>,
+}
+
+#[napi]
+impl Multiceiver {
+    /// Construct a receiver, opening the same hardware that {@link RF24}'s constructor
+    /// would, and registering up to 6 `addresses` across pipes `0 - 5` in order.
+    ///
+    /// @param cePin - The GPIO pin number connected to the radio's CE pin.
+    /// @param csPin - The identifying number for the SPI bus' CS pin.
+    /// @param addresses - Up to 6 addresses, one per sender, assigned to pipes `0 - 5`
+    /// in order. Use {@link Multiceiver.register} to add more after construction.
+    /// @param hardwareConfig - Optional parameters to fine tune hardware configuration.
+    #[napi(constructor)]
+    pub fn new(
+        ce_pin: u32,
+        cs_pin: u8,
+        addresses: Vec<Buffer>,
+        hardware_config: Option<HardwareConfig>,
+    ) -> Result<Self> {
+        if addresses.len() > MAX_PIPES as usize {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!(
+                    "at most {MAX_PIPES} addresses are supported, got {}",
+                    addresses.len()
+                ),
+            ));
+        }
+        let (ce_pin, spi, _hw_config) = open_hardware(ce_pin, cs_pin, hardware_config)?;
+        let mut radio = rf24::radio::RF24::new(ce_pin, spi, Delay);
+        radio.init().map_err(radio_err)?;
+        for (pipe, address) in addresses.iter().enumerate() {
+            radio.open_rx_pipe(pipe as u8, address).map_err(radio_err)?;
+        }
+        radio.as_rx().map_err(radio_err)?;
+        Ok(Self { radio })
+    }
+
+    /// Open `pipe` to receive from `address`, joining it to this receiver without
+    /// disturbing the other pipes already registered.
+    ///
+    /// @param pipe - The pipe number to assign `address` to. Must be in range [0, 5].
+    /// @param address - The sender's address.
+    #[napi]
+    pub fn register(&mut self, pipe: u8, address: Buffer) -> Result<()> {
+        check_pipe(pipe)?;
+        self.radio.open_rx_pipe(pipe, &address).map_err(radio_err)
+    }
+
+    /// Close `pipe`, so its sender can no longer be heard by this receiver.
+    ///
+    /// @param pipe - The pipe number to close. Must be in range [0, 5].
+    #[napi]
+    pub fn unregister(&mut self, pipe: u8) -> Result<()> {
+        check_pipe(pipe)?;
+        self.radio.close_rx_pipe(pipe).map_err(radio_err)
+    }
+
+    /// Drain every payload currently waiting in the RX FIFO, tagging each with the pipe
+    /// it arrived on.
+    ///
+    /// @returns The drained frames, oldest first. Empty if nothing was waiting.
+    #[napi]
+    pub fn poll(&mut self) -> Result<Vec<MulticeiverFrame>> {
+        let mut frames = Vec::new();
+        let mut pipe = 0u8;
+        let mut buf = [0u8; 32];
+        while self.radio.available_pipe(&mut pipe).map_err(radio_err)? {
+            let len = self.radio.read(&mut buf, None).map_err(radio_err)? as usize;
+            frames.push(MulticeiverFrame {
+                pipe,
+                payload: Buffer::from(buf[..len].to_vec()),
+            });
+        }
+        Ok(frames)
+    }
+}
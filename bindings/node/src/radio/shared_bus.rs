@@ -0,0 +1,152 @@
+#![cfg(target_os = "linux")]
+
+//! Support for multiple [`RF24`] instances sharing one physical SPI bus, each
+//! arbitrated by its own GPIO-driven chip-select line.
+//!
+//! A plain {@link RF24} opens a dedicated `/dev/spidev{bus}.{cs}` handle, which the
+//! kernel's SPI controller multiplexes by its own devfs-owned CS lines. That caps the
+//! number of radios (or other SPI peripherals) sharing a controller at however many CS
+//! lines it exposes, and two radios can never be constructed against the same bus
+//! handle. [`SpiBus`] instead opens the bus once as a raw [`SpidevBus`] (configured with
+//! `SPI_NO_CS`, since chip-select is driven in software instead) and hands out
+//! [`RF24`] instances that each arbitrate the shared bus through an `embedded-hal-bus`
+//! [`RefCellDevice`], using an arbitrary GPIO line as their chip-select.
+
+use std::cell::RefCell;
+
+use embedded_hal::spi::{ErrorKind, ErrorType as SpiErrorType, Operation, SpiDevice};
+use embedded_hal_bus::spi::{DeviceError, RefCellDevice};
+use linux_embedded_hal::{
+    spidev::{SpiModeFlags, SpidevOptions},
+    CdevPin, SpidevBus, SpidevDevice,
+};
+use napi::{Error, Result, Status};
+
+use super::interface::{open_gpio_chip, request_output_pin, Delay, RF24};
+use super::irq::EventCallbacks;
+
+use std::sync::{Arc, Mutex};
+
+/// The concrete SPI backend used by {@link RF24}, dispatching to whichever kind of SPI
+/// device the radio was constructed with: a dedicated devfs handle, or a [`RefCellDevice`]
+/// sharing a bus opened through [`SpiBus`].
+pub(crate) enum SpiBackend {
+    Dedicated(SpidevDevice),
+    Shared(RefCellDevice<'static, SpidevBus, CdevPin, Delay>),
+}
+
+/// The combined error type for [`SpiBackend`], wrapping whichever backend produced it.
+#[derive(Debug)]
+pub(crate) enum SpiBackendError {
+    Dedicated(<SpidevDevice as SpiErrorType>::Error),
+    Shared(DeviceError<<SpidevBus as SpiErrorType>::Error, <CdevPin as embedded_hal::digital::ErrorType>::Error>),
+}
+
+impl embedded_hal::spi::Error for SpiBackendError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            SpiBackendError::Dedicated(e) => e.kind(),
+            SpiBackendError::Shared(e) => e.kind(),
+        }
+    }
+}
+
+impl SpiErrorType for SpiBackend {
+    type Error = SpiBackendError;
+}
+
+impl SpiDevice for SpiBackend {
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+        match self {
+            SpiBackend::Dedicated(spi) => spi
+                .transaction(operations)
+                .map_err(SpiBackendError::Dedicated),
+            SpiBackend::Shared(spi) => spi
+                .transaction(operations)
+                .map_err(SpiBackendError::Shared),
+        }
+    }
+}
+
+/// A raw SPI bus shared by multiple radios (or other peripherals), each driving its own
+/// GPIO pin for chip-select instead of relying on a devfs-owned CS line.
+///
+/// Construct one {@link SpiBus} per physical bus, then call {@link SpiBus.open} once
+/// per radio to get an {@link RF24} instance bound to that bus with its own CE/CS pins.
+///
+/// ```ts
+/// import { SpiBus } from "@rf24/rf24";
+///
+/// const bus = new SpiBus(0);
+/// const radio1 = bus.open(22, 27);
+/// const radio2 = bus.open(23, 24);
+/// ```
+#[napi(js_name = "SpiBus")]
+pub struct SpiBus {
+    bus: &'static RefCell<SpidevBus>,
+    dev_gpio_chip: u8,
+}
+
+#[napi]
+impl SpiBus {
+    /// Open `/dev/spidev{devSpiBus}.0` as a bus shared by radios opened via
+    /// {@link SpiBus.open}.
+    ///
+    /// @param devSpiBus - Which `/dev/spidev{devSpiBus}.0` to open.
+    /// @param spiSpeed - The SPI clock frequency (in Hz) used for every radio opened
+    /// from this bus.
+    /// @param devGpioChip - Which `/dev/gpiochip{devGpioChip}` each radio's CE and CS
+    /// pins are requested from.
+    #[napi(constructor)]
+    pub fn new(dev_spi_bus: u8, spi_speed: Option<u32>, dev_gpio_chip: Option<u8>) -> Result<Self> {
+        let mut bus = SpidevBus::open(format!("/dev/spidev{dev_spi_bus}.0")).map_err(|_| {
+            Error::new(
+                Status::InvalidArg,
+                format!("SPI bus {dev_spi_bus} is not available in this system"),
+            )
+        })?;
+        let config = SpidevOptions::new()
+            .max_speed_hz(spi_speed.unwrap_or(10_000_000))
+            .mode(SpiModeFlags::SPI_MODE_0 | SpiModeFlags::SPI_NO_CS)
+            .bits_per_word(8)
+            .build();
+        bus.configure(&config)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+
+        Ok(Self {
+            // The bus must outlive every radio opened from it, and radios are opened
+            // and dropped independently of this `SpiBus` (and of each other), so there
+            // is no single owner to borrow from; leaking gives it the `'static`
+            // lifetime `RefCellDevice` needs instead.
+            bus: Box::leak(Box::new(RefCell::new(bus))),
+            dev_gpio_chip: dev_gpio_chip.unwrap_or_default(),
+        })
+    }
+
+    /// Open a new {@link RF24} instance bound to this shared bus.
+    ///
+    /// @param cePin - The GPIO pin number connected to the new radio's CE pin.
+    /// @param csPin - The GPIO pin number used to drive the new radio's chip-select
+    /// line in software. Unlike {@link RF24}'s constructor, this is a GPIO line number
+    /// rather than a devfs CS index, since the shared bus has no CS line of its own.
+    #[napi]
+    pub fn open(&self, ce_pin: u32, cs_pin: u32) -> Result<RF24> {
+        let mut dev_gpio = open_gpio_chip(self.dev_gpio_chip)?;
+        let ce_pin = request_output_pin(&mut dev_gpio, ce_pin, 0)?;
+        let cs_pin = request_output_pin(&mut dev_gpio, cs_pin, 1)?;
+
+        let spi = RefCellDevice::new(self.bus, cs_pin, Delay)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("{e:?}")))?;
+
+        Ok(RF24 {
+            inner: rf24::radio::RF24::new(ce_pin, SpiBackend::Shared(spi), Delay),
+            read_buf: [0u8; 32],
+            events: Arc::<Mutex<EventCallbacks>>::default(),
+            next_msg_id: 0,
+            msg_reassembler: rf24::transport::Reassembler::new(),
+            msg_reassembly_started_at: None,
+            saved_ce_state: None,
+            rx_ring: Default::default(),
+        })
+    }
+}
@@ -1,6 +1,6 @@
 //! This module defines thin wrappers around rust native types to be exposed in node.js
 
-use napi::{JsNumber, Result};
+use napi::{bindgen_prelude::Buffer, JsNumber, Result};
 
 /// A private helper to implicitly convert JS numbers to boolean values (falling back to a `default` value)
 pub fn coerce_to_bool(napi_instance: Option<JsNumber>, default: bool) -> Result<bool> {
@@ -33,6 +33,15 @@ pub struct HardwareConfig {
     /// supported speed. Lower this to 6 or 4 MHz when using long wires or
     /// if builtin pull-up resistors are weak.
     pub spi_speed: Option<u32>,
+
+    /// The GPIO pin number connected to the radio's IRQ pin.
+    ///
+    /// If specified, {@link RF24.on} can be used to receive `"dataReady"`,
+    /// `"dataSent"`, and `"dataFail"` events instead of polling
+    /// {@link RF24.update} and {@link RF24.getStatusFlags}.
+    ///
+    /// @defaultValue `undefined`, which leaves the IRQ pin unused.
+    pub irq_pin: Option<u32>,
 }
 
 impl Default for HardwareConfig {
@@ -41,6 +50,7 @@ impl Default for HardwareConfig {
             dev_gpio_chip: Some(0),
             dev_spi_bus: Some(0),
             spi_speed: Some(10_000_000),
+            irq_pin: None,
         }
     }
 }
@@ -96,6 +106,14 @@ pub struct WriteConfig {
     /// at least once beforehand, otherwise this option will have no affect at all.
     pub ask_no_ack: Option<bool>,
 
+    /// An alias for {@link WriteConfig.askNoAck}, named after the `multicast` parameter of
+    /// the upstream C++ API's `write()`. Both options drive the same per-payload
+    /// "no ACK" command; if both are set, {@link WriteConfig.askNoAck} takes precedence.
+    ///
+    /// @defaultValue `false`. Be sure to set {@link RF24.allowAskNoAck} to `true`
+    /// at least once beforehand, otherwise this option will have no affect at all.
+    pub multicast: Option<bool>,
+
     /// Set to `true` to assert the radio's CE pin (and begin active TX mode) after the payload is
     /// uploaded to the TX FIFO.
     ///
@@ -104,13 +122,132 @@ pub struct WriteConfig {
     ///
     /// @defaultValue `true`
     pub start_tx: Option<bool>,
+
+    /// Set to `true` to re-assert CE on the existing top-of-FIFO payload (see
+    /// {@link RF24.rewrite}) instead of uploading `buf` as a new payload.
+    ///
+    /// @defaultValue `false`
+    pub reuse_tx: Option<bool>,
 }
 
 impl Default for WriteConfig {
     fn default() -> Self {
         Self {
             ask_no_ack: Some(false),
+            multicast: Some(false),
             start_tx: Some(true),
+            reuse_tx: Some(false),
+        }
+    }
+}
+
+/// The auto-retry delay/count pair used by {@link RF24.getAutoRetry} and {@link RF24.setAutoRetry}.
+#[napi(object)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AutoRetryConfig {
+    /// How long to wait between each retry, in multiples of 250 us (microseconds).
+    /// Clamped to range `[0, 15]` (`[250, 4000]` us).
+    pub delay: u8,
+    /// How many retries before giving up. Clamped to range `[0, 15]`. Use `0` to
+    /// disable the auto-retry feature.
+    pub count: u8,
+}
+
+/// A structured snapshot of the radio's decoded register state. See {@link RF24.getDetails}.
+#[napi(object)]
+pub struct RadioDetails {
+    /// Is the radio module a nRF24L01+ (as opposed to a non-plus variant)?
+    pub is_plus_variant: bool,
+    /// The radio's current RF channel, in range `[0, 125]`.
+    pub channel: u8,
+    /// The radio's current over-the-air data rate.
+    pub data_rate: DataRate,
+    /// The radio's current Power Amplifier level.
+    pub pa_level: PaLevel,
+    /// Is the radio's Low Noise Amplifier (LNA) feature currently enabled?
+    pub lna_enabled: bool,
+    /// The radio's current CRC encoding scheme.
+    pub crc_length: CrcLength,
+    /// The number of bytes used for on-air addresses, in range `[2, 5]`.
+    pub address_length: u8,
+    /// The number of bytes used for statically sized payloads.
+    pub payload_length: u8,
+    /// A bit mask (pipes `0` - `5`) of which pipes have dynamic payloads enabled.
+    pub dynamic_payloads: u8,
+    /// A bit mask (pipes `0` - `5`) of which pipes have auto-ack enabled.
+    pub auto_ack: u8,
+    /// Are ACK payloads currently enabled?
+    pub ack_payloads_enabled: bool,
+    /// Is the `NO_ACK` flag honored for payloads that request it?
+    pub ask_no_ack_enabled: bool,
+    /// A bit mask (pipes `0` - `5`) of which RX pipes are currently open.
+    pub open_rx_pipes: u8,
+    /// Is the radio currently powered up?
+    pub is_powered: bool,
+    /// Is the radio currently configured for RX mode (as opposed to TX mode)?
+    pub is_rx: bool,
+    /// The 5-byte address used for transmissions.
+    pub tx_address: Buffer,
+    /// The 5-byte addresses bound to RX pipes `0` - `5`.
+    pub rx_addresses: Vec<Buffer>,
+    /// The most recently cached IRQ status flags (the latched bits from the `STATUS`
+    /// register).
+    pub status_flags: StatusFlags,
+    /// Is the "RX Data Ready" IRQ event currently unmasked (enabled)?
+    pub irq_rx_dr_enabled: bool,
+    /// Is the "TX Data Sent" IRQ event currently unmasked (enabled)?
+    pub irq_tx_ds_enabled: bool,
+    /// Is the "TX Data Fail" IRQ event currently unmasked (enabled)?
+    pub irq_tx_df_enabled: bool,
+    /// The current state of the TX FIFO.
+    pub tx_fifo: FifoState,
+    /// The current state of the RX FIFO.
+    pub rx_fifo: FifoState,
+    /// Will the radio re-transmit the last TX FIFO payload the next time it enters TX mode?
+    pub reuse_tx: bool,
+    /// The delay (in microseconds) awaited after transmitting, allowing time for the
+    /// radio to receive (and this class to wait for) an ACK packet.
+    pub tx_delay: u32,
+    /// The count of lost packets (PLOS) since the last time the radio's channel was set.
+    pub packets_lost: u8,
+    /// The Auto-Retry Count (ARC) about the previous transmission.
+    pub retry_count: u8,
+}
+
+impl RadioDetails {
+    pub fn from_inner(other: rf24::RadioDetails) -> Self {
+        Self {
+            is_plus_variant: other.is_plus_variant,
+            channel: other.channel,
+            data_rate: DataRate::from_inner(other.data_rate),
+            pa_level: PaLevel::from_inner(other.pa_level),
+            lna_enabled: other.lna_enabled,
+            crc_length: CrcLength::from_inner(other.crc_length),
+            address_length: other.address_length,
+            payload_length: other.payload_length,
+            dynamic_payloads: other.dynamic_payloads,
+            auto_ack: other.auto_ack,
+            ack_payloads_enabled: other.ack_payloads_enabled,
+            ask_no_ack_enabled: other.ask_no_ack_enabled,
+            open_rx_pipes: other.open_rx_pipes,
+            is_powered: other.is_powered,
+            is_rx: other.is_rx,
+            tx_address: Buffer::from(other.tx_address.to_vec()),
+            rx_addresses: other
+                .rx_addresses
+                .iter()
+                .map(|a| Buffer::from(a.to_vec()))
+                .collect(),
+            status_flags: StatusFlags::from_inner(other.status_flags),
+            irq_rx_dr_enabled: other.irq_rx_dr_enabled,
+            irq_tx_ds_enabled: other.irq_tx_ds_enabled,
+            irq_tx_df_enabled: other.irq_tx_df_enabled,
+            tx_fifo: FifoState::from_inner(other.tx_fifo),
+            rx_fifo: FifoState::from_inner(other.rx_fifo),
+            reuse_tx: other.reuse_tx,
+            tx_delay: other.tx_delay,
+            packets_lost: other.packets_lost,
+            retry_count: other.retry_count,
         }
     }
 }
@@ -126,6 +263,25 @@ pub struct AvailablePipe {
     pub pipe: u8,
 }
 
+/// The return type for {@link RF24.readRegister} and {@link RF24.spiCommand}.
+#[napi(object)]
+pub struct SpiTransaction {
+    /// The STATUS register's value, latched by the same SPI transaction.
+    pub status: u8,
+    /// The bytes shifted back in over MISO during the transaction.
+    pub buf: Buffer,
+}
+
+/// A received payload buffered by {@link RF24.drainFifo}, returned by
+/// {@link RF24.readFrames}.
+#[napi(object)]
+pub struct RxFrame {
+    /// The pipe (0-5) the payload was received on.
+    pub pipe: u8,
+    /// The payload bytes.
+    pub payload: Buffer,
+}
+
 /// Power Amplifier level. The units dBm (decibel-milliwatts or dB<sub>mW</sub>)
 /// represents a logarithmic signal loss.
 #[napi]
@@ -260,3 +416,62 @@ impl FifoState {
         }
     }
 }
+
+/// The coarse operating state of the radio, as reported by {@link RF24.getState}.
+#[napi]
+#[derive(Debug, PartialEq)]
+pub enum RadioState {
+    /// The radio is powered down (asleep). This is the lowest power consumption state.
+    PowerDown,
+    /// The radio is powered up but neither transmitting nor receiving.
+    StandbyI,
+    /// The radio is powered up and actively transmitting (or about to) payloads.
+    TxMode,
+    /// The radio is powered up and actively listening for incoming payloads.
+    RxMode,
+}
+
+#[cfg_attr(
+    not(target_os = "linux"),
+    allow(dead_code, reason = "only used on linux")
+)]
+impl RadioState {
+    pub fn from_inner(other: rf24::RadioState) -> RadioState {
+        match other {
+            rf24::RadioState::PowerDown => RadioState::PowerDown,
+            rf24::RadioState::StandbyI => RadioState::StandbyI,
+            rf24::RadioState::TxMode => RadioState::TxMode,
+            rf24::RadioState::RxMode => RadioState::RxMode,
+        }
+    }
+}
+
+/// The idle state that {@link RF24.send} settles the radio into after a
+/// transmission completes.
+#[napi]
+#[derive(Debug, PartialEq)]
+pub enum FallbackMode {
+    /// The lowest power standby state (CE inactive). This is the default.
+    StandbyI,
+    /// A standby state (CE active) that allows faster re-transmission.
+    StandbyII,
+}
+
+#[cfg_attr(
+    not(target_os = "linux"),
+    allow(dead_code, reason = "only used on linux")
+)]
+impl FallbackMode {
+    pub fn into_inner(self) -> rf24::FallbackMode {
+        match self {
+            FallbackMode::StandbyI => rf24::FallbackMode::StandbyI,
+            FallbackMode::StandbyII => rf24::FallbackMode::StandbyII,
+        }
+    }
+    pub fn from_inner(other: rf24::FallbackMode) -> FallbackMode {
+        match other {
+            rf24::FallbackMode::StandbyI => FallbackMode::StandbyI,
+            rf24::FallbackMode::StandbyII => FallbackMode::StandbyII,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rf24ble::AdvertisementError;
+
+fn to_py_err(err: AdvertisementError) -> PyErr {
+    match err {
+        AdvertisementError::BufferFull => {
+            PyValueError::new_err("AD structure does not fit in the 18-byte advertisement budget")
+        }
+        AdvertisementError::ExceedsDeviceBudget => PyValueError::new_err(
+            "payload (combined with the device name and/or PA level) exceeds the advertisement budget",
+        ),
+    }
+}
+
+/// A composable builder for packing multiple GAP AD (Advertising Data) structures into a
+/// single advertisement payload, for use with [`FakeBle.send()`][rf24_py.FakeBle.send].
+///
+/// This mirrors the structured advertise-data model used by full BLE stacks, so callers
+/// don't have to hand-format the length/type/data layout documented on
+/// [`FakeBle.send()`][rf24_py.FakeBle.send] themselves.
+#[pyclass(module = "rf24_py")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AdvertisementBuilder {
+    inner: rf24ble::AdvertisementBuilder,
+}
+
+#[pymethods]
+impl AdvertisementBuilder {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How many bytes are still free for additional AD structures.
+    #[getter]
+    pub fn get_remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+
+    /// Append a raw length-prefixed AD structure, as produced by one of the built-in
+    /// service classes' `buffer` attribute (e.g.
+    /// [`BatteryService.buffer`][rf24_py.BatteryService.buffer]).
+    pub fn add_raw(&mut self, ad_structure: &[u8]) -> PyResult<()> {
+        self.inner.add_raw(ad_structure).map_err(to_py_err)?;
+        Ok(())
+    }
+
+    /// Append a Flags AD structure (`0x01`).
+    pub fn add_flags(&mut self, flags: u8) -> PyResult<()> {
+        self.inner.add_flags(flags).map_err(to_py_err)?;
+        Ok(())
+    }
+
+    /// Append a Local Name AD structure: Complete (`0x09`) if `complete` is `True`,
+    /// otherwise Shortened (`0x08`).
+    #[pyo3(signature = (name, complete = true))]
+    pub fn add_name(&mut self, name: &str, complete: bool) -> PyResult<()> {
+        self.inner.add_name(name, complete).map_err(to_py_err)?;
+        Ok(())
+    }
+
+    /// Append a Manufacturer Specific Data AD structure (`0xFF`): a 2-byte little-endian
+    /// company identifier followed by `data`.
+    pub fn add_manufacturer_data(&mut self, company_id: u16, data: &[u8]) -> PyResult<()> {
+        self.inner
+            .add_manufacturer_data(company_id, data)
+            .map_err(to_py_err)?;
+        Ok(())
+    }
+
+    /// Append a Service Data AD structure (`0x16`): a 16-bit little-endian service UUID
+    /// followed by arbitrary service-specific `data`.
+    pub fn add_service_data(&mut self, uuid: u16, data: &[u8]) -> PyResult<()> {
+        self.inner
+            .add_service_data(uuid, data)
+            .map_err(to_py_err)?;
+        Ok(())
+    }
+
+    /// Append an Incomplete (`0x02`) or Complete (`0x03`) List of 16-bit Service UUIDs.
+    #[pyo3(signature = (uuids, complete = false))]
+    pub fn add_service_uuids16(&mut self, uuids: Vec<u16>, complete: bool) -> PyResult<()> {
+        self.inner
+            .add_service_uuids16(&uuids, complete)
+            .map_err(to_py_err)?;
+        Ok(())
+    }
+
+    /// Emit the packed payload built so far, ready for
+    /// [`FakeBle.send()`][rf24_py.FakeBle.send].
+    ///
+    /// Use [`FakeBle.len_available()`][rf24_py.FakeBle.len_available] beforehand to check
+    /// that the payload (combined with the device name and/or PA level, if enabled) fits
+    /// in a single advertisement.
+    #[getter]
+    pub fn get_buffer(&self) -> Cow<[u8]> {
+        Cow::from(self.inner.build())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod advertisement;
 pub mod radio;
 pub mod services;
 
@@ -14,3 +15,37 @@ use pyo3::prelude::*;
 pub fn ble_config() -> RadioConfig {
     RadioConfig::from_inner(rf24ble::ble_config())
 }
+
+/// Returns a [`RadioConfig`][rf24_py.RadioConfig] object tailored for passively sniffing
+/// real BLE advertisements (as opposed to [`ble_config()`]'s FakeBle-to-FakeBle link).
+///
+/// See also:
+///     This configuration complies with inherent
+///     [Limitations](https://docs.rs/rf24ble-rs/latest/rf24ble/index.html#limitations).
+#[pyfunction]
+pub fn sniffer_config() -> RadioConfig {
+    RadioConfig::from_inner(rf24ble::sniffer_config())
+}
+
+/// Whiten (or de-whiten) `data` as it would be transmitted/received on the given BLE `channel`.
+///
+/// This is a convenience function for users building custom/proprietary PDUs
+/// (eg. the `0xFF` manufacturer-specific path documented on
+/// [`FakeBle.send()`][rf24_py.FakeBle.send]).
+#[pyfunction]
+pub fn whiten(data: Vec<u8>, channel: u8) -> Vec<u8> {
+    let mut data = data;
+    rf24ble::whiten(&mut data, channel);
+    data
+}
+
+/// Calculate a 24 bit CRC checksum for `data`, as used by the BLE specification.
+///
+/// This is a convenience function for users building custom/proprietary PDUs
+/// (eg. the `0xFF` manufacturer-specific path documented on
+/// [`FakeBle.send()`][rf24_py.FakeBle.send]). The returned bytes shall be appended to the
+/// transmitted payload *before* applying [`whiten()`].
+#[pyfunction]
+pub fn crc24(data: Vec<u8>) -> [u8; 3] {
+    rf24ble::crc24(&data)
+}
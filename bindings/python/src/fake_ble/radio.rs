@@ -141,6 +141,9 @@ impl FakeBle {
     /// - advertise a Battery's remaining change level: [`BatteryService`][rf24_py.BatteryService]
     /// - advertise a Temperature measurement: [`TemperatureService`][rf24_py.TemperatureService]
     /// - advertise a URL: [`UrlService`][rf24_py.UrlService]
+    /// - advertise an Eddystone-UID beacon identity: [`EddystoneUidService`][rf24_py.EddystoneUidService]
+    /// - advertise an Eddystone-TLM telemetry frame: [`EddystoneTlmService`][rf24_py.EddystoneTlmService]
+    /// - advertise an Apple iBeacon: [`IBeaconService`][rf24_py.IBeaconService]
     ///
     /// For a custom/proprietary BLE service, the given `buf` must adopt compliance with BLE specifications.
     /// For example, a buffer of `n` bytes shall be formed as follows:
@@ -150,6 +153,11 @@ impl FakeBle {
     /// | `0` | `n - 1` |
     /// | `1` | `0xFF`  |
     /// | `2 ... n - 1` | custom data |
+    ///
+    /// If `buf` (combined with the device name and/or PA level, if enabled) does not
+    /// fit in a single advertisement, this returns `False` without transmitting
+    /// anything. Use [`FakeBle.len_available()`][rf24_py.FakeBle.len_available] to
+    /// check beforehand.
     pub fn send(&mut self, buf: &[u8]) -> PyResult<bool> {
         Python::with_gil(|py| {
             let mut radio = self.radio.bind(py).borrow_mut();
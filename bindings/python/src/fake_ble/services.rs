@@ -127,6 +127,226 @@ impl UrlService {
     }
 }
 
+/// A BLE data service for broadcasting an Eddystone-UID beacon identity.
+///
+/// Conforms to the UID frame specified by [Google's EddyStone][eddystone] data format.
+///
+/// [eddystone]: https://github.com/google/eddystone
+#[pyclass(module = "rf24_py")]
+#[derive(Debug, Clone, Copy)]
+pub struct EddystoneUidService {
+    inner: rf24ble::services::EddystoneUidService,
+}
+
+#[pymethods]
+impl EddystoneUidService {
+    #[new]
+    pub fn new() -> Self {
+        Self {
+            inner: rf24ble::services::EddystoneUidService::default(),
+        }
+    }
+
+    /// The predicted PA (Power Amplitude) level at 1 meter radius.
+    #[getter]
+    pub fn get_pa_level(&self) -> i8 {
+        self.inner.pa_level()
+    }
+
+    #[setter]
+    pub fn set_pa_level(&mut self, value: i8) {
+        self.inner.set_pa_level(value);
+    }
+
+    /// The 10-byte namespace ID.
+    #[getter]
+    pub fn get_namespace(&self) -> Cow<[u8]> {
+        Cow::from(self.inner.namespace().to_vec())
+    }
+
+    #[setter]
+    pub fn set_namespace(&mut self, value: [u8; 10]) {
+        self.inner.set_namespace(value);
+    }
+
+    /// The 6-byte instance ID.
+    #[getter]
+    pub fn get_instance(&self) -> Cow<[u8]> {
+        Cow::from(self.inner.instance().to_vec())
+    }
+
+    #[setter]
+    pub fn set_instance(&mut self, value: [u8; 6]) {
+        self.inner.set_instance(value);
+    }
+
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    #[getter]
+    pub fn get_buffer(&self) -> Cow<[u8]> {
+        Cow::from(self.inner.buffer())
+    }
+}
+
+/// A BLE data service for broadcasting an Eddystone-TLM telemetry frame.
+///
+/// Conforms to the unencrypted TLM frame specified by [Google's EddyStone][eddystone] data
+/// format.
+///
+/// [eddystone]: https://github.com/google/eddystone
+#[pyclass(module = "rf24_py")]
+#[derive(Debug, Clone, Copy)]
+pub struct EddystoneTlmService {
+    inner: rf24ble::services::EddystoneTlmService,
+}
+
+#[pymethods]
+impl EddystoneTlmService {
+    #[new]
+    pub fn new() -> Self {
+        Self {
+            inner: rf24ble::services::EddystoneTlmService::default(),
+        }
+    }
+
+    /// The TLM frame version. Always `0`, the only version currently defined by the
+    /// Eddystone-TLM spec.
+    #[getter]
+    pub fn get_version(&self) -> u8 {
+        self.inner.version()
+    }
+
+    /// The battery voltage (in mV). `0` means "unsupported".
+    #[getter]
+    pub fn get_battery_voltage(&self) -> u16 {
+        self.inner.battery_voltage()
+    }
+
+    #[setter]
+    pub fn set_battery_voltage(&mut self, value: u16) {
+        self.inner.set_battery_voltage(value);
+    }
+
+    /// Does this frame report a battery voltage, or is it the "unsupported" sentinel?
+    #[getter]
+    pub fn get_has_battery_voltage(&self) -> bool {
+        self.inner.has_battery_voltage()
+    }
+
+    /// The beacon's temperature (in Celsius). `-128.0` means "unsupported".
+    #[getter]
+    pub fn get_temperature(&self) -> f32 {
+        self.inner.temperature()
+    }
+
+    #[setter]
+    pub fn set_temperature(&mut self, value: f32) {
+        self.inner.set_temperature(value);
+    }
+
+    /// Does this frame report a temperature, or is it the "unsupported" sentinel?
+    #[getter]
+    pub fn get_has_temperature(&self) -> bool {
+        self.inner.has_temperature()
+    }
+
+    /// The count of advertising PDUs sent since power-up (or reboot).
+    #[getter]
+    pub fn get_pdu_count(&self) -> u32 {
+        self.inner.pdu_count()
+    }
+
+    #[setter]
+    pub fn set_pdu_count(&mut self, value: u32) {
+        self.inner.set_pdu_count(value);
+    }
+
+    /// The time since power-up (or reboot), in 0.1 second units.
+    #[getter]
+    pub fn get_uptime(&self) -> u32 {
+        self.inner.uptime()
+    }
+
+    #[setter]
+    pub fn set_uptime(&mut self, value: u32) {
+        self.inner.set_uptime(value);
+    }
+
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    #[getter]
+    pub fn get_buffer(&self) -> Cow<[u8]> {
+        Cow::from(self.inner.buffer())
+    }
+}
+
+/// A BLE data service for broadcasting an Apple iBeacon.
+///
+/// Conforms to the iBeacon advertising format.
+#[pyclass(module = "rf24_py")]
+#[derive(Debug, Clone, Copy)]
+pub struct IBeaconService {
+    inner: rf24ble::services::IBeaconService,
+}
+
+#[pymethods]
+impl IBeaconService {
+    #[new]
+    pub fn new() -> Self {
+        Self {
+            inner: rf24ble::services::IBeaconService::default(),
+        }
+    }
+
+    /// The 16-byte proximity UUID.
+    #[getter]
+    pub fn get_uuid(&self) -> Cow<[u8]> {
+        Cow::from(self.inner.uuid().to_vec())
+    }
+
+    #[setter]
+    pub fn set_uuid(&mut self, value: [u8; 16]) {
+        self.inner.set_uuid(value);
+    }
+
+    /// The major value.
+    #[getter]
+    pub fn get_major(&self) -> u16 {
+        self.inner.major()
+    }
+
+    #[setter]
+    pub fn set_major(&mut self, value: u16) {
+        self.inner.set_major(value);
+    }
+
+    /// The minor value.
+    #[getter]
+    pub fn get_minor(&self) -> u16 {
+        self.inner.minor()
+    }
+
+    #[setter]
+    pub fn set_minor(&mut self, value: u16) {
+        self.inner.set_minor(value);
+    }
+
+    /// The measured power (in dBm) at 1 meter.
+    #[getter]
+    pub fn get_measured_power(&self) -> i8 {
+        self.inner.measured_power()
+    }
+
+    #[setter]
+    pub fn set_measured_power(&mut self, value: i8) {
+        self.inner.set_measured_power(value);
+    }
+
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    #[getter]
+    pub fn get_buffer(&self) -> Cow<[u8]> {
+        Cow::from(self.inner.buffer())
+    }
+}
+
 /// A structure to represent received BLE data.
 #[pyclass(frozen, get_all)]
 pub struct BlePayload {
@@ -142,6 +362,12 @@ pub struct BlePayload {
     pub url: Option<UrlService>,
     /// The transmitting device's temperature measurement.
     pub temperature: Option<TemperatureService>,
+    /// The transmitting device's advertised Eddystone-UID beacon identity.
+    pub eddystone_uid: Option<EddystoneUidService>,
+    /// The transmitting device's advertised Eddystone-TLM telemetry.
+    pub eddystone_tlm: Option<EddystoneTlmService>,
+    /// The transmitting device's advertised iBeacon.
+    pub ibeacon: Option<IBeaconService>,
 }
 
 impl BlePayload {
@@ -166,6 +392,13 @@ impl BlePayload {
                     .map(|bat| BatteryService { inner: bat }),
                 url: payload.url.map(|u| UrlService { inner: u }),
                 temperature: payload.temperature.map(|t| TemperatureService { inner: t }),
+                eddystone_uid: payload
+                    .eddystone_uid
+                    .map(|uid| EddystoneUidService { inner: uid }),
+                eddystone_tlm: payload
+                    .eddystone_tlm
+                    .map(|tlm| EddystoneTlmService { inner: tlm }),
+                ibeacon: payload.ibeacon.map(|b| IBeaconService { inner: b }),
             });
         }
         None
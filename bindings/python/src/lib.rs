@@ -1,11 +1,14 @@
 use pyo3::prelude::*;
 mod fake_ble;
+mod network;
 mod radio;
 
 #[cfg(target_os = "linux")]
 fn bind_radio_impl(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<radio::interface::RF24>()?;
     m.add_class::<fake_ble::radio::FakeBle>()?;
+    m.add_class::<network::network::RF24Network>()?;
+    m.add_class::<network::mesh::RF24Mesh>()?;
     Ok(())
 }
 
@@ -23,11 +26,24 @@ fn rf24_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<radio::types::FifoState>()?;
     m.add_class::<radio::types::PaLevel>()?;
     m.add_class::<radio::types::StatusFlags>()?;
+    m.add_class::<radio::types::RadioDetails>()?;
+    m.add_class::<radio::types::LinkStats>()?;
+    m.add_class::<radio::types::IrqEvent>()?;
+    m.add_class::<radio::types::RadioState>()?;
+    m.add_class::<radio::types::FallbackMode>()?;
     m.add_class::<radio::config::RadioConfig>()?;
+    m.add("RadioError", m.py().get_type::<radio::error::RadioError>())?;
     m.add_class::<fake_ble::services::BatteryService>()?;
     m.add_class::<fake_ble::services::TemperatureService>()?;
     m.add_class::<fake_ble::services::UrlService>()?;
+    m.add_class::<fake_ble::services::EddystoneUidService>()?;
+    m.add_class::<fake_ble::services::EddystoneTlmService>()?;
+    m.add_class::<fake_ble::services::IBeaconService>()?;
     m.add_class::<fake_ble::services::BlePayload>()?;
+    m.add_class::<fake_ble::advertisement::AdvertisementBuilder>()?;
     m.add_function(wrap_pyfunction!(fake_ble::ble_config, m)?)?;
+    m.add_function(wrap_pyfunction!(fake_ble::sniffer_config, m)?)?;
+    m.add_function(wrap_pyfunction!(fake_ble::whiten, m)?)?;
+    m.add_function(wrap_pyfunction!(fake_ble::crc24, m)?)?;
     Ok(())
 }
@@ -0,0 +1,64 @@
+//! Helpers for the octal-tree logical addressing scheme used by
+//! [`RF24Network`](super::network::RF24Network) and
+//! [`RF24Mesh`](super::mesh::RF24Mesh).
+//!
+//! Every node is identified by a 16-bit logical address. The master node is
+//! always address `0`. Each node may have up to 5 children (indices `1..=5`);
+//! a child's address is its parent's address with the child index written
+//! into the next free octal digit (a 3-bit group), starting from the least
+//! significant bits.
+
+/// How many octal digits (3-bit groups) deep `address` is from the master (`0`).
+pub fn depth(address: u16) -> u32 {
+    let mut remaining = address;
+    let mut level = 0;
+    while remaining & 0b111 != 0 {
+        remaining >>= 3;
+        level += 1;
+    }
+    level
+}
+
+/// The address of `child_index`'s (`1..=5`) child node under `parent`.
+pub fn child_address(parent: u16, child_index: u8) -> u16 {
+    parent | ((child_index as u16) << (3 * depth(parent)))
+}
+
+/// The address of `address`'s parent node. Returns `0` (the master) if
+/// `address` is already `0` or one of the master's direct children.
+pub fn parent_address(address: u16) -> u16 {
+    if address == 0 {
+        return 0;
+    }
+    let shift = 3 * (depth(address) - 1);
+    address & !(0b111 << shift)
+}
+
+/// The child index (`1..=5`) that `address` occupies under `parent`.
+pub fn child_index(parent: u16, address: u16) -> u8 {
+    (((address >> (3 * depth(parent))) & 0b111) as u8).min(5)
+}
+
+/// Is `address` a (possibly indirect) child of `ancestor`?
+pub fn is_descendant(ancestor: u16, address: u16) -> bool {
+    if address == ancestor {
+        return false;
+    }
+    if ancestor == 0 {
+        return address != 0;
+    }
+    let mask = (1u16 << (3 * depth(ancestor))) - 1;
+    address & mask == ancestor
+}
+
+/// Derive a unique 5-byte radio address for the link between `owner` and its
+/// `slot` (`1..=5`, the index of one of `owner`'s children).
+///
+/// Both ends of a link derive the same address independently: the parent
+/// calls this with its own address and the child's index, and the child
+/// calls this with its parent's address and its own index.
+pub fn link_address(owner: u16, slot: u8) -> [u8; 5] {
+    let hi = (owner >> 8) as u8;
+    let lo = (owner & 0xFF) as u8;
+    [slot, 0xC3, hi, lo, 0x3C]
+}
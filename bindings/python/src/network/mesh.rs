@@ -0,0 +1,210 @@
+#![cfg(target_os = "linux")]
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use pyo3::{exceptions::PyRuntimeError, prelude::*};
+
+use super::addressing::child_address;
+use super::network::RF24Network;
+
+/// The header type reserved for mesh address-assignment requests and their
+/// replies. Application code should not use this value for its own frames.
+const MESH_ADDR_REQUEST: u8 = 0xFE;
+/// Every unassigned node listens on this logical address (the master's 5th
+/// child, i.e. `child_address(0, 5)`) so it can reach the master before it
+/// has been given an address of its own.
+const MESH_LOOKUP_ADDRESS: u16 = 5;
+
+/// Dynamic address assignment on top of [`RF24Network`][rf24_py.RF24Network].
+///
+/// Every mesh node is identified by a stable application-chosen `node_id`
+/// (`1..=255`). Nodes other than the master start out unassigned, listening
+/// on a shared lookup address, and ask the master (address `0`) for a
+/// logical network address the first time [`update()`][rf24_py.RF24Mesh.update]
+/// or [`renew_address()`][rf24_py.RF24Mesh.renew_address] is called. The
+/// master keeps a `node_id -> logical address` table and reuses the same
+/// address for a `node_id` it has already seen.
+///
+/// ```py
+/// from rf24_py import RF24, RF24Network, RF24Mesh
+///
+/// radio = RF24(22, 0)
+/// radio.begin()
+/// mesh = RF24Mesh(RF24Network(radio))
+/// mesh.begin(node_id=5)
+///
+/// while True:
+///     mesh.update()
+///     if mesh.available():
+///         from_address, header_type, payload = mesh.read()
+/// ```
+#[pyclass(module = "rf24_py")]
+pub struct RF24Mesh {
+    network: Py<RF24Network>,
+    node_id: u8,
+    /// Only populated on the master (`node_id == 0`).
+    assignments: HashMap<u8, u16>,
+    next_child_slot: u8,
+}
+
+#[pymethods]
+impl RF24Mesh {
+    /// Wrap the given `network` in a mesh layer.
+    #[new]
+    pub fn new(network: Py<RF24Network>) -> Self {
+        Self {
+            network,
+            node_id: 0,
+            assignments: HashMap::new(),
+            next_child_slot: 1,
+        }
+    }
+
+    /// Join the mesh as `node_id`.
+    ///
+    /// `node_id` of `0` identifies this node as the mesh master; any other
+    /// node starts unassigned and requests its logical address from the
+    /// master during [`update()`][rf24_py.RF24Mesh.update].
+    pub fn begin(&mut self, node_id: u8) -> PyResult<()> {
+        self.node_id = node_id;
+        let initial_address = if node_id == 0 { 0 } else { MESH_LOOKUP_ADDRESS };
+        Python::with_gil(|py| self.network.bind(py).borrow_mut().begin(initial_address))
+    }
+
+    /// This node's stable `node_id`, as given to [`begin()`][rf24_py.RF24Mesh.begin].
+    #[getter]
+    pub fn node_id(&self) -> u8 {
+        self.node_id
+    }
+
+    /// This node's current logical network address, or `None` if it has not
+    /// been assigned one yet (always `0` for the master).
+    #[getter]
+    pub fn node_address(&self) -> Option<u16> {
+        Python::with_gil(|py| {
+            let network = self.network.bind(py).borrow();
+            if self.node_id != 0 && network.node_address() == MESH_LOOKUP_ADDRESS {
+                None
+            } else {
+                Some(network.node_address())
+            }
+        })
+    }
+
+    /// Process pending network traffic, answering address requests (as the
+    /// master) or retrying an address request (as an unassigned node).
+    pub fn update(&mut self) -> PyResult<()> {
+        Python::with_gil(|py| {
+            self.network.bind(py).borrow_mut().update()?;
+            if self.node_id == 0 {
+                self.serve_address_requests(py)?;
+            } else if self.network.bind(py).borrow().node_address() == MESH_LOOKUP_ADDRESS {
+                self.renew_address()?;
+            }
+            Ok(())
+        })
+    }
+
+    /// (Re-)request a logical address from the master. Returns the newly
+    /// assigned address, or `None` if the master has not replied yet.
+    pub fn renew_address(&mut self) -> PyResult<Option<u16>> {
+        Python::with_gil(|py| {
+            let mut network = self.network.bind(py).borrow_mut();
+            network.write(&[self.node_id], 0, MESH_ADDR_REQUEST)?;
+            drop(network);
+            self.network.bind(py).borrow_mut().update()?;
+            let mut network = self.network.bind(py).borrow_mut();
+            while network.available() {
+                let Some((from, header_type, payload)) = network.read() else {
+                    break;
+                };
+                if from == 0 && header_type == MESH_ADDR_REQUEST && payload.len() >= 2 {
+                    let address = u16::from_le_bytes([payload[0], payload[1]]);
+                    drop(network);
+                    self.network.bind(py).borrow_mut().begin(address)?;
+                    return Ok(Some(address));
+                }
+            }
+            Ok(None)
+        })
+    }
+
+    /// Is there a fully received payload waiting in the local queue?
+    pub fn available(&self) -> bool {
+        Python::with_gil(|py| self.network.bind(py).borrow().available())
+    }
+
+    /// Pop the next locally addressed payload from the queue.
+    pub fn read(&mut self) -> Option<(u16, u8, Cow<'static, [u8]>)> {
+        Python::with_gil(|py| self.network.bind(py).borrow_mut().read())
+    }
+
+    /// Send `data` to the node identified by `node_id` (looked up via the
+    /// master's assignment table), fragmenting it if needed.
+    ///
+    /// Other parameters:
+    ///     header_type: An application-defined value carried alongside the payload.
+    #[pyo3(signature = (data, node_id, header_type = 0u8))]
+    pub fn write(&mut self, data: &[u8], node_id: u8, header_type: u8) -> PyResult<bool> {
+        let dest = *self.assignments.get(&node_id).ok_or_else(|| {
+            PyRuntimeError::new_err(format!("no known address for node_id {node_id}"))
+        })?;
+        Python::with_gil(|py| {
+            self.network
+                .bind(py)
+                .borrow_mut()
+                .write(data, dest, header_type)
+        })
+    }
+}
+
+impl RF24Mesh {
+    /// As the master, answer any pending [`MESH_ADDR_REQUEST`] frames by
+    /// allocating (or recalling) a logical address for the requesting node_id.
+    fn serve_address_requests(&mut self, py: Python<'_>) -> PyResult<()> {
+        let mut pending = Vec::new();
+        {
+            let mut network = self.network.bind(py).borrow_mut();
+            while network.available() {
+                let Some((from, header_type, payload)) = network.read() else {
+                    break;
+                };
+                if header_type == MESH_ADDR_REQUEST {
+                    if let Some(&node_id) = payload.first() {
+                        pending.push((from, node_id));
+                    }
+                }
+            }
+        }
+        for (from, node_id) in pending {
+            let address = self.allocate_address(node_id);
+            let mut network = self.network.bind(py).borrow_mut();
+            network.write(&address.to_le_bytes(), from, MESH_ADDR_REQUEST)?;
+        }
+        Ok(())
+    }
+
+    /// Return the logical address assigned to `node_id`, allocating a fresh
+    /// one (the next free child slot under the master) if this is the first
+    /// time this `node_id` has been seen.
+    fn allocate_address(&mut self, node_id: u8) -> u16 {
+        if let Some(&address) = self.assignments.get(&node_id) {
+            return address;
+        }
+        while self.next_child_slot <= 4 {
+            let candidate = child_address(0, self.next_child_slot);
+            self.next_child_slot += 1;
+            if candidate != MESH_LOOKUP_ADDRESS && !self.assignments.values().any(|&a| a == candidate)
+            {
+                self.assignments.insert(node_id, candidate);
+                return candidate;
+            }
+        }
+        // Slots exhausted: fall back to the lookup address's sibling tree is
+        // out of scope for this simplified allocator; reuse slot 4 to at
+        // least avoid a panic.
+        let fallback = child_address(0, 4);
+        self.assignments.insert(node_id, fallback);
+        fallback
+    }
+}
@@ -0,0 +1,4 @@
+#![cfg(target_os = "linux")]
+pub mod addressing;
+pub mod mesh;
+pub mod network;
@@ -0,0 +1,239 @@
+#![cfg(target_os = "linux")]
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+
+use pyo3::{exceptions::PyRuntimeError, prelude::*};
+
+use crate::radio::interface::RF24;
+
+use super::addressing::{child_address, child_index, is_descendant, link_address, parent_address};
+
+/// `to`(2) + `from`(2) + `header_type`(1) + `frag_id`(1) + `frag_total`(1).
+const HEADER_LEN: usize = 7;
+/// The largest chunk of user data that fits a single 32-byte radio payload
+/// alongside [`HEADER_LEN`].
+const CHUNK_LEN: usize = 32 - HEADER_LEN;
+
+/// A logical, addressed, multi-hop network built on top of [`RF24`][rf24_py.RF24].
+///
+/// Every node is identified by a 16-bit logical address arranged in an octal
+/// tree (see [`RF24Network.child_address()`][rf24_py.RF24Network.child_address]):
+/// the master is address `0`, and each node may have up to 5 children. Payloads
+/// larger than what fits in a single radio payload are automatically fragmented
+/// and reassembled. Frames not addressed to this node are automatically
+/// forwarded toward their destination.
+///
+/// ```py
+/// from rf24_py import RF24, RF24Network
+///
+/// radio = RF24(22, 0)
+/// radio.begin()
+/// network = RF24Network(radio)
+/// network.begin(0)  # this node is the master
+///
+/// while True:
+///     network.update()
+///     if network.available():
+///         from_address, header_type, payload = network.read()
+/// ```
+#[pyclass(module = "rf24_py")]
+pub struct RF24Network {
+    radio: Py<RF24>,
+    node_address: u16,
+    frame_queue: VecDeque<(u16, u8, Vec<u8>)>,
+    fragments: HashMap<(u16, u8), Vec<Option<Vec<u8>>>>,
+}
+
+#[pymethods]
+impl RF24Network {
+    /// Wrap the given `radio` in a network layer.
+    #[new]
+    pub fn new(radio: Py<RF24>) -> Self {
+        Self {
+            radio,
+            node_address: 0,
+            frame_queue: VecDeque::new(),
+            fragments: HashMap::new(),
+        }
+    }
+
+    /// Join the network as `node_address`.
+    ///
+    /// This configures the underlying radio's pipes to receive from this
+    /// node's parent (if any) and up to 5 children, then enters RX mode.
+    pub fn begin(&mut self, node_address: u16) -> PyResult<()> {
+        Python::with_gil(|py| {
+            let mut radio = self.radio.bind(py).borrow_mut();
+            radio.set_address_length(5)?;
+            if node_address != 0 {
+                let parent = parent_address(node_address);
+                let my_slot = child_index(parent, node_address);
+                radio.open_rx_pipe(0, &link_address(parent, my_slot))?;
+            }
+            for child in 1u8..=5 {
+                radio.open_rx_pipe(child, &link_address(node_address, child))?;
+            }
+            radio.as_rx()
+        })?;
+        self.node_address = node_address;
+        Ok(())
+    }
+
+    /// This node's logical address, as given to [`begin()`][rf24_py.RF24Network.begin].
+    #[getter]
+    pub fn node_address(&self) -> u16 {
+        self.node_address
+    }
+
+    /// Process any payloads waiting in the radio's RX FIFO.
+    ///
+    /// Frames addressed to this node are reassembled (if fragmented) and queued
+    /// for [`read()`][rf24_py.RF24Network.read]. Frames addressed elsewhere are
+    /// forwarded toward their destination.
+    pub fn update(&mut self) -> PyResult<()> {
+        Python::with_gil(|py| {
+            let mut radio = self.radio.bind(py).borrow_mut();
+            while radio.available()? {
+                let buf = radio.read(None)?.into_owned();
+                if buf.len() < HEADER_LEN {
+                    continue;
+                }
+                let to = u16::from_le_bytes([buf[0], buf[1]]);
+                let from = u16::from_le_bytes([buf[2], buf[3]]);
+                let header_type = buf[4];
+                let frag_id = buf[5];
+                let frag_total = buf[6];
+                if to == self.node_address {
+                    let payload = buf[HEADER_LEN..].to_vec();
+                    self.deliver(from, header_type, frag_id, frag_total, payload);
+                } else {
+                    Self::forward(self.node_address, &mut radio, to, &buf)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Is there a fully received payload waiting in the local queue?
+    pub fn available(&self) -> bool {
+        !self.frame_queue.is_empty()
+    }
+
+    /// Pop the next locally addressed payload from the queue.
+    ///
+    /// Returns `None` if [`available()`][rf24_py.RF24Network.available] is `false`.
+    pub fn read(&mut self) -> Option<(u16, u8, Cow<'static, [u8]>)> {
+        self.frame_queue
+            .pop_front()
+            .map(|(from, header_type, data)| (from, header_type, Cow::Owned(data)))
+    }
+
+    /// Send `data` to the node at logical address `dest`, fragmenting it if needed.
+    ///
+    /// Other parameters:
+    ///     header_type: An application-defined value carried alongside the payload.
+    ///         This is returned as-is by [`read()`][rf24_py.RF24Network.read].
+    #[pyo3(signature = (data, dest, header_type = 0u8))]
+    pub fn write(&mut self, data: &[u8], dest: u16, header_type: u8) -> PyResult<bool> {
+        let chunks: Vec<&[u8]> = if data.is_empty() {
+            vec![&[][..]]
+        } else {
+            data.chunks(CHUNK_LEN).collect()
+        };
+        let frag_total = chunks.len() as u8;
+        Python::with_gil(|py| {
+            let mut radio = self.radio.bind(py).borrow_mut();
+            for (frag_id, chunk) in chunks.iter().enumerate() {
+                let mut frame = Vec::with_capacity(HEADER_LEN + chunk.len());
+                frame.extend_from_slice(&dest.to_le_bytes());
+                frame.extend_from_slice(&self.node_address.to_le_bytes());
+                frame.push(header_type);
+                frame.push(frag_id as u8);
+                frame.push(frag_total);
+                frame.extend_from_slice(chunk);
+                Self::forward(self.node_address, &mut radio, dest, &frame)?;
+            }
+            Ok(true)
+        })
+    }
+
+    /// The address of `parent`'s child at `child_index` (`1..=5`).
+    #[staticmethod]
+    pub fn child_address(parent: u16, child_index: u8) -> u16 {
+        child_address(parent, child_index)
+    }
+
+    /// The address of `address`'s parent node.
+    #[staticmethod]
+    pub fn parent_address(address: u16) -> u16 {
+        parent_address(address)
+    }
+}
+
+impl RF24Network {
+    fn deliver(
+        &mut self,
+        from: u16,
+        header_type: u8,
+        frag_id: u8,
+        frag_total: u8,
+        payload: Vec<u8>,
+    ) {
+        if frag_total <= 1 {
+            self.frame_queue.push_back((from, header_type, payload));
+            return;
+        }
+        let key = (from, header_type);
+        if frag_id == 0 {
+            // A new message (re)started for this peer/type: drop whatever
+            // incomplete sequence was pending rather than let it linger forever.
+            self.fragments.remove(&key);
+        }
+        let slots = self
+            .fragments
+            .entry(key)
+            .or_insert_with(|| vec![None; frag_total as usize]);
+        if slots.len() != frag_total as usize {
+            // frag_total changed mid-sequence: discard the stale fragments.
+            *slots = vec![None; frag_total as usize];
+        }
+        if let Some(slot) = slots.get_mut(frag_id as usize) {
+            *slot = Some(payload);
+        }
+        if slots.iter().all(Option::is_some) {
+            let complete = self
+                .fragments
+                .remove(&key)
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .flatten()
+                .collect();
+            self.frame_queue.push_back((from, header_type, complete));
+        }
+    }
+
+    /// Forward a raw (already header-prefixed) frame toward `to`, one hop at a time.
+    pub(super) fn forward(
+        node_address: u16,
+        radio: &mut PyRefMut<RF24>,
+        to: u16,
+        frame: &[u8],
+    ) -> PyResult<()> {
+        let next_hop = if is_descendant(node_address, to) {
+            link_address(node_address, child_index(node_address, to))
+        } else if node_address == 0 {
+            return Err(PyRuntimeError::new_err(format!(
+                "no route to node {to}: it is not a descendant of this master node"
+            )));
+        } else {
+            let parent = parent_address(node_address);
+            link_address(parent, child_index(parent, node_address))
+        };
+        radio.as_tx()?;
+        radio.open_tx_pipe(&next_hop)?;
+        radio.send(frame, 0)?;
+        radio.as_rx()?;
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(clippy::new_without_default)]
 use super::types::{CrcLength, DataRate, PaLevel};
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
 
 use std::borrow::Cow;
 
@@ -88,6 +88,20 @@ impl RadioConfig {
         self.inner = self.inner.with_payload_length(value);
     }
 
+    /// Get the static payload length that a specified RX `pipe` (0 - 5) will use,
+    /// falling back to [`RadioConfig.payload_length`][rf24_py.RadioConfig.payload_length]
+    /// if `pipe` has no value of its own set via
+    /// [`RadioConfig.set_pipe_payload_length()`][rf24_py.RadioConfig.set_pipe_payload_length].
+    pub fn get_pipe_payload_length(&self, pipe: u8) -> u8 {
+        self.inner.pipe_payload_length(pipe)
+    }
+
+    /// Set a static payload length for a specified RX `pipe` (0 - 5), overriding
+    /// [`RadioConfig.payload_length`][rf24_py.RadioConfig.payload_length] on that pipe only.
+    pub fn set_pipe_payload_length(&mut self, pipe: u8, value: u8) {
+        self.inner = self.inner.with_pipe_payload_length(pipe, value);
+    }
+
     /// The address length.
     ///
     /// This value is clamped to range [2, 5].
@@ -213,6 +227,21 @@ impl RadioConfig {
         self.inner = self.inner.with_dynamic_payloads(value != 0);
     }
 
+    /// Enable or disable dynamically sized payloads on a per-pipe basis.
+    ///
+    /// The given value (in binary form) is used to control the feature for each pipe,
+    /// mirroring [`RadioConfig.auto_ack`][rf24_py.RadioConfig.auto_ack]'s bitmask
+    /// convention: bit 0 controls pipe 0, bit 1 controls pipe 1, and so on.
+    #[getter]
+    pub fn get_dynamic_payloads_bin(&self) -> u8 {
+        self.inner.dynamic_payloads_bin()
+    }
+
+    #[setter]
+    pub fn set_dynamic_payloads_bin(&mut self, value: u8) {
+        self.inner = self.inner.with_dynamic_payloads_bin(value);
+    }
+
     /// Enable or disable custom ACK payloads for auto-ACK packets.
     ///
     /// ACK payloads require the [`RadioConfig.auto_ack`][rf24_py.RadioConfig.auto_ack]
@@ -229,6 +258,25 @@ impl RadioConfig {
         self.inner = self.inner.with_ack_payloads(value != 0);
     }
 
+    /// Enable or disable custom ACK payloads on a per-pipe basis.
+    ///
+    /// The given value (in binary form) is used to control the feature for each pipe,
+    /// mirroring [`RadioConfig.auto_ack`][rf24_py.RadioConfig.auto_ack]'s bitmask
+    /// convention: bit 0 controls pipe 0, bit 1 controls pipe 1, and so on.
+    ///
+    /// Any pipe enabled here also has [`RadioConfig.auto_ack`][rf24_py.RadioConfig.auto_ack]
+    /// and [`RadioConfig.dynamic_payloads_bin`][rf24_py.RadioConfig.dynamic_payloads_bin]
+    /// enabled for that pipe, since ACK payloads require both.
+    #[getter]
+    pub fn get_ack_payloads_bin(&self) -> u8 {
+        self.inner.ack_payloads_bin()
+    }
+
+    #[setter]
+    pub fn set_ack_payloads_bin(&mut self, value: u8) {
+        self.inner = self.inner.with_ack_payloads_bin(value);
+    }
+
     /// Allow disabling auto-ack per payload.
     ///
     /// See `ask_no_ack` parameter for
@@ -329,6 +377,50 @@ impl RadioConfig {
     pub fn close_rx_pipe(&mut self, pipe: u8) {
         self.inner = self.inner.close_rx_pipe(pipe);
     }
+
+    /// Check this configuration for illegal combinations that this class's setters do
+    /// not themselves reject, before pushing it to hardware.
+    ///
+    /// Raises a `ValueError` describing the conflict, or returns `None` if the
+    /// configuration is consistent.
+    pub fn validate(&self) -> PyResult<()> {
+        self.inner
+            .validate()
+            .map_err(|err| PyValueError::new_err(format!("{err:?}")))
+    }
+
+    /// Pack the whole configuration into a compact, versioned `bytes` blob.
+    ///
+    /// This covers every field settable through this class (the channel, address
+    /// length, PA level, data rate, CRC length, payload length, dynamic/ACK/ask-no-ack
+    /// flags, auto-ACK mask, auto-retry delay/count, all RX pipe addresses and their
+    /// open/closed state, the TX address, and the IRQ masks).
+    ///
+    /// Pair this with [`RadioConfig.from_bytes()`][rf24_py.RadioConfig.from_bytes] to
+    /// save a known-good radio profile to a file and restore it later, or to ship an
+    /// identical config to a peer node.
+    pub fn __bytes__(&self) -> Cow<[u8]> {
+        Cow::from(self.inner.to_bytes().to_vec())
+    }
+
+    /// The inverse of [`RadioConfig.__bytes__()`][rf24_py.RadioConfig.__bytes__].
+    ///
+    /// Raises a `ValueError` if `data`'s version byte does not match the version
+    /// stamped by [`RadioConfig.__bytes__()`][rf24_py.RadioConfig.__bytes__].
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        let blob: [u8; rf24::radio::RADIO_CONFIG_SERIALIZED_LEN] =
+            data.try_into().map_err(|_| {
+                PyValueError::new_err(format!(
+                    "expected {} bytes, got {}",
+                    rf24::radio::RADIO_CONFIG_SERIALIZED_LEN,
+                    data.len()
+                ))
+            })?;
+        rf24::radio::RadioConfig::from_bytes(&blob)
+            .map(Self::from_inner)
+            .ok_or_else(|| PyValueError::new_err("unsupported RadioConfig blob version"))
+    }
 }
 
 impl RadioConfig {
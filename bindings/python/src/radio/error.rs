@@ -0,0 +1,8 @@
+use pyo3::{create_exception, exceptions::PyException};
+
+create_exception!(
+    rf24_py,
+    RadioError,
+    PyException,
+    "Raised when the radio hardware reports an error (e.g. a failed SPI transaction)."
+);
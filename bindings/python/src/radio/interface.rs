@@ -1,15 +1,22 @@
 #![cfg(target_os = "linux")]
 use std::borrow::Cow;
-use std::time::Duration;
+use std::collections::BTreeMap;
+use std::os::unix::io::AsRawFd;
+use std::time::{Duration, Instant};
 
 use super::config::RadioConfig;
-use super::types::{CrcLength, DataRate, FifoState, PaLevel, StatusFlags};
+use super::error::RadioError;
+use super::types::{
+    CrcLength, DataRate, FallbackMode, FifoState, IrqEvent, LinkStats, PaLevel, RadioDetails,
+    RadioState, StatusFlags,
+};
 use embedded_hal::{delay::DelayNs, digital::OutputPin};
 use linux_embedded_hal::{
-    gpio_cdev::{chips, LineRequestFlags},
+    gpio_cdev::{chips, EventRequestFlags, LineEventHandle, LineRequestFlags},
     spidev::{SpiModeFlags, SpidevOptions},
     CdevPin, SpidevDevice,
 };
+use nix::poll::{poll, PollFd, PollFlags};
 use nix::sys::time::TimeSpec;
 use nix::time::{clock_nanosleep, ClockId, ClockNanosleepFlags};
 
@@ -18,13 +25,16 @@ use pyo3::{
     prelude::*,
 };
 use rf24::radio::prelude::*;
+use rf24::radio::RADIO_CONFIG_BLOB_LEN;
+#[cfg(feature = "asyncio")]
+use pyo3_async_runtimes::tokio::future_into_py;
 
 struct Delay;
 
 impl DelayNs for Delay {
     fn delay_ns(&mut self, ns: u32) {
         clock_nanosleep(
-            ClockId::CLOCK_REALTIME,
+            ClockId::CLOCK_MONOTONIC,
             ClockNanosleepFlags::empty(),
             &TimeSpec::from_duration(Duration::from_nanos(ns as u64)),
         )
@@ -32,6 +42,100 @@ impl DelayNs for Delay {
     }
 }
 
+/// The number of frames a single [`RF24.send_message()`][rf24_py.RF24.send_message]
+/// message can be split into, bounding it (alongside
+/// [`rf24::transport::MAX_FRAME_DATA`]) to
+/// `MAX_MESSAGE_FRAMES * rf24::transport::MAX_FRAME_DATA` bytes.
+const MAX_MESSAGE_FRAMES: usize = 64;
+
+/// How long a partially received message is buffered before
+/// [`RF24.read_message()`][rf24_py.RF24.read_message] gives up on it and raises an error.
+const MESSAGE_REASSEMBLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The registers [`RF24.dump_config()`][rf24_py.RF24.dump_config] and
+/// [`RF24.load_config()`][rf24_py.RF24.load_config] expose, in the order they occupy a
+/// [`RADIO_CONFIG_BLOB_LEN`]-byte blob (skipping the leading version byte) and the order
+/// `load_config()` writes them back in.
+const CONFIG_KEYS: [&str; 12] = [
+    "config",
+    "en_aa",
+    "en_rxaddr",
+    "setup_aw",
+    "setup_retr",
+    "rf_ch",
+    "rf_setup",
+    "feature",
+    "dynpd",
+    "rx_pw_p0",
+    "tx_addr",
+    "rx_addr_p0",
+];
+
+/// The byte range within a config blob that `CONFIG_KEYS[index]` occupies.
+fn config_blob_field(blob: &[u8; RADIO_CONFIG_BLOB_LEN], index: usize) -> &[u8] {
+    match index {
+        0..=9 => &blob[index + 1..index + 2],
+        10 => &blob[11..16],
+        11 => &blob[16..21],
+        _ => unreachable!("CONFIG_KEYS has 12 entries"),
+    }
+}
+
+/// Like [`config_blob_field()`] but mutable, for [`RF24.load_config()`][rf24_py.RF24.load_config].
+fn config_blob_field_mut(blob: &mut [u8; RADIO_CONFIG_BLOB_LEN], index: usize) -> &mut [u8] {
+    match index {
+        0..=9 => &mut blob[index + 1..index + 2],
+        10 => &mut blob[11..16],
+        11 => &mut blob[16..21],
+        _ => unreachable!("CONFIG_KEYS has 12 entries"),
+    }
+}
+
+/// Serialize `bytes` as a lowercase hex string, 2 digits per byte.
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Parse a hex string produced by [`hex_encode()`] back into bytes, naming `key` in any
+/// error so the caller knows which config entry was malformed.
+fn hex_decode(value: &str, key: &str) -> PyResult<Vec<u8>> {
+    if value.len() % 2 != 0 {
+        return Err(PyValueError::new_err(format!(
+            "value for \"{key}\" must have an even number of hex digits"
+        )));
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&value[i..i + 2], 16).map_err(|_| {
+                PyValueError::new_err(format!("value for \"{key}\" is not valid hex"))
+            })
+        })
+        .collect()
+}
+
+/// Raw counters accumulated while [`RF24.link_stats_enabled`][rf24_py.RF24.link_stats_enabled]
+/// is set. See [`RF24.get_link_stats()`][rf24_py.RF24.get_link_stats] for the derived metrics.
+struct LinkStatsCounters {
+    started: Instant,
+    packets_sent: u32,
+    acked: u32,
+    retries_total: u32,
+    bytes_acked: u64,
+}
+
+impl LinkStatsCounters {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            packets_sent: 0,
+            acked: 0,
+            retries_total: 0,
+            bytes_acked: 0,
+        }
+    }
+}
+
 /// Construct an object to control the radio.
 ///
 /// Parameters:
@@ -48,18 +152,48 @@ impl DelayNs for Delay {
 ///         and `Y` is the `cs_pin` parameter's value.
 ///     spi_speed: The SPI bus speed in Hz. Defaults to the radio's maximum supported
 ///         speed (10 MHz).
+///     irq_pin: The GPIO pin number connected to the radio's active-low IRQ pin.
+///         If specified, this enables [`RF24.wait_for_irq()`][rf24_py.RF24.wait_for_irq]
+///         and [`RF24.service_irq_events()`][rf24_py.RF24.service_irq_events].
+///     spi_mode: The SPI clock polarity/phase, in the range `[0, 3]`. Defaults to mode `0`
+///         (CPOL = 0, CPHA = 0), which is what the radio expects.
+///     bits_per_word: The number of bits per SPI word. Defaults to `8`.
+///     lsb_first: Set to `true` to shift the least significant bit out first.
+///         Defaults to `false` (most significant bit first), which is what the radio expects.
+///     three_wire: Set to `true` to operate the SPI bus in half-duplex mode (MOSI and MISO
+///         share a single wire). Defaults to `false`.
+///     cs_high: Set to `true` if the SPI bus' CS pin is active-high. Defaults to `false`
+///         (active-low), which is what the radio (and most SPI peripherals) expect.
 #[pyclass(module = "rf24_py")]
 pub struct RF24 {
     inner: rf24::radio::RF24<SpidevDevice, CdevPin, Delay>,
     read_buf: [u8; 32],
+    irq_pin: Option<LineEventHandle>,
+    irq_callbacks: Vec<(IrqEvent, Py<PyAny>)>,
+    message_reassembler: rf24::transport::Reassembler<MAX_MESSAGE_FRAMES>,
+    message_rx_started_at: Option<Instant>,
+    message_tx_id: u8,
+    link_stats: Option<LinkStatsCounters>,
 }
 
 #[pymethods]
 impl RF24 {
     #[new]
     #[pyo3(
-        text_signature = "(ce_pin: int, cs_pin: int, dev_gpio_chip: int = 0, dev_spi_bus: int = 0, spi_speed: int = 10000000) -> RF24",
-        signature = (ce_pin, cs_pin, dev_gpio_chip = 0u8, dev_spi_bus = 0u8, spi_speed = 10_000_000),
+        text_signature = "(ce_pin: int, cs_pin: int, dev_gpio_chip: int = 0, dev_spi_bus: int = 0, spi_speed: int = 10000000, irq_pin: int | None = None, spi_mode: int = 0, bits_per_word: int = 8, lsb_first: bool = False, three_wire: bool = False, cs_high: bool = False) -> RF24",
+        signature = (
+            ce_pin,
+            cs_pin,
+            dev_gpio_chip = 0u8,
+            dev_spi_bus = 0u8,
+            spi_speed = 10_000_000,
+            irq_pin = None,
+            spi_mode = 0u8,
+            bits_per_word = 8u8,
+            lsb_first = 0i32,
+            three_wire = 0i32,
+            cs_high = 0i32,
+        ),
     )]
     pub fn new(
         ce_pin: u32,
@@ -67,6 +201,12 @@ impl RF24 {
         dev_gpio_chip: u8,
         dev_spi_bus: u8,
         spi_speed: u32,
+        irq_pin: Option<u32>,
+        spi_mode: u8,
+        bits_per_word: u8,
+        lsb_first: i32,
+        three_wire: i32,
+        cs_high: i32,
     ) -> PyResult<Self> {
         // get the desired "/dev/gpiochip{dev_gpio_chip}"
         let mut dev_gpio = chips()
@@ -100,6 +240,23 @@ impl RF24 {
         let ce_pin =
             CdevPin::new(ce_line_handle).map_err(|e| PyOSError::new_err(format!("{e:?}")))?;
 
+        let irq_pin = irq_pin
+            .map(|irq_pin| {
+                let irq_line = dev_gpio.get_line(irq_pin).map_err(|e| {
+                    PyValueError::new_err(format!("GPIO{irq_pin} is unavailable: {e:?}"))
+                })?;
+                irq_line
+                    .events(
+                        LineRequestFlags::INPUT,
+                        EventRequestFlags::FALLING_EDGE,
+                        "rf24-rs",
+                    )
+                    .map_err(|e| {
+                        PyOSError::new_err(format!("GPIO{irq_pin} is already in use: {e:?}"))
+                    })
+            })
+            .transpose()?;
+
         let mut spi =
             SpidevDevice::open(format!("/dev/spidev{dev_spi_bus}.{cs_pin}")).map_err(|_| {
                 PyOSError::new_err(format!(
@@ -107,10 +264,30 @@ impl RF24 {
                 )
             )
             })?;
+        let mut mode = match spi_mode {
+            0 => SpiModeFlags::SPI_MODE_0,
+            1 => SpiModeFlags::SPI_MODE_1,
+            2 => SpiModeFlags::SPI_MODE_2,
+            3 => SpiModeFlags::SPI_MODE_3,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "spi_mode must be in range [0, 3], but got {spi_mode}"
+                )))
+            }
+        };
+        if lsb_first != 0 {
+            mode |= SpiModeFlags::SPI_LSB_FIRST;
+        }
+        if three_wire != 0 {
+            mode |= SpiModeFlags::SPI_3WIRE;
+        }
+        if cs_high != 0 {
+            mode |= SpiModeFlags::SPI_CS_HIGH;
+        }
         let spi_config = SpidevOptions::new()
             .max_speed_hz(spi_speed)
-            .mode(SpiModeFlags::SPI_MODE_0)
-            .bits_per_word(8)
+            .mode(mode)
+            .bits_per_word(bits_per_word)
             .build();
         spi.configure(&spi_config)
             .map_err(|e| PyOSError::new_err(format!("{e:?}")))?;
@@ -118,6 +295,12 @@ impl RF24 {
         Ok(Self {
             inner: rf24::radio::RF24::new(ce_pin, spi, Delay),
             read_buf: [0u8; 32],
+            irq_pin,
+            irq_callbacks: Vec::new(),
+            message_reassembler: rf24::transport::Reassembler::new(),
+            message_rx_started_at: None,
+            message_tx_id: 0,
+            link_stats: None,
         })
     }
 
@@ -138,6 +321,19 @@ impl RF24 {
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
 
+    /// Verify the radio is actually responding on the SPI bus.
+    ///
+    /// This writes a probe pattern to the `SETUP_AW` register and reads it back,
+    /// restoring the register's original value afterward. Unlike
+    /// [`RF24.begin()`][rf24_py.RF24.begin], this does not reconfigure the radio, so
+    /// it is safe to call at any time (e.g. from a watchdog) to confirm the
+    /// transceiver is still wired and powered.
+    pub fn is_chip_connected(&mut self) -> PyResult<bool> {
+        self.inner
+            .is_chip_connected()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
     /// Reconfigure the radio with a specified [`RadioConfig`][rf24_py.RadioConfig].
     ///
     /// Warning:
@@ -152,6 +348,21 @@ impl RF24 {
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
 
+    /// Reconstruct a [`RadioConfig`][rf24_py.RadioConfig] from the radio's current
+    /// register state.
+    ///
+    /// This is the inverse of [`RF24.with_config()`][rf24_py.RF24.with_config] and is
+    /// useful for verifying that a prior
+    /// [`RF24.with_config()`][rf24_py.RF24.with_config] call actually took effect,
+    /// detecting SPI wiring faults (a returned config of all `0`s or all `0xFF`s), or
+    /// snapshotting a pre-configured radio's settings to reapply later.
+    pub fn get_config(&mut self) -> PyResult<RadioConfig> {
+        self.inner
+            .get_config()
+            .map(RadioConfig::from_inner)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
     /// Set the radio's CE pin HIGH (`True`) or LOW (`False`).
     ///
     /// This is only exposed for advanced use of TX FIFO during
@@ -207,9 +418,40 @@ impl RF24 {
         text_signature = "(buf: bytes | bytearray, ask_no_ack: bool | int = False) -> bool",
     )]
     pub fn send(&mut self, buf: &[u8], ask_no_ack: i32) -> PyResult<bool> {
-        self.inner
+        let acked = self
+            .inner
             .send(buf, ask_no_ack != 0)
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        self.record_link_stats(acked, buf.len())?;
+        Ok(acked)
+    }
+
+    /// Like [`RF24.send()`][rf24_py.RF24.send], but returns an `asyncio`-awaitable
+    /// instead of blocking.
+    ///
+    /// The blocking SPI transaction (and subsequent ACK wait) runs on a background
+    /// thread, so the `asyncio` event loop stays responsive while a transmission is
+    /// in flight.
+    ///
+    /// Only available when this package is built with the `asyncio` feature.
+    #[cfg(feature = "asyncio")]
+    #[pyo3(
+        signature = (buf, ask_no_ack = 0i32),
+        text_signature = "(buf: bytes | bytearray, ask_no_ack: bool | int = False) -> Awaitable[bool]",
+    )]
+    pub fn send_async<'py>(
+        slf: Py<Self>,
+        py: Python<'py>,
+        buf: Vec<u8>,
+        ask_no_ack: i32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        future_into_py(py, async move {
+            tokio::task::spawn_blocking(move || {
+                Python::with_gil(|py| slf.borrow_mut(py).send(&buf, ask_no_ack))
+            })
+            .await
+            .map_err(|e| PyRuntimeError::new_err(format!("send_async task panicked: {e}")))?
+        })
     }
 
     /// A non-blocking function that uploads a given `buf` to the radio's TX FIFO.
@@ -265,13 +507,158 @@ impl RF24 {
         Ok(Cow::from(&self.read_buf[0..len as usize]))
     }
 
+    /// Block until a payload is received or `timeout_ms` elapses, then
+    /// [`RF24.read()`][rf24_py.RF24.read] it.
+    ///
+    /// This puts the radio into RX mode (see [`RF24.as_rx()`][rf24_py.RF24.as_rx]) if it
+    /// is not already, then polls [`RF24.available()`][rf24_py.RF24.available] against a
+    /// monotonic deadline so the wait is unaffected by clock adjustments (e.g. NTP steps)
+    /// made while waiting.
+    ///
+    /// Other parameters:
+    ///     timeout_ms: The maximum amount of time (in milliseconds) to wait for a payload.
+    ///     len: An optional number of bytes to read from the FIFO. This is capped at `32`.
+    ///         If not specified, then the length of the next available payload is used (which
+    ///         automatically respects if dynamic payloads are enabled).
+    ///
+    /// Returns:
+    ///     `None` if `timeout_ms` elapses before a payload is received.
+    #[pyo3(signature = (timeout_ms, len = None))]
+    pub fn read_with_timeout(
+        &mut self,
+        timeout_ms: u64,
+        len: Option<u8>,
+    ) -> PyResult<Option<Cow<[u8]>>> {
+        self.read_until_deadline(Instant::now() + Duration::from_millis(timeout_ms), len)
+    }
+
+    /// Block until a payload is received or `timeout_us` elapses, then
+    /// [`RF24.read()`][rf24_py.RF24.read] it.
+    ///
+    /// This is identical to [`RF24.read_with_timeout()`][rf24_py.RF24.read_with_timeout]
+    /// except the timeout is given in microseconds, for callers needing finer-grained
+    /// deadlines (e.g. tight request/response turnarounds). Both measure the deadline
+    /// against [`std::time::Instant`], a monotonic clock, so the wait cannot be thrown
+    /// off by wall-clock adjustments made while polling.
+    ///
+    /// Other parameters:
+    ///     timeout_us: The maximum amount of time (in microseconds) to wait for a payload.
+    ///     len: An optional number of bytes to read from the FIFO. This is capped at `32`.
+    ///         If not specified, then the length of the next available payload is used (which
+    ///         automatically respects if dynamic payloads are enabled).
+    ///
+    /// Returns:
+    ///     `None` if `timeout_us` elapses before a payload is received.
+    #[pyo3(signature = (timeout_us, len = None))]
+    pub fn read_blocking(
+        &mut self,
+        timeout_us: u64,
+        len: Option<u8>,
+    ) -> PyResult<Option<Cow<[u8]>>> {
+        self.read_until_deadline(Instant::now() + Duration::from_micros(timeout_us), len)
+    }
+
+    /// Split an arbitrary-length `data` object into fragments and send each one (using
+    /// the same auto-ack path as [`RF24.send()`][rf24_py.RF24.send]).
+    ///
+    /// Each fragment is prefixed with a 3-byte header holding a message id, the
+    /// fragment's index, and the total fragment count. The message id increments with
+    /// every call and is used by
+    /// [`RF24.read_message()`][rf24_py.RF24.read_message] to detect a new message
+    /// arriving before a prior one was fully reassembled.
+    ///
+    /// Parameters:
+    ///     data: The arbitrary-length buffer of bytes to transmit.
+    ///
+    /// Other parameters:
+    ///     ask_no_ack: A flag to disable the auto-ack feature for the given payload.
+    ///         This has no effect if auto-ack is disabled or
+    ///         [`RF24.allow_ask_no_ack`][rf24_py.RF24.allow_ask_no_ack] is not enabled.
+    ///
+    /// This uses the same [`rf24::transport`] wire format as every other binding's
+    /// `send_message()`/`read_message()` (or equivalent) helper, so a message sent here
+    /// reassembles correctly wherever it's received, and vice versa.
+    ///
+    /// Returns:
+    ///     `False` if any fragment fails to send (the remaining fragments are not sent
+    ///     in that case), otherwise `True`.
+    ///
+    /// Raises:
+    ///     ValueError: If `data` needs more than [`MAX_MESSAGE_FRAMES`] fragments to send.
+    #[pyo3(
+        signature = (data, ask_no_ack = 0i32),
+        text_signature = "(data: bytes | bytearray, ask_no_ack: bool | int = False) -> bool",
+    )]
+    pub fn send_message(&mut self, data: &[u8], ask_no_ack: i32) -> PyResult<bool> {
+        let fragmenter =
+            rf24::transport::Fragmenter::<MAX_MESSAGE_FRAMES>::new(self.message_tx_id, data)
+                .ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "data needs more than {MAX_MESSAGE_FRAMES} fragments to send"
+                    ))
+                })?;
+        self.message_tx_id = self.message_tx_id.wrapping_add(1);
+
+        for (frame, len) in fragmenter {
+            if !self.send(&frame[..len], ask_no_ack)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Drain the RX FIFO, reassembling fragments sent by
+    /// [`RF24.send_message()`][rf24_py.RF24.send_message].
+    ///
+    /// Returns:
+    ///     `None` if no complete message is ready yet (after draining all payloads
+    ///     currently available in the RX FIFO).
+    ///
+    /// Raises:
+    ///     RuntimeError: If a partially received message (including one stalled on a
+    ///         missing fragment) is not completed within 5 seconds of its first fragment.
+    pub fn read_message(&mut self) -> PyResult<Option<Cow<[u8]>>> {
+        while self.available()? {
+            if self.message_reassembler.is_empty() {
+                self.message_rx_started_at = Some(Instant::now());
+            }
+            let payload = self.read(None)?.into_owned();
+
+            let mut out = [0u8; MAX_MESSAGE_FRAMES * rf24::transport::MAX_FRAME_DATA];
+            if let Some(len) = self.message_reassembler.receive_frame(&payload, &mut out) {
+                self.message_rx_started_at = None;
+                return Ok(Some(Cow::from(out[..len].to_vec())));
+            }
+            if self
+                .message_rx_started_at
+                .is_some_and(|started| started.elapsed() > MESSAGE_REASSEMBLY_TIMEOUT)
+            {
+                self.message_reassembler = rf24::transport::Reassembler::new();
+                self.message_rx_started_at = None;
+                return Err(RadioError::new_err(
+                    "timed out waiting for the remaining fragments of a message",
+                ));
+            }
+        }
+        Ok(None)
+    }
+
     /// A blocking function to resend a failed payload in the TX FIFO.
     ///
     /// This is similar to [`RF24.send()`][rf24_py.RF24.send] but specifically for
     /// failed transmissions.
-    pub fn resend(&mut self) -> PyResult<bool> {
+    ///
+    /// Args:
+    ///     send_only: A flag to leave any ACK payload sitting in the RX FIFO instead
+    ///         of flushing it after a successful resend. Use
+    ///         [`RF24.read()`][rf24_py.RF24.read] to fetch that ACK payload.
+    #[pyo3(
+        signature = (send_only = 0i32),
+        text_signature = "(send_only: bool | int = False) -> bool",
+    )]
+    pub fn resend(&mut self, send_only: i32) -> PyResult<bool> {
         self.inner
-            .resend()
+            .resend(send_only != 0)
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
 
@@ -281,7 +668,11 @@ impl RF24 {
     /// Use [`RF24.update()`][rf24_py.RF24.update] and
     /// [`RF24.get_status_flags()`][rf24_py.RF24.get_status_flags] to determine if
     /// retransmission was successful.
-    pub fn rewrite(&mut self) -> PyResult<()> {
+    ///
+    /// Returns:
+    ///     `False` (without doing anything else) if the TX FIFO is empty, since there
+    ///     is no payload to reuse in that case.
+    pub fn rewrite(&mut self) -> PyResult<bool> {
         self.inner
             .rewrite()
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
@@ -301,6 +692,38 @@ impl RF24 {
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
 
+    /// Get the count of lost packets (PLOS) since the last time the radio's channel was set.
+    ///
+    /// This counter is saturated at 15; it does not overflow/reset on its own. Setting the
+    /// channel (via [`RF24.channel`][rf24_py.RF24.channel]) resets it back to `0`, so this
+    /// value is only meaningful relative to the currently configured channel.
+    pub fn get_lost_packets(&mut self) -> PyResult<u8> {
+        self.inner
+            .get_lost_packets()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
+    /// Nudge the auto-retry `delay` and `count` (see
+    /// [`RF24.set_auto_retries`][rf24_py.RF24.set_auto_retries]) based on how the most
+    /// recent transmission actually went, instead of committing to one static retry
+    /// profile for the whole session.
+    ///
+    /// Call this after a [`RF24.send`][rf24_py.RF24.send] or
+    /// [`RF24.write`][rf24_py.RF24.write] attempt.
+    pub fn adapt_auto_retries(&mut self) -> PyResult<()> {
+        self.inner
+            .adapt_auto_retries()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
+    /// Get the radio's coarse operating state, as tracked by this instance.
+    ///
+    /// This reflects the local cache of the `CONFIG` register and the CE pin's last
+    /// known level; it does not perform any SPI transactions.
+    pub fn get_state(&self) -> RadioState {
+        RadioState::from_inner(self.inner.get_state())
+    }
+
     /// A property that describes if the radio is a nRF24L01+ or not.
     #[getter]
     pub fn is_plus_variant(&self) -> bool {
@@ -346,6 +769,219 @@ impl RF24 {
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
 
+    /// Survey the given channel range for ambient RF activity.
+    ///
+    /// For each channel in `[start_channel, end_channel]` (inclusive), the radio is tuned
+    /// to that channel and put into RX mode, then the Received Power Detector is sampled
+    /// `samples_per_channel` times (see [`RF24.get_rpd`][rf24_py.RF24.get_rpd]). The
+    /// radio's prior channel and RX/TX mode are restored before returning.
+    ///
+    /// Parameters:
+    ///     start_channel: The first channel (inclusive) to survey.
+    ///     end_channel: The last channel (inclusive) to survey.
+    ///     samples_per_channel: The number of times to sample the RPD flag per channel.
+    ///
+    /// Returns a list of hit counts, one per channel, indexed by the channel's offset
+    /// from `start_channel`.
+    pub fn scan_channels(
+        &mut self,
+        start_channel: u8,
+        end_channel: u8,
+        samples_per_channel: u8,
+    ) -> PyResult<Cow<[u8]>> {
+        if start_channel > end_channel {
+            return Err(PyValueError::new_err(format!(
+                "start_channel ({start_channel}) must not be greater than end_channel ({end_channel})"
+            )));
+        }
+        let prior_channel = self
+            .inner
+            .get_channel()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        let mut hits = Vec::with_capacity(end_channel as usize - start_channel as usize + 1);
+        for channel in start_channel..=end_channel {
+            let [count] = self
+                .inner
+                .scan_channels(&[channel], samples_per_channel)
+                .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+            hits.push(count);
+        }
+        self.inner
+            .set_channel(prior_channel)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        Ok(Cow::from(hits))
+    }
+
+    /// Survey an explicit (not necessarily contiguous) list of channels for ambient RF
+    /// activity.
+    ///
+    /// This is the same survey [`RF24.scan_channels()`][rf24_py.RF24.scan_channels]
+    /// performs, but for an arbitrary `channels` list instead of a contiguous range —
+    /// useful for re-checking only the channels a prior scan flagged as busy.
+    ///
+    /// Parameters:
+    ///     channels: The channels to survey, in the order they should be sampled.
+    ///     samples_per_channel: The number of times to sample the RPD flag per channel.
+    ///
+    /// Returns a list of hit counts, one per channel, in the same order as `channels`.
+    pub fn scan_channel_list(
+        &mut self,
+        channels: Vec<u8>,
+        samples_per_channel: u8,
+    ) -> PyResult<Cow<[u8]>> {
+        let mut hits = Vec::with_capacity(channels.len());
+        for channel in channels {
+            let [count] = self
+                .inner
+                .scan_channels(&[channel], samples_per_channel)
+                .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+            hits.push(count);
+        }
+        Ok(Cow::from(hits))
+    }
+
+    /// Find the quietest channel among `channels`, i.e. whichever has the fewest RPD
+    /// hits over `samples_per_channel` samples.
+    ///
+    /// This is a convenience wrapper around
+    /// [`RF24.scan_channel_list()`][rf24_py.RF24.scan_channel_list] for picking a
+    /// low-noise operating frequency at startup instead of hand-rolling the scan and
+    /// comparing its histogram. If multiple channels tie for the fewest hits, the
+    /// first (lowest-indexed) one is returned.
+    ///
+    /// Parameters:
+    ///     channels: The channels to survey, in the order they should be sampled.
+    ///     samples_per_channel: The number of times to sample the RPD flag per channel.
+    ///
+    /// Returns the quietest channel found.
+    pub fn find_clear_channel(
+        &mut self,
+        channels: Vec<u8>,
+        samples_per_channel: u8,
+    ) -> PyResult<u8> {
+        let mut quietest = (channels.first().copied().unwrap_or(0), u8::MAX);
+        for channel in channels {
+            let [count] = self
+                .inner
+                .scan_channels(&[channel], samples_per_channel)
+                .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+            if count < quietest.1 {
+                quietest = (channel, count);
+            }
+        }
+        Ok(quietest.0)
+    }
+
+    /// Like [`RF24.scan_channels()`][rf24_py.RF24.scan_channels], but the radio's full
+    /// configuration (not just its channel and RX/TX mode) is snapshotted beforehand
+    /// and restored afterward, so the radio is handed back exactly as it was found.
+    ///
+    /// Useful for borrowing the radio for a one-off spectrum survey (e.g. to build a
+    /// textual band display) from code that otherwise expects the radio to keep
+    /// whatever network settings it configured earlier.
+    ///
+    /// Parameters:
+    ///     start_channel: The first channel (inclusive) to survey.
+    ///     end_channel: The last channel (inclusive) to survey.
+    ///     samples_per_channel: The number of times to sample the RPD flag per channel.
+    ///
+    /// Returns a list of hit counts, one per channel, indexed by the channel's offset
+    /// from `start_channel`.
+    pub fn scan_channels_preserving_config(
+        &mut self,
+        start_channel: u8,
+        end_channel: u8,
+        samples_per_channel: u8,
+    ) -> PyResult<Cow<[u8]>> {
+        if start_channel > end_channel {
+            return Err(PyValueError::new_err(format!(
+                "start_channel ({start_channel}) must not be greater than end_channel ({end_channel})"
+            )));
+        }
+        let config = self
+            .inner
+            .get_config()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        let mut hits = Vec::with_capacity(end_channel as usize - start_channel as usize + 1);
+        for channel in start_channel..=end_channel {
+            let [count] = self
+                .inner
+                .scan_channels(&[channel], samples_per_channel)
+                .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+            hits.push(count);
+        }
+        self.inner
+            .flush_rx()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        self.inner
+            .with_config(&config)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        Ok(Cow::from(hits))
+    }
+
+    /// Read `len` bytes from a register, bypassing the driver's cached shadow state.
+    ///
+    /// This is a low-level diagnostic primitive: it performs a single SPI transaction
+    /// and does not consult or update any of this driver's cached shadow state. Useful
+    /// for dumping the full register map for a bug report or driving undocumented
+    /// clone-chip features the typed API does not cover.
+    ///
+    /// Parameters:
+    ///     address: The register's address.
+    ///     len: The number of bytes to read (at most 32).
+    ///
+    /// Returns a tuple of the STATUS byte latched by the transaction and the bytes read.
+    pub fn read_register(&mut self, address: u8, len: u8) -> PyResult<(u8, Vec<u8>)> {
+        let mut buf = vec![0u8; len as usize];
+        let status = self
+            .inner
+            .read_register(address, &mut buf)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        Ok((status, buf))
+    }
+
+    /// Write `buf` to a register, bypassing the driver's cached shadow state.
+    ///
+    /// Unlike the typed setters elsewhere in this class, this does not keep any of this
+    /// class' cached state in sync with the register written. See
+    /// [`RF24.read_register()`][rf24_py.RF24.read_register] for the rationale behind
+    /// exposing this.
+    ///
+    /// Parameters:
+    ///     address: The register's address.
+    ///     buf: The bytes to write.
+    ///
+    /// Returns the STATUS byte latched by the transaction.
+    pub fn write_register(&mut self, address: u8, buf: Vec<u8>) -> PyResult<u8> {
+        self.inner
+            .write_register(address, &buf)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
+    /// Perform a single raw SPI transaction: write `command` followed by `buf`, then
+    /// return the bytes shifted back in over MISO.
+    ///
+    /// Unlike [`RF24.read_register()`][rf24_py.RF24.read_register] and
+    /// [`RF24.write_register()`][rf24_py.RF24.write_register], `command` is sent as-is
+    /// (it is not combined with `W_REGISTER`/`R_REGISTER`), so this can drive any SPI
+    /// command the nRF24L01 (or a clone chip) supports, documented or not.
+    ///
+    /// Parameters:
+    ///     command: The command byte to send.
+    ///     buf: The bytes to send after `command`. Pass an empty buffer for commands
+    ///         (like `NOP`) that take no arguments.
+    ///
+    /// Returns a tuple of the STATUS byte latched by the transaction and the bytes
+    /// shifted back in over MISO.
+    pub fn spi_command(&mut self, command: u8, buf: Vec<u8>) -> PyResult<(u8, Vec<u8>)> {
+        let mut buf = buf;
+        let status = self
+            .inner
+            .spi_command(command, &mut buf)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        Ok((status, buf))
+    }
+
     /// Enable or disable the LNA feature.
     ///
     /// On nRF24L01+ modules with a builtin antenna, this feature is always enabled.
@@ -406,6 +1042,21 @@ impl RF24 {
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
 
+    /// Set the auto-ack feature for all pipes (0-5) in a single SPI transaction.
+    ///
+    /// Note:
+    ///     This feature requires CRC to be enabled.
+    ///     See [`RF24.crc_length`][rf24_py.RF24.crc_length] for more detail.
+    ///
+    /// Parameters:
+    ///     mask: A bitmask in which bits 0-5 map to pipes 0-5; a set bit enables
+    ///         auto-ack for that pipe.
+    pub fn set_auto_ack_bin(&mut self, mask: u8) -> PyResult<()> {
+        self.inner
+            .set_auto_ack_bin(mask)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
     /// Allow disabling the auto-ack feature for individual payloads.
     ///
     /// Parameters:
@@ -470,14 +1121,14 @@ impl RF24 {
     pub fn set_channel(&mut self, channel: u8) -> PyResult<()> {
         self.inner
             .set_channel(channel)
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
     }
 
     #[getter]
     pub fn get_channel(&mut self) -> PyResult<u8> {
         self.inner
             .get_channel()
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
     }
 
     /// Set/get the [`CrcLength`][rf24_py.CrcLength] used for all outgoing and incoming
@@ -490,14 +1141,14 @@ impl RF24 {
     pub fn set_crc_length(&mut self, crc_length: CrcLength) -> PyResult<()> {
         self.inner
             .set_crc_length(crc_length.into_inner())
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
     }
 
     #[getter]
     pub fn get_crc_length(&mut self) -> PyResult<CrcLength> {
         self.inner
             .get_crc_length()
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
             .map(CrcLength::from_inner)
     }
 
@@ -581,6 +1232,33 @@ impl RF24 {
             .map(PaLevel::from_inner)
     }
 
+    /// Set the PA level alongside the LNA (Low Noise Amplifier) gain bit found on
+    /// Si24R1 clone modules.
+    ///
+    /// On genuine nRF24L01(+) silicon this bit is reserved and `lna_enable` has no
+    /// effect; on Si24R1 clones, disabling it shifts the actual dBm output at every
+    /// PA step, so set it to match the module actually in use.
+    ///
+    /// Parameters:
+    ///     pa_level: The Power Amplitude level to use for all transmissions.
+    ///     lna_enable: Whether to assert the Si24R1 LNA gain bit.
+    pub fn set_pa_level_lna(&mut self, pa_level: PaLevel, lna_enable: bool) -> PyResult<()> {
+        self.inner
+            .set_pa_level_lna(pa_level.into_inner(), lna_enable)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
+    /// Get the PA level alongside the state of the Si24R1 LNA gain bit.
+    ///
+    /// Returns a 2-tuple of the [`PaLevel`][rf24_py.PaLevel] and whether the LNA gain
+    /// bit is asserted.
+    pub fn get_pa_level_lna(&mut self) -> PyResult<(PaLevel, bool)> {
+        self.inner
+            .get_pa_level_lna()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map(|(level, lna)| (PaLevel::from_inner(level), lna))
+    }
+
     /// Set/get the statically sized payload length.
     ///
     /// This configuration is not used if dynamic payloads are enabled.
@@ -617,6 +1295,34 @@ impl RF24 {
         self.inner.get_dynamic_payloads()
     }
 
+    /// Enable or disable the dynamically sized payloads feature for a single `pipe`,
+    /// leaving the other pipes' settings untouched.
+    ///
+    /// Unlike [`RF24.dynamic_payloads`][rf24_py.RF24.dynamic_payloads], this allows
+    /// mixing a dynamic-length pipe with statically sized pipes on the same radio.
+    ///
+    /// Parameters:
+    ///     enable: Pass true to enable dynamically sized payloads for the specified `pipe`.
+    ///     pipe: The pipe number to configure. This must be in range [0, 5], otherwise
+    ///         this function does nothing.
+    pub fn set_dynamic_payload_pipe(&mut self, enable: i32, pipe: u8) -> PyResult<()> {
+        self.inner
+            .set_dynamic_payload_pipe(enable != 0, pipe)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
+    /// Set the dynamically sized payloads feature for all pipes (0-5) in a single SPI
+    /// transaction.
+    ///
+    /// Parameters:
+    ///     mask: A bitmask in which bits 0-5 map to pipes 0-5; a set bit enables
+    ///         dynamically sized payloads for that pipe.
+    pub fn set_dynamic_payloads_bin(&mut self, mask: u8) -> PyResult<()> {
+        self.inner
+            .set_dynamic_payloads_bin(mask)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
     /// Get the length of the next available payload in the RX FIFO.
     ///
     /// If dynamically sized payloads are not enabled (via
@@ -642,10 +1348,16 @@ impl RF24 {
     ///     pipe: The pipe number to receive data. This must be in range [0, 5],
     ///         otherwise this function does nothing.
     ///     address: The address to receive data from.
+    ///
+    /// Raises:
+    ///     ValueError: If `address` is empty.
     pub fn open_rx_pipe(&mut self, pipe: u8, address: &[u8]) -> PyResult<()> {
+        if address.is_empty() {
+            return Err(PyValueError::new_err("address must not be empty"));
+        }
         self.inner
             .open_rx_pipe(pipe, address)
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
     }
 
     /// Set the address used for transmitting on pipe 0.
@@ -655,10 +1367,16 @@ impl RF24 {
     ///
     /// Parameters:
     ///     address: The address to receive data from.
+    ///
+    /// Raises:
+    ///     ValueError: If `address` is empty.
     pub fn open_tx_pipe(&mut self, address: &[u8]) -> PyResult<()> {
+        if address.is_empty() {
+            return Err(PyValueError::new_err("address must not be empty"));
+        }
         self.inner
             .open_tx_pipe(address)
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
     }
 
     /// Close the specified pipe from receiving transmissions.
@@ -672,24 +1390,30 @@ impl RF24 {
     pub fn close_rx_pipe(&mut self, pipe: u8) -> PyResult<()> {
         self.inner
             .close_rx_pipe(pipe)
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
     }
 
     /// Set/get the address length (applied to all pipes).
     ///
-    /// The address length is only allowed to be in range [2, 5].
+    /// Raises:
+    ///     ValueError: If `length` is not in range [2, 5].
     #[setter]
     pub fn set_address_length(&mut self, length: u8) -> PyResult<()> {
+        if !(2..=5).contains(&length) {
+            return Err(PyValueError::new_err(format!(
+                "address length must be in range [2, 5], got {length}"
+            )));
+        }
         self.inner
             .set_address_length(length)
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
     }
 
     #[getter]
     pub fn get_address_length(&mut self) -> PyResult<u8> {
         self.inner
             .get_address_length()
-            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
     }
 
     /// Power Up/Down the radio.
@@ -738,6 +1462,67 @@ impl RF24 {
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
 
+    /// Explicitly settle the radio in Standby-I (CE inactive).
+    ///
+    /// This is the lowest standby current draw available while still powered up.
+    /// Re-entering TX or RX mode from here pays the usual CE settling time.
+    pub fn as_standby_i(&mut self) -> PyResult<()> {
+        self.inner
+            .as_standby_i()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
+    /// Explicitly settle the radio in Standby-II (CE active, TX FIFO empty).
+    ///
+    /// This allows sub-millisecond re-transmit latency, at a slightly higher standby
+    /// current draw than Standby-I.
+    pub fn as_standby_ii(&mut self) -> PyResult<()> {
+        self.inner
+            .as_standby_ii()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
+    /// The idle state that [`send()`][rf24_py.RF24.send] settles the radio into after a
+    /// transmission completes.
+    ///
+    /// Defaults to [`FallbackMode.StandbyI`][rf24_py.FallbackMode.StandbyI].
+    #[setter]
+    pub fn set_fallback_mode(&mut self, mode: FallbackMode) {
+        self.inner.set_fallback_mode(mode.into_inner());
+    }
+
+    #[getter]
+    pub fn get_fallback_mode(&self) -> FallbackMode {
+        FallbackMode::from_inner(self.inner.get_fallback_mode())
+    }
+
+    /// Enter a `with RF24(...) as radio:` block by powering the radio up.
+    ///
+    /// See also:
+    ///     [`RF24.power_up()`][rf24_py.RF24.power_up]
+    pub fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyResult<PyRefMut<'_, Self>> {
+        slf.power_up(None)?;
+        Ok(slf)
+    }
+
+    /// Exit a `with RF24(...) as radio:` block by idling the CE pin, flushing both
+    /// FIFOs, and powering the radio down.
+    ///
+    /// This runs even if the `with` block raised an exception, so a script that aborts
+    /// mid-transmission does not leave the radio powered up and transmitting.
+    #[pyo3(signature = (_exc_type = None, _exc_value = None, _traceback = None))]
+    pub fn __exit__(
+        &mut self,
+        _exc_type: Option<Py<PyAny>>,
+        _exc_value: Option<Py<PyAny>>,
+        _traceback: Option<Py<PyAny>>,
+    ) -> PyResult<()> {
+        self.ce_pin(0)?;
+        self.flush_tx()?;
+        self.flush_rx()?;
+        self.power_down()
+    }
+
     /// The driver will delay for this duration (32 bit unsigned int of microseconds)
     /// when [`as_tx()`][rf24_py.RF24.as_tx] is called.
     ///
@@ -781,6 +1566,43 @@ impl RF24 {
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
 
+    /// Mask the individual IRQ sources routed to the `irq_pin`.
+    ///
+    /// This is a convenience wrapper around
+    /// [`RF24.set_status_flags()`][rf24_py.RF24.set_status_flags] for the common case of
+    /// toggling the three interrupt sources individually instead of building a
+    /// [`StatusFlags`][rf24_py.StatusFlags] object.
+    ///
+    /// Parameters:
+    ///     data_ready: Allow the `irq_pin` to assert when RX Data Ready fires.
+    ///     data_sent: Allow the `irq_pin` to assert when TX Data Sent fires.
+    ///     data_fail: Allow the `irq_pin` to assert when TX Data Failed (max retransmits) fires.
+    pub fn interrupt_config(
+        &mut self,
+        data_ready: i32,
+        data_sent: i32,
+        data_fail: i32,
+    ) -> PyResult<()> {
+        self.set_status_flags(Some(StatusFlags {
+            rx_dr: data_ready != 0,
+            tx_ds: data_sent != 0,
+            tx_df: data_fail != 0,
+        }))
+    }
+
+    /// Get the [`StatusFlags`][rf24_py.StatusFlags] that are currently configured
+    /// to assert the IRQ pin.
+    ///
+    /// This is the inverse of [`RF24.set_status_flags()`][rf24_py.RF24.set_status_flags]:
+    /// a `True` member of the returned flags means that event is enabled and will
+    /// trigger the IRQ pin.
+    pub fn get_masked_flags(&mut self) -> PyResult<StatusFlags> {
+        self.inner
+            .get_masked_flags()
+            .map(StatusFlags::from_inner)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
     /// Reset the specified [`StatusFlags`][rf24_py.StatusFlags].
     ///
     /// Other Parameters:
@@ -817,10 +1639,373 @@ impl RF24 {
         StatusFlags::from_inner(flags)
     }
 
+    /// Block until the `irq_pin` (given to the constructor) asserts or `timeout_ms`
+    /// elapses, then return the latched [`StatusFlags`][rf24_py.StatusFlags].
+    ///
+    /// Upon returning the latched flags, this also clears them (see
+    /// [`RF24.clear_status_flags()`][rf24_py.RF24.clear_status_flags]) since the IRQ
+    /// pin stays asserted until they are cleared.
+    ///
+    /// Other parameters:
+    ///     timeout_ms: The maximum amount of time (in milliseconds) to wait for the
+    ///         IRQ pin to assert.
+    ///
+    /// Returns:
+    ///     `None` if `timeout_ms` elapses before the IRQ pin asserts.
+    ///
+    /// Raises:
+    ///     RuntimeError: If no `irq_pin` was given to the constructor.
+    #[pyo3(signature = (timeout_ms = 1000u64))]
+    pub fn wait_for_irq(&mut self, timeout_ms: u64) -> PyResult<Option<StatusFlags>> {
+        let irq_pin = self.irq_pin.as_mut().ok_or_else(|| {
+            PyRuntimeError::new_err("No irq_pin was given to this RF24 object's constructor")
+        })?;
+        let mut fds = [PollFd::new(irq_pin.as_raw_fd(), PollFlags::POLLIN)];
+        let events = poll(&mut fds, timeout_ms as i32)
+            .map_err(|e| PyOSError::new_err(format!("Failed to poll the irq_pin: {e:?}")))?;
+        if events == 0 {
+            return Ok(None);
+        }
+        // consume the event so the next wait_for_irq() call does not return immediately
+        irq_pin.next();
+        self.inner
+            .update()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        let flags = self.get_status_flags();
+        self.inner
+            .clear_status_flags(flags.clone().into_inner())
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        Ok(Some(flags))
+    }
+
+    /// Like [`RF24.wait_for_irq()`][rf24_py.RF24.wait_for_irq], but returns an
+    /// `asyncio`-awaitable instead of blocking.
+    ///
+    /// The poll on `irq_pin` runs on a background thread, so the `asyncio` event
+    /// loop stays responsive while waiting for the radio's IRQ line to assert.
+    ///
+    /// Only available when this package is built with the `asyncio` feature.
+    #[cfg(feature = "asyncio")]
+    #[pyo3(signature = (timeout_ms = 1000u64))]
+    pub fn wait_for_irq_async<'py>(
+        slf: Py<Self>,
+        py: Python<'py>,
+        timeout_ms: u64,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        future_into_py(py, async move {
+            tokio::task::spawn_blocking(move || {
+                Python::with_gil(|py| slf.borrow_mut(py).wait_for_irq(timeout_ms))
+            })
+            .await
+            .map_err(|e| {
+                PyRuntimeError::new_err(format!("wait_for_irq_async task panicked: {e}"))
+            })?
+        })
+    }
+
+    /// Identical to [`RF24.wait_for_irq()`][rf24_py.RF24.wait_for_irq], but when the
+    /// latched flags indicate RX Data Ready, this also returns the pipe number and
+    /// length of the payload now sitting at the front of the RX FIFO.
+    ///
+    /// Other parameters:
+    ///     timeout_ms: The maximum amount of time (in milliseconds) to wait for the
+    ///         IRQ pin to assert.
+    ///
+    /// Returns:
+    ///     `None` if `timeout_ms` elapses before the IRQ pin asserts. Otherwise a tuple
+    ///     of the latched [`StatusFlags`][rf24_py.StatusFlags], the pipe number, and the
+    ///     payload length (the latter two are `None` unless RX Data Ready fired).
+    ///
+    /// Raises:
+    ///     RuntimeError: If no `irq_pin` was given to the constructor.
+    #[pyo3(signature = (timeout_ms = 1000u64))]
+    pub fn wait_for_rx_event(
+        &mut self,
+        timeout_ms: u64,
+    ) -> PyResult<Option<(StatusFlags, Option<u8>, Option<u8>)>> {
+        let Some(flags) = self.wait_for_irq(timeout_ms)? else {
+            return Ok(None);
+        };
+        if !flags.rx_dr {
+            return Ok(Some((flags, None, None)));
+        }
+        let (_, pipe) = self.available_pipe()?;
+        let len = if self.get_dynamic_payloads() {
+            self.get_dynamic_payload_length()?
+        } else {
+            self.get_payload_length()?
+        };
+        Ok(Some((flags, Some(pipe), Some(len))))
+    }
+
+    /// Register `callback` to be invoked (with no arguments) whenever `flag` fires.
+    ///
+    /// Registered callbacks are only dispatched from
+    /// [`RF24.service_irq_events()`][rf24_py.RF24.service_irq_events]; they are not
+    /// invoked automatically in the background.
+    pub fn on_irq(&mut self, flag: IrqEvent, callback: Py<PyAny>) {
+        self.irq_callbacks.push((flag, callback));
+    }
+
+    /// Unregister every callback previously registered (via
+    /// [`RF24.on_irq()`][rf24_py.RF24.on_irq] or its `on_data_*` convenience wrappers)
+    /// for `flag`.
+    ///
+    /// This is the inverse of [`RF24.on_irq()`][rf24_py.RF24.on_irq]. Calling it for a
+    /// `flag` with no registered callbacks is a no-op.
+    pub fn off_irq(&mut self, flag: IrqEvent) {
+        self.irq_callbacks.retain(|(event, _)| *event != flag);
+    }
+
+    /// Register `callback` to be invoked whenever a payload is received (RX Data Ready).
+    ///
+    /// This is a convenience wrapper around
+    /// [`RF24.on_irq()`][rf24_py.RF24.on_irq] for [`IrqEvent.RxDataReady`][rf24_py.IrqEvent.RxDataReady].
+    pub fn on_data_ready(&mut self, callback: Py<PyAny>) {
+        self.on_irq(IrqEvent::RxDataReady, callback);
+    }
+
+    /// Register `callback` to be invoked whenever a transmission is acknowledged (TX Data Sent).
+    ///
+    /// This is a convenience wrapper around
+    /// [`RF24.on_irq()`][rf24_py.RF24.on_irq] for [`IrqEvent.TxDataSent`][rf24_py.IrqEvent.TxDataSent].
+    pub fn on_data_sent(&mut self, callback: Py<PyAny>) {
+        self.on_irq(IrqEvent::TxDataSent, callback);
+    }
+
+    /// Register `callback` to be invoked whenever a transmission exhausts its automatic
+    /// retries without being acknowledged (TX Data Failed).
+    ///
+    /// This is a convenience wrapper around
+    /// [`RF24.on_irq()`][rf24_py.RF24.on_irq] for [`IrqEvent.TxDataFail`][rf24_py.IrqEvent.TxDataFail].
+    pub fn on_data_fail(&mut self, callback: Py<PyAny>) {
+        self.on_irq(IrqEvent::TxDataFail, callback);
+    }
+
+    /// Block for up to `timeout_ms` waiting on the IRQ pin (see
+    /// [`RF24.wait_for_irq()`][rf24_py.RF24.wait_for_irq]), then dispatch any
+    /// callbacks registered via [`RF24.on_irq()`][rf24_py.RF24.on_irq] whose flag
+    /// is set in the latched [`StatusFlags`][rf24_py.StatusFlags].
+    ///
+    /// Other parameters:
+    ///     timeout_ms: The maximum amount of time (in milliseconds) to wait for the
+    ///         IRQ pin to assert.
+    ///
+    /// Returns:
+    ///     `None` if `timeout_ms` elapses before the IRQ pin asserts, otherwise the
+    ///     latched [`StatusFlags`][rf24_py.StatusFlags] that were dispatched.
+    ///
+    /// Raises:
+    ///     RuntimeError: If no `irq_pin` was given to the constructor.
+    #[pyo3(signature = (timeout_ms = 1000u64))]
+    pub fn service_irq_events(&mut self, timeout_ms: u64) -> PyResult<Option<StatusFlags>> {
+        let Some(flags) = self.wait_for_irq(timeout_ms)? else {
+            return Ok(None);
+        };
+        Python::with_gil(|py| -> PyResult<()> {
+            for (event, callback) in &self.irq_callbacks {
+                if event.matches(&flags) {
+                    callback.call0(py)?;
+                }
+            }
+            Ok(())
+        })?;
+        Ok(Some(flags))
+    }
+
     /// Print helpful debug information to stdout.
+    ///
+    /// See also:
+    ///     [`RF24.get_details()`][rf24_py.RF24.get_details] for the same information as a
+    ///     structured object, useful for logging or asserting configuration in tests.
     pub fn print_details(&mut self) -> PyResult<()> {
         self.inner
             .print_details()
             .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
     }
+
+    /// Get the radio's current configuration as a structured object.
+    ///
+    /// Unlike [`print_details()`][rf24_py.RF24.print_details], this does not
+    /// print anything; it is meant for logging, GUIs, or automated diagnostics
+    /// (e.g. asserting radio configuration in tests or serializing to JSON).
+    pub fn get_details(&mut self) -> PyResult<RadioDetails> {
+        self.inner
+            .get_details()
+            .map(RadioDetails::from_inner)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))
+    }
+
+    /// Enable or disable link-quality statistics tracking.
+    ///
+    /// While enabled, every call to [`RF24.send()`][rf24_py.RF24.send] counts towards
+    /// the packets sent/acknowledged/failed and automatic retry totals that
+    /// [`RF24.get_link_stats()`][rf24_py.RF24.get_link_stats] derives its metrics from.
+    /// Setting this to `True` resets any previously accumulated counters.
+    #[setter]
+    pub fn set_link_stats_enabled(&mut self, enable: i32) {
+        self.link_stats = if enable != 0 {
+            Some(LinkStatsCounters::new())
+        } else {
+            None
+        };
+    }
+
+    #[getter]
+    pub fn get_link_stats_enabled(&self) -> bool {
+        self.link_stats.is_some()
+    }
+
+    /// Get the [`LinkStats`][rf24_py.LinkStats] accumulated since
+    /// [`RF24.link_stats_enabled`][rf24_py.RF24.link_stats_enabled] was last turned on.
+    ///
+    /// Raises:
+    ///     RuntimeError: If [`RF24.link_stats_enabled`][rf24_py.RF24.link_stats_enabled]
+    ///         is not currently `True`.
+    pub fn get_link_stats(&self) -> PyResult<LinkStats> {
+        let stats = self.link_stats.as_ref().ok_or_else(|| {
+            PyRuntimeError::new_err(
+                "link_stats_enabled must be set to True before calling get_link_stats()",
+            )
+        })?;
+        let delivery_ratio = if stats.packets_sent > 0 {
+            stats.acked as f32 / stats.packets_sent as f32
+        } else {
+            0.0
+        };
+        let retries_per_packet = if stats.packets_sent > 0 {
+            stats.retries_total as f32 / stats.packets_sent as f32
+        } else {
+            0.0
+        };
+        let elapsed = stats.started.elapsed().as_secs_f32();
+        let goodput_bps = if elapsed > 0.0 {
+            (stats.bytes_acked as f32 * 8.0) / elapsed
+        } else {
+            0.0
+        };
+        Ok(LinkStats {
+            packets_sent: stats.packets_sent,
+            delivery_ratio,
+            retries_per_packet,
+            goodput_bps,
+        })
+    }
+
+    /// Snapshot the radio's channel, data rate/PA level, CRC length, address length,
+    /// auto-ack mask, dynamic-payload mask, ACK-payload feature bits, static payload
+    /// length, and TX/pipe-0 RX addresses into an ordered `{register: hex value}` map.
+    ///
+    /// The returned dict can be serialized as `config.txt`-style `key=value` lines and
+    /// later handed to [`RF24.load_config()`][rf24_py.RF24.load_config] to provision
+    /// another radio with the same configuration.
+    pub fn dump_config(&mut self) -> PyResult<BTreeMap<String, String>> {
+        let mut blob = [0u8; RADIO_CONFIG_BLOB_LEN];
+        self.inner
+            .save_config(&mut blob)
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))?;
+        Ok(CONFIG_KEYS
+            .iter()
+            .enumerate()
+            .map(|(index, key)| (key.to_string(), hex_encode(config_blob_field(&blob, index))))
+            .collect())
+    }
+
+    /// Apply a map produced by [`RF24.dump_config()`][rf24_py.RF24.dump_config] (or a
+    /// hand-written subset of it) to the radio. Registers are written in a fixed,
+    /// dependency-correct order (e.g. `feature` is written before the
+    /// `dynpd`/`rx_pw_p0` state it gates), whatever the order of the keys in `config`.
+    ///
+    /// Keys absent from `config` are left at whatever value is currently on the radio,
+    /// so a caller can provision only the registers they care about. An empty `config`
+    /// is a no-op.
+    ///
+    /// Raises:
+    ///     ValueError: If `config` has a key that is not one of the keys
+    ///         [`RF24.dump_config()`][rf24_py.RF24.dump_config] produces, or a value that
+    ///         is not valid hex of the expected length for that key.
+    pub fn load_config(&mut self, config: BTreeMap<String, String>) -> PyResult<()> {
+        let mut blob = [0u8; RADIO_CONFIG_BLOB_LEN];
+        self.inner
+            .save_config(&mut blob)
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))?;
+        for (key, value) in &config {
+            let index = CONFIG_KEYS
+                .iter()
+                .position(|candidate| candidate == key)
+                .ok_or_else(|| PyValueError::new_err(format!("unknown config key \"{key}\"")))?;
+            let decoded = hex_decode(value, key)?;
+            let field = config_blob_field_mut(&mut blob, index);
+            if decoded.len() != field.len() {
+                return Err(PyValueError::new_err(format!(
+                    "value for \"{key}\" must be {} hex bytes, got {}",
+                    field.len(),
+                    decoded.len()
+                )));
+            }
+            field.copy_from_slice(&decoded);
+        }
+        self.inner
+            .load_config(&blob)
+            .map_err(|e| RadioError::new_err(format!("{e:?}")))
+    }
+}
+
+impl RF24 {
+    /// Update the link-quality counters (if enabled) after a call to [`RF24::send()`].
+    fn record_link_stats(&mut self, acked: bool, len: usize) -> PyResult<()> {
+        if self.link_stats.is_none() {
+            return Ok(());
+        }
+        let arc = self
+            .inner
+            .get_last_arc()
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        let stats = self
+            .link_stats
+            .as_mut()
+            .expect("link_stats was just checked to be Some");
+        stats.packets_sent += 1;
+        stats.retries_total += arc as u32;
+        if acked {
+            stats.acked += 1;
+            stats.bytes_acked += len as u64;
+        }
+        Ok(())
+    }
+
+    /// Shared polling loop for [`RF24::read_with_timeout()`] and [`RF24::read_blocking()`].
+    fn read_until_deadline(
+        &mut self,
+        deadline: Instant,
+        len: Option<u8>,
+    ) -> PyResult<Option<Cow<[u8]>>> {
+        if !self.inner.is_rx() {
+            self.inner
+                .as_rx()
+                .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        }
+        loop {
+            if self
+                .inner
+                .available()
+                .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?
+            {
+                let len = self
+                    .inner
+                    .read(&mut self.read_buf, len)
+                    .map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+                return Ok(Some(Cow::from(&self.read_buf[0..len as usize])));
+            }
+            if Instant::now() >= deadline {
+                return Ok(None);
+            }
+            clock_nanosleep(
+                ClockId::CLOCK_MONOTONIC,
+                ClockNanosleepFlags::empty(),
+                &TimeSpec::from_duration(Duration::from_millis(1)),
+            )
+            .map_err(|e| PyOSError::new_err(format!("{e:?}")))?;
+        }
+    }
 }
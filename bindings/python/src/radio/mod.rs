@@ -0,0 +1,4 @@
+pub mod config;
+pub mod error;
+pub mod interface;
+pub mod types;
@@ -203,3 +203,202 @@ impl FifoState {
         }
     }
 }
+
+/// The coarse operating state of the radio, as reported by
+/// [`RF24.get_state()`][rf24_py.RF24.get_state].
+///
+/// Attributes:
+///     PowerDown: The radio is powered down (asleep). This is the lowest power consumption state.
+///     StandbyI: The radio is powered up but neither transmitting nor receiving.
+///     TxMode: The radio is powered up and actively transmitting (or about to) payloads.
+///     RxMode: The radio is powered up and actively listening for incoming payloads.
+#[pyclass(eq, eq_int, module = "rf24_py")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RadioState {
+    PowerDown,
+    StandbyI,
+    TxMode,
+    RxMode,
+}
+
+impl RadioState {
+    pub fn from_inner(other: rf24::RadioState) -> RadioState {
+        match other {
+            rf24::RadioState::PowerDown => RadioState::PowerDown,
+            rf24::RadioState::StandbyI => RadioState::StandbyI,
+            rf24::RadioState::TxMode => RadioState::TxMode,
+            rf24::RadioState::RxMode => RadioState::RxMode,
+        }
+    }
+}
+
+/// The idle state that [`RF24.send()`][rf24_py.RF24.send] settles the radio into after
+/// a transmission completes.
+///
+/// Attributes:
+///     StandbyI: The lowest power standby state (CE inactive). This is the default.
+///     StandbyII: A standby state (CE active) that allows faster re-transmission.
+#[pyclass(eq, eq_int, module = "rf24_py")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FallbackMode {
+    StandbyI,
+    StandbyII,
+}
+
+impl FallbackMode {
+    pub fn into_inner(self) -> rf24::FallbackMode {
+        match self {
+            FallbackMode::StandbyI => rf24::FallbackMode::StandbyI,
+            FallbackMode::StandbyII => rf24::FallbackMode::StandbyII,
+        }
+    }
+    pub fn from_inner(other: rf24::FallbackMode) -> FallbackMode {
+        match other {
+            rf24::FallbackMode::StandbyI => FallbackMode::StandbyI,
+            rf24::FallbackMode::StandbyII => FallbackMode::StandbyII,
+        }
+    }
+}
+
+/// An IRQ event that [`RF24.on_irq()`][rf24_py.RF24.on_irq] can dispatch a callback for.
+///
+/// Attributes:
+///     RxDataReady: Dispatched when [`StatusFlags.rx_dr`][rf24_py.StatusFlags.rx_dr] is set.
+///     TxDataSent: Dispatched when [`StatusFlags.tx_ds`][rf24_py.StatusFlags.tx_ds] is set.
+///     TxDataFail: Dispatched when [`StatusFlags.tx_df`][rf24_py.StatusFlags.tx_df] is set.
+#[pyclass(eq, eq_int, module = "rf24_py")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IrqEvent {
+    RxDataReady,
+    TxDataSent,
+    TxDataFail,
+}
+
+impl IrqEvent {
+    /// Is this event among the ones latched in `flags`?
+    pub fn matches(&self, flags: &StatusFlags) -> bool {
+        match self {
+            IrqEvent::RxDataReady => flags.rx_dr,
+            IrqEvent::TxDataSent => flags.tx_ds,
+            IrqEvent::TxDataFail => flags.tx_df,
+        }
+    }
+}
+
+/// A snapshot of link-quality telemetry gathered since
+/// [`RF24.link_stats_enabled`][rf24_py.RF24.link_stats_enabled] was last turned on.
+///
+/// See [`RF24.get_link_stats()`][rf24_py.RF24.get_link_stats].
+#[pyclass(frozen, get_all, module = "rf24_py")]
+#[derive(Clone, Default)]
+pub struct LinkStats {
+    /// The total number of packets sent via [`RF24.send()`][rf24_py.RF24.send]
+    /// while tracking was enabled.
+    pub packets_sent: u32,
+    /// The fraction (in range `[0.0, 1.0]`) of sent packets that were acknowledged.
+    pub delivery_ratio: f32,
+    /// The average number of automatic retries (per the `ARC_CNT` observed
+    /// immediately after each transmission) spent per packet.
+    pub retries_per_packet: f32,
+    /// A rolling estimate (in bits per second) of the throughput of acknowledged
+    /// payloads, averaged over the time since tracking was enabled.
+    pub goodput_bps: f32,
+}
+
+/// A structured snapshot of the radio's decoded register state.
+///
+/// See [`RF24.get_details()`][rf24_py.RF24.get_details].
+#[pyclass(frozen, get_all, module = "rf24_py")]
+#[derive(Clone)]
+pub struct RadioDetails {
+    /// Is the radio module a nRF24L01+ (as opposed to a non-plus variant)?
+    pub is_plus_variant: bool,
+    /// The radio's current RF channel, in range `[0, 125]`.
+    pub channel: u8,
+    /// The radio's current over-the-air data rate.
+    pub data_rate: DataRate,
+    /// The radio's current Power Amplifier level.
+    pub pa_level: PaLevel,
+    /// Is the radio's Low Noise Amplifier (LNA) feature currently enabled?
+    pub lna_enabled: bool,
+    /// The radio's current CRC encoding scheme.
+    pub crc_length: CrcLength,
+    /// The number of bytes used for on-air addresses, in range `[2, 5]`.
+    pub address_length: u8,
+    /// The number of bytes used for statically sized payloads.
+    pub payload_length: u8,
+    /// A bit mask (pipes `0` - `5`) of which pipes have dynamic payloads enabled.
+    pub dynamic_payloads: u8,
+    /// A bit mask (pipes `0` - `5`) of which pipes have auto-ack enabled.
+    pub auto_ack: u8,
+    /// Are ACK payloads currently enabled?
+    pub ack_payloads_enabled: bool,
+    /// Is the `NO_ACK` flag honored for payloads that request it?
+    pub ask_no_ack_enabled: bool,
+    /// A bit mask (pipes `0` - `5`) of which RX pipes are currently open.
+    pub open_rx_pipes: u8,
+    /// Is the radio currently powered up?
+    pub is_powered: bool,
+    /// Is the radio currently configured for RX mode (as opposed to TX mode)?
+    pub is_rx: bool,
+    /// The address used for transmissions.
+    pub tx_address: Vec<u8>,
+    /// The addresses bound to RX pipes `0` - `5`.
+    pub rx_addresses: Vec<Vec<u8>>,
+    /// The most recently cached IRQ status flags (the latched bits from the `STATUS`
+    /// register).
+    pub status_flags: StatusFlags,
+    /// Is the "RX Data Ready" IRQ event currently unmasked (enabled)?
+    pub irq_rx_dr_enabled: bool,
+    /// Is the "TX Data Sent" IRQ event currently unmasked (enabled)?
+    pub irq_tx_ds_enabled: bool,
+    /// Is the "TX Data Fail" IRQ event currently unmasked (enabled)?
+    pub irq_tx_df_enabled: bool,
+    /// The current state of the TX FIFO.
+    pub tx_fifo: FifoState,
+    /// The current state of the RX FIFO.
+    pub rx_fifo: FifoState,
+    /// Will the radio re-transmit the last TX FIFO payload the next time it enters TX mode?
+    pub reuse_tx: bool,
+    /// The delay (in microseconds) awaited after transmitting, allowing time for the
+    /// radio to receive (and this class to wait for) an ACK packet.
+    pub tx_delay: u32,
+    /// The count of lost packets (PLOS) since the last time the radio's channel was set.
+    pub packets_lost: u8,
+    /// The Auto-Retry Count (ARC) about the previous transmission.
+    pub retry_count: u8,
+}
+
+impl RadioDetails {
+    pub fn from_inner(other: rf24::RadioDetails) -> Self {
+        Self {
+            is_plus_variant: other.is_plus_variant,
+            channel: other.channel,
+            data_rate: DataRate::from_inner(other.data_rate),
+            pa_level: PaLevel::from_inner(other.pa_level),
+            lna_enabled: other.lna_enabled,
+            crc_length: CrcLength::from_inner(other.crc_length),
+            address_length: other.address_length,
+            payload_length: other.payload_length,
+            dynamic_payloads: other.dynamic_payloads,
+            auto_ack: other.auto_ack,
+            ack_payloads_enabled: other.ack_payloads_enabled,
+            ask_no_ack_enabled: other.ask_no_ack_enabled,
+            open_rx_pipes: other.open_rx_pipes,
+            is_powered: other.is_powered,
+            is_rx: other.is_rx,
+            tx_address: other.tx_address.to_vec(),
+            rx_addresses: other.rx_addresses.iter().map(|a| a.to_vec()).collect(),
+            status_flags: StatusFlags::from_inner(other.status_flags),
+            irq_rx_dr_enabled: other.irq_rx_dr_enabled,
+            irq_tx_ds_enabled: other.irq_tx_ds_enabled,
+            irq_tx_df_enabled: other.irq_tx_df_enabled,
+            tx_fifo: FifoState::from_inner(other.tx_fifo),
+            rx_fifo: FifoState::from_inner(other.rx_fifo),
+            reuse_tx: other.reuse_tx,
+            tx_delay: other.tx_delay,
+            packets_lost: other.packets_lost,
+            retry_count: other.retry_count,
+        }
+    }
+}
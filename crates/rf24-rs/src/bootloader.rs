@@ -0,0 +1,292 @@
+//! Over-the-air firmware update support, layered on the [`EsbAutoAck`](crate::radio::prelude::EsbAutoAck)
+//! ACK payload feature.
+//!
+//! A sender splits a firmware image into fixed-size [`CHUNK_DATA_LEN`]-byte chunks, each
+//! prefixed with a 2-byte sequence number, and transmits them in order with
+//! [`EsbRadio::send()`](crate::radio::prelude::EsbRadio::send). The receiver piggy-backs
+//! its progress (the sequence number of the next chunk it expects, one past the last
+//! contiguous chunk it has accepted, plus a running
+//! CRC16 of the bytes accepted so far) on the auto-ack reply via
+//! [`EsbAutoAck::write_ack_payload()`](crate::radio::prelude::EsbAutoAck::write_ack_payload),
+//! so the sender can detect gaps and retransmit without a separate reply channel.
+//!
+//! A [`Handshake`](Frame::Handshake) frame (carrying the image's total size) precedes the chunk stream, and
+//! a [`Verify`](Frame::Verify) frame (carrying the sender's CRC16 of the whole image)
+//! follows it. [`FirmwareReceiver`] drops any chunk that does not extend its contiguous
+//! run, and only calls [`FirmwareSink::finalize()`] once the image size and CRC both
+//! check out, so a transmission that is interrupted or corrupted never leaves a
+//! half-written image eligible to boot.
+//!
+//! This module is only compiled with the `bootloader` feature enabled.
+#![cfg(feature = "bootloader")]
+
+/// The number of firmware bytes carried per chunk frame (2 bytes of sequence number are
+/// added on top of this, keeping each frame within the nRF24L01's 32-byte payload limit).
+pub const CHUNK_DATA_LEN: usize = 28;
+
+/// The on-air size (in bytes) of a [`Frame::Chunk`] frame.
+pub const CHUNK_FRAME_LEN: usize = CHUNK_DATA_LEN + 2;
+
+fn crc16_update(mut crc: u16, data: &[u8]) -> u16 {
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Computes the CRC16-CCITT (polynomial `0x1021`, initial value `0xFFFF`) of `data`.
+pub fn crc16(data: &[u8]) -> u16 {
+    crc16_update(0xFFFF, data)
+}
+
+/// A frame exchanged between [`FirmwareSender`] and [`FirmwareReceiver`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Frame<'a> {
+    /// Precedes the chunk stream. Carries the total size (in bytes) of the image.
+    Handshake {
+        /// The total number of bytes in the firmware image.
+        image_size: u32,
+    },
+    /// One chunk of the firmware image.
+    Chunk {
+        /// This chunk's position in the image, in units of [`CHUNK_DATA_LEN`] bytes.
+        sequence: u16,
+        /// This chunk's firmware bytes. Only the final chunk may be shorter than
+        /// [`CHUNK_DATA_LEN`].
+        data: &'a [u8],
+    },
+    /// Follows the chunk stream. Carries the sender's [`crc16()`] of the whole image.
+    Verify {
+        /// The CRC16 of the entire firmware image, as computed by the sender.
+        crc: u16,
+    },
+}
+
+/// A backend that a [`FirmwareReceiver`] commits accepted firmware bytes to.
+///
+/// `PAGE_SIZE` is the erase/write granularity of the backing storage (e.g. a flash
+/// sector); [`FirmwareReceiver`] buffers chunks until a full page is available before
+/// calling [`FirmwareSink::write_page()`].
+pub trait FirmwareSink<const PAGE_SIZE: usize> {
+    /// An error specific to this storage backend.
+    type Error;
+
+    /// Erase the page at `page_index` (in units of `PAGE_SIZE` bytes) in preparation
+    /// for [`FirmwareSink::write_page()`].
+    fn erase_page(&mut self, page_index: u32) -> Result<(), Self::Error>;
+
+    /// Write a full page of firmware bytes to `page_index` (in units of `PAGE_SIZE`
+    /// bytes). The page has already been erased with [`FirmwareSink::erase_page()`].
+    fn write_page(&mut self, page_index: u32, page: &[u8; PAGE_SIZE]) -> Result<(), Self::Error>;
+
+    /// Commit the written pages as the new firmware image, e.g. by updating a boot
+    /// descriptor. Only called by [`FirmwareReceiver::verify()`] once the image size
+    /// and CRC16 have both been confirmed.
+    fn finalize(&mut self) -> Result<(), Self::Error>;
+}
+
+/// The lifecycle stage of a [`FirmwareReceiver`], queryable via
+/// [`FirmwareReceiver::state()`].
+///
+/// Mirrors the "swap then self-verify" pattern common to bootloader updaters: a
+/// caller can hold off acting on a transfer (e.g. rebooting into the new image) until
+/// it observes [`TransferState::Verified`], rather than trusting [`Frame::Chunk`]
+/// delivery alone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferState {
+    /// No chunks have been accepted yet.
+    Idle,
+    /// At least one chunk has been accepted, but not all of the handshake's
+    /// advertised image size.
+    Receiving,
+    /// Every byte of the advertised image size has been accepted, but
+    /// [`FirmwareReceiver::verify()`] has not yet been called.
+    Complete,
+    /// [`FirmwareReceiver::verify()`] succeeded: the CRC16 matched and
+    /// [`FirmwareSink::finalize()`] was called.
+    Verified,
+    /// [`FirmwareReceiver::verify()`] was called but the CRC16 did not match;
+    /// [`FirmwareSink::finalize()`] was *not* called.
+    Failed,
+}
+
+/// An error returned while accepting chunks or verifying a transfer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransferError<E> {
+    /// [`FirmwareSink::erase_page()`], [`FirmwareSink::write_page()`], or
+    /// [`FirmwareSink::finalize()`] failed.
+    Sink(E),
+    /// [`FirmwareReceiver::verify()`] was called before every chunk of the handshake's
+    /// advertised image size was accepted.
+    Incomplete,
+    /// The sender's [`Frame::Verify`] CRC16 did not match the bytes actually accepted.
+    CrcMismatch,
+}
+
+/// Splits a firmware `image` into chunks and builds the frames to transmit it.
+pub struct FirmwareSender<'a> {
+    image: &'a [u8],
+}
+
+impl<'a> FirmwareSender<'a> {
+    /// Create a sender for the given firmware `image`.
+    pub fn new(image: &'a [u8]) -> Self {
+        Self { image }
+    }
+
+    /// The [`Frame::Handshake`] frame to send before the first chunk.
+    pub fn handshake(&self) -> Frame<'static> {
+        Frame::Handshake {
+            image_size: self.image.len() as u32,
+        }
+    }
+
+    /// The number of chunks in this transfer.
+    pub fn chunk_count(&self) -> u16 {
+        self.image.len().div_ceil(CHUNK_DATA_LEN) as u16
+    }
+
+    /// The [`Frame::Chunk`] frame for the given `sequence` number, or `None` if
+    /// `sequence` is out of range.
+    pub fn chunk(&self, sequence: u16) -> Option<Frame<'_>> {
+        let start = sequence as usize * CHUNK_DATA_LEN;
+        if start >= self.image.len() {
+            return None;
+        }
+        let end = (start + CHUNK_DATA_LEN).min(self.image.len());
+        Some(Frame::Chunk {
+            sequence,
+            data: &self.image[start..end],
+        })
+    }
+
+    /// The [`Frame::Verify`] frame to send after the last chunk.
+    pub fn verify(&self) -> Frame<'static> {
+        Frame::Verify {
+            crc: crc16(self.image),
+        }
+    }
+}
+
+/// Accepts a firmware image from a [`FirmwareSender`], buffering it into
+/// `PAGE_SIZE`-byte pages before committing each page to `sink`.
+pub struct FirmwareReceiver<S, const PAGE_SIZE: usize> {
+    sink: S,
+    image_size: u32,
+    bytes_accepted: u32,
+    crc: u16,
+    page_buf: [u8; PAGE_SIZE],
+    page_index: u32,
+    state: TransferState,
+}
+
+impl<S, E, const PAGE_SIZE: usize> FirmwareReceiver<S, PAGE_SIZE>
+where
+    S: FirmwareSink<PAGE_SIZE, Error = E>,
+{
+    /// Start a new transfer for an image of `image_size` bytes, as advertised by the
+    /// sender's [`Frame::Handshake`].
+    pub fn new(sink: S, image_size: u32) -> Self {
+        Self {
+            sink,
+            image_size,
+            bytes_accepted: 0,
+            crc: 0xFFFF,
+            page_buf: [0u8; PAGE_SIZE],
+            page_index: 0,
+            state: TransferState::Idle,
+        }
+    }
+
+    /// This transfer's current lifecycle stage.
+    pub fn state(&self) -> TransferState {
+        self.state
+    }
+
+    /// The sequence number of the next chunk expected, i.e. the count of contiguous
+    /// chunks accepted so far (one past the last accepted chunk's sequence number).
+    ///
+    /// This is the value to piggy-back in the auto-ack reply
+    /// (via [`EsbAutoAck::write_ack_payload()`](crate::radio::prelude::EsbAutoAck::write_ack_payload))
+    /// so the sender knows where to resume.
+    pub fn last_contiguous_sequence(&self) -> u16 {
+        (self.bytes_accepted / CHUNK_DATA_LEN as u32) as u16
+    }
+
+    /// The running CRC16 of the bytes accepted so far, to piggy-back alongside
+    /// [`FirmwareReceiver::last_contiguous_sequence()`].
+    pub fn running_crc(&self) -> u16 {
+        self.crc
+    }
+
+    /// Accept one chunk, dropping it if it does not extend the contiguous run (e.g. a
+    /// retransmitted duplicate, or one that arrived out of order).
+    pub fn accept_chunk(
+        &mut self,
+        sequence: u16,
+        data: &[u8],
+    ) -> Result<(), TransferError<E>> {
+        if sequence != self.last_contiguous_sequence() {
+            // Out of order (or a duplicate of an already-accepted chunk): drop it. The
+            // sender will retransmit starting from `last_contiguous_sequence()`.
+            return Ok(());
+        }
+        self.crc = crc16_update(self.crc, data);
+        self.bytes_accepted += data.len() as u32;
+        self.state = if self.bytes_accepted >= self.image_size {
+            TransferState::Complete
+        } else {
+            TransferState::Receiving
+        };
+
+        let mut offset = 0;
+        while offset < data.len() {
+            let page_offset = (self.bytes_accepted as usize - (data.len() - offset)) % PAGE_SIZE;
+            let take = (PAGE_SIZE - page_offset).min(data.len() - offset);
+            self.page_buf[page_offset..page_offset + take]
+                .copy_from_slice(&data[offset..offset + take]);
+            offset += take;
+            let page_full = page_offset + take == PAGE_SIZE;
+            let last_chunk = self.bytes_accepted >= self.image_size;
+            if page_full || last_chunk {
+                self.sink
+                    .erase_page(self.page_index)
+                    .map_err(TransferError::Sink)?;
+                self.sink
+                    .write_page(self.page_index, &self.page_buf)
+                    .map_err(TransferError::Sink)?;
+                self.page_index += 1;
+                self.page_buf = [0u8; PAGE_SIZE];
+            }
+        }
+        Ok(())
+    }
+
+    /// Confirm the transfer against the sender's [`Frame::Verify`] CRC16, committing
+    /// the image via [`FirmwareSink::finalize()`] only if it matches.
+    ///
+    /// Returns [`TransferError::Incomplete`] if fewer bytes than the handshake's
+    /// advertised image size have been accepted, or [`TransferError::CrcMismatch`] if
+    /// the CRC does not match; in either case
+    /// [`FirmwareSink::finalize()`] is *not* called, so a half-written image is never
+    /// eligible to boot.
+    pub fn verify(&mut self, remote_crc: u16) -> Result<(), TransferError<E>> {
+        if self.bytes_accepted < self.image_size {
+            return Err(TransferError::Incomplete);
+        }
+        if self.crc != remote_crc {
+            self.state = TransferState::Failed;
+            return Err(TransferError::CrcMismatch);
+        }
+        self.sink.finalize().map_err(TransferError::Sink)?;
+        self.state = TransferState::Verified;
+        Ok(())
+    }
+}
@@ -58,6 +58,7 @@
 //! - [`RF24::set_pa_level()`](radio/struct.RF24.html#method.set_pa_level)
 //! - [`RF24::get_pa_level()`](radio/struct.RF24.html#method.get_pa_level)
 //! - [`RF24::set_lna()`](fn@crate::radio::RF24::set_lna)
+//! - [`RF24::get_lna()`](fn@crate::radio::RF24::get_lna)
 //! - [`RF24::set_crc_length()`](radio/struct.RF24.html#method.set_crc_length)
 //! - [`RF24::get_crc_length()`](radio/struct.RF24.html#method.get_crc_length)
 //! - [`RF24::is_powered()`](radio/struct.RF24.html#method.is_powered)
@@ -69,8 +70,15 @@
 #![no_std]
 
 mod types;
-pub use types::{CrcLength, DataRate, FifoState, PaLevel, StatusFlags};
+pub use types::{
+    ByteOrder, CrcLength, DataRate, FallbackMode, FifoState, PaLevel, RadioDetails, RadioState,
+    RadioStats, SendOutcome, StatusFlags,
+};
 pub mod radio;
+pub mod transport;
+
+#[cfg(feature = "bootloader")]
+pub mod bootloader;
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,239 @@
+use crate::{radio::RadioConfig, DataRate, PaLevel};
+
+/// A closed-loop controller that nudges a [`RadioConfig`]'s [`PaLevel`] and
+/// [`DataRate`] in response to observed auto-retry counts, borrowing the idea behind
+/// LoRaWAN's Adaptive Data Rate (ADR).
+///
+/// [`AutoTune::record_tx()`] is fed the outcome of every transmission. It keeps a
+/// sliding window of the last `N` outcomes; once that window is full (and at least
+/// [`AutoTune::new()`]'s `hysteresis` outcomes have been observed since the last
+/// adjustment), it compares the window's average retry count against the configured
+/// thresholds:
+/// - above the upper threshold (or any fully failed send, immediately, regardless of
+///   the window or hysteresis) steps [`RadioConfig::pa_level()`] up one level, or --
+///   once already at [`PaLevel::Max`] -- steps [`RadioConfig::data_rate()`] down
+///   (`Mbps2` -> `Mbps1` -> `Kbps250`) to trade throughput for range.
+/// - below the lower threshold for the whole window steps [`RadioConfig::data_rate()`]
+///   back up, or -- once already at `Mbps2` -- trims [`RadioConfig::pa_level()`] down
+///   to save power.
+///
+/// Each adjustment resets the hysteresis counter, so a caller only needs to re-apply
+/// the [`RadioConfig`] returned from [`AutoTune::record_tx()`] when it is [`Some`].
+pub struct AutoTune<const N: usize> {
+    config: RadioConfig,
+    upper_threshold: u8,
+    lower_threshold: u8,
+    hysteresis: u8,
+    window: [u8; N],
+    index: usize,
+    filled: usize,
+    since_change: u16,
+}
+
+impl<const N: usize> AutoTune<N> {
+    /// Start tuning from `config`, using a sliding window of the last `N` outcomes.
+    ///
+    /// A step is only considered once `hysteresis` outcomes have been recorded since
+    /// the last adjustment (and the window is full), to damp oscillation between two
+    /// settings. `upper_threshold` and `lower_threshold` are compared against the
+    /// window's average retry count (see [`AutoTune`] for the full rule set).
+    pub fn new(
+        config: RadioConfig,
+        upper_threshold: u8,
+        lower_threshold: u8,
+        hysteresis: u8,
+    ) -> Self {
+        Self {
+            config,
+            upper_threshold,
+            lower_threshold,
+            hysteresis,
+            window: [0u8; N],
+            index: 0,
+            filled: 0,
+            since_change: 0,
+        }
+    }
+
+    /// The configuration as last adjusted by [`AutoTune::record_tx()`].
+    pub const fn config(&self) -> &RadioConfig {
+        &self.config
+    }
+
+    /// Record the outcome of a single transmission: the observed auto-retry count on
+    /// success, or `success: false` for a fully failed send (the radio exhausted its
+    /// auto-retries without an ACK).
+    ///
+    /// Returns the new [`RadioConfig`] to re-apply (e.g. via
+    /// [`EsbInit::with_config()`](fn@crate::radio::prelude::EsbInit::with_config)) only
+    /// when an adjustment was warranted; `None` otherwise.
+    pub fn record_tx(&mut self, retries: u8, success: bool) -> Option<RadioConfig> {
+        if !success {
+            return self.step_up();
+        }
+        if N == 0 {
+            return None;
+        }
+        self.window[self.index] = retries;
+        self.index = (self.index + 1) % N;
+        self.filled = (self.filled + 1).min(N);
+        self.since_change = self.since_change.saturating_add(1);
+        if self.filled < N || (self.since_change as usize) < self.hysteresis as usize {
+            return None;
+        }
+        let average = (self.window.iter().map(|&r| r as u32).sum::<u32>() / N as u32) as u8;
+        if average > self.upper_threshold {
+            self.step_up()
+        } else if average < self.lower_threshold {
+            self.step_down()
+        } else {
+            None
+        }
+    }
+
+    /// Step power up one level, or (once already at [`PaLevel::Max`]) step the data
+    /// rate down. Returns `None` if already at the most robust setting (`Max` PA level
+    /// and `Kbps250` data rate), since there is nothing left to trade for link quality.
+    fn step_up(&mut self) -> Option<RadioConfig> {
+        let level = self.config.pa_level();
+        if level != PaLevel::Max {
+            self.apply(self.config.with_pa_level(step_pa_up(level)))
+        } else {
+            step_rate_down(self.config.data_rate()).and_then(|rate| {
+                let config = self.config.with_data_rate(rate);
+                self.apply(config)
+            })
+        }
+    }
+
+    /// Step the data rate up one level, or (once already at [`DataRate::Mbps2`]) trim
+    /// power down one level. Returns `None` if already at the most frugal setting
+    /// (`Mbps2` data rate and `Min` PA level), since there is nothing left to save.
+    fn step_down(&mut self) -> Option<RadioConfig> {
+        match step_rate_up(self.config.data_rate()) {
+            Some(rate) => self.apply(self.config.with_data_rate(rate)),
+            None => step_pa_down(self.config.pa_level())
+                .and_then(|level| self.apply(self.config.with_pa_level(level))),
+        }
+    }
+
+    fn apply(&mut self, config: RadioConfig) -> Option<RadioConfig> {
+        self.config = config;
+        self.since_change = 0;
+        Some(config)
+    }
+}
+
+fn step_pa_up(level: PaLevel) -> PaLevel {
+    match level {
+        PaLevel::Min => PaLevel::Low,
+        PaLevel::Low => PaLevel::High,
+        PaLevel::High => PaLevel::Max,
+        PaLevel::Max => PaLevel::Max,
+    }
+}
+
+fn step_pa_down(level: PaLevel) -> Option<PaLevel> {
+    match level {
+        PaLevel::Max => Some(PaLevel::High),
+        PaLevel::High => Some(PaLevel::Low),
+        PaLevel::Low => Some(PaLevel::Min),
+        PaLevel::Min => None,
+    }
+}
+
+fn step_rate_down(rate: DataRate) -> Option<DataRate> {
+    match rate {
+        DataRate::Mbps2 => Some(DataRate::Mbps1),
+        DataRate::Mbps1 => Some(DataRate::Kbps250),
+        DataRate::Kbps250 => None,
+    }
+}
+
+fn step_rate_up(rate: DataRate) -> Option<DataRate> {
+    match rate {
+        DataRate::Kbps250 => Some(DataRate::Mbps1),
+        DataRate::Mbps1 => Some(DataRate::Mbps2),
+        DataRate::Mbps2 => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AutoTune;
+    use crate::{radio::RadioConfig, DataRate, PaLevel};
+
+    #[test]
+    fn steps_pa_level_up_before_data_rate_down() {
+        let config = RadioConfig::default().with_pa_level(PaLevel::Low);
+        let mut tuner: AutoTune<3> = AutoTune::new(config, 4, 1, 0);
+        assert_eq!(tuner.record_tx(5, true), None);
+        assert_eq!(tuner.record_tx(5, true), None);
+        let updated = tuner.record_tx(5, true).expect("average exceeds upper");
+        assert_eq!(updated.pa_level(), PaLevel::High);
+        assert_eq!(updated.data_rate(), config.data_rate());
+    }
+
+    #[test]
+    fn a_full_failure_steps_immediately_regardless_of_window() {
+        let config = RadioConfig::default().with_pa_level(PaLevel::Max);
+        let mut tuner: AutoTune<8> = AutoTune::new(config, 4, 1, 0);
+        let updated = tuner.record_tx(0, false).expect("failure always steps");
+        assert_eq!(updated.pa_level(), PaLevel::Max);
+        assert_eq!(updated.data_rate(), DataRate::Kbps250);
+    }
+
+    #[test]
+    fn steps_data_rate_up_before_trimming_pa_level() {
+        let config = RadioConfig::default()
+            .with_pa_level(PaLevel::High)
+            .with_data_rate(DataRate::Kbps250);
+        let mut tuner: AutoTune<2> = AutoTune::new(config, 10, 1, 0);
+        assert_eq!(tuner.record_tx(0, true), None);
+        let updated = tuner.record_tx(0, true).expect("average below lower");
+        assert_eq!(updated.data_rate(), DataRate::Mbps1);
+        assert_eq!(updated.pa_level(), config.pa_level());
+    }
+
+    #[test]
+    fn trims_pa_level_once_data_rate_is_already_maxed() {
+        let config = RadioConfig::default()
+            .with_pa_level(PaLevel::High)
+            .with_data_rate(DataRate::Mbps2);
+        let mut tuner: AutoTune<2> = AutoTune::new(config, 10, 1, 0);
+        assert_eq!(tuner.record_tx(0, true), None);
+        let updated = tuner.record_tx(0, true).expect("average below lower");
+        assert_eq!(updated.data_rate(), DataRate::Mbps2);
+        assert_eq!(updated.pa_level(), PaLevel::Low);
+    }
+
+    #[test]
+    fn hysteresis_suppresses_reevaluation_until_satisfied() {
+        let config = RadioConfig::default().with_pa_level(PaLevel::Low);
+        let mut tuner: AutoTune<2> = AutoTune::new(config, 4, 1, 3);
+        // window fills after 2 samples, but hysteresis (3) is not yet satisfied
+        assert_eq!(tuner.record_tx(5, true), None);
+        assert_eq!(tuner.record_tx(5, true), None);
+        let updated = tuner.record_tx(5, true).expect("hysteresis now satisfied");
+        assert_eq!(updated.pa_level(), PaLevel::High);
+    }
+
+    #[test]
+    fn no_step_when_already_at_the_most_robust_setting() {
+        let config = RadioConfig::default()
+            .with_pa_level(PaLevel::Max)
+            .with_data_rate(DataRate::Kbps250);
+        let mut tuner: AutoTune<4> = AutoTune::new(config, 4, 1, 0);
+        assert_eq!(tuner.record_tx(0, false), None);
+    }
+
+    #[test]
+    fn no_step_when_already_at_the_most_frugal_setting() {
+        let config = RadioConfig::default()
+            .with_pa_level(PaLevel::Min)
+            .with_data_rate(DataRate::Mbps2);
+        let mut tuner: AutoTune<2> = AutoTune::new(config, 10, 1, 0);
+        assert_eq!(tuner.record_tx(0, true), None);
+        assert_eq!(tuner.record_tx(0, true), None);
+    }
+}
@@ -1,5 +1,18 @@
 use crate::radio::rf24::bit_fields::{Config, Feature, SetupRetry, SetupRfAw};
-use crate::{CrcLength, DataRate, PaLevel};
+use crate::{ByteOrder, CrcLength, DataRate, PaLevel};
+
+/// The number of bytes in a [`RadioConfig::to_bytes()`]/[`RadioConfig::from_bytes()`] blob.
+pub const RADIO_CONFIG_SERIALIZED_LEN: usize = 41;
+
+/// The version byte stamped into every blob produced by [`RadioConfig::to_bytes()`].
+///
+/// [`RadioConfig::from_bytes()`] rejects a blob whose version byte does not match this,
+/// since the remaining byte layout is only meaningful for this specific version.
+///
+/// Bumped to `5` when [`RadioConfig::dynamic_payloads_bin()`] and
+/// [`RadioConfig::ack_payloads_bin()`] became per-pipe masks, since those masks can no
+/// longer be reconstructed from the single-bit `FEATURE` byte alone.
+const CONFIG_SERIALIZATION_VERSION: u8 = 5;
 
 /// A struct to contain configuration about pipe addresses.
 #[derive(Debug, Clone, Copy)]
@@ -14,6 +27,9 @@ pub struct EsbPipeConfig {
     pipe6: u8,
     pipe7: u8,
     pub(super) rx_pipes_enabled: u8,
+    payload_lengths: [u8; 6],
+    payload_lengths_set: u8,
+    max_addr_len_supplied: u8,
 }
 
 impl Default for EsbPipeConfig {
@@ -29,6 +45,9 @@ impl Default for EsbPipeConfig {
             pipe6: 0xC7,
             pipe7: 0xC8,
             rx_pipes_enabled: 2,
+            payload_lengths: [0; 6],
+            payload_lengths_set: 0,
+            max_addr_len_supplied: 5,
         }
     }
 }
@@ -37,6 +56,7 @@ impl EsbPipeConfig {
     pub fn set_tx_address(&mut self, address: &[u8]) {
         let len = address.len().min(5);
         self.tx_address[..len].copy_from_slice(&address[..len]);
+        self.max_addr_len_supplied = self.max_addr_len_supplied.max(address.len() as u8);
     }
 
     pub fn set_rx_address(&mut self, pipe: u8, address: &[u8]) {
@@ -47,6 +67,9 @@ impl EsbPipeConfig {
         if pipe < 8 {
             self.rx_pipes_enabled |= 1 << pipe;
         }
+        if pipe < 2 {
+            self.max_addr_len_supplied = self.max_addr_len_supplied.max(address.len() as u8);
+        }
         match pipe {
             0 => self.pipe0[..len].copy_from_slice(&address[..len]),
             1 => self.pipe1[..len].copy_from_slice(&address[..len]),
@@ -66,6 +89,26 @@ impl EsbPipeConfig {
         }
     }
 
+    /// Set a static payload length for a specific RX `pipe` (0 - 5), overriding the
+    /// global [`RadioConfig::payload_length()`] on that pipe only.
+    pub fn set_pipe_payload_length(&mut self, pipe: u8, len: u8) {
+        if pipe < 6 {
+            self.payload_lengths[pipe as usize] = len;
+            self.payload_lengths_set |= 1 << pipe;
+        }
+    }
+
+    /// Returns the value set by [`EsbPipeConfig::set_pipe_payload_length()`], or `None`
+    /// if `pipe` has no value of its own (and so should fall back to the global
+    /// [`RadioConfig::payload_length()`]).
+    pub fn get_pipe_payload_length(&self, pipe: u8) -> Option<u8> {
+        if pipe < 6 && self.payload_lengths_set & (1 << pipe) != 0 {
+            Some(self.payload_lengths[pipe as usize])
+        } else {
+            None
+        }
+    }
+
     pub(super) fn get_rx_address(&self, pipe: u8, address: &mut [u8]) {
         let len = address.len().min(5);
         match pipe {
@@ -83,6 +126,95 @@ impl EsbPipeConfig {
             address[1..(len - 1)].copy_from_slice(&self.pipe1[1..(len - 1)]);
         }
     }
+
+    /// The largest number of address bytes the caller has supplied to
+    /// [`EsbPipeConfig::set_tx_address()`] or [`EsbPipeConfig::set_rx_address()`] (for
+    /// pipes 0 and 1) so far, used by [`RadioConfig::validate()`] to catch a shorter
+    /// [`RadioConfig::address_length()`] silently truncating an address the caller
+    /// thought was fully in effect.
+    pub(super) fn max_addr_len_supplied(&self) -> u8 {
+        self.max_addr_len_supplied
+    }
+
+    /// Pack all pipe addresses, the open/closed bitmask, and the per-pipe payload
+    /// lengths into a fixed-size byte array.
+    fn to_bytes(&self) -> [u8; 30] {
+        let mut bytes = [0u8; 30];
+        bytes[0..5].copy_from_slice(&self.tx_address);
+        bytes[5..10].copy_from_slice(&self.pipe0);
+        bytes[10..15].copy_from_slice(&self.pipe1);
+        bytes[15] = self.pipe2;
+        bytes[16] = self.pipe3;
+        bytes[17] = self.pipe4;
+        bytes[18] = self.pipe5;
+        bytes[19] = self.pipe6;
+        bytes[20] = self.pipe7;
+        bytes[21] = self.rx_pipes_enabled;
+        bytes[22..28].copy_from_slice(&self.payload_lengths);
+        bytes[28] = self.payload_lengths_set;
+        bytes[29] = self.max_addr_len_supplied;
+        bytes
+    }
+
+    /// The inverse of [`EsbPipeConfig::to_bytes()`].
+    fn from_bytes(bytes: &[u8; 30]) -> Self {
+        let mut tx_address = [0u8; 5];
+        tx_address.copy_from_slice(&bytes[0..5]);
+        let mut pipe0 = [0u8; 5];
+        pipe0.copy_from_slice(&bytes[5..10]);
+        let mut pipe1 = [0u8; 5];
+        pipe1.copy_from_slice(&bytes[10..15]);
+        let mut payload_lengths = [0u8; 6];
+        payload_lengths.copy_from_slice(&bytes[22..28]);
+        Self {
+            tx_address,
+            pipe0,
+            pipe1,
+            pipe2: bytes[15],
+            pipe3: bytes[16],
+            pipe4: bytes[17],
+            pipe5: bytes[18],
+            pipe6: bytes[19],
+            pipe7: bytes[20],
+            rx_pipes_enabled: bytes[21],
+            payload_lengths,
+            payload_lengths_set: bytes[28],
+            max_addr_len_supplied: bytes[29],
+        }
+    }
+}
+
+/// The raw nRF24L01 register values backing a [`RadioConfig`], with no dependency on
+/// any live radio.
+///
+/// Pair [`RadioConfig::to_registers()`] with [`RadioConfig::from_registers()`] to
+/// round-trip through register-shaped state instead of the library-specific blob
+/// format used by [`RadioConfig::to_bytes()`]/[`RadioConfig::from_bytes()`] -- handy
+/// when a peer (or some other driver) only knows the radio's registers by name.
+///
+/// [`RadioConfig::from_registers()`] has no way to tell a per-pipe payload length
+/// that merely happens to match the global length apart from one set explicitly via
+/// [`RadioConfig::with_pipe_payload_length()`], so every pipe comes back with its own
+/// override set to whatever [`RadioRegisters::rx_pw`] reports for it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RadioRegisters {
+    pub config: u8,
+    pub rf_setup: u8,
+    pub setup_aw: u8,
+    pub setup_retr: u8,
+    pub en_aa: u8,
+    pub en_rxaddr: u8,
+    pub dynpd: u8,
+    pub feature: u8,
+    pub rf_ch: u8,
+    /// `RX_PW_P0` through `RX_PW_P5`, in pipe order.
+    pub rx_pw: [u8; 6],
+    pub tx_addr: [u8; 5],
+    pub rx_addr_p0: [u8; 5],
+    pub rx_addr_p1: [u8; 5],
+    /// The MSByte of `RX_ADDR_P2` through `RX_ADDR_P5`, in pipe order. Pipes 2 - 5
+    /// share pipe 1's 4 LSBytes, so only their MSByte is independently meaningful.
+    pub rx_addr_p2_p5: [u8; 4],
 }
 
 /// An object to configure the radio.
@@ -102,7 +234,10 @@ pub struct RadioConfig {
     channel: u8,
     payload_length: u8,
     auto_ack: u8,
+    dynamic_payloads: u8,
+    ack_payloads: u8,
     pipes: EsbPipeConfig,
+    address_byte_order: ByteOrder,
 }
 
 impl Default for RadioConfig {
@@ -124,6 +259,7 @@ impl Default for RadioConfig {
     /// | [`RadioConfig::auto_retry_delay()`] | `5` |
     /// | [`RadioConfig::auto_retry_count()`] | `15` |
     /// | [`RadioConfig::tx_address()`] | `[0xE7; 5]` |
+    /// | [`RadioConfig::address_byte_order()`] | [`ByteOrder::LsbFirst`] |
     /// | [`RadioConfig::rx_address()`] | See below table about [Default RX addresses](#default-rx-pipes-configuration) |
     /// | [`RadioConfig::rx_dr()`] | `true` |
     /// | [`RadioConfig::tx_ds()`] | `true` |
@@ -173,11 +309,75 @@ impl Default for RadioConfig {
             payload_length: 32,
             // enable auto-ACK for pipes 0 - 5
             auto_ack: 0x3F,
+            // disabled dynamic/ACK payloads for every pipe
+            dynamic_payloads: 0,
+            ack_payloads: 0,
             pipes: EsbPipeConfig::default(),
+            address_byte_order: ByteOrder::LsbFirst,
         }
     }
 }
 
+/// A resolved snapshot of a single pipe's configuration, yielded by
+/// [`RadioConfig::rx_pipes()`] and returned by [`RadioConfig::tx_pipe()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipeConfig {
+    pipe: u8,
+    open: bool,
+    address: [u8; 5],
+    payload_length: u8,
+}
+
+impl PipeConfig {
+    /// The pipe number (0 - 5). [`RadioConfig::tx_pipe()`] always reports `0`, since TX
+    /// operations (and their auto-ACK replies) are associated with pipe 0.
+    pub const fn pipe(&self) -> u8 {
+        self.pipe
+    }
+
+    /// Is this pipe open (`true`) or closed (`false`)?
+    ///
+    /// Always `true` for pipes yielded by [`RadioConfig::rx_pipes()`] (closed pipes are
+    /// omitted) and for [`RadioConfig::tx_pipe()`].
+    pub const fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// The fully resolved 5-byte address, with pipes 2 - 5's shared LSBytes already
+    /// filled in from pipe 1's base address.
+    pub const fn address(&self) -> &[u8; 5] {
+        &self.address
+    }
+
+    /// The resolved static payload length this pipe will use (see
+    /// [`RadioConfig::pipe_payload_length()`]).
+    pub const fn payload_length(&self) -> u8 {
+        self.payload_length
+    }
+}
+
+/// An error returned by [`RadioConfig::validate()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// [`RadioConfig::auto_ack()`] is enabled for a pipe other than 0 while pipe 0's bit
+    /// is clear. Pipe 0 is used to transmit auto-ACK packets, so it must stay enabled
+    /// whenever any other pipe relies on auto-ACK.
+    AckPipeZeroDisabled,
+    /// [`RadioConfig::ack_payloads()`] is enabled without
+    /// [`RadioConfig::dynamic_payloads()`].
+    AckPayloadsNeedDynamicPayloads,
+    /// [`RadioConfig::auto_ack()`] has a bit set for a pipe that
+    /// [`RadioConfig::is_rx_pipe_enabled()`] reports as closed.
+    AutoAckOnClosedPipe,
+    /// Two open pipes among 2 - 5 share the same MSByte, making their addresses
+    /// (which also share pipe 1's 4 LSBytes) indistinguishable on the air.
+    DuplicatePipeAddress,
+    /// [`RadioConfig::address_length()`] is shorter than the number of bytes supplied
+    /// to [`RadioConfig::with_tx_address()`] or [`RadioConfig::with_rx_address()`],
+    /// silently truncating an address the caller thought was fully in effect.
+    AddressLengthTooShort,
+}
+
 impl RadioConfig {
     /// Returns the value set by [`RadioConfig::with_crc_length()`].
     pub const fn crc_length(&self) -> CrcLength {
@@ -374,16 +574,38 @@ impl RadioConfig {
     /// This feature is enabled automatically when enabling ACK payloads
     /// via [`RadioConfig::with_ack_payloads()`].
     pub const fn dynamic_payloads(&self) -> bool {
-        self.feature.dynamic_payloads()
+        self.dynamic_payloads != 0
     }
 
-    /// Enable or disable dynamically sized payloads.
+    /// Enable or disable dynamically sized payloads (for all pipes).
     ///
     /// Enabling this feature nullifies the utility of [`RadioConfig::payload_length()`].
+    ///
+    /// This is a convenience wrapper around [`RadioConfig::with_dynamic_payloads_bin()`]
+    /// that sets (or clears) every pipe's bit at once.
     pub fn with_dynamic_payloads(self, enable: bool) -> Self {
-        let new_config = self.feature.with_dynamic_payloads(enable);
+        self.with_dynamic_payloads_bin(if enable { 0x3F } else { 0 })
+    }
+
+    /// Return the value set by [`RadioConfig::with_dynamic_payloads_bin()`].
+    pub const fn dynamic_payloads_bin(&self) -> u8 {
+        self.dynamic_payloads
+    }
+
+    /// Enable or disable dynamically sized payloads on a per-pipe basis.
+    ///
+    /// The given value (in binary form) controls the feature for each pipe, mirroring
+    /// [`RadioConfig::with_auto_ack()`]'s bitmask convention: bit 0 controls pipe 0, bit 1
+    /// controls pipe 1, and so on.
+    ///
+    /// This does not clear [`RadioConfig::ack_payloads_bin()`] for any pipe disabled
+    /// here; [`RadioConfig::validate()`] rejects the resulting config if that leaves a
+    /// pipe with ACK payloads enabled but dynamic payloads disabled.
+    pub fn with_dynamic_payloads_bin(self, enable: u8) -> Self {
+        let new_feature = self.feature.set_dynamic_payloads_bit(enable != 0);
         Self {
-            feature: new_config,
+            dynamic_payloads: enable,
+            feature: new_feature,
             ..self
         }
     }
@@ -413,20 +635,47 @@ impl RadioConfig {
 
     /// Return the value set by [`RadioConfig::with_ack_payloads()`].
     pub const fn ack_payloads(&self) -> bool {
-        self.feature.ack_payloads()
+        self.ack_payloads != 0
     }
 
-    /// Enable or disable custom ACK payloads for auto-ACK packets.
+    /// Enable or disable custom ACK payloads for auto-ACK packets (for all pipes).
     ///
     /// ACK payloads require the [`RadioConfig::auto_ack`] and [`RadioConfig::dynamic_payloads`]
     /// to be enabled. If ACK payloads are enabled, then this function also enables those
     /// features (for all pipes).
+    ///
+    /// This is a convenience wrapper around [`RadioConfig::with_ack_payloads_bin()`] that
+    /// sets (or clears) every pipe's bit at once.
     pub fn with_ack_payloads(self, enable: bool) -> Self {
-        let auto_ack = if enable { 0xFF } else { self.auto_ack };
-        let new_config = self.feature.with_ack_payloads(enable);
+        self.with_ack_payloads_bin(if enable { 0x3F } else { 0 })
+    }
+
+    /// Return the value set by [`RadioConfig::with_ack_payloads_bin()`].
+    pub const fn ack_payloads_bin(&self) -> u8 {
+        self.ack_payloads
+    }
+
+    /// Enable or disable custom ACK payloads on a per-pipe basis.
+    ///
+    /// The given value (in binary form) controls the feature for each pipe, mirroring
+    /// [`RadioConfig::with_auto_ack()`]'s bitmask convention: bit 0 controls pipe 0, bit 1
+    /// controls pipe 1, and so on.
+    ///
+    /// Any pipe enabled here also has [`RadioConfig::with_auto_ack()`] and
+    /// [`RadioConfig::with_dynamic_payloads_bin()`] enabled for that pipe, since ACK
+    /// payloads require both.
+    pub fn with_ack_payloads_bin(self, enable: u8) -> Self {
+        let dynamic_payloads = self.dynamic_payloads | enable;
+        let auto_ack = self.auto_ack | enable;
+        let new_feature = self
+            .feature
+            .set_ack_payloads_bit(enable != 0)
+            .set_dynamic_payloads_bit(dynamic_payloads != 0);
         Self {
+            ack_payloads: enable,
+            dynamic_payloads,
             auto_ack,
-            feature: new_config,
+            feature: new_feature,
             ..self
         }
     }
@@ -450,6 +699,27 @@ impl RadioConfig {
         }
     }
 
+    /// Returns the static payload length that a specified RX `pipe` (0 - 5) will use,
+    /// falling back to [`RadioConfig::payload_length()`] if `pipe` has no value of its
+    /// own set via [`RadioConfig::with_pipe_payload_length()`].
+    pub fn pipe_payload_length(&self, pipe: u8) -> u8 {
+        self.pipes
+            .get_pipe_payload_length(pipe)
+            .unwrap_or(self.payload_length)
+    }
+
+    /// Set a static payload length for a specific RX `pipe` (0 - 5), overriding
+    /// [`RadioConfig::payload_length()`] on that pipe only.
+    ///
+    /// This lets a receiver listen to several senders that use different static frame
+    /// sizes on different pipes. It has no effect if dynamic payloads are enabled (see
+    /// [`RadioConfig::with_dynamic_payloads()`]).
+    pub fn with_pipe_payload_length(self, pipe: u8, value: u8) -> Self {
+        let mut pipes = self.pipes;
+        pipes.set_pipe_payload_length(pipe, value);
+        Self { pipes, ..self }
+    }
+
     // Close a RX pipe from receiving data.
     //
     // This is only useful if pipe 1 should be closed instead of open (after [`RadioConfig::default()`]).
@@ -485,6 +755,47 @@ impl RadioConfig {
         Self { pipes, ..self }
     }
 
+    /// A resolved view of every currently open RX pipe, in pipe order.
+    ///
+    /// This reconstructs each pipe's full 5-byte address (sharing pipe 1's base for
+    /// pipes 2 - 5, as [`RadioConfig::rx_address()`] does) and resolved payload length,
+    /// instead of making the caller probe [`RadioConfig::is_rx_pipe_enabled()`] and fill
+    /// address buffers pipe by pipe. Closed pipes are omitted entirely.
+    pub fn rx_pipes(&self) -> impl Iterator<Item = PipeConfig> {
+        let config = *self;
+        (0..6u8)
+            .map(move |pipe| config.pipe_config(pipe))
+            .filter(PipeConfig::is_open)
+    }
+
+    /// A resolved view of the TX pipe: the address set by
+    /// [`RadioConfig::with_tx_address()`] and [`RadioConfig::payload_length()`].
+    pub fn tx_pipe(&self) -> PipeConfig {
+        let mut address = [0u8; 5];
+        self.tx_address(&mut address);
+        PipeConfig {
+            pipe: 0,
+            open: true,
+            address,
+            payload_length: self.payload_length,
+        }
+    }
+
+    fn pipe_config(&self, pipe: u8) -> PipeConfig {
+        let mut address = [0u8; 5];
+        self.rx_address(pipe, &mut address);
+        if pipe > 1 {
+            // pipes 2 - 5 share every byte after the MSByte with pipe 1
+            address[1..].copy_from_slice(&self.pipes.pipe1[1..]);
+        }
+        PipeConfig {
+            pipe,
+            open: self.is_rx_pipe_enabled(pipe),
+            address,
+            payload_length: self.pipe_payload_length(pipe),
+        }
+    }
+
     /// Get the address set by [`RadioConfig::with_tx_address()`]
     pub fn tx_address(&self, address: &mut [u8]) {
         let len = address.len().min(5);
@@ -499,11 +810,232 @@ impl RadioConfig {
         pipes.set_tx_address(address);
         Self { pipes, ..self }
     }
+
+    /// Returns the value set by [`RadioConfig::with_address_byte_order()`].
+    pub const fn address_byte_order(&self) -> ByteOrder {
+        self.address_byte_order
+    }
+
+    /// The byte order that [`RadioConfig::with_tx_address()`] and
+    /// [`RadioConfig::with_rx_address()`] addresses are declared in.
+    ///
+    /// The nRF24 always shifts an address out LSByte-first; setting this to
+    /// [`ByteOrder::MsbFirst`] reverses each multi-byte pipe 0/pipe 1/TX address before
+    /// it is written to the chip, so a user can declare addresses in whichever
+    /// endianness their protocol peer uses. This has no effect on the single-byte pipe
+    /// 2 - 5 prefixes, which have no byte order to speak of.
+    pub fn with_address_byte_order(self, order: ByteOrder) -> Self {
+        Self {
+            address_byte_order: order,
+            ..self
+        }
+    }
+
+    /// Reorder a multi-byte pipe 0/pipe 1/TX `address` per
+    /// [`RadioConfig::address_byte_order()`], ready to be shifted out to the chip.
+    ///
+    /// Only the significant [`RadioConfig::address_length()`] leading bytes are
+    /// reversed; any trailing bytes beyond that are unused padding regardless of order.
+    pub(crate) fn ordered_address(&self, mut address: [u8; 5]) -> [u8; 5] {
+        if self.address_byte_order == ByteOrder::MsbFirst {
+            let len = self.address_length() as usize;
+            address[..len].reverse();
+        }
+        address
+    }
+
+    /// Check this configuration for illegal combinations that the builder itself does
+    /// not reject, before pushing it to hardware via
+    /// [`EsbInit::with_config()`](fn@crate::radio::prelude::EsbInit::with_config).
+    ///
+    /// The builder stays infallible (every `with_*()` method always returns a usable
+    /// [`RadioConfig`]), so callers that want a pre-flight sanity check should call this
+    /// explicitly.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.auto_ack & !1 != 0 && self.auto_ack & 1 == 0 {
+            return Err(ConfigError::AckPipeZeroDisabled);
+        }
+        if self.ack_payloads & !self.dynamic_payloads != 0 {
+            return Err(ConfigError::AckPayloadsNeedDynamicPayloads);
+        }
+        if self.auto_ack & !self.pipes.rx_pipes_enabled & 0x3F != 0 {
+            return Err(ConfigError::AutoAckOnClosedPipe);
+        }
+        let mut open_msbs: [Option<u8>; 4] = [None; 4];
+        for pipe in 2..6u8 {
+            if !self.is_rx_pipe_enabled(pipe) {
+                continue;
+            }
+            let mut byte = [0u8; 1];
+            self.rx_address(pipe, &mut byte);
+            if open_msbs.contains(&Some(byte[0])) {
+                return Err(ConfigError::DuplicatePipeAddress);
+            }
+            open_msbs[pipe as usize - 2] = Some(byte[0]);
+        }
+        if self.address_length() < self.pipes.max_addr_len_supplied() {
+            return Err(ConfigError::AddressLengthTooShort);
+        }
+        Ok(())
+    }
+
+    /// Pack the whole configuration into a compact, versioned byte blob.
+    ///
+    /// This covers every field settable through this struct's builder methods (the
+    /// channel, address length, PA level, data rate, CRC length, payload length,
+    /// ask-no-ack flag, auto-ACK/dynamic-payloads/ACK-payloads masks, auto-retry
+    /// delay/count, all RX pipe addresses and their open/closed state, the TX address,
+    /// the address byte order, and the IRQ masks), with no dependency on any live radio
+    /// hardware.
+    ///
+    /// This is handy for persisting a known-good radio profile to a small key/value
+    /// flash store (or any other file) and restoring it later via
+    /// [`RadioConfig::from_bytes()`], or for shipping an identical config to a peer
+    /// node, much like [`RF24::save_config()`](crate::radio::RF24::save_config) does
+    /// for live register state.
+    pub fn to_bytes(&self) -> [u8; RADIO_CONFIG_SERIALIZED_LEN] {
+        let mut bytes = [0u8; RADIO_CONFIG_SERIALIZED_LEN];
+        bytes[0] = CONFIG_SERIALIZATION_VERSION;
+        bytes[1] = self.config_reg.into_bits();
+        bytes[2] = self.auto_retries.into_bits();
+        bytes[3] = self.setup_rf_aw.into_bits();
+        bytes[4] = self.feature.into_bits();
+        bytes[5] = self.channel;
+        bytes[6] = self.payload_length;
+        bytes[7] = self.auto_ack;
+        bytes[8..38].copy_from_slice(&self.pipes.to_bytes());
+        bytes[38] = matches!(self.address_byte_order, ByteOrder::MsbFirst) as u8;
+        bytes[39] = self.dynamic_payloads;
+        bytes[40] = self.ack_payloads;
+        bytes
+    }
+
+    /// The inverse of [`RadioConfig::to_bytes()`].
+    ///
+    /// Returns `None` if `bytes`' version byte does not match the version
+    /// [`RadioConfig::to_bytes()`] stamps, since the remaining byte layout is only
+    /// meaningful for that specific version.
+    pub fn from_bytes(bytes: &[u8; RADIO_CONFIG_SERIALIZED_LEN]) -> Option<Self> {
+        if bytes[0] != CONFIG_SERIALIZATION_VERSION {
+            return None;
+        }
+        let mut pipe_bytes = [0u8; 30];
+        pipe_bytes.copy_from_slice(&bytes[8..38]);
+        Some(Self {
+            config_reg: Config::from_bits(bytes[1]),
+            auto_retries: SetupRetry::from_bits(bytes[2]),
+            setup_rf_aw: SetupRfAw::from_bits(bytes[3]),
+            feature: Feature::from_bits(bytes[4]),
+            channel: bytes[5],
+            payload_length: bytes[6],
+            auto_ack: bytes[7],
+            pipes: EsbPipeConfig::from_bytes(&pipe_bytes),
+            address_byte_order: if bytes[38] == 1 {
+                ByteOrder::MsbFirst
+            } else {
+                ByteOrder::LsbFirst
+            },
+            dynamic_payloads: bytes[39],
+            ack_payloads: bytes[40],
+        })
+    }
+
+    /// Export this configuration as the raw nRF24L01 register values it describes (the
+    /// registers that [`EsbInit::with_config()`](fn@crate::radio::prelude::EsbInit::with_config)
+    /// writes to hardware), with no dependency on any live radio.
+    pub fn to_registers(&self) -> RadioRegisters {
+        let mut tx_addr = [0u8; 5];
+        self.tx_address(&mut tx_addr);
+        let tx_addr = self.ordered_address(tx_addr);
+        let mut rx_addr_p0 = [0u8; 5];
+        self.rx_address(0, &mut rx_addr_p0);
+        let rx_addr_p0 = self.ordered_address(rx_addr_p0);
+        let mut rx_addr_p1 = [0u8; 5];
+        self.rx_address(1, &mut rx_addr_p1);
+        let rx_addr_p1 = self.ordered_address(rx_addr_p1);
+        let mut rx_addr_p2_p5 = [0u8; 4];
+        for pipe in 2..6u8 {
+            let mut byte = [0u8; 1];
+            self.rx_address(pipe, &mut byte);
+            rx_addr_p2_p5[pipe as usize - 2] = byte[0];
+        }
+        let mut rx_pw = [0u8; 6];
+        for (pipe, len) in rx_pw.iter_mut().enumerate() {
+            *len = self.pipe_payload_length(pipe as u8);
+        }
+        RadioRegisters {
+            config: self.config_reg.into_bits(),
+            rf_setup: self.setup_rf_aw.into_bits() & 0x2F,
+            setup_aw: self.address_length() - 2,
+            setup_retr: self.auto_retries.into_bits(),
+            en_aa: self.auto_ack & 0x3F,
+            en_rxaddr: self.pipes.rx_pipes_enabled | 1,
+            dynpd: self.dynamic_payloads & 0x3F,
+            feature: self.feature.into_bits() & Feature::REG_MASK,
+            rf_ch: self.channel,
+            rx_pw,
+            tx_addr,
+            rx_addr_p0,
+            rx_addr_p1,
+            rx_addr_p2_p5,
+        }
+    }
+
+    /// The inverse of [`RadioConfig::to_registers()`].
+    ///
+    /// This mirrors [`EsbInit::read_config()`](fn@crate::radio::prelude::EsbInit::read_config)'s
+    /// decoding, but operates on register values already in hand (e.g. captured by some
+    /// other means) instead of performing the SPI reads itself.
+    pub fn from_registers(regs: &RadioRegisters) -> Self {
+        let config_reg = Config::from_bits(regs.config);
+        let mut config = Self::default()
+            .with_crc_length(config_reg.crc_length())
+            .with_rx_dr(config_reg.rx_dr())
+            .with_tx_ds(config_reg.tx_ds())
+            .with_tx_df(config_reg.tx_df())
+            .with_pa_level(PaLevel::from_bits(regs.rf_setup & PaLevel::MASK))
+            .with_data_rate(DataRate::from_bits(regs.rf_setup & DataRate::MASK))
+            .with_lna_enable(regs.rf_setup & 1 == 1)
+            .with_address_length(regs.setup_aw.min(3) + 2)
+            .with_auto_retries(regs.setup_retr >> 4, regs.setup_retr & 0xF)
+            .with_channel(regs.rf_ch)
+            .with_auto_ack(regs.en_aa & 0x3F)
+            .with_tx_address(&regs.tx_addr)
+            .with_rx_address(0, &regs.rx_addr_p0)
+            .with_rx_address(1, &regs.rx_addr_p1);
+
+        let feature = Feature::from_bits(regs.feature & Feature::REG_MASK);
+        let dynpd_mask = regs.dynpd & 0x3F;
+        config = config
+            .with_dynamic_payloads_bin(dynpd_mask)
+            // the FEATURE register's EN_ACK_PAY bit is global, so the per-pipe mask is
+            // inferred from which pipes also have dynamic payloads enabled
+            .with_ack_payloads_bin(if feature.ack_payloads() {
+                dynpd_mask
+            } else {
+                0
+            })
+            .with_ask_no_ack(feature.ask_no_ack());
+
+        for pipe in 2..6u8 {
+            config = config.with_rx_address(
+                pipe,
+                &regs.rx_addr_p2_p5[pipe as usize - 2..pipe as usize - 1],
+            );
+        }
+        for (pipe, len) in regs.rx_pw.into_iter().enumerate() {
+            config = config.with_pipe_payload_length(pipe as u8, len);
+            if regs.en_rxaddr & (1 << pipe) == 0 {
+                config = config.close_rx_pipe(pipe as u8);
+            }
+        }
+        config
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::RadioConfig;
+    use super::{ByteOrder, ConfigError, RadioConfig, RADIO_CONFIG_SERIALIZED_LEN};
     use crate::{CrcLength, DataRate, PaLevel};
 
     #[test]
@@ -566,7 +1098,7 @@ mod test {
         assert!(!config.ask_no_ack());
 
         config = config.with_ack_payloads(true);
-        assert_eq!(config.auto_ack(), 0xFF);
+        assert_eq!(config.auto_ack(), 0x3F);
         assert!(config.ack_payloads());
         assert!(config.dynamic_payloads());
         assert!(!config.ask_no_ack());
@@ -586,12 +1118,57 @@ mod test {
         assert!(!config.dynamic_payloads());
     }
 
+    #[test]
+    fn per_pipe_dynamic_and_ack_payloads() {
+        // enable dynamic payloads for pipes 0 and 2 only
+        let mut config = RadioConfig::default().with_dynamic_payloads_bin(0b101);
+        assert_eq!(config.dynamic_payloads_bin(), 0b101);
+        assert!(config.dynamic_payloads());
+        assert_eq!(config.ack_payloads_bin(), 0);
+
+        // enabling ACK payloads for pipe 1 also implicitly enables dynamic payloads
+        // and auto-ack for pipe 1, without disturbing pipes 0 and 2
+        config = config.with_ack_payloads_bin(0b10);
+        assert_eq!(config.ack_payloads_bin(), 0b10);
+        assert_eq!(config.dynamic_payloads_bin(), 0b111);
+        assert_eq!(config.auto_ack() & 0b111, 0b111);
+
+        // disabling dynamic payloads for pipe 1 leaves its ACK payloads bit set, which
+        // validate() is responsible for catching
+        config = config.with_dynamic_payloads_bin(0b101);
+        assert_eq!(config.ack_payloads_bin(), 0b10);
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::AckPayloadsNeedDynamicPayloads)
+        );
+    }
+
     #[test]
     fn payload_length() {
         let config = RadioConfig::default().with_payload_length(255);
         assert_eq!(config.payload_length(), 255);
     }
 
+    #[test]
+    fn pipe_payload_length() {
+        let mut config = RadioConfig::default().with_payload_length(32);
+        for pipe in 0..6 {
+            assert_eq!(config.pipe_payload_length(pipe), 32);
+        }
+        config = config.with_pipe_payload_length(2, 8);
+        assert_eq!(config.pipe_payload_length(2), 8);
+        // unrelated pipes still fall back to the global payload length
+        assert_eq!(config.pipe_payload_length(1), 32);
+        assert_eq!(config.pipe_payload_length(3), 32);
+        // changing the global length does not disturb pipe 2's override
+        config = config.with_payload_length(16);
+        assert_eq!(config.pipe_payload_length(2), 8);
+        assert_eq!(config.pipe_payload_length(1), 16);
+        // out of range pipe numbers are a no-op
+        config = config.with_pipe_payload_length(6, 4);
+        assert_eq!(config.pipe_payload_length(6), config.payload_length());
+    }
+
     #[test]
     fn channel() {
         let config = RadioConfig::default().with_channel(255);
@@ -641,4 +1218,245 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn serialization_round_trip() {
+        let mut address = [0xAB; 5];
+        let mut config = RadioConfig::default()
+            .with_channel(100)
+            .with_address_length(4)
+            .with_pa_level(PaLevel::Low)
+            .with_lna_enable(false)
+            .with_crc_length(CrcLength::Bit8)
+            .with_data_rate(DataRate::Mbps2)
+            .with_payload_length(16)
+            .with_ack_payloads(true)
+            .with_ask_no_ack(true)
+            .with_auto_retries(10, 7)
+            .with_rx_dr(false)
+            .with_tx_ds(false)
+            .with_tx_df(false)
+            .with_address_byte_order(ByteOrder::MsbFirst)
+            .with_tx_address(&address);
+        for pipe in 0..=7u8 {
+            address.copy_from_slice(&[0xB0 + pipe; 5]);
+            config = config.with_rx_address(pipe, &address);
+        }
+        config = config.close_rx_pipe(3);
+        config = config
+            .with_pipe_payload_length(1, 4)
+            .with_pipe_payload_length(4, 30);
+
+        let bytes = config.to_bytes();
+        let restored = RadioConfig::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.channel(), config.channel());
+        assert_eq!(restored.address_length(), config.address_length());
+        assert_eq!(restored.pa_level(), config.pa_level());
+        assert_eq!(restored.lna_enable(), config.lna_enable());
+        assert_eq!(restored.crc_length(), config.crc_length());
+        assert_eq!(restored.data_rate(), config.data_rate());
+        assert_eq!(restored.payload_length(), config.payload_length());
+        assert_eq!(restored.ack_payloads(), config.ack_payloads());
+        assert_eq!(restored.dynamic_payloads(), config.dynamic_payloads());
+        assert_eq!(restored.ask_no_ack(), config.ask_no_ack());
+        assert_eq!(restored.auto_ack(), config.auto_ack());
+        assert_eq!(restored.auto_retry_delay(), config.auto_retry_delay());
+        assert_eq!(restored.auto_retry_count(), config.auto_retry_count());
+        assert_eq!(restored.rx_dr(), config.rx_dr());
+        assert_eq!(restored.tx_ds(), config.tx_ds());
+        assert_eq!(restored.tx_df(), config.tx_df());
+
+        assert_eq!(restored.address_byte_order(), config.address_byte_order());
+
+        let mut expected = [0u8; 5];
+        let mut actual = [0u8; 5];
+        config.tx_address(&mut expected);
+        restored.tx_address(&mut actual);
+        assert_eq!(expected, actual);
+
+        for pipe in 0..=7u8 {
+            assert_eq!(
+                restored.is_rx_pipe_enabled(pipe),
+                config.is_rx_pipe_enabled(pipe)
+            );
+            config.rx_address(pipe, &mut expected);
+            restored.rx_address(pipe, &mut actual);
+            assert_eq!(expected, actual);
+        }
+
+        for pipe in 0..6u8 {
+            assert_eq!(
+                restored.pipe_payload_length(pipe),
+                config.pipe_payload_length(pipe)
+            );
+        }
+    }
+
+    #[test]
+    fn validate_accepts_consistent_config() {
+        // auto-ack disabled altogether sidesteps every auto-ack-related rule, leaving
+        // only the default pipe addresses and address length, which are consistent.
+        assert_eq!(RadioConfig::default().with_auto_ack(0).validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_ack_pipe_zero_disabled() {
+        let config = RadioConfig::default().with_auto_ack(0b10);
+        assert_eq!(config.validate(), Err(ConfigError::AckPipeZeroDisabled));
+    }
+
+    #[test]
+    fn validate_rejects_ack_payloads_without_dynamic_payloads() {
+        let config = RadioConfig::default()
+            .with_ack_payloads(true)
+            .with_dynamic_payloads(false);
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::AckPayloadsNeedDynamicPayloads)
+        );
+    }
+
+    #[test]
+    fn validate_rejects_auto_ack_on_closed_pipe() {
+        // the library default leaves pipes 0 and 2 - 5 closed while still enabling
+        // auto-ack for them, which is exactly the mismatch this rule exists to catch.
+        let config = RadioConfig::default();
+        assert_eq!(config.validate(), Err(ConfigError::AutoAckOnClosedPipe));
+    }
+
+    #[test]
+    fn validate_rejects_duplicate_pipe_address() {
+        let config = RadioConfig::default()
+            .with_auto_ack(0)
+            .with_rx_address(2, &[0xC9])
+            .with_rx_address(3, &[0xC9]);
+        assert_eq!(config.validate(), Err(ConfigError::DuplicatePipeAddress));
+    }
+
+    #[test]
+    fn validate_rejects_address_length_too_short() {
+        let config = RadioConfig::default()
+            .with_auto_ack(0)
+            .with_tx_address(&[0xAB; 5])
+            .with_address_length(3);
+        assert_eq!(config.validate(), Err(ConfigError::AddressLengthTooShort));
+    }
+
+    #[test]
+    fn rx_pipes_yields_only_open_pipes_resolved() {
+        let mut config = RadioConfig::default()
+            .with_payload_length(32)
+            .with_rx_address(0, &[0xAA; 5])
+            .with_pipe_payload_length(2, 8);
+        config = config.with_rx_address(2, &[0xC9]);
+        config = config.close_rx_pipe(1);
+
+        let mut pipes = config.rx_pipes();
+
+        let pipe0 = pipes.next().unwrap();
+        assert_eq!(pipe0.pipe(), 0);
+        assert!(pipe0.is_open());
+        assert_eq!(pipe0.address(), &[0xAA; 5]);
+        assert_eq!(pipe0.payload_length(), 32);
+
+        let pipe2 = pipes.next().unwrap();
+        assert_eq!(pipe2.pipe(), 2);
+        assert!(pipe2.is_open());
+        assert_eq!(pipe2.address()[0], 0xC9);
+        // pipes 2 - 5 share pipe 1's LSBytes, even though pipe 1 itself is now closed
+        assert_eq!(&pipe2.address()[1..], &[0xC2, 0xC2, 0xC2, 0xC2]);
+        assert_eq!(pipe2.payload_length(), 8);
+
+        assert!(pipes.next().is_none());
+    }
+
+    #[test]
+    fn tx_pipe_resolves_address_and_payload_length() {
+        let config = RadioConfig::default()
+            .with_tx_address(&[0xBB; 5])
+            .with_payload_length(16);
+        let tx = config.tx_pipe();
+        assert_eq!(tx.pipe(), 0);
+        assert!(tx.is_open());
+        assert_eq!(tx.address(), &[0xBB; 5]);
+        assert_eq!(tx.payload_length(), 16);
+    }
+
+    #[test]
+    fn from_bytes_rejects_bad_version() {
+        let mut bytes = RadioConfig::default().to_bytes();
+        bytes[0] = 0xFF;
+        assert!(RadioConfig::from_bytes(&bytes).is_none());
+    }
+
+    #[test]
+    fn serialized_len_matches_blob_size() {
+        assert_eq!(
+            RadioConfig::default().to_bytes().len(),
+            RADIO_CONFIG_SERIALIZED_LEN
+        );
+    }
+
+    #[test]
+    fn register_round_trip() {
+        let mut address = [0xAB; 5];
+        let mut config = RadioConfig::default()
+            .with_channel(100)
+            .with_address_length(4)
+            .with_pa_level(PaLevel::Low)
+            .with_lna_enable(false)
+            .with_crc_length(CrcLength::Bit8)
+            .with_data_rate(DataRate::Mbps2)
+            .with_ack_payloads(true)
+            .with_ask_no_ack(true)
+            .with_auto_retries(10, 7)
+            .with_rx_dr(false)
+            .with_tx_ds(false)
+            .with_tx_df(false)
+            .with_tx_address(&address);
+        for pipe in 0..=5u8 {
+            address.copy_from_slice(&[0xB0 + pipe; 5]);
+            config = config.with_rx_address(pipe, &address);
+            config = config.with_pipe_payload_length(pipe, 4 + pipe);
+        }
+        config = config.close_rx_pipe(3);
+
+        let restored = RadioConfig::from_registers(&config.to_registers());
+
+        assert_eq!(restored.channel(), config.channel());
+        assert_eq!(restored.address_length(), config.address_length());
+        assert_eq!(restored.pa_level(), config.pa_level());
+        assert_eq!(restored.lna_enable(), config.lna_enable());
+        assert_eq!(restored.crc_length(), config.crc_length());
+        assert_eq!(restored.data_rate(), config.data_rate());
+        assert_eq!(restored.ack_payloads(), config.ack_payloads());
+        assert_eq!(restored.dynamic_payloads(), config.dynamic_payloads());
+        assert_eq!(restored.ask_no_ack(), config.ask_no_ack());
+        // EN_AA only implements 6 bits in hardware, so only that much survives a
+        // register round trip (unlike `to_bytes()`'s lossless blob).
+        assert_eq!(restored.auto_ack(), config.auto_ack() & 0x3F);
+        assert_eq!(restored.auto_retry_delay(), config.auto_retry_delay());
+        assert_eq!(restored.auto_retry_count(), config.auto_retry_count());
+        assert_eq!(restored.rx_dr(), config.rx_dr());
+        assert_eq!(restored.tx_ds(), config.tx_ds());
+        assert_eq!(restored.tx_df(), config.tx_df());
+
+        let mut expected = [0u8; 5];
+        let mut actual = [0u8; 5];
+        config.tx_address(&mut expected);
+        restored.tx_address(&mut actual);
+        assert_eq!(expected, actual);
+
+        for pipe in 0..=5u8 {
+            assert_eq!(
+                restored.is_rx_pipe_enabled(pipe),
+                config.is_rx_pipe_enabled(pipe)
+            );
+            assert_eq!(restored.pipe_payload_length(pipe), 4 + pipe);
+            config.rx_address(pipe, &mut expected);
+            restored.rx_address(pipe, &mut actual);
+            assert_eq!(expected, actual);
+        }
+    }
 }
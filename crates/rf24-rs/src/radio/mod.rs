@@ -2,7 +2,21 @@
 pub mod prelude;
 
 mod rf24;
-pub use rf24::{Nrf24Error, RF24};
+pub use rf24::{
+    quietest_channel, AckPayloadQueue, CarrierSweep, ChannelScanner, FrameQueue, Nrf24Error,
+    Runtime, RxFrame, Scanner, StreamError, DETAILS_SNAPSHOT_LEN, RADIO_CONFIG_BLOB_LEN, RF24,
+    STREAM_CHUNK_LEN, STREAM_MAX_CHUNKS, STREAM_MAX_MESSAGE_LEN,
+};
 
 mod config;
-pub use config::RadioConfig;
+pub use config::{
+    ConfigError, PipeConfig, RadioConfig, RadioRegisters, RADIO_CONFIG_SERIALIZED_LEN,
+};
+
+mod auto_tune;
+pub use auto_tune::AutoTune;
+
+#[cfg(feature = "async")]
+mod rf24_async;
+#[cfg(feature = "async")]
+pub use rf24_async::AsyncRF24;
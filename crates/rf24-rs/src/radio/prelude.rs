@@ -8,7 +8,9 @@
 //! use rf24::radio::prelude::*;
 //! ```
 
-use crate::types::{CrcLength, DataRate, FifoState, PaLevel, StatusFlags};
+use core::time::Duration;
+
+use crate::types::{CrcLength, DataRate, FallbackMode, FifoState, PaLevel, StatusFlags};
 
 use super::RadioConfig;
 
@@ -119,6 +121,56 @@ pub trait EsbStatus {
     ///
     /// Use [`EsbStatus::get_status_flags()`] to get the updated status flags.
     fn update(&mut self) -> Result<(), Self::StatusErrorType>;
+
+    /// A single-call interrupt-service routine.
+    ///
+    /// This reads the STATUS register once (like [`EsbStatus::update()`]), decodes it
+    /// into a [`StatusFlags`] (including the received pipe number), then clears
+    /// whichever flags are `true` in `mask` (like [`EsbStatus::clear_status_flags()`])
+    /// in the same SPI transaction.
+    ///
+    /// This mirrors how other ESB/radio drivers return a single decoded interrupt
+    /// struct from their ISR entry point:
+    /// ```ignore
+    /// match radio.what_happened(StatusFlags::new())? {
+    ///     flags if flags.rx_dr() => { /* read the payload from flags.pipe() */ }
+    ///     flags if flags.tx_df() => { /* handle the failed transmission */ }
+    ///     _ => {}
+    /// }
+    /// ```
+    fn what_happened(&mut self, mask: StatusFlags) -> Result<StatusFlags, Self::StatusErrorType>;
+
+    /// Query which events are currently unmasked (i.e. able to pull the IRQ pin low).
+    ///
+    /// This is the inverse of [`EsbStatus::set_status_flags()`]: a `true` member of the
+    /// returned [`StatusFlags`] means that event is enabled and will trigger the IRQ pin.
+    fn get_masked_flags(&mut self) -> Result<StatusFlags, Self::StatusErrorType>;
+
+    /// A maskable, callback-based interrupt dispatcher.
+    ///
+    /// This performs the same single SPI transaction as [`EsbStatus::what_happened()`]
+    /// (refresh the STATUS register, decode it, clear whichever events fired), but instead
+    /// of returning the raw [`StatusFlags`] for the caller to match on, it invokes the
+    /// supplied closure for each event that fired:
+    ///
+    /// - `on_rx_dr` is called if a payload arrived in the RX FIFO.
+    /// - `on_tx_ds` is called if a payload was sent (and acknowledged, if applicable).
+    /// - `on_tx_df` is called if a payload exhausted the configured auto-retry attempts.
+    ///
+    /// Only the events that fired are cleared, so an IRQ pin shared with other interrupt
+    /// sources (or a caller that only handles a subset of events) won't lose unrelated,
+    /// still-pending events. Returns the decoded [`StatusFlags`] for convenience (e.g. to
+    /// inspect [`StatusFlags::pipe()`] after `on_rx_dr` fires).
+    fn handle_interrupt<RX, TX, TXF>(
+        &mut self,
+        on_rx_dr: RX,
+        on_tx_ds: TX,
+        on_tx_df: TXF,
+    ) -> Result<StatusFlags, Self::StatusErrorType>
+    where
+        RX: FnOnce(),
+        TX: FnOnce(),
+        TXF: FnOnce();
 }
 
 /// A trait to represent manipulation of RX and TX FIFOs
@@ -225,6 +277,31 @@ pub trait EsbPayloadLength {
     /// or there is no [`EsbFifo::available()`] payload in the RX FIFO, this function's
     /// returned value shall be considered invalid.
     fn get_dynamic_payload_length(&mut self) -> Result<u8, Self::PayloadLengthErrorType>;
+
+    /// Set the dynamic payloads feature for a single `pipe` (0-5), leaving the other
+    /// pipes' `DYNPD` bits untouched.
+    ///
+    /// Unlike [`EsbPayloadLength::set_dynamic_payloads()`], this does not touch pipes
+    /// other than `pipe`, so a receiver can mix a dynamic-length pipe with statically
+    /// sized pipes. The global `EN_DPL` feature bit is still asserted whenever any pipe
+    /// has dynamic payloads enabled, and cleared only when none do.
+    ///
+    /// Out-of-range `pipe` values (greater than 5) are ignored.
+    fn set_dynamic_payload_pipe(
+        &mut self,
+        enable: bool,
+        pipe: u8,
+    ) -> Result<(), Self::PayloadLengthErrorType>;
+
+    /// Set the dynamic payloads feature for all 6 pipes at once using a bitmask.
+    ///
+    /// Bits 0 through 5 of `mask` map to pipes 0 through 5 (`1` enables dynamic
+    /// payloads on that pipe, `0` disables it); bits 6 and 7 are ignored. This
+    /// configures all six pipes in a single `DYNPD` write, instead of calling
+    /// [`EsbPayloadLength::set_dynamic_payload_pipe()`] six times. The global
+    /// `EN_DPL` feature bit is asserted whenever `mask` is non-zero, and cleared
+    /// when it is `0`.
+    fn set_dynamic_payloads_bin(&mut self, mask: u8) -> Result<(), Self::PayloadLengthErrorType>;
 }
 
 /// A trait to represent manipulation of the automatic acknowledgement feature
@@ -326,6 +403,17 @@ pub trait EsbAutoAck: EsbPayloadLength {
     /// to send ACK payloads.
     fn set_auto_ack_pipe(&mut self, enable: bool, pipe: u8) -> Result<(), Self::AutoAckErrorType>;
 
+    /// Set auto-ack for all 6 pipes at once using a bitmask.
+    ///
+    /// Bits 0 through 5 of `mask` map to pipes 0 through 5 (`1` enables auto-ack on
+    /// that pipe, `0` disables it); bits 6 and 7 are ignored. This configures all six
+    /// pipes in a single `EN_AA` write, instead of calling
+    /// [`EsbAutoAck::set_auto_ack_pipe()`] six times.
+    ///
+    /// If pipe 0's bit is cleared while ACK payloads are enabled, the ACK payloads
+    /// feature is also disabled (see [`EsbAutoAck::set_auto_ack_pipe()`]).
+    fn set_auto_ack_bin(&mut self, mask: u8) -> Result<(), Self::AutoAckErrorType>;
+
     /// Set the number of retry attempts and delay between retry attempts when
     /// transmitting a payload.
     ///
@@ -346,6 +434,10 @@ pub trait EsbAutoAck: EsbPayloadLength {
     /// the payload was not acknowledged on the first attempt.
     fn set_auto_retries(&mut self, delay: u8, count: u8) -> Result<(), Self::AutoAckErrorType>;
 
+    /// Get the currently configured auto-retry `delay` and `count`
+    /// (see [`EsbAutoAck::set_auto_retries()`]), in that order.
+    fn get_auto_retries(&mut self) -> Result<(u8, u8), Self::AutoAckErrorType>;
+
     /// Allow the functionality of the `ask_no_ack` parameter in [`EsbRadio::send()`] and
     /// [`EsbRadio::write()`].
     ///
@@ -363,10 +455,38 @@ pub trait EsbPaLevel {
     type PaLevelErrorType;
 
     /// Get the currently configured Power Amplitude Level (PA Level)
-    fn get_pa_level(&mut self) -> Result<PaLevel, Self::PaLevelErrorType>;
-
-    /// Set the radio's Power Amplitude Level (PA Level)
-    fn set_pa_level(&mut self, pa_level: PaLevel) -> Result<(), Self::PaLevelErrorType>;
+    fn get_pa_level(&mut self) -> Result<PaLevel, Self::PaLevelErrorType> {
+        Ok(self.get_pa_level_lna()?.0)
+    }
+
+    /// Get the currently configured PA Level alongside the state of the LNA (Low
+    /// Noise Amplifier) gain bit (`RF_SETUP` bit 0), as found on Si24R1 clone
+    /// modules.
+    ///
+    /// On genuine nRF24L01(+) silicon this bit is reserved; only Si24R1 clones use it
+    /// to gate an LNA stage that shifts the actual dBm output at every PA step.
+    fn get_pa_level_lna(&mut self) -> Result<(PaLevel, bool), Self::PaLevelErrorType>;
+
+    /// Set the radio's Power Amplitude Level (PA Level).
+    ///
+    /// This defaults the Si24R1 LNA gain bit (see [`EsbPaLevel::set_pa_level_lna()`])
+    /// to enabled, matching the hardware's reset state.
+    fn set_pa_level(&mut self, pa_level: PaLevel) -> Result<(), Self::PaLevelErrorType> {
+        self.set_pa_level_lna(pa_level, true)
+    }
+
+    /// Set the radio's PA Level and the LNA (Low Noise Amplifier) gain bit
+    /// (`RF_SETUP` bit 0), as found on Si24R1 clone modules.
+    ///
+    /// On genuine nRF24L01(+) silicon this bit is reserved and `lna_enable` has no
+    /// effect; on Si24R1 clones, disabling it shifts the actual dBm output at every
+    /// PA step (see the pyRF24 PA-level table), so set it to match the module
+    /// actually in use.
+    fn set_pa_level_lna(
+        &mut self,
+        pa_level: PaLevel,
+        lna_enable: bool,
+    ) -> Result<(), Self::PaLevelErrorType>;
 }
 
 /// A trait to represent manipulation of the state of power
@@ -401,10 +521,61 @@ pub trait EsbPower {
     /// ```
     fn power_up(&mut self, delay: Option<u32>) -> Result<(), Self::PowerErrorType>;
 
+    /// Write the CONFIG register's power bit and return immediately, without blocking
+    /// for the Tpd2stby settling delay that [`EsbPower::power_up()`] would otherwise
+    /// spend in [`DelayNs::delay_us()`](embedded_hal::delay::DelayNs::delay_us).
+    ///
+    /// Poll [`EsbPower::power_up_ready()`] until it returns `true` before entering RX
+    /// or TX mode (e.g. [`EsbRadio::as_rx()`]). This lets a cooperative scheduler
+    /// (an async executor, a round-robin `loop {}`) overlap the oscillator warm-up
+    /// with other work instead of stalling on a single blocking delay.
+    ///
+    /// [`EsbPower::power_up()`] is a thin wrapper over this pair, so callers that don't
+    /// care about non-blocking behavior are unaffected.
+    fn begin_power_up(&mut self) -> Result<(), Self::PowerErrorType>;
+
+    /// Poll whether the Tpd2stby settling delay (begun by [`EsbPower::begin_power_up()`])
+    /// has elapsed, given `elapsed_us` microseconds since the previous call to this
+    /// function (or to [`EsbPower::begin_power_up()`], for the first poll).
+    ///
+    /// Returns `true` once the full 5 millisecond settling window has elapsed (or
+    /// immediately, if the radio was already powered up). The caller is responsible for
+    /// measuring `elapsed_us` using whatever clock/timer is available in their
+    /// environment; this crate stays `no_std` and does not assume one exists.
+    fn power_up_ready(&mut self, elapsed_us: u32) -> bool;
+
     /// Get the current (cached) state of the radio's power.
     ///
     /// Returns `true` if powered up or `false` if powered down.
     fn is_powered(&self) -> bool;
+
+    /// Explicitly settle the radio in Standby-I (CE low).
+    ///
+    /// This is the lowest standby current draw (~26uA) available while still powered up.
+    /// Re-entering TX or RX mode from here pays the usual CE settling time.
+    fn as_standby_i(&mut self) -> Result<(), Self::PowerErrorType>;
+
+    /// Explicitly settle the radio in Standby-II (CE held high, TX FIFO empty).
+    ///
+    /// This allows sub-millisecond re-transmit latency, at a slightly higher standby
+    /// current draw than Standby-I.
+    ///
+    /// <div class="warning">
+    ///
+    /// The radio must not be in RX mode, nor have anything left in the TX FIFO, or this
+    /// will instead (re)start active RX/TX operation.
+    ///
+    /// </div>
+    fn as_standby_ii(&mut self) -> Result<(), Self::PowerErrorType>;
+
+    /// Set the idle state that [`EsbRadio::send()`] and [`EsbRadio::resend()`] settle the
+    /// radio into after a (re)transmission completes.
+    ///
+    /// Defaults to [`FallbackMode::StandbyI`].
+    fn set_fallback_mode(&mut self, mode: FallbackMode);
+
+    /// Get the currently configured fallback mode (see [`EsbPower::set_fallback_mode()`]).
+    fn get_fallback_mode(&self) -> FallbackMode;
 }
 
 /// A trait to represent manipulation of Cyclical Redundancy Checksums
@@ -442,6 +613,14 @@ pub trait EsbDetails {
     /// Using this in production should be limited due to a significant increase in
     /// compile size.
     fn print_details(&mut self) -> Result<(), Self::DetailsErrorType>;
+
+    /// Fetch and decode the radio's current configuration into a structured
+    /// [`RadioDetails`](crate::types::RadioDetails).
+    ///
+    /// This is the structured counterpart to [`EsbDetails::print_details()`], intended
+    /// for host tooling (logging, GUIs, automated diagnostics) that would rather consume
+    /// typed data than parse printed text.
+    fn get_details(&mut self) -> Result<crate::types::RadioDetails, Self::DetailsErrorType>;
 }
 
 pub trait EsbInit {
@@ -467,6 +646,92 @@ pub trait EsbInit {
     /// This function is a convenience where calling multiple configuration functions may
     /// be cumbersome.
     fn with_config(&mut self, config: &RadioConfig) -> Result<(), Self::ConfigErrorType>;
+
+    /// Read the radio's live configuration back into a [`RadioConfig`] object.
+    ///
+    /// This is the inverse of [`EsbInit::with_config()`] and is useful for verifying
+    /// that a prior [`EsbInit::with_config()`] call actually took effect, or for
+    /// snapshotting a radio's configuration (e.g. to clone it onto another radio).
+    fn read_config(&mut self) -> Result<RadioConfig, Self::ConfigErrorType>;
+
+    /// Re-verify that the radio is still responding on the SPI bus, without
+    /// reconfiguring it.
+    ///
+    /// This performs the same kind of write-then-read-back probe [`EsbInit::init()`]
+    /// uses to detect an incompatible/unresponsive module, but against a register whose
+    /// value this function restores afterward, so it is safe to call at any time (e.g.
+    /// periodically, to detect a module that went offline after a power glitch or loose
+    /// wiring) instead of only once at startup.
+    fn is_chip_connected(&mut self) -> Result<bool, Self::ConfigErrorType>;
+}
+
+/// A trait to represent surveying channel activity using the Received Power Detector
+/// (RPD).
+pub trait EsbScanner {
+    type ScannerErrorType;
+
+    /// Survey the given `channels` for activity, sampling the RPD `samples_per_channel`
+    /// times per channel.
+    ///
+    /// See [`RF24::scan_channels()`](crate::radio::RF24::scan_channels) for the exact
+    /// sampling behavior.
+    fn scan_channels<const N: usize>(
+        &mut self,
+        channels: &[u8; N],
+        samples_per_channel: u8,
+    ) -> Result<[u8; N], Self::ScannerErrorType>;
+
+    /// Survey all 126 channels (0..=125), sampling the RPD `dwell` times per channel.
+    ///
+    /// Like [`EsbScanner::scan_channels()`], but fixed to a full-band histogram, for
+    /// callers building a spectrum display or otherwise picking the quietest channel
+    /// out of everything available.
+    fn scan_all(&mut self, dwell: u8) -> Result<[u8; 126], Self::ScannerErrorType>;
+
+    /// Like [`EsbScanner::scan_all()`], but the radio's full configuration (not just
+    /// the RX/TX mode and CE pin state already restored by [`EsbScanner::scan_all()`])
+    /// is snapshotted beforehand and restored once the scan completes, even if the scan
+    /// itself fails partway through.
+    ///
+    /// This is the config-preserving entry point for a caller that wants to borrow the
+    /// radio for a one-off spectrum survey (e.g. to render a textual band display) and
+    /// hand it back exactly as found, without manually snapshotting
+    /// [`EsbInit::read_config()`]/[`EsbInit::with_config()`] around the call.
+    fn scan_all_preserving_config(
+        &mut self,
+        dwell: u8,
+    ) -> Result<[u8; 126], Self::ScannerErrorType>;
+}
+
+/// A trait to represent the constant-carrier-wave test mode used for
+/// regulatory/antenna-tuning work, built on the `RF_SETUP` register's `CONT_WAVE` and
+/// `PLL_LOCK` bits.
+pub trait EsbTestMode {
+    type TestModeErrorType;
+
+    /// Start emitting an unmodulated carrier wave at the given [`PaLevel`] and
+    /// `channel`.
+    ///
+    /// See [`RF24::start_carrier_wave()`](crate::radio::RF24::start_carrier_wave) for
+    /// the exact register-level behavior.
+    fn start_carrier_wave(
+        &mut self,
+        level: PaLevel,
+        channel: u8,
+    ) -> Result<(), Self::TestModeErrorType>;
+
+    /// Stop a constant carrier wave started via [`EsbTestMode::start_carrier_wave()`].
+    ///
+    /// See [`RF24::stop_carrier_wave()`](crate::radio::RF24::stop_carrier_wave) for the
+    /// configuration left behind afterward.
+    fn stop_carrier_wave(&mut self) -> Result<(), Self::TestModeErrorType>;
+
+    /// Read the Received Power Detector (RPD) flag.
+    ///
+    /// This is an alias of [`RF24::rpd()`](crate::radio::RF24::rpd), named after its
+    /// typical use case: confirming (on a second radio) that a carrier wave started via
+    /// [`EsbTestMode::start_carrier_wave()`] is actually being received.
+    fn test_rpd(&mut self) -> Result<bool, Self::TestModeErrorType>;
 }
 
 /// A trait to represent manipulation of an ESB capable transceiver.
@@ -542,7 +807,12 @@ pub trait EsbRadio {
     ///
     /// Unlike [`EsbRadio::rewrite()`], this function will only make one attempt to
     /// resend the failed payload.
-    fn resend(&mut self) -> Result<bool, Self::RadioErrorType>;
+    ///
+    /// If `send_only` is `false` and the resend succeeds, the RX FIFO is flushed
+    /// afterward (via [`EsbFifo::flush_rx()`]) to discard any ACK payload the peer sent
+    /// back with its acknowledgement. Pass `send_only` as `true` to leave the RX FIFO
+    /// untouched instead, so that ACK payload can be fetched with [`EsbRadio::read()`].
+    fn resend(&mut self, send_only: bool) -> Result<bool, Self::RadioErrorType>;
 
     /// Similar to [`EsbRadio::write()`] but specifically for failed transmissions.
     ///
@@ -561,7 +831,29 @@ pub trait EsbRadio {
     /// - The radio's TX FIFO is flushed (via [`EsbFifo::flush_tx()`]).
     /// - The radio's CE pin is set to inactive LOW. This can be done directly on the pin or by calling
     ///   [`EsbRadio::as_tx()`].
-    fn rewrite(&mut self) -> Result<(), Self::RadioErrorType>;
+    ///
+    /// Returns `false` (and does nothing else) if the TX FIFO is empty, since there is no
+    /// payload to reuse in that case.
+    fn rewrite(&mut self) -> Result<bool, Self::RadioErrorType>;
+
+    /// Write `buf` to the TX FIFO, blocking (up to `timeout`) while the 3-level TX FIFO
+    /// is full instead of returning `false` immediately like [`EsbRadio::write()`] does.
+    ///
+    /// This generalizes the one-shot [`EsbRadio::resend()`]/continuous
+    /// [`EsbRadio::rewrite()`] pair into a single bounded call meant for queuing a stream
+    /// of payloads over a noisy or low-signal link: while the FIFO is full, this polls
+    /// for a `tx_df` (auto-retry exhausted) event and, whenever one occurs, reuses the
+    /// stuck payload (the same FIFO-reuse [`EsbRadio::rewrite()`] performs) and keeps the
+    /// CE pin asserted so the FIFO keeps draining, rather than giving up.
+    ///
+    /// Returns `true` once a FIFO slot frees up and `buf` has been accepted, or `false`
+    /// if `timeout` elapses first. The radio is left in active TX mode either way; call
+    /// [`EsbRadio::as_tx()`] (or let the CE pin go low) to stop it.
+    fn write_blocking(
+        &mut self,
+        buf: &[u8],
+        timeout: Duration,
+    ) -> Result<bool, Self::RadioErrorType>;
 
     /// Get the Auto-Retry Count (ARC) about the previous transmission.
     ///
@@ -570,6 +862,15 @@ pub trait EsbRadio {
     /// If auto-ack feature is disabled, then this function provides no useful data.
     fn get_last_arc(&mut self) -> Result<u8, Self::RadioErrorType>;
 
+    /// Get the count of lost packets (PLOS) since the last time the radio's channel was set.
+    ///
+    /// This counter is saturated at 15; it does not overflow/reset on its own. Setting the
+    /// channel (via [`EsbChannel::set_channel()`]) resets it back to `0`, so this value is
+    /// only meaningful relative to the currently configured channel. Combined with
+    /// [`EsbRadio::get_last_arc()`], this can be used to gauge link quality and decide
+    /// whether to switch channels or adjust [`EsbAutoAck::set_auto_retries()`].
+    fn get_lost_packets(&mut self) -> Result<u8, Self::RadioErrorType>;
+
     /// Read data from the radio's RX FIFO into the specified `buf`.
     ///
     /// All payloads received by the radio are stored in the RX FIFO (a 3 layer stack).
@@ -582,4 +883,129 @@ pub trait EsbRadio {
     /// (fetched internally using [`EsbPayloadLength::get_dynamic_payload_length()`]) if
     /// dynamic payload lengths are enable (see [`EsbPayloadLength::set_dynamic_payloads()`]).
     fn read(&mut self, buf: &mut [u8], len: Option<u8>) -> Result<u8, Self::RadioErrorType>;
+
+    /// Like [`EsbRadio::read()`], but also reports which pipe the payload arrived on.
+    ///
+    /// The pipe number is read from the same STATUS byte returned by the SPI transaction
+    /// that fetches the payload, so (unlike a separate call to
+    /// [`EsbFifo::available_pipe()`]) there is no race where the RX FIFO advances to the
+    /// next payload (on a different pipe) between the two reads.
+    ///
+    /// Returns the number of bytes copied into `buf` and the pipe (0-5) the payload was
+    /// received on, or `7` if the RX FIFO was already empty.
+    fn read_with_pipe(
+        &mut self,
+        buf: &mut [u8],
+        len: Option<u8>,
+    ) -> Result<(u8, u8), Self::RadioErrorType>;
+
+    /// Drain every payload currently in the RX FIFO into `buf`, packed back-to-back.
+    ///
+    /// Unlike [`EsbRadio::read()`], which only fetches the first available payload and
+    /// leaves the caller to re-infer lengths for anything beyond that, this loops while
+    /// [`EsbFifo::available()`] is `true`, fetching each payload's length (via
+    /// [`EsbPayloadLength::get_dynamic_payload_length()`] if dynamic payloads are
+    /// enabled, otherwise the static length set by
+    /// [`EsbPayloadLength::set_payload_length()`]) and appending it to `buf`.
+    ///
+    /// Each drained payload's length is recorded (in order) in `lengths`. Draining stops
+    /// early if `buf` or `lengths` would overflow, even if the RX FIFO is not yet empty.
+    /// Returns the number of payloads drained.
+    ///
+    /// The `rx_dr` event is cleared once at the end, after every payload is fetched,
+    /// rather than once per payload.
+    fn read_all(
+        &mut self,
+        buf: &mut [u8],
+        lengths: &mut [u8],
+    ) -> Result<usize, Self::RadioErrorType>;
+
+    /// Transmit several payloads back-to-back, pipelining them through the TX FIFO
+    /// instead of waiting for each one individually (as repeated calls to
+    /// [`EsbRadio::send()`] would).
+    ///
+    /// This flushes the TX FIFO upon entry, then preloads up to 3 payloads (the TX
+    /// FIFO's depth) and keeps the radio in active TX mode, topping the FIFO back up
+    /// with the next pending payload every time a `tx_ds` event frees a slot. If a
+    /// `tx_df` event occurs (the auto-retry limit was reached for some payload), this
+    /// stops early; the failed payload remains at the top of the TX FIFO for a
+    /// subsequent [`EsbRadio::resend()`] or [`EsbFifo::flush_tx()`] call.
+    ///
+    /// Returns the number of payloads that were successfully acknowledged (or, if
+    /// [`EsbAutoAck::allow_ask_no_ack()`] was used for `ask_no_ack`, successfully sent).
+    fn send_stream(
+        &mut self,
+        payloads: &[&[u8]],
+        ask_no_ack: bool,
+    ) -> Result<usize, Self::RadioErrorType>;
+}
+
+/// An `async` counterpart to [`EsbChannel`], for use with
+/// [`AsyncRF24`](crate::radio::AsyncRF24).
+///
+/// Only compiled with the `async` feature enabled.
+#[cfg(feature = "async")]
+pub trait EsbChannelAsync {
+    type ChannelErrorType;
+
+    /// Set the radio's currently selected channel.
+    ///
+    /// See [`EsbChannel::set_channel()`] for the exact clamping/encoding behavior.
+    async fn set_channel(&mut self, channel: u8) -> Result<(), Self::ChannelErrorType>;
+
+    /// Get the radio's currently selected channel.
+    async fn get_channel(&mut self) -> Result<u8, Self::ChannelErrorType>;
+}
+
+/// An `async` counterpart to [`EsbFifo`], for use with
+/// [`AsyncRF24`](crate::radio::AsyncRF24).
+///
+/// Only compiled with the `async` feature enabled.
+#[cfg(feature = "async")]
+pub trait EsbFifoAsync {
+    type FifoErrorType;
+
+    /// Flush the radio's RX FIFO.
+    async fn flush_rx(&mut self) -> Result<(), Self::FifoErrorType>;
+
+    /// Flush the radio's TX FIFO.
+    async fn flush_tx(&mut self) -> Result<(), Self::FifoErrorType>;
+
+    /// Get the state of the specified FIFO. See [`EsbFifo::get_fifo_state()`].
+    async fn get_fifo_state(&mut self, about_tx: bool) -> Result<FifoState, Self::FifoErrorType>;
+
+    /// Is there a payload available in the radio's RX FIFO?
+    async fn available(&mut self) -> Result<bool, Self::FifoErrorType>;
+
+    /// Like [`EsbFifoAsync::available()`], but also reports the receiving pipe. See
+    /// [`EsbFifo::available_pipe()`].
+    async fn available_pipe(&mut self, pipe: &mut u8) -> Result<bool, Self::FifoErrorType>;
+}
+
+/// An `async` counterpart to [`EsbPayloadLength`], for use with
+/// [`AsyncRF24`](crate::radio::AsyncRF24).
+///
+/// Only compiled with the `async` feature enabled.
+#[cfg(feature = "async")]
+pub trait EsbPayloadLengthAsync {
+    type PayloadLengthErrorType;
+
+    /// Set the radio's static payload length. See [`EsbPayloadLength::set_payload_length()`].
+    async fn set_payload_length(&mut self, length: u8) -> Result<(), Self::PayloadLengthErrorType>;
+
+    /// Get the currently configured static payload length used on pipe 0.
+    async fn get_payload_length(&mut self) -> Result<u8, Self::PayloadLengthErrorType>;
+
+    /// Set the dynamic payloads feature for all pipes. See
+    /// [`EsbPayloadLength::set_dynamic_payloads()`].
+    async fn set_dynamic_payloads(
+        &mut self,
+        enable: bool,
+    ) -> Result<(), Self::PayloadLengthErrorType>;
+
+    /// Get the current setting of the dynamic payloads feature.
+    fn get_dynamic_payloads(&self) -> bool;
+
+    /// Get the dynamic length of the next available payload in the RX FIFO.
+    async fn get_dynamic_payload_length(&mut self) -> Result<u8, Self::PayloadLengthErrorType>;
 }
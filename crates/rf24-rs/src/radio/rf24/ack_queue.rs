@@ -0,0 +1,238 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::RF24;
+use crate::radio::{prelude::EsbAutoAck, Nrf24Error};
+
+/// A single pipe's fixed-capacity ring buffer of outgoing ACK payloads, holding up to
+/// `N` payloads of at most 32 bytes each.
+struct RingBuffer<const N: usize> {
+    bufs: [[u8; 32]; N],
+    lens: [u8; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for RingBuffer<N> {
+    fn default() -> Self {
+        Self {
+            bufs: [[0u8; 32]; N],
+            lens: [0u8; N],
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> RingBuffer<N> {
+    fn push(&mut self, data: &[u8]) -> bool {
+        if self.len >= N {
+            return false;
+        }
+        let tail = (self.head + self.len) % N;
+        let copy_len = data.len().min(32);
+        self.bufs[tail][..copy_len].copy_from_slice(&data[..copy_len]);
+        self.lens[tail] = copy_len as u8;
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<([u8; 32], u8)> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = (self.bufs[self.head], self.lens[self.head]);
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(item)
+    }
+}
+
+/// A fixed-capacity, per-pipe queue of outgoing ACK payloads for use with
+/// [`RF24::service_ack_queue()`].
+///
+/// Each of the 6 pipes gets its own ring buffer holding up to `N` payloads, so a PRX
+/// application can queue up replies ahead of time instead of racing to call
+/// [`EsbAutoAck::write_ack_payload()`] right before the next packet arrives.
+pub struct AckPayloadQueue<const N: usize> {
+    pipes: [RingBuffer<N>; 6],
+}
+
+impl<const N: usize> Default for AckPayloadQueue<N> {
+    fn default() -> Self {
+        Self {
+            pipes: core::array::from_fn(|_| RingBuffer::default()),
+        }
+    }
+}
+
+impl<const N: usize> AckPayloadQueue<N> {
+    /// Construct an empty queue for all 6 pipes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `data` (truncated to 32 bytes) as the next outgoing ACK payload for `pipe`.
+    ///
+    /// Returns `false` (and queues nothing) if `pipe` is greater than `5` or that
+    /// pipe's queue already holds `N` payloads.
+    pub fn push(&mut self, pipe: u8, data: &[u8]) -> bool {
+        match self.pipes.get_mut(pipe as usize) {
+            Some(ring) => ring.push(data),
+            None => false,
+        }
+    }
+
+    /// The number of payloads currently queued for `pipe` (`0` if `pipe` is out of
+    /// range).
+    pub fn len(&self, pipe: u8) -> usize {
+        self.pipes.get(pipe as usize).map_or(0, |ring| ring.len)
+    }
+
+    /// `true` if `pipe` has no payloads queued (or is out of range).
+    pub fn is_empty(&self, pipe: u8) -> bool {
+        self.len(pipe) == 0
+    }
+
+    fn pop(&mut self, pipe: u8) -> Option<([u8; 32], u8)> {
+        self.pipes.get_mut(pipe as usize)?.pop()
+    }
+}
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Pre-load the TX FIFO with the next ACK payload queued for `pipe` in `queue`, or a
+    /// zero-length ACK if that pipe's queue is currently empty.
+    ///
+    /// Because an ACK payload must already be sitting in the TX FIFO before the packet
+    /// it acknowledges arrives, call this once per
+    /// [`EsbFifo::available()`](crate::radio::prelude::EsbFifo::available)/
+    /// [`EsbRadio::read()`](crate::radio::prelude::EsbRadio::read)
+    /// cycle (e.g. from an IRQ service routine) so replies keep flowing without the
+    /// application racing the radio. This turns the manual
+    /// [`EsbAutoAck::write_ack_payload()`] dance into a driven PRX exchange.
+    pub fn service_ack_queue<const N: usize>(
+        &mut self,
+        pipe: u8,
+        queue: &mut AckPayloadQueue<N>,
+    ) -> Result<bool, Nrf24Error<SPI::Error, DO::Error>> {
+        match queue.pop(pipe) {
+            Some((buf, len)) => self.write_ack_payload(pipe, &buf[..len as usize]),
+            None => self.write_ack_payload(pipe, &[]),
+        }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::AckPayloadQueue;
+    use crate::{
+        radio::{prelude::EsbAutoAck, rf24::commands, rf24::registers},
+        spi_test_expects,
+        test::mk_radio,
+    };
+    use embedded_hal_mock::eh1::spi::Transaction as SpiTransaction;
+    use std::vec;
+
+    const EN_ACK_PAY: u8 = 1 << 1;
+    const EN_DPL: u8 = 1 << 2;
+
+    #[test]
+    fn queues_and_pops_in_fifo_order() {
+        let mut queue = AckPayloadQueue::<3>::new();
+        assert!(queue.is_empty(2));
+        assert!(queue.push(2, &[1, 2, 3]));
+        assert!(queue.push(2, &[4, 5]));
+        assert_eq!(queue.len(2), 2);
+        assert!(!queue.is_empty(2));
+
+        let ack_buf = [commands::W_ACK_PAYLOAD | 2, 1, 2, 3];
+        let spi_expectations = spi_test_expects![
+            // enable ACK payloads
+            (vec![registers::FEATURE, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![
+                    registers::FEATURE | commands::W_REGISTER,
+                    EN_ACK_PAY | EN_DPL,
+                ],
+                vec![0xEu8, 0u8],
+            ),
+            (vec![registers::EN_AA, 0u8], vec![0xEu8, 0u8]),
+            (vec![registers::DYNPD, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0u8],
+                vec![0xEu8, 0u8],
+            ),
+            // service_ack_queue(2, ..) writes the first queued payload
+            (ack_buf.to_vec(), vec![0u8; 4]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_ack_payloads(true).unwrap();
+        assert!(radio.service_ack_queue(2, &mut queue).unwrap());
+        assert_eq!(queue.len(2), 1);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn falls_back_to_zero_length_ack_when_empty() {
+        let mut queue = AckPayloadQueue::<3>::new();
+
+        let ack_buf = [commands::W_ACK_PAYLOAD | 1];
+        let spi_expectations = spi_test_expects![
+            // enable ACK payloads
+            (vec![registers::FEATURE, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![
+                    registers::FEATURE | commands::W_REGISTER,
+                    EN_ACK_PAY | EN_DPL,
+                ],
+                vec![0xEu8, 0u8],
+            ),
+            (vec![registers::EN_AA, 0u8], vec![0xEu8, 0u8]),
+            (vec![registers::DYNPD, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0u8],
+                vec![0xEu8, 0u8],
+            ),
+            // service_ack_queue(1, ..) finds nothing queued, so it sends a zero-length ACK
+            (ack_buf.to_vec(), vec![0u8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_ack_payloads(true).unwrap();
+        assert!(radio.service_ack_queue(1, &mut queue).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn push_rejects_out_of_range_pipe_and_full_queue() {
+        let mut queue = AckPayloadQueue::<1>::new();
+        assert!(!queue.push(6, &[1]));
+        assert!(queue.push(0, &[1]));
+        assert!(!queue.push(0, &[2]));
+        assert_eq!(queue.len(6), 0);
+    }
+
+    #[test]
+    fn service_ack_queue_sends_nothing_when_ack_payloads_disabled() {
+        // with the ACK payloads feature left disabled, write_ack_payload() is a no-op
+        // (no SPI transaction), but the queue is still drained a payload per cycle
+        let mut queue = AckPayloadQueue::<3>::new();
+        assert!(queue.push(0, &[1, 2]));
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert!(!radio.service_ack_queue(0, &mut queue).unwrap());
+        assert_eq!(queue.len(0), 0);
+        spi.done();
+        ce_pin.done();
+    }
+}
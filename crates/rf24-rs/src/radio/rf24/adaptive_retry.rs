@@ -0,0 +1,152 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::RF24;
+use crate::radio::{
+    prelude::{EsbAutoAck, EsbRadio},
+    Nrf24Error,
+};
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Nudge the auto-retry `delay` and `count` (see [`EsbAutoAck::set_auto_retries()`])
+    /// based on how the most recent transmission actually went, instead of committing to
+    /// one static retry profile for the whole session.
+    ///
+    /// Call this after a [`EsbRadio::send()`] or [`EsbRadio::write()`] attempt. It reads
+    /// back the Auto-Retry Count (via [`EsbRadio::get_last_arc()`]) to judge link quality:
+    ///
+    /// - If the last packet exhausted all configured retries (ARC reached `count`), `delay`
+    ///   is increased (clamped at 15) to give a congested link more time to clear. If `delay`
+    ///   is already at 15, `count` is increased instead (clamped at 15).
+    /// - If the last packet was acknowledged on the first attempt (ARC is `0`), `delay` is
+    ///   decreased (clamped at 0) to favor lower latency once the link is clean again.
+    /// - Otherwise, the current profile is left as-is.
+    pub fn adapt_auto_retries(&mut self) -> Result<(), Nrf24Error<SPI::Error, DO::Error>> {
+        let arc = self.get_last_arc()?;
+        let (mut delay, mut count) = self.get_auto_retries()?;
+        if arc >= count {
+            if delay < 15 {
+                delay += 1;
+            } else if count < 15 {
+                count += 1;
+            }
+        } else if arc == 0 && delay > 0 {
+            delay -= 1;
+        } else {
+            return Ok(());
+        }
+        self.set_auto_retries(delay, count)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::{EsbAutoAck, EsbRadio};
+    use crate::{
+        radio::rf24::{commands, registers},
+        spi_test_expects,
+        test::mk_radio,
+    };
+    use std::vec;
+
+    #[test]
+    fn increase_delay_on_saturated_arc() {
+        let spi_expectations = spi_test_expects![
+            (vec![registers::OBSERVE_TX, 0u8], vec![0xEu8, 0x5Fu8]),
+            (vec![registers::SETUP_RETR, 0u8], vec![0xEu8, 0x5Fu8]),
+            (
+                vec![registers::SETUP_RETR | commands::W_REGISTER, 0x6Fu8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.adapt_auto_retries().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn increase_count_when_delay_saturated() {
+        let spi_expectations = spi_test_expects![
+            (vec![registers::OBSERVE_TX, 0u8], vec![0xEu8, 0x0Au8]),
+            (vec![registers::SETUP_RETR, 0u8], vec![0xEu8, 0xFAu8]),
+            (
+                vec![registers::SETUP_RETR | commands::W_REGISTER, 0xFBu8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.adapt_auto_retries().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn no_further_increase_once_fully_saturated() {
+        let spi_expectations = spi_test_expects![
+            (vec![registers::OBSERVE_TX, 0u8], vec![0xEu8, 0xFFu8]),
+            (vec![registers::SETUP_RETR, 0u8], vec![0xEu8, 0xFFu8]),
+            (
+                vec![registers::SETUP_RETR | commands::W_REGISTER, 0xFFu8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.adapt_auto_retries().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn decrease_delay_on_first_try_success() {
+        let spi_expectations = spi_test_expects![
+            (vec![registers::OBSERVE_TX, 0u8], vec![0xEu8, 0u8]),
+            (vec![registers::SETUP_RETR, 0u8], vec![0xEu8, 0x5Fu8]),
+            (
+                vec![registers::SETUP_RETR | commands::W_REGISTER, 0x4Fu8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.adapt_auto_retries().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn no_change_when_already_minimal_delay() {
+        let spi_expectations = spi_test_expects![
+            (vec![registers::OBSERVE_TX, 0u8], vec![0xEu8, 0u8]),
+            (vec![registers::SETUP_RETR, 0u8], vec![0xEu8, 0x0Fu8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.adapt_auto_retries().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn no_change_on_partial_retries() {
+        let spi_expectations = spi_test_expects![
+            (vec![registers::OBSERVE_TX, 0u8], vec![0xEu8, 0x03u8]),
+            (vec![registers::SETUP_RETR, 0u8], vec![0xEu8, 0x5Fu8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.adapt_auto_retries().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+}
@@ -13,19 +13,27 @@ where
     type AutoAckErrorType = Nrf24Error<SPI::Error, DO::Error>;
 
     fn set_ack_payloads(&mut self, enable: bool) -> Result<(), Self::AutoAckErrorType> {
-        if self._feature.ack_payloads() != enable {
+        if self.feature.ack_payloads() != enable {
             self.spi_read(1, registers::FEATURE)?;
-            self._feature =
-                Feature::from_bits(self._feature.into_bits() & !Feature::REG_MASK | self._buf[1])
+            self.feature =
+                Feature::from_bits(self.feature.into_bits() & !Feature::REG_MASK | self.buf[1])
                     .with_ack_payloads(enable);
-            self.spi_write_byte(
+            self.spi_write_byte_checked(
                 registers::FEATURE,
-                self._feature.into_bits() & Feature::REG_MASK,
+                self.feature.into_bits() & Feature::REG_MASK,
             )?;
 
             if enable {
-                // Enable dynamic payload on all pipes
-                self.spi_write_byte(registers::DYNPD, 0x3F)?;
+                // Only force dynamic payloads on the pipes that actually have
+                // auto-ack enabled, since those are the only pipes capable of
+                // carrying an ACK payload. Pipes without auto-ack keep whatever
+                // DYNPD state the user already configured via
+                // `EsbPayloadLength::set_dynamic_payload_pipe()`.
+                self.spi_read(1, registers::EN_AA)?;
+                let auto_ack_pipes = self.buf[1];
+                self.spi_read(1, registers::DYNPD)?;
+                let dynpd = self.buf[1] | auto_ack_pipes;
+                self.spi_write_byte_checked(registers::DYNPD, dynpd)?;
             }
             // else disable ack payloads, but leave dynamic payload features as is
         }
@@ -33,13 +41,13 @@ where
     }
 
     fn get_ack_payloads(&self) -> bool {
-        self._feature.ack_payloads()
+        self.feature.ack_payloads()
     }
 
     fn set_auto_ack(&mut self, enable: bool) -> Result<(), Self::AutoAckErrorType> {
-        self.spi_write_byte(registers::EN_AA, 0x3F * enable as u8)?;
+        self.spi_write_byte_checked(registers::EN_AA, 0x3F * enable as u8)?;
         // accommodate ACK payloads feature
-        if !enable && self._feature.ack_payloads() {
+        if !enable && self.feature.ack_payloads() {
             self.set_ack_payloads(false)?;
         }
         Ok(())
@@ -51,23 +59,31 @@ where
         }
         self.spi_read(1, registers::EN_AA)?;
         let mask = 1 << pipe;
-        let reg_val = self._buf[1];
-        if !enable && self._feature.ack_payloads() && pipe == 0 {
+        let reg_val = self.buf[1];
+        if !enable && self.feature.ack_payloads() && pipe == 0 {
             self.set_ack_payloads(enable)?;
         }
-        self.spi_write_byte(registers::EN_AA, reg_val & !mask | (mask * enable as u8))
+        self.spi_write_byte_checked(registers::EN_AA, reg_val & !mask | (mask * enable as u8))
+    }
+
+    fn set_auto_ack_bin(&mut self, mask: u8) -> Result<(), Self::AutoAckErrorType> {
+        let mask = mask & 0x3F;
+        if mask & 1 == 0 && self.feature.ack_payloads() {
+            self.set_ack_payloads(false)?;
+        }
+        self.spi_write_byte_checked(registers::EN_AA, mask)
     }
 
     fn allow_ask_no_ack(&mut self, enable: bool) -> Result<(), Self::AutoAckErrorType> {
         self.spi_read(1, registers::FEATURE)?;
-        self.spi_write_byte(registers::FEATURE, self._buf[1] & !1 | enable as u8)
+        self.spi_write_byte_checked(registers::FEATURE, self.buf[1] & !1 | enable as u8)
     }
 
     fn write_ack_payload(&mut self, pipe: u8, buf: &[u8]) -> Result<bool, Self::AutoAckErrorType> {
-        if self._feature.ack_payloads() && pipe <= 5 {
+        if self.feature.ack_payloads() && pipe <= 5 {
             let len = buf.len().min(32);
             self.spi_write_buf(commands::W_ACK_PAYLOAD | pipe, &buf[..len])?;
-            return Ok(!self._status.tx_full());
+            return Ok(!self.status.tx_full());
         }
         Ok(false)
     }
@@ -75,6 +91,12 @@ where
     fn set_auto_retries(&mut self, delay: u8, count: u8) -> Result<(), Self::AutoAckErrorType> {
         self.spi_write_byte(registers::SETUP_RETR, count.min(15) | (delay.min(15) << 4))
     }
+
+    fn get_auto_retries(&mut self) -> Result<(u8, u8), Self::AutoAckErrorType> {
+        self.spi_read(1, registers::SETUP_RETR)?;
+        let reg_val = self.buf[1];
+        Ok((reg_val >> 4, reg_val & 0xF))
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////////
@@ -82,7 +104,7 @@ where
 #[cfg(test)]
 mod test {
     extern crate std;
-    use super::{commands, registers, EsbAutoAck};
+    use super::{commands, registers, EsbAutoAck, Nrf24Error};
     use crate::{radio::prelude::EsbPayloadLength, spi_test_expects, test::mk_radio};
     use embedded_hal_mock::eh1::spi::Transaction as SpiTransaction;
     use std::vec;
@@ -107,7 +129,11 @@ mod test {
                 ],
                 vec![0xEu8, 0u8],
             ),
-            // write DYNPD register
+            // read EN_AA register to determine which pipes to force DYNPD on
+            (vec![registers::EN_AA, 0u8], vec![0xEu8, 0x3Fu8]),
+            // read current DYNPD register
+            (vec![registers::DYNPD, 0u8], vec![0xEu8, 0u8]),
+            // write DYNPD register with the auto-ack pipes OR'd in
             (
                 vec![registers::DYNPD | commands::W_REGISTER, 0x3Fu8],
                 vec![0xEu8, 0u8],
@@ -175,9 +201,13 @@ mod test {
                 ],
                 vec![0xEu8, 0u8],
             ),
-            // write DYNPD register
+            // read EN_AA register to determine which pipes to force DYNPD on
+            (vec![registers::EN_AA, 0u8], vec![0xEu8, 0u8]),
+            // read current DYNPD register
+            (vec![registers::DYNPD, 0u8], vec![0xEu8, 0u8]),
+            // write DYNPD register with the auto-ack pipes OR'd in
             (
-                vec![registers::DYNPD | commands::W_REGISTER, 0x3Fu8],
+                vec![registers::DYNPD | commands::W_REGISTER, 0u8],
                 vec![0xEu8, 0u8],
             ),
             // write EN_AA register value
@@ -207,6 +237,64 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    pub fn set_auto_ack_bin() {
+        let spi_expectations = spi_test_expects![
+            // enable ACK payloads
+            // read/write FEATURE register
+            (vec![registers::FEATURE, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![
+                    registers::FEATURE | commands::W_REGISTER,
+                    EN_ACK_PAY | EN_DPL,
+                ],
+                vec![0xEu8, 0u8],
+            ),
+            // read EN_AA register to determine which pipes to force DYNPD on
+            (vec![registers::EN_AA, 0u8], vec![0xEu8, 0u8]),
+            // read current DYNPD register
+            (vec![registers::DYNPD, 0u8], vec![0xEu8, 0u8]),
+            // write DYNPD register with the auto-ack pipes OR'd in
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0u8],
+                vec![0xEu8, 0u8],
+            ),
+            // set_auto_ack_bin(0x05): pipes 0 and 2 enabled in one write
+            (
+                vec![registers::EN_AA | commands::W_REGISTER, 0x05u8],
+                vec![0xEu8, 0u8],
+            ),
+            // set_auto_ack_bin(0x3E): pipe 0 cleared while ACK payloads is still
+            // enabled, so ACK payloads is disabled first
+            (
+                vec![registers::FEATURE, 0u8],
+                vec![0u8, EN_ACK_PAY | EN_DPL],
+            ),
+            (
+                vec![registers::FEATURE | commands::W_REGISTER, EN_DPL],
+                vec![0xEu8, 0u8],
+            ),
+            (
+                vec![registers::EN_AA | commands::W_REGISTER, 0x3Eu8],
+                vec![0xEu8, 0u8],
+            ),
+            // upper 2 bits of the mask are ignored
+            (
+                vec![registers::EN_AA | commands::W_REGISTER, 0x3Fu8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_ack_payloads(true).unwrap();
+        radio.set_auto_ack_bin(0x05).unwrap();
+        radio.set_auto_ack_bin(0x3E).unwrap();
+        assert!(!radio.get_ack_payloads());
+        radio.set_auto_ack_bin(0xFF).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     pub fn allow_ask_no_ack() {
         let spi_expectations = spi_test_expects![
@@ -223,4 +311,64 @@ mod test {
         spi.done();
         ce_pin.done();
     }
+
+    #[test]
+    pub fn verified_write_mismatch() {
+        let spi_expectations = spi_test_expects![
+            // write EN_AA register value
+            (
+                vec![registers::EN_AA | commands::W_REGISTER, 0x3Fu8],
+                vec![0xEu8, 0u8],
+            ),
+            // verify_critical_writes reads the register back; it doesn't hold 0x3F
+            (vec![registers::EN_AA, 0u8], vec![0xEu8, 0u8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.verify_critical_writes = true;
+        assert_eq!(
+            radio.set_auto_ack(true),
+            Err(Nrf24Error::RegisterMismatch {
+                register: registers::EN_AA,
+                expected: 0x3Fu8,
+                actual: 0u8,
+            })
+        );
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn verified_write_module_unreachable() {
+        let spi_expectations = spi_test_expects![
+            // the STATUS byte returned with the write is all-ones: module unreachable
+            (
+                vec![registers::EN_AA | commands::W_REGISTER, 0x3Fu8],
+                vec![0xFFu8, 0xFFu8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.verify_critical_writes = true;
+        assert_eq!(radio.set_auto_ack(true), Err(Nrf24Error::ModuleUnreachable));
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn auto_retries() {
+        let spi_expectations = spi_test_expects![
+            (
+                vec![registers::SETUP_RETR | commands::W_REGISTER, 0x5Fu8],
+                vec![0xEu8, 0u8],
+            ),
+            (vec![registers::SETUP_RETR, 0u8], vec![0xEu8, 0x5Fu8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_auto_retries(5, 15).unwrap();
+        assert_eq!(radio.get_auto_retries(), Ok((5, 15)));
+        spi.done();
+        ce_pin.done();
+    }
 }
@@ -184,6 +184,36 @@ impl Feature {
         }
         Self::from_bits(new_value)
     }
+
+    /// Set the dynamic-payloads bit in isolation, without [`Feature::with_dynamic_payloads()`]'s
+    /// cascade that also disables ACK payloads.
+    ///
+    /// [`RadioConfig`](crate::radio::config::RadioConfig) tracks dynamic/ACK payloads as
+    /// independent per-pipe masks and relies on
+    /// [`RadioConfig::validate()`](crate::radio::config::RadioConfig::validate) (not this
+    /// bit's cascade) to catch a mask enabling ACK payloads without dynamic payloads.
+    pub fn set_dynamic_payloads_bit(self, enable: bool) -> Self {
+        let bit = 1u8 << Self::DYNAMIC_PAYLOADS_OFFSET;
+        Self::from_bits(if enable {
+            self.into_bits() | bit
+        } else {
+            self.into_bits() & !bit
+        })
+    }
+
+    /// Set the ACK-payloads bit in isolation, without [`Feature::with_ack_payloads()`]'s
+    /// cascade that also enables dynamic payloads.
+    ///
+    /// See [`Feature::set_dynamic_payloads_bit()`] for why [`RadioConfig`](crate::radio::config::RadioConfig)
+    /// needs this.
+    pub fn set_ack_payloads_bit(self, enable: bool) -> Self {
+        let bit = 1u8 << Self::ACK_PAYLOADS_OFFSET;
+        Self::from_bits(if enable {
+            self.into_bits() | bit
+        } else {
+            self.into_bits() & !bit
+        })
+    }
 }
 
 // unit tests found in crate::radio::config::test
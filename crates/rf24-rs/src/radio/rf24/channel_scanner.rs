@@ -0,0 +1,367 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::RF24;
+use crate::{
+    radio::{
+        prelude::{EsbFifo, EsbInit, EsbScanner},
+        Nrf24Error,
+    },
+    PaLevel,
+};
+
+/// Incremental, non-blocking state for an RPD-based channel survey, driven one sample
+/// at a time via [`RF24::scan_step()`].
+///
+/// This is the caller-driven counterpart to [`RF24::scan_channels()`]/
+/// [`RF24::scan_all()`], for callers that don't want a single call to block for the
+/// whole scan's duration (e.g. a main loop that also needs to service other
+/// peripherals between samples).
+pub struct ChannelScanner<const N: usize> {
+    channels: [u8; N],
+    samples_per_channel: u8,
+    hits: [u8; N],
+    channel_index: usize,
+    sample_index: u8,
+}
+
+impl<const N: usize> ChannelScanner<N> {
+    /// Start a new scan over `channels`, sampling the Received Power Detector
+    /// `samples_per_channel` times per channel (clamped to a minimum of `1`).
+    pub fn new(channels: [u8; N], samples_per_channel: u8) -> Self {
+        Self {
+            channels,
+            samples_per_channel: samples_per_channel.max(1),
+            hits: [0u8; N],
+            channel_index: 0,
+            sample_index: 0,
+        }
+    }
+
+    /// Has every channel finished its dwell?
+    pub fn is_complete(&self) -> bool {
+        self.channel_index >= N
+    }
+
+    /// The per-channel hit counts accumulated so far (channels not yet reached are `0`).
+    pub fn hits(&self) -> &[u8; N] {
+        &self.hits
+    }
+}
+
+/// Incremental, non-blocking state for a constant-carrier transmit sweep, driven one
+/// channel at a time via [`RF24::sweep_step()`].
+///
+/// Pair this (on a second board) with [`ChannelScanner`] to map interference/occupancy
+/// across the 2.4 GHz band: this board parks a constant carrier on each channel in
+/// turn while the other board's [`ChannelScanner`] samples [`RF24::rpd()`].
+pub struct CarrierSweep<const N: usize> {
+    channels: [u8; N],
+    level: PaLevel,
+    index: usize,
+}
+
+impl<const N: usize> CarrierSweep<N> {
+    /// Start a new sweep over `channels` at the given power amplifier `level`.
+    pub fn new(channels: [u8; N], level: PaLevel) -> Self {
+        Self {
+            channels,
+            level,
+            index: 0,
+        }
+    }
+
+    /// Has every channel been transmitted on?
+    pub fn is_complete(&self) -> bool {
+        self.index >= N
+    }
+}
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Advance `scanner` by a single RPD sample.
+    ///
+    /// On the first sample of a channel, this switches the radio to that channel and
+    /// enters RX mode (as [`RF24::scan_channels()`] does internally); every call still
+    /// blocks for the mandatory 130 microsecond RPD assertion delay (see
+    /// [`RF24::rpd()`]), but never for a whole channel's dwell, so a caller can
+    /// interleave this with other work instead of blocking for the whole scan.
+    ///
+    /// Returns the just-completed `(channel, hits)` pair once a channel's dwell is
+    /// done, or [`None`] while a channel is still being sampled (or `scanner` is
+    /// already [`ChannelScanner::is_complete()`]). Unlike [`RF24::scan_channels()`],
+    /// the radio's CE pin and CONFIG register's PRIM_RX bit are left as RX mode once
+    /// the scan completes; restore them (e.g. via [`RF24::as_tx()`]) if needed.
+    pub fn scan_step<const N: usize>(
+        &mut self,
+        scanner: &mut ChannelScanner<N>,
+    ) -> Result<Option<(u8, u8)>, Nrf24Error<SPI::Error, DO::Error>> {
+        if scanner.is_complete() {
+            return Ok(None);
+        }
+        if scanner.sample_index == 0 {
+            self.set_channel(scanner.channels[scanner.channel_index])?;
+            self.as_rx()?;
+        }
+        self.delay_impl.delay_us(130);
+        if self.rpd()? {
+            scanner.hits[scanner.channel_index] += 1;
+        }
+        scanner.sample_index += 1;
+        if scanner.sample_index < scanner.samples_per_channel {
+            return Ok(None);
+        }
+        let result = (
+            scanner.channels[scanner.channel_index],
+            scanner.hits[scanner.channel_index],
+        );
+        scanner.channel_index += 1;
+        scanner.sample_index = 0;
+        Ok(Some(result))
+    }
+
+    /// Advance `sweep` to its next channel, (re)starting the constant carrier wave
+    /// there via [`RF24::start_carrier_wave()`].
+    ///
+    /// Returns the channel now being transmitted on, or [`None`] once `sweep` is
+    /// already [`CarrierSweep::is_complete()`]. Call [`RF24::stop_carrier_wave()`]
+    /// once the sweep completes (or is abandoned early) to restore the radio.
+    pub fn sweep_step<const N: usize>(
+        &mut self,
+        sweep: &mut CarrierSweep<N>,
+    ) -> Result<Option<u8>, Nrf24Error<SPI::Error, DO::Error>> {
+        if sweep.is_complete() {
+            return Ok(None);
+        }
+        let channel = sweep.channels[sweep.index];
+        self.start_carrier_wave(sweep.level, channel)?;
+        sweep.index += 1;
+        Ok(Some(channel))
+    }
+}
+
+impl<SPI, DO, DELAY> EsbScanner for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type ScannerErrorType = Nrf24Error<SPI::Error, DO::Error>;
+
+    fn scan_channels<const N: usize>(
+        &mut self,
+        channels: &[u8; N],
+        samples_per_channel: u8,
+    ) -> Result<[u8; N], Self::ScannerErrorType> {
+        RF24::scan_channels(self, channels, samples_per_channel)
+    }
+
+    fn scan_all(&mut self, dwell: u8) -> Result<[u8; 126], Self::ScannerErrorType> {
+        RF24::scan_all(self, dwell)
+    }
+
+    /// Snapshots the current configuration via [`EsbInit::read_config()`] beforehand,
+    /// flushes the RX FIFO once the survey completes (clearing out anything a noisy
+    /// channel latched into it while the radio sat in RX mode), then restores the
+    /// snapshot via [`EsbInit::with_config()`] so the caller gets the radio back
+    /// exactly as they lent it.
+    fn scan_all_preserving_config(
+        &mut self,
+        dwell: u8,
+    ) -> Result<[u8; 126], Self::ScannerErrorType> {
+        let config = EsbInit::read_config(self)?;
+        let hits = RF24::scan_all(self, dwell)?;
+        self.flush_rx()?;
+        EsbInit::with_config(self, &config)?;
+        Ok(hits)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::{CarrierSweep, ChannelScanner};
+    use crate::{
+        radio::{
+            prelude::EsbScanner,
+            rf24::{commands, registers},
+        },
+        spi_test_expects,
+        test::mk_radio,
+        PaLevel,
+    };
+    use embedded_hal_mock::eh1::{
+        digital::{State as PinState, Transaction as PinTransaction},
+        spi::Transaction as SpiTransaction,
+    };
+    use std::vec;
+
+    #[test]
+    fn scan_step_drives_a_single_channel() {
+        let mut scanner = ChannelScanner::new([76u8], 2);
+
+        let ce_expectations = [PinTransaction::set(PinState::High)];
+        let spi_expectations = spi_test_expects![
+            // set_channel(76)
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): assert PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): close_rx_pipe(0)
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // first sample: a hit
+            (vec![registers::RPD, 0], vec![0xEu8, 1]),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        // first sample of the channel does not complete it yet
+        assert_eq!(radio.scan_step(&mut scanner).unwrap(), None);
+        assert!(!scanner.is_complete());
+        spi.done();
+        ce_pin.done();
+
+        let spi_expectations = spi_test_expects![
+            // second sample: a miss, no channel switch needed
+            (vec![registers::RPD, 0], vec![0xEu8, 0]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        (radio, spi, ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        // second sample completes the channel's dwell
+        assert_eq!(radio.scan_step(&mut scanner).unwrap(), Some((76, 1)));
+        assert!(scanner.is_complete());
+        assert_eq!(scanner.hits(), &[1u8]);
+        spi.done();
+        ce_pin.done();
+
+        // the scan is done; further calls are a no-op
+        assert_eq!(radio.scan_step(&mut scanner).unwrap(), None);
+    }
+
+    #[test]
+    fn sweep_step_starts_the_carrier_on_the_current_channel() {
+        // single-channel sweep, mirroring the non-plus-variant case of
+        // `start_carrier_wave()`'s own test
+        let mut sweep = CarrierSweep::new([125u8], PaLevel::Max);
+
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+        ];
+        let spi_expectations = spi_test_expects![
+            // as_tx(): clear PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+            // as_tx(): set cached TX address to RX pipe 0
+            (
+                vec![
+                    registers::RX_ADDR_P0 | commands::W_REGISTER,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7
+                ],
+                vec![0xEu8, 0, 0, 0, 0, 0],
+            ),
+            // as_tx(): open pipe 0 for TX (regardless of auto-ack)
+            (vec![registers::EN_RXADDR, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 1],
+                vec![0xEu8, 0],
+            ),
+            // set special flags in RF_SETUP register value
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 0x90],
+                vec![0xEu8, 0],
+            ),
+            // set_pa_level(Max)
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 0x91]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 0x97],
+                vec![0xEu8, 0],
+            ),
+            // set_channel(125)
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 125],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        assert_eq!(radio.sweep_step(&mut sweep).unwrap(), Some(125));
+        assert!(sweep.is_complete());
+        assert_eq!(radio.sweep_step(&mut sweep).unwrap(), None);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn esb_scanner_trait_delegates_to_the_inherent_method() {
+        // same exchange as `scan_channels()`'s own test, driven through the
+        // `EsbScanner` trait instead of the inherent method
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            // get_data_rate(): already Mbps2, so the scan leaves it untouched
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 8u8]),
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::RPD, 0], vec![0xEu8, 1]),
+            (vec![registers::RPD, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(
+            EsbScanner::scan_channels(&mut radio, &[76u8], 2).unwrap(),
+            [1u8]
+        );
+        spi.done();
+        ce_pin.done();
+    }
+}
@@ -1,6 +1,6 @@
 use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
 
-use super::{registers, ConfigReg};
+use super::{registers, Config};
 use crate::radio::{prelude::EsbCrcLength, Nrf24Error, RF24};
 use crate::CrcLength;
 
@@ -12,17 +12,21 @@ where
 {
     fn get_crc_length(&mut self) -> Result<CrcLength, Self::Error> {
         self.spi_read(1, registers::CONFIG)?;
-        if self.buf[1] & ConfigReg::CRC_MASK == 4 {
+        if self.buf[1] & Config::CRC_MASK == 4 {
             return Err(Nrf24Error::BinaryCorruption);
         }
-        self.config_reg = ConfigReg::from_bits(self.buf[1]);
+        self.config_reg = Config::from_bits(self.buf[1]);
         Ok(self.config_reg.crc_length())
     }
 
     fn set_crc_length(&mut self, crc_length: CrcLength) -> Result<(), Self::Error> {
-        self.spi_read(1, registers::CONFIG)?;
-        self.config_reg = self.config_reg.with_crc_length(crc_length);
-        self.spi_write_byte(registers::CONFIG, self.config_reg.into_bits())
+        let mut new_config = self.config_reg;
+        self.update_register(registers::CONFIG, |old| {
+            new_config = Config::from_bits(old).with_crc_length(crc_length);
+            new_config.into_bits()
+        })?;
+        self.config_reg = new_config;
+        Ok(())
     }
 }
 
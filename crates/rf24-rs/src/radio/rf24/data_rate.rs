@@ -6,8 +6,9 @@ use crate::DataRate;
 
 /// A function to set the [`RF24::tx_delay`] in accordance with the desired [`DataRate`].
 ///
-/// This function is only public to the crate::radio::rf24 module.
-pub(super) fn set_tx_delay(data_rate: DataRate) -> u32 {
+/// This is shared with [`AsyncRF24`](struct@crate::radio::rf24_async::AsyncRF24) so the
+/// blocking and async front-ends don't duplicate the timing table.
+pub(crate) fn set_tx_delay(data_rate: DataRate) -> u32 {
     match data_rate {
         DataRate::Mbps1 => 280,
         DataRate::Mbps2 => 240,
@@ -31,6 +32,9 @@ where
     }
 
     fn set_data_rate(&mut self, data_rate: DataRate) -> Result<(), Self::Error> {
+        if data_rate == DataRate::Kbps250 && !self.is_plus_variant() {
+            return Err(Nrf24Error::UnsupportedDataRate);
+        }
         self.tx_delay = set_tx_delay(data_rate);
         self.spi_read(1, registers::RF_SETUP)?;
         let da_bin = data_rate.into_bits();
@@ -90,10 +94,24 @@ mod test {
         ];
         let mocks = mk_radio(&[], &spi_expectations);
         let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.feature = radio.feature.with_is_plus_variant(true);
         radio.set_data_rate(DataRate::Mbps1).unwrap();
         radio.set_data_rate(DataRate::Mbps2).unwrap();
         radio.set_data_rate(DataRate::Kbps250).unwrap();
         spi.done();
         ce_pin.done();
     }
+
+    #[test]
+    pub fn set_data_rate_unsupported() {
+        // a non-plus variant radio cannot use Kbps250
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(
+            radio.set_data_rate(DataRate::Kbps250),
+            Err(Nrf24Error::UnsupportedDataRate)
+        );
+        spi.done();
+        ce_pin.done();
+    }
 }
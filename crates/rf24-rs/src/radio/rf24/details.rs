@@ -1,13 +1,10 @@
-use super::{Nrf24Error, RF24};
-use crate::radio::prelude::EsbDetails;
-use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
-
-#[cfg(any(feature = "defmt", feature = "std"))]
-use super::registers;
-#[cfg(any(feature = "defmt", feature = "std"))]
+use super::{registers, Nrf24Error, RF24};
 use crate::radio::prelude::{
-    EsbChannel, EsbCrcLength, EsbDataRate, EsbFifo, EsbPaLevel, EsbPayloadLength, EsbPipe, EsbPower,
+    EsbChannel, EsbCrcLength, EsbDataRate, EsbDetails, EsbFifo, EsbPaLevel, EsbPayloadLength,
+    EsbPipe, EsbPower, EsbRadio,
 };
+use crate::RadioDetails;
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -23,134 +20,81 @@ where
     #[cfg(feature = "defmt")]
     #[cfg(target_os = "none")]
     fn print_details(&mut self) -> Result<(), Self::DetailsErrorType> {
-        defmt::println!("Is a plus variant_________{=bool}", self.is_plus_variant());
+        let details = self.get_details()?;
 
-        let channel = self.get_channel()?;
+        defmt::println!("Is a plus variant_________{=bool}", details.is_plus_variant);
         defmt::println!(
             "Channel___________________{=u8} ~ {=u16} Hz",
-            channel,
-            channel as u16 + 2400u16
+            details.channel,
+            details.channel as u16 + 2400u16
         );
-
-        defmt::println!("RF Data Rate______________{}", self.get_data_rate()?);
-        defmt::println!("RF Power Amplifier________{}", self.get_pa_level()?);
-
-        self.spi_read(1, registers::RF_SETUP)?;
-        let rf_setup = self._buf[1];
-        defmt::println!("RF LNA enabled____________{=bool}", rf_setup & 1 > 0);
-
-        defmt::println!("CRC Length________________{}", self.get_crc_length()?);
-
+        defmt::println!("RF Data Rate______________{}", details.data_rate);
+        defmt::println!("RF Power Amplifier________{}", details.pa_level);
+        defmt::println!("LNA Enabled_______________{=bool}", details.lna_enabled);
+        defmt::println!("CRC Length________________{}", details.crc_length);
         defmt::println!(
             "Address length____________{=u8} bytes",
-            self.get_address_length()?
+            details.address_length
         );
-
         defmt::println!(
             "TX Payload lengths________{=u8} bytes",
-            self.get_payload_length()?
+            details.payload_length
         );
-
-        self.spi_read(1, registers::SETUP_RETR)?;
-        let retry_setup = self._buf[1];
         defmt::println!(
-            "Auto retry delay__________{=u16} microseconds",
-            (retry_setup >> 4) as u16 * 250 + 250
+            "IRQ on Data Ready_________{=bool}",
+            details.status_flags.rx_dr()
         );
         defmt::println!(
-            "Auto retry attempts_______{=u8} maximum",
-            retry_setup & 0x0F
+            "IRQ on Data Sent__________{=bool}",
+            details.status_flags.tx_ds()
         );
-
-        self.spi_read(1, registers::FIFO_STATUS)?;
         defmt::println!(
-            "Re-use TX FIFO____________{=bool}",
-            (self._buf[1] & 0x80) > 0
+            "IRQ on Data Fail__________{=bool}",
+            details.status_flags.tx_df()
         );
-
-        self.spi_read(1, registers::OBSERVE_TX)?;
-        let observer = self._buf[1];
         defmt::println!(
-            "Packets lost\n    on current channel____{=u8}",
-            observer >> 4
+            "IRQ mask (RX/TX/Fail)_____{=bool}/{=bool}/{=bool}",
+            details.irq_rx_dr_enabled,
+            details.irq_tx_ds_enabled,
+            details.irq_tx_df_enabled
         );
         defmt::println!(
-            "Retry attempts made\n    for last transmission_{=u8}",
-            observer & 0xF
+            "Dynamic Payloads__________0b{=0..8}",
+            details.dynamic_payloads
         );
-
-        self.spi_read(1, registers::CONFIG)?;
-        self._config_reg = Config::from_bits(self._buf[1]);
+        defmt::println!("Auto Acknowledgment_______0b{=0..8}", details.auto_ack);
         defmt::println!(
-            "IRQ on Data Ready_________{=bool}",
-            self._config_reg.rx_dr()
+            "ACK Payloads______________{=bool}",
+            details.ack_payloads_enabled
         );
-        defmt::println!("    Data Ready triggered__{=bool}", self._status.rx_dr());
         defmt::println!(
-            "IRQ on Data Sent__________{=bool}",
-            self._config_reg.tx_ds()
+            "Dynamic Ack_______________{=bool}",
+            details.ask_no_ack_enabled
         );
-        defmt::println!("    Data Sent triggered___{=bool}", self._status.tx_ds());
-        defmt::println!(
-            "IRQ on Data Fail__________{=bool}",
-            self._config_reg.tx_df()
-        );
-        defmt::println!("    Data Fail triggered___{=bool}", self._status.tx_df());
-
-        let fifo = self.get_fifo_state(true)?;
-        defmt::println!("TX FIFO___________________{}", fifo);
-        let fifo = self.get_fifo_state(false)?;
-        defmt::println!("RX FIFO___________________{}", fifo);
-
-        self.spi_read(1, registers::FEATURE)?;
-        let features = self._buf[1];
-        defmt::println!("Ask no ACK allowed________{=bool}", features & 1 > 0);
-        defmt::println!("ACK Payload enabled_______{=bool}", features & 2 > 0);
-
-        self.spi_read(1, registers::DYNPD)?;
-        defmt::println!("Dynamic Payloads__________0b{=0..8}", self._buf[1]);
-
-        self.spi_read(1, registers::EN_AA)?;
-        defmt::println!("Auto Acknowledgment_______0b{=0..8}", self._buf[1]);
         let rx = defmt::intern!("R");
         let tx = defmt::intern!("T");
         defmt::println!(
             "Primary Mode______________{=istr}X",
-            if self._config_reg & 1 > 0 { rx } else { tx }
+            if details.is_rx { rx } else { tx }
         );
-        defmt::println!("Powered Up________________{=bool}", self.is_powered());
+        defmt::println!("Powered Up________________{=bool}", details.is_powered);
+        defmt::println!("TX FIFO___________________{}", details.tx_fifo);
+        defmt::println!("RX FIFO___________________{}", details.rx_fifo);
+        defmt::println!("TX Reuse__________________{=bool}", details.reuse_tx);
 
-        // print pipe addresses
-        self.spi_read(5, registers::TX_ADDR)?;
-        let mut address = [0u8; 4];
-        address.copy_from_slice(&self._buf[2..6]);
-        address.reverse();
-        defmt::println!(
-            "TX address_______________{=[u8; 4]:#08X}{=u8:02X}",
-            address,
-            self._buf[1]
-        );
-        self.spi_read(1, registers::EN_RXADDR)?;
-        let open_pipes = self._buf[1];
+        defmt::println!("TX address_______________{=[u8]:#04X}", details.tx_address);
         let opened = defmt::intern!(" open ");
         let closed = defmt::intern!("closed");
-        for pipe in 0..=5 {
-            self.spi_read(if pipe < 2 { 5 } else { 1 }, registers::RX_ADDR_P0 + pipe)?;
-            if pipe < 2 {
-                address.copy_from_slice(&self._buf[2..6]);
-                address.reverse();
-            }
+        for (pipe, address) in details.rx_addresses.iter().enumerate() {
             defmt::println!(
-                "Pipe {=u8} ({=istr}) bound to {=[u8; 4]:#08X}{=u8:02X}",
+                "Pipe {=usize} ({=istr}) bound to {=[u8]:#04X}",
                 pipe,
-                if (open_pipes & (1u8 << pipe)) > 0 {
+                if (details.open_rx_pipes & (1u8 << pipe)) > 0 {
                     opened
                 } else {
                     closed
                 },
-                // reverse the bytes read to represent how memory is stored
                 address,
-                self._buf[1],
             );
         }
         Ok(())
@@ -164,121 +108,157 @@ where
     #[cfg(not(target_os = "none"))]
     #[cfg(feature = "std")]
     fn print_details(&mut self) -> Result<(), Self::DetailsErrorType> {
-        use crate::radio::rf24::Config;
-
-        std::println!("Is a plus variant_________{}", self.is_plus_variant());
+        let details = self.get_details()?;
 
-        let channel = self.get_channel()?;
+        std::println!("Is a plus variant_________{}", details.is_plus_variant);
         std::println!(
-            "Channel___________________{channel} ~ {} Hz",
-            channel as u16 + 2400u16
+            "Channel___________________{} ~ {} Hz",
+            details.channel,
+            details.channel as u16 + 2400u16
         );
-
-        std::println!("RF Data Rate______________{}", self.get_data_rate()?);
-        std::println!("RF Power Amplifier________{}", self.get_pa_level()?);
-
-        self.spi_read(1, registers::RF_SETUP)?;
-        let rf_setup = self._buf[1];
-        std::println!("RF LNA enabled____________{}", rf_setup & 1 > 0);
-
-        std::println!("CRC Length________________{}", self.get_crc_length()?);
-
+        std::println!("RF Data Rate______________{}", details.data_rate);
+        std::println!("RF Power Amplifier________{}", details.pa_level);
+        std::println!("LNA Enabled_______________{}", details.lna_enabled);
+        std::println!("CRC Length________________{}", details.crc_length);
+        std::println!("Address length____________{} bytes", details.address_length);
+        std::println!("TX Payload lengths________{} bytes", details.payload_length);
+        std::println!("IRQ on Data Ready_________{}", details.status_flags.rx_dr());
+        std::println!("IRQ on Data Sent__________{}", details.status_flags.tx_ds());
+        std::println!("IRQ on Data Fail__________{}", details.status_flags.tx_df());
         std::println!(
-            "Address length____________{} bytes",
-            self.get_address_length()?
+            "IRQ mask (RX/TX/Fail)_____{}/{}/{}",
+            details.irq_rx_dr_enabled,
+            details.irq_tx_ds_enabled,
+            details.irq_tx_df_enabled
         );
-
         std::println!(
-            "TX Payload lengths________{} bytes",
-            self.get_payload_length()?
+            "Dynamic Payloads__________{:#010b}",
+            details.dynamic_payloads
         );
-
-        self.spi_read(1, registers::SETUP_RETR)?;
-        let retry_setup = self._buf[1];
+        std::println!("Auto Acknowledgment_______{:#010b}", details.auto_ack);
+        std::println!("ACK Payloads______________{}", details.ack_payloads_enabled);
+        std::println!("Dynamic Ack_______________{}", details.ask_no_ack_enabled);
         std::println!(
-            "Auto retry delay__________{} microseconds",
-            (retry_setup >> 4) as u16 * 250 + 250
+            "Primary Mode______________{}X",
+            if details.is_rx { "R" } else { "T" }
         );
-        std::println!("Auto retry attempts_______{} maximum", retry_setup & 0x0F);
+        std::println!("Powered Up________________{}", details.is_powered);
+        std::println!("TX FIFO___________________{}", details.tx_fifo);
+        std::println!("RX FIFO___________________{}", details.rx_fifo);
+        std::println!("TX Reuse__________________{}", details.reuse_tx);
 
-        self.spi_read(1, registers::FIFO_STATUS)?;
-        std::println!("Re-use TX FIFO____________{}", (self._buf[1] & 0x80) > 0);
+        std::println!("TX address_______________{:02X?}", details.tx_address);
+        for (pipe, address) in details.rx_addresses.iter().enumerate() {
+            std::println!(
+                "Pipe {pipe} ({}) bound to {:02X?}",
+                if (details.open_rx_pipes & (1u8 << pipe)) > 0 {
+                    " open "
+                } else {
+                    "closed"
+                },
+                address,
+            );
+        }
+        Ok(())
+    }
 
-        self.spi_read(1, registers::OBSERVE_TX)?;
-        let observer = self._buf[1];
-        std::println!("Packets lost\n    on current channel____{}", observer >> 4);
-        std::println!(
-            "Retry attempts made\n    for last transmission_{}",
-            observer & 0xF
-        );
+    /// See [`EsbDetails::get_details()`] for implementation-agnostic detail.
+    fn get_details(&mut self) -> Result<RadioDetails, Self::DetailsErrorType> {
+        use crate::radio::rf24::Config;
 
-        self.spi_read(1, registers::CONFIG)?;
-        self._config_reg = Config::from_bits(self._buf[1]);
-        std::println!("IRQ on Data Ready_________{}", self._config_reg.rx_dr());
-        std::println!("    Data Ready triggered__{}", self._status.rx_dr());
-        std::println!("IRQ on Data Sent__________{}", self._config_reg.tx_ds());
-        std::println!("    Data Sent triggered___{}", self._status.tx_ds());
-        std::println!("IRQ on Data Fail__________{}", self._config_reg.tx_df());
-        std::println!("    Data Fail triggered___{}", self._status.tx_df());
+        let is_plus_variant = self.is_plus_variant();
+        let channel = self.get_channel()?;
+        let data_rate = self.get_data_rate()?;
+        let pa_level = self.get_pa_level()?;
+        let crc_length = self.get_crc_length()?;
+        let address_length = self.get_address_length()?;
+        let payload_length = self.get_payload_length()?;
 
-        let fifo = self.get_fifo_state(true)?;
-        std::println!("TX FIFO___________________{}", fifo);
-        let fifo = self.get_fifo_state(false)?;
-        std::println!("RX FIFO___________________{}", fifo);
+        self.spi_read(1, registers::CONFIG)?;
+        self.config_reg = Config::from_bits(self.buf[1]);
 
-        self.spi_read(1, registers::FEATURE)?;
-        let features = self._buf[1];
-        std::println!("Ask no ACK allowed________{}", features & 1 > 0);
-        std::println!("ACK Payload enabled_______{}", features & 2 > 0);
+        self.spi_read(1, registers::RF_SETUP)?;
+        let lna_enabled = self.buf[1] & 1 != 0;
 
         self.spi_read(1, registers::DYNPD)?;
-        std::println!("Dynamic Payloads__________{:#010b}", self._buf[1]);
+        let dynamic_payloads = self.buf[1];
 
         self.spi_read(1, registers::EN_AA)?;
-        std::println!("Auto Acknowledgment_______{:#010b}", self._buf[1]);
+        let auto_ack = self.buf[1];
 
-        std::println!(
-            "Primary Mode______________{}X",
-            if self._config_reg.is_rx() { "R" } else { "T" }
-        );
-        std::println!("Powered Up________________{}", self.is_powered());
+        let ack_payloads_enabled = self.feature.ack_payloads();
+        let ask_no_ack_enabled = self.feature.ask_no_ack();
+
+        let is_powered = self.is_powered();
+        let is_rx = self.config_reg.is_rx();
 
-        // print pipe addresses
         self.spi_read(5, registers::TX_ADDR)?;
-        let mut address = [0u8; 4];
-        address.copy_from_slice(&self._buf[2..6]);
-        std::println!(
-            "TX address_______________{:#08X}{:02X}",
-            u32::from_le_bytes(address),
-            self._buf[1]
-        );
+        let mut tx_address = [0u8; 5];
+        tx_address.copy_from_slice(&self.buf[1..6]);
+
         self.spi_read(1, registers::EN_RXADDR)?;
-        let open_pipes = self._buf[1];
-        for pipe in 0..=5 {
+        let open_rx_pipes = self.buf[1];
+
+        let mut rx_addresses = [[0u8; 5]; 6];
+        let mut shared_upper = [0u8; 4];
+        for (pipe, rx_address) in rx_addresses.iter_mut().enumerate() {
+            let pipe = pipe as u8;
             self.spi_read(if pipe < 2 { 5 } else { 1 }, registers::RX_ADDR_P0 + pipe)?;
             if pipe < 2 {
-                address.copy_from_slice(&self._buf[2..6]);
+                shared_upper.copy_from_slice(&self.buf[2..6]);
             }
-            std::println!(
-                "Pipe {pipe} ({}) bound to {:#08X}{:02X}",
-                if (open_pipes & (1u8 << pipe)) > 0 {
-                    " open "
-                } else {
-                    "closed"
-                },
-                // reverse the bytes read to represent how memory is stored
-                u32::from_le_bytes(address),
-                self._buf[1],
-            );
+            rx_address[0] = self.buf[1];
+            rx_address[1..5].copy_from_slice(&shared_upper);
         }
-        Ok(())
+
+        let tx_fifo = self.get_fifo_state(true)?;
+        let reuse_tx = self.buf[1] & 0x40 != 0;
+        let rx_fifo = self.get_fifo_state(false)?;
+        let packets_lost = self.get_lost_packets()?;
+        let retry_count = self.get_last_arc()?;
+
+        Ok(RadioDetails {
+            is_plus_variant,
+            channel,
+            data_rate,
+            pa_level,
+            lna_enabled,
+            crc_length,
+            address_length,
+            payload_length,
+            dynamic_payloads,
+            auto_ack,
+            ack_payloads_enabled,
+            ask_no_ack_enabled,
+            open_rx_pipes,
+            is_powered,
+            is_rx,
+            tx_address,
+            rx_addresses,
+            status_flags: self.status,
+            irq_rx_dr_enabled: self.config_reg.rx_dr(),
+            irq_tx_ds_enabled: self.config_reg.tx_ds(),
+            irq_tx_df_enabled: self.config_reg.tx_df(),
+            tx_fifo,
+            rx_fifo,
+            reuse_tx,
+            tx_delay: self.tx_delay,
+            packets_lost,
+            retry_count,
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::EsbDetails;
-    use crate::test::mk_radio;
+    extern crate std;
+    use super::{registers, EsbDetails};
+    use crate::{
+        spi_test_expects, test::mk_radio, CrcLength, DataRate, FifoState, PaLevel, RadioDetails,
+        StatusFlags,
+    };
+    use embedded_hal_mock::eh1::spi::Transaction as SpiTransaction;
+    use std::vec;
 
     #[test]
     fn print_nothing() {
@@ -288,4 +268,106 @@ mod test {
         spi.done();
         ce_pin.done();
     }
+
+    #[test]
+    fn get_details() {
+        let spi_expectations = spi_test_expects![
+            // get_channel()
+            (vec![registers::RF_CH, 0], vec![0xEu8, 76]),
+            // get_data_rate()
+            (vec![registers::RF_SETUP, 76], vec![0xEu8, 0]),
+            // get_pa_level()
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 2]),
+            // get_crc_length()
+            (vec![registers::CONFIG, 2], vec![0xEu8, 8]),
+            // get_address_length()
+            (vec![registers::SETUP_AW, 8], vec![0xEu8, 3]),
+            // get_payload_length()
+            (vec![registers::RX_PW_P0, 3], vec![0xEu8, 32]),
+            // explicit CONFIG read to cache self.config_reg
+            (vec![registers::CONFIG, 0x20], vec![0xEu8, 0xF]),
+            // RF_SETUP read (LNA flag)
+            (vec![registers::RF_SETUP, 0xF], vec![0xEu8, 1]),
+            // DYNPD read
+            (vec![registers::DYNPD, 1], vec![0xEu8, 3]),
+            // EN_AA read
+            (vec![registers::EN_AA, 3], vec![0xEu8, 0x3F]),
+            // TX_ADDR read
+            (
+                vec![registers::TX_ADDR, 0x3F, 0, 0, 0, 0],
+                vec![0xEu8, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE],
+            ),
+            // EN_RXADDR read
+            (vec![registers::EN_RXADDR, 0xAA], vec![0xEu8, 3]),
+            // RX_ADDR_P0 read
+            (
+                vec![registers::RX_ADDR_P0, 3, 0xBB, 0xCC, 0xDD, 0xEE],
+                vec![0xEu8, 0x11, 0x22, 0x33, 0x44, 0x55],
+            ),
+            // RX_ADDR_P0 + 1 (RX_ADDR_P1) read
+            (
+                vec![registers::RX_ADDR_P0 + 1, 0x11, 0x22, 0x33, 0x44, 0x55],
+                vec![0xEu8, 0x66, 0x77, 0x88, 0x99, 0xAA],
+            ),
+            // RX_ADDR_P0 + 2 (RX_ADDR_P2) read
+            (vec![registers::RX_ADDR_P0 + 2, 0x66], vec![0xEu8, 0xC2]),
+            // RX_ADDR_P0 + 3 (RX_ADDR_P3) read
+            (vec![registers::RX_ADDR_P0 + 3, 0xC2], vec![0xEu8, 0xC3]),
+            // RX_ADDR_P0 + 4 (RX_ADDR_P4) read
+            (vec![registers::RX_ADDR_P0 + 4, 0xC3], vec![0xEu8, 0xC4]),
+            // RX_ADDR_P0 + 5 (RX_ADDR_P5) read
+            (vec![registers::RX_ADDR_P0 + 5, 0xC4], vec![0xEu8, 0xC5]),
+            // get_fifo_state(true)
+            (vec![registers::FIFO_STATUS, 0xC5], vec![0xEu8, 0x10]),
+            // get_fifo_state(false)
+            (vec![registers::FIFO_STATUS, 0x10], vec![0xEu8, 1]),
+            // get_lost_packets()
+            (vec![registers::OBSERVE_TX, 1], vec![0xEu8, 0xF0]),
+            // get_last_arc()
+            (vec![registers::OBSERVE_TX, 0xF0], vec![0xEu8, 0xFF]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(
+            radio.get_details(),
+            Ok(RadioDetails {
+                is_plus_variant: true,
+                channel: 76,
+                data_rate: DataRate::Mbps1,
+                pa_level: PaLevel::Low,
+                lna_enabled: true,
+                crc_length: CrcLength::Bit8,
+                address_length: 5,
+                payload_length: 32,
+                dynamic_payloads: 3,
+                auto_ack: 0x3F,
+                ack_payloads_enabled: false,
+                ask_no_ack_enabled: false,
+                open_rx_pipes: 3,
+                is_powered: true,
+                is_rx: true,
+                tx_address: [0xAA, 0xBB, 0xCC, 0xDD, 0xEE],
+                rx_addresses: [
+                    [0x11, 0x22, 0x33, 0x44, 0x55],
+                    [0x66, 0x77, 0x88, 0x99, 0xAA],
+                    [0xC2, 0x77, 0x88, 0x99, 0xAA],
+                    [0xC3, 0x77, 0x88, 0x99, 0xAA],
+                    [0xC4, 0x77, 0x88, 0x99, 0xAA],
+                    [0xC5, 0x77, 0x88, 0x99, 0xAA],
+                ],
+                status_flags: StatusFlags::from_bits(0xE),
+                irq_rx_dr_enabled: true,
+                irq_tx_ds_enabled: true,
+                irq_tx_df_enabled: true,
+                tx_fifo: FifoState::Empty,
+                rx_fifo: FifoState::Empty,
+                reuse_tx: false,
+                tx_delay: 250,
+                packets_lost: 15,
+                retry_count: 15,
+            })
+        );
+        spi.done();
+        ce_pin.done();
+    }
 }
@@ -0,0 +1,118 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::{registers, Nrf24Error, RF24};
+use crate::radio::prelude::{
+    EsbAutoAck, EsbChannel, EsbCrcLength, EsbDataRate, EsbPaLevel, EsbPayloadLength, EsbPipe,
+    EsbRadio,
+};
+
+/// The number of bytes in an [`RF24::encode_details()`] snapshot.
+pub const DETAILS_SNAPSHOT_LEN: usize = 13;
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Pack the radio's current configuration and link-quality counters into a
+    /// fixed-size, `core::fmt`-free byte buffer, mirroring TMRh20's `encodeRadioDetails`.
+    ///
+    /// This is meant for cheap remote telemetry or logging over a constrained link
+    /// (unlike [`EsbDetails::get_details()`](crate::radio::prelude::EsbDetails::get_details),
+    /// which returns a human-readable struct). `out` is laid out as:
+    ///
+    /// | Index | Field |
+    /// |------:|-------|
+    /// | 0 | channel (see [`EsbChannel::get_channel()`]) |
+    /// | 1 | data rate, encoded per [`DataRate::into_bits()`](crate::DataRate) |
+    /// | 2 | PA level, encoded per [`PaLevel::into_bits()`](crate::PaLevel) |
+    /// | 3 | CRC length, encoded per [`CrcLength::into_bits()`](crate::CrcLength) |
+    /// | 4 | address width, in bytes |
+    /// | 5 | static payload length, in bytes |
+    /// | 6 | raw `DYNPD` register (dynamic payloads enabled per pipe) |
+    /// | 7 | auto-retry delay (ARD) |
+    /// | 8 | auto-retry count (ARC) |
+    /// | 9 | lost packet count (PLOS) |
+    /// | 10 | last transmission's auto-retry count (ARC) |
+    /// | 11 | raw `CONFIG` register (IRQ mask, CRC, power, and RX/TX mode bits) |
+    /// | 12 | `1` if the radio is a plus variant, `0` otherwise |
+    pub fn encode_details(
+        &mut self,
+        out: &mut [u8; DETAILS_SNAPSHOT_LEN],
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>> {
+        out[0] = self.get_channel()?;
+        out[1] = self.get_data_rate()?.into_bits();
+        out[2] = self.get_pa_level()?.into_bits();
+        out[3] = self.get_crc_length()?.into_bits();
+        out[4] = self.get_address_length()?;
+        out[5] = self.get_payload_length()?;
+        self.spi_read(1, registers::DYNPD)?;
+        out[6] = self.buf[1];
+        let (auto_retry_delay, auto_retry_count) = self.get_auto_retries()?;
+        out[7] = auto_retry_delay;
+        out[8] = auto_retry_count;
+        out[9] = self.get_lost_packets()?;
+        out[10] = self.get_last_arc()?;
+        out[11] = self.config_reg.into_bits();
+        out[12] = self.is_plus_variant() as u8;
+        Ok(())
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::DETAILS_SNAPSHOT_LEN;
+    use crate::{radio::rf24::registers, spi_test_expects, test::mk_radio};
+    use std::vec;
+
+    #[test]
+    fn encode_details() {
+        // each MOSI byte (after the command byte) is whatever was left over from the
+        // previous transaction's response, since `self.buf` is reused as-is
+        let spi_expectations = spi_test_expects![
+            // get_channel()
+            (vec![registers::RF_CH, 0], vec![0xEu8, 76]),
+            // get_data_rate()
+            (vec![registers::RF_SETUP, 76], vec![0xEu8, 0]),
+            // get_pa_level()
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 6]),
+            // get_crc_length()
+            (vec![registers::CONFIG, 6], vec![0xEu8, 0xC]),
+            // get_address_length()
+            (vec![registers::SETUP_AW, 0xC], vec![0xEu8, 3]),
+            // get_payload_length()
+            (vec![registers::RX_PW_P0, 3], vec![0xEu8, 32]),
+            // raw DYNPD register
+            (vec![registers::DYNPD, 32], vec![0xEu8, 0x3F]),
+            // get_auto_retries()
+            (vec![registers::SETUP_RETR, 0x3F], vec![0xEu8, 0x5F]),
+            // get_lost_packets()/get_last_arc() share OBSERVE_TX
+            (vec![registers::OBSERVE_TX, 0x5F], vec![0xEu8, 0xAB]),
+            (vec![registers::OBSERVE_TX, 0xAB], vec![0xEu8, 0xAB]),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut out = [0u8; DETAILS_SNAPSHOT_LEN];
+        radio.encode_details(&mut out).unwrap();
+        assert_eq!(out[0], 76);
+        assert_eq!(out[1], 0); // Mbps1
+        assert_eq!(out[2], 6); // PaLevel::Max
+        assert_eq!(out[3], 0xC); // CrcLength::Bit16
+        assert_eq!(out[4], 5); // 3 + 2 address bytes
+        assert_eq!(out[5], 32);
+        assert_eq!(out[6], 0x3F);
+        assert_eq!(out[7], 5); // ARD nibble
+        assert_eq!(out[8], 0xF); // ARC nibble
+        assert_eq!(out[9], 0xA); // PLOS
+        assert_eq!(out[10], 0xB); // ARC of last transmission
+        assert_eq!(out[11], 0xC); // raw CONFIG register
+        assert_eq!(out[12], 1); // `RF24::new()` assumes a plus variant by default
+        spi.done();
+        ce_pin.done();
+    }
+}
@@ -0,0 +1,591 @@
+//! Implements the vendor-neutral traits from the [`radio`] crate for [`RF24`].
+//!
+//! Application code (or higher-level stacks) written against `radio::{State, Channel,
+//! Power, Interrupts, Transmit, Receive, Rssi}` can drive an nRF24L01 the same way it
+//! would drive any other `radio`-compatible transceiver (e.g. `radio-sx128x`), without
+//! depending on the [`Esb*` traits](mod@crate::radio::prelude) directly.
+//!
+//! This module is only compiled with the `radio-trait` feature enabled.
+#![cfg(feature = "radio-trait")]
+
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::{mnemonics, registers, Nrf24Error, RF24};
+use crate::{
+    radio::prelude::{EsbChannel, EsbFifo, EsbPaLevel, EsbPower, EsbRadio, EsbStatus},
+    PaLevel, StatusFlags,
+};
+
+/// The coarse operating state of the radio, as understood by [`radio::State`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcosystemState {
+    /// The radio is powered down (asleep).
+    PowerDown,
+    /// The radio is powered up but neither transmitting nor receiving.
+    Standby,
+    /// The radio is actively listening for incoming payloads.
+    Rx,
+    /// The radio is actively transmitting (or ready to transmit) payloads.
+    Tx,
+}
+
+bitflags::bitflags! {
+    /// The IRQ events latched in the nRF24L01's STATUS register, as returned by
+    /// [`radio::Interrupts::get_interrupts()`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Irq: u8 {
+        /// A payload arrived in the RX FIFO.
+        const RX_DR = mnemonics::MASK_RX_DR;
+        /// A payload was sent and acknowledged (if applicable).
+        const TX_DS = mnemonics::MASK_TX_DS;
+        /// A payload exhausted the configured auto-retry attempts without an ACK.
+        const MAX_RT = mnemonics::MASK_MAX_RT;
+    }
+}
+
+impl From<StatusFlags> for Irq {
+    fn from(flags: StatusFlags) -> Self {
+        let mut irq = Irq::empty();
+        irq.set(Irq::RX_DR, flags.rx_dr());
+        irq.set(Irq::TX_DS, flags.tx_ds());
+        irq.set(Irq::MAX_RT, flags.tx_df());
+        irq
+    }
+}
+
+impl From<Irq> for StatusFlags {
+    /// Converts back to the richer [`StatusFlags`] struct used by the [`Esb*`](mod@crate::radio::prelude)
+    /// traits, for callers that start out with a [`radio::Interrupts::get_interrupts()`]
+    /// result but still need to pass flags to (or compare against) the `Esb*` API.
+    fn from(irq: Irq) -> Self {
+        StatusFlags::default()
+            .with_rx_dr(irq.contains(Irq::RX_DR))
+            .with_tx_ds(irq.contains(Irq::TX_DS))
+            .with_tx_df(irq.contains(Irq::MAX_RT))
+    }
+}
+
+impl<SPI, DO, DELAY> radio::Power for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type Error = Nrf24Error<SPI::Error, DO::Error>;
+
+    /// Maps the given dBm `power` to a [`PaLevel`] and calls
+    /// [`EsbPaLevel::set_pa_level()`]: `-12` or lower selects [`PaLevel::Min`],
+    /// `-11..=-6` selects [`PaLevel::Low`], `-5..=0` selects [`PaLevel::High`], and
+    /// anything above `0` selects [`PaLevel::Max`].
+    fn set_power(&mut self, power: i8) -> Result<(), Self::Error> {
+        let level = if power <= -12 {
+            PaLevel::Min
+        } else if power <= -6 {
+            PaLevel::Low
+        } else if power <= 0 {
+            PaLevel::High
+        } else {
+            PaLevel::Max
+        };
+        self.set_pa_level(level)
+    }
+}
+
+impl<SPI, DO, DELAY> radio::Channel for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type Channel = u8;
+    type Error = Nrf24Error<SPI::Error, DO::Error>;
+
+    /// The channel index is 0-125, matching the nRF24L01's `RF_CH` register.
+    ///
+    /// `radio::Channel` has no getter counterpart; call [`EsbChannel::get_channel()`]
+    /// directly on the radio to read back the currently selected channel.
+    fn set_channel(&mut self, channel: &Self::Channel) -> Result<(), Self::Error> {
+        EsbChannel::set_channel(self, *channel)
+    }
+}
+
+impl<SPI, DO, DELAY> radio::Interrupts for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type Irq = Irq;
+    type Error = Nrf24Error<SPI::Error, DO::Error>;
+
+    /// Reads and decodes the STATUS register, optionally clearing the latched events
+    /// (see [`EsbStatus::clear_status_flags()`]) in the same transaction.
+    fn get_interrupts(&mut self, clear: bool) -> Result<Self::Irq, Self::Error> {
+        self.update()?;
+        let mut flags = StatusFlags::default();
+        self.get_status_flags(&mut flags);
+        if clear {
+            self.clear_status_flags(flags)?;
+        }
+        Ok(Irq::from(flags))
+    }
+}
+
+impl<SPI, DO, DELAY> radio::Busy for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type Error = Nrf24Error<SPI::Error, DO::Error>;
+
+    /// The nRF24L01 has no dedicated busy signal; a radio in active TX mode with a
+    /// pending (not yet sent) payload is the closest equivalent.
+    fn is_busy(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.is_rx() && self.get_fifo_state(true)? != crate::FifoState::Empty)
+    }
+}
+
+impl<SPI, DO, DELAY> radio::Rssi for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type Error = Nrf24Error<SPI::Error, DO::Error>;
+
+    /// The nRF24L01 has no true RSSI measurement, only a Received Power Detector (RPD)
+    /// flag that asserts once the received power exceeds roughly -64 dBm (see
+    /// [`RF24::rpd()`]). This maps that boolean onto the extremes of `i16`: `0` if RPD
+    /// is asserted, or `i16::MIN` if it is not.
+    fn poll_rssi(&mut self) -> Result<i16, Self::Error> {
+        Ok(if self.rpd()? { 0 } else { i16::MIN })
+    }
+}
+
+impl<SPI, DO, DELAY> radio::State for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type State = EcosystemState;
+    type Error = Nrf24Error<SPI::Error, DO::Error>;
+
+    fn set_state(&mut self, state: Self::State) -> Result<(), Self::Error> {
+        match state {
+            EcosystemState::PowerDown => self.power_down(),
+            EcosystemState::Standby => {
+                self.ce_pin.set_low().map_err(|e| e.kind())?;
+                self.ce_active = false;
+                self.power_up(None)
+            }
+            EcosystemState::Rx => {
+                self.power_up(None)?;
+                self.as_rx()
+            }
+            EcosystemState::Tx => {
+                self.power_up(None)?;
+                self.as_tx(None)
+            }
+        }
+    }
+
+    fn get_state(&mut self) -> Result<Self::State, Self::Error> {
+        if !self.is_powered() {
+            return Ok(EcosystemState::PowerDown);
+        }
+        Ok(if self.is_rx() {
+            EcosystemState::Rx
+        } else {
+            EcosystemState::Tx
+        })
+    }
+}
+
+impl<SPI, DO, DELAY> radio::Transmit for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type Error = Nrf24Error<SPI::Error, DO::Error>;
+
+    /// Writes `data` to the TX FIFO and starts active TX mode. See [`EsbRadio::write()`].
+    fn start_transmit(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.write(data, false, true)?;
+        Ok(())
+    }
+
+    /// Returns `true` once the previously started transmission has either been sent or failed.
+    fn check_transmit(&mut self) -> Result<bool, Self::Error> {
+        self.update()?;
+        let mut flags = StatusFlags::default();
+        self.get_status_flags(&mut flags);
+        Ok(flags.tx_ds() || flags.tx_df())
+    }
+}
+
+impl<SPI, DO, DELAY> radio::Receive for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// The pipe number (0-5) that received the available payload.
+    type Info = u8;
+    type Error = Nrf24Error<SPI::Error, DO::Error>;
+
+    fn start_receive(&mut self) -> Result<(), Self::Error> {
+        self.as_rx()
+    }
+
+    /// Checks if a payload is available. If `restart` is `true` and the radio is not
+    /// currently in RX mode (e.g. after [`RF24::as_tx()`] was called to send an ACK
+    /// payload), this re-enters RX mode first via [`RF24::as_rx()`].
+    fn check_receive(&mut self, restart: bool) -> Result<bool, Self::Error> {
+        if restart && !self.is_rx() {
+            self.as_rx()?;
+        }
+        self.available()
+    }
+
+    fn get_received(&mut self, buff: &mut [u8]) -> Result<(usize, Self::Info), Self::Error> {
+        let mut pipe = 7u8;
+        self.available_pipe(&mut pipe)?;
+        let len = EsbRadio::read(self, buff, None)?;
+        Ok((len as usize, pipe))
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::{registers, EcosystemState, Irq};
+    use crate::{
+        radio::rf24::{commands, mnemonics},
+        spi_test_expects,
+        test::mk_radio,
+        StatusFlags,
+    };
+    use embedded_hal_mock::eh1::{
+        digital::{State as PinState, Transaction as PinTransaction},
+        spi::Transaction as SpiTransaction,
+    };
+    use std::vec;
+
+    #[test]
+    pub fn irq_round_trips_with_status_flags() {
+        let flags = StatusFlags::default().with_rx_dr(true).with_tx_df(true);
+        let irq = Irq::from(flags);
+        assert!(irq.contains(Irq::RX_DR));
+        assert!(!irq.contains(Irq::TX_DS));
+        assert!(irq.contains(Irq::MAX_RT));
+        assert_eq!(StatusFlags::from(irq), flags);
+    }
+
+    #[test]
+    pub fn power_and_channel() {
+        let spi_expectations = spi_test_expects![
+            // set_power(-20) maps to PaLevel::Min
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 7u8]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 1u8],
+                vec![0xEu8, 0u8],
+            ),
+            // radio::Channel::set_channel(&5)
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 5u8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio::Power::set_power(&mut radio, -20).unwrap();
+        radio::Channel::set_channel(&mut radio, &5).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn power_boundaries() {
+        let spi_expectations = spi_test_expects![
+            // -12 dBm sits exactly on the Min/Low boundary and maps to PaLevel::Min
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 1u8],
+                vec![0xEu8, 0u8],
+            ),
+            // -6 dBm sits exactly on the Low/High boundary and maps to PaLevel::Low
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 3u8],
+                vec![0xEu8, 0u8],
+            ),
+            // 0 dBm sits exactly on the High/Max boundary and maps to PaLevel::High
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 5u8],
+                vec![0xEu8, 0u8],
+            ),
+            // anything above 0 dBm maps to PaLevel::Max
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 7u8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio::Power::set_power(&mut radio, -12).unwrap();
+        radio::Power::set_power(&mut radio, -6).unwrap();
+        radio::Power::set_power(&mut radio, 0).unwrap();
+        radio::Power::set_power(&mut radio, 1).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn rssi() {
+        let spi_expectations = spi_test_expects![
+            (vec![registers::RPD, 0], vec![0xEu8, 0xFFu8]),
+            (vec![registers::RPD, 0xFF], vec![0xEu8, 0u8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(radio::Rssi::poll_rssi(&mut radio).unwrap(), 0);
+        assert_eq!(radio::Rssi::poll_rssi(&mut radio).unwrap(), i16::MIN);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn interrupts_and_busy() {
+        let spi_expectations = spi_test_expects![
+            // update() via NOP: RX_DR and TX_DS latched
+            (vec![commands::NOP], vec![0x60u8]),
+            // clear_status_flags(flags)
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x60u8],
+                vec![0xEu8, 0u8],
+            ),
+            // is_busy(): get_fifo_state(true) reports the TX FIFO is occupied
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 0u8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let irq = radio::Interrupts::get_interrupts(&mut radio, true).unwrap();
+        assert!(irq.contains(Irq::RX_DR));
+        assert!(irq.contains(Irq::TX_DS));
+        assert!(!irq.contains(Irq::MAX_RT));
+        assert!(radio::Busy::is_busy(&mut radio).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn is_busy_ignores_tx_fifo_while_in_rx_mode() {
+        let ce_expectations = [PinTransaction::set(PinState::High)];
+        let spi_expectations = spi_test_expects![
+            // power_up(None)
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xEu8],
+                vec![0xEu8, 0u8],
+            ),
+            // as_rx(): assert PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xFu8],
+                vec![0xEu8, 0u8],
+            ),
+            // as_rx(): clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70u8],
+                vec![0xEu8, 0u8],
+            ),
+            // as_rx(): no cached pipe0 address, so close_rx_pipe(0)
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1u8]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0u8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio::State::set_state(&mut radio, EcosystemState::Rx).unwrap();
+        // `is_busy()` short-circuits on `is_rx()`, so no further SPI transaction (e.g.
+        // a TX FIFO state check) is issued once the radio is in RX mode.
+        assert!(!radio::Busy::is_busy(&mut radio).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn state_getter() {
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        // without calling `RF24::init()`, the lib _assumes_ the radio is powered down.
+        assert_eq!(
+            radio::State::get_state(&mut radio).unwrap(),
+            EcosystemState::PowerDown
+        );
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn state_set_rx() {
+        let ce_expectations = [PinTransaction::set(PinState::High)];
+        let spi_expectations = spi_test_expects![
+            // power_up(None)
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xEu8],
+                vec![0xEu8, 0u8],
+            ),
+            // as_rx(): assert PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xFu8],
+                vec![0xEu8, 0u8],
+            ),
+            // as_rx(): clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70u8],
+                vec![0xEu8, 0u8],
+            ),
+            // as_rx(): no cached pipe0 address, so close_rx_pipe(0)
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1u8]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0u8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio::State::set_state(&mut radio, EcosystemState::Rx).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn state_set_tx() {
+        let ce_expectations = [PinTransaction::set(PinState::Low)];
+        let mut spi_expectations = spi_test_expects![
+            // power_up(None)
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xEu8],
+                vec![0xEu8, 0u8],
+            ),
+            // as_tx(None): clear PRIM_RX flag (already clear)
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xEu8],
+                vec![0xEu8, 0u8],
+            ),
+            // as_tx(None): write cached TX address to pipe 0 for auto-ack
+            (
+                vec![
+                    registers::RX_ADDR_P0 | commands::W_REGISTER,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7
+                ],
+                vec![0xEu8, 0, 0, 0, 0, 0],
+            ),
+        ]
+        .to_vec();
+        spi_expectations.extend(spi_test_expects![
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 0u8]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 1u8],
+                vec![0xEu8, 0u8],
+            ),
+        ]);
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio::State::set_state(&mut radio, EcosystemState::Tx).unwrap();
+        assert_eq!(
+            radio::State::get_state(&mut radio).unwrap(),
+            EcosystemState::Tx
+        );
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn state_set_standby() {
+        let ce_expectations = [PinTransaction::set(PinState::Low)];
+        let spi_expectations = spi_test_expects![(
+            vec![registers::CONFIG | commands::W_REGISTER, 0xEu8],
+            vec![0xEu8, 0u8],
+        ),];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio::State::set_state(&mut radio, EcosystemState::Standby).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn state_set_power_down() {
+        let ce_expectations = [PinTransaction::set(PinState::Low)];
+        let spi_expectations = spi_test_expects![(
+            vec![registers::CONFIG | commands::W_REGISTER, 0xCu8],
+            vec![0xEu8, 0u8],
+        ),];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio::State::set_state(&mut radio, EcosystemState::PowerDown).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn transmit_and_receive() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::High),
+        ];
+        let mut tx_buf = [0u8; 33];
+        tx_buf[0] = commands::W_TX_PAYLOAD;
+        tx_buf[1..9].copy_from_slice(&[0x55; 8]);
+        let spi_expectations = spi_test_expects![
+            // start_transmit(): write(data, false, true) clears status then writes the payload
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0u8],
+            ),
+            (tx_buf.to_vec(), vec![0xEu8; 33]),
+            // check_transmit(): update()
+            (vec![commands::NOP], vec![mnemonics::MASK_TX_DS]),
+            // start_receive(): as_rx() asserts PRIM_RX and has no cached pipe0 address
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xDu8],
+                vec![0xEu8, 0u8],
+            ),
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70u8],
+                vec![0xEu8, 0u8],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1u8]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0u8],
+                vec![0xEu8, 0u8],
+            ),
+            // check_receive(): available() reports the RX FIFO is empty
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 1u8]),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio::Transmit::start_transmit(&mut radio, &[0x55; 8]).unwrap();
+        assert!(radio::Transmit::check_transmit(&mut radio).unwrap());
+        radio::Receive::start_receive(&mut radio).unwrap();
+        assert!(!radio::Receive::check_receive(&mut radio, false).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+}
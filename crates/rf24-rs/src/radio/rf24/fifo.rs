@@ -5,6 +5,25 @@ use crate::FifoState;
 
 use super::{commands, registers, Nrf24Error};
 
+/// Decode a raw `FIFO_STATUS` register value into a [`FifoState`].
+///
+/// This is a pure, transport-agnostic helper shared by the blocking [`RF24`] and the
+/// async `AsyncRF24` implementations of `get_fifo_state()`, so the register-layout
+/// knowledge only needs to live in one place.
+pub(crate) fn decode_fifo_state<SPI, DO>(
+    fifo_status: u8,
+    about_tx: bool,
+) -> Result<FifoState, Nrf24Error<SPI, DO>> {
+    let offset = about_tx as u8 * 4;
+    let status = (fifo_status & (3 << offset)) >> offset;
+    match status {
+        0 => Ok(FifoState::Occupied),
+        1 => Ok(FifoState::Empty),
+        2 => Ok(FifoState::Full),
+        _ => Err(Nrf24Error::BinaryCorruption),
+    }
+}
+
 impl<SPI, DO, DELAY> EsbFifo for RF24<SPI, DO, DELAY>
 where
     SPI: SpiDevice,
@@ -21,7 +40,15 @@ where
             // RX FIFO is not empty
             // get last used pipe
             self.spi_read(0, commands::NOP)?;
-            *pipe = self.status.rx_pipe();
+            let rx_pipe = self.status.rx_pipe();
+            // A pipe of 7 is the "RX FIFO empty" sentinel (see the `STATUS` register's
+            // `RX_P_NO` field in the datasheet). It should never coincide with
+            // `FIFO_STATUS` reporting a non-empty RX FIFO, but treat it as "not
+            // available" rather than handing a bogus pipe number to the caller.
+            if rx_pipe == 7 {
+                return Ok(false);
+            }
+            *pipe = rx_pipe;
             return Ok(true);
         }
         Ok(false)
@@ -39,14 +66,7 @@ where
 
     fn get_fifo_state(&mut self, about_tx: bool) -> Result<FifoState, Self::Error> {
         self.spi_read(1, registers::FIFO_STATUS)?;
-        let offset = about_tx as u8 * 4;
-        let status = (self.buf[1] & (3 << offset)) >> offset;
-        match status {
-            0 => Ok(FifoState::Occupied),
-            1 => Ok(FifoState::Empty),
-            2 => Ok(FifoState::Full),
-            _ => Err(Nrf24Error::BinaryCorruption),
-        }
+        decode_fifo_state(self.buf[1], about_tx)
     }
 }
 
@@ -91,8 +111,11 @@ mod test {
         let mut pipe = 9;
         assert!(!radio.available_pipe(&mut pipe).unwrap());
         assert_eq!(pipe, 9);
-        assert!(radio.available_pipe(&mut pipe).unwrap());
-        assert_eq!(pipe, 7);
+        // `FIFO_STATUS` claims the RX FIFO is not empty, but `STATUS` reports the
+        // `RX_P_NO` sentinel (7) used for "RX FIFO empty". Trust the sentinel and
+        // report no pipe as available, instead of handing back the bogus pipe 7.
+        assert!(!radio.available_pipe(&mut pipe).unwrap());
+        assert_eq!(pipe, 9);
         spi.done();
         ce_pin.done();
     }
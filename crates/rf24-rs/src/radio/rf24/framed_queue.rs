@@ -0,0 +1,222 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::RF24;
+use crate::radio::{
+    prelude::{EsbFifo, EsbRadio},
+    Nrf24Error,
+};
+use crate::FifoState;
+
+/// A fixed-capacity ring buffer of frames, each up to 32 bytes, holding up to `N` frames.
+struct FrameRing<const N: usize> {
+    bufs: [[u8; 32]; N],
+    lens: [u8; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for FrameRing<N> {
+    fn default() -> Self {
+        Self {
+            bufs: [[0u8; 32]; N],
+            lens: [0u8; N],
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> FrameRing<N> {
+    fn push(&mut self, data: &[u8]) -> bool {
+        if self.len >= N {
+            return false;
+        }
+        let tail = (self.head + self.len) % N;
+        let copy_len = data.len().min(32);
+        self.bufs[tail][..copy_len].copy_from_slice(&data[..copy_len]);
+        self.lens[tail] = copy_len as u8;
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<([u8; 32], u8)> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = (self.bufs[self.head], self.lens[self.head]);
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(item)
+    }
+}
+
+/// A fixed-capacity, producer/consumer queue of framed payloads for use with
+/// [`RF24::poll_queue()`].
+///
+/// This is the app-side half of a background TX/RX pipeline over the radio's 3-slot
+/// FIFOs: the application pushes outgoing frames with [`FrameQueue::send()`] and pops
+/// received ones with [`FrameQueue::receive()`], while [`RF24::poll_queue()`] does the
+/// actual SPI work of draining [`FrameQueue::send()`]'s frames into the TX FIFO and the
+/// RX FIFO's payloads into [`FrameQueue::receive()`]. This lets a caller pipeline more
+/// than 3 outstanding packets without busy-waiting on the blocking
+/// [`EsbRadio::send()`](crate::radio::prelude::EsbRadio::send)/
+/// [`EsbRadio::read()`](crate::radio::prelude::EsbRadio::read) calls.
+pub struct FrameQueue<const TX_N: usize, const RX_N: usize> {
+    outgoing: FrameRing<TX_N>,
+    incoming: FrameRing<RX_N>,
+}
+
+impl<const TX_N: usize, const RX_N: usize> Default for FrameQueue<TX_N, RX_N> {
+    fn default() -> Self {
+        Self {
+            outgoing: FrameRing::default(),
+            incoming: FrameRing::default(),
+        }
+    }
+}
+
+impl<const TX_N: usize, const RX_N: usize> FrameQueue<TX_N, RX_N> {
+    /// Construct an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `data` (truncated to 32 bytes) as the next outgoing frame.
+    ///
+    /// Returns `false` (and queues nothing) if the outgoing queue already holds `TX_N`
+    /// frames.
+    pub fn send(&mut self, data: &[u8]) -> bool {
+        self.outgoing.push(data)
+    }
+
+    /// Pop the oldest received frame (and its length), if any.
+    pub fn receive(&mut self) -> Option<([u8; 32], u8)> {
+        self.incoming.pop()
+    }
+
+    /// The number of frames currently awaiting transmission.
+    pub fn pending_tx(&self) -> usize {
+        self.outgoing.len
+    }
+
+    /// The number of received frames awaiting [`FrameQueue::receive()`].
+    pub fn pending_rx(&self) -> usize {
+        self.incoming.len
+    }
+}
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Drive `queue` for one round: load the TX FIFO with queued outgoing frames while
+    /// it has room, then drain the RX FIFO's available payloads into the queue's
+    /// incoming side.
+    ///
+    /// Call this repeatedly (e.g. from a main loop or an IRQ service routine) instead of
+    /// calling [`EsbRadio::send()`](crate::radio::prelude::EsbRadio::send)/
+    /// [`EsbRadio::read()`](crate::radio::prelude::EsbRadio::read) directly, so more than
+    /// 3 outstanding packets can be pipelined through the radio's FIFOs without the
+    /// caller busy-waiting on each one. Once [`FrameQueue`]'s incoming side is full,
+    /// further payloads are left in the RX FIFO until a later call.
+    pub fn poll_queue<const TX_N: usize, const RX_N: usize>(
+        &mut self,
+        queue: &mut FrameQueue<TX_N, RX_N>,
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>> {
+        while self.get_fifo_state(true)? != FifoState::Full {
+            match queue.outgoing.pop() {
+                Some((buf, len)) => {
+                    self.write(&buf[..len as usize], false, true)?;
+                }
+                None => break,
+            }
+        }
+
+        while queue.incoming.len < RX_N && self.available()? {
+            let mut buf = [0u8; 32];
+            let len = self.read(&mut buf, None)?;
+            queue.incoming.push(&buf[..len as usize]);
+        }
+
+        Ok(())
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::FrameQueue;
+    use crate::{
+        radio::rf24::{commands, mnemonics, registers},
+        spi_test_expects,
+        test::mk_radio,
+    };
+    use embedded_hal_mock::eh1::{
+        digital::{State as PinState, Transaction as PinTransaction},
+        spi::Transaction as SpiTransaction,
+    };
+    use std::vec;
+
+    #[test]
+    fn drains_outgoing_and_fills_incoming() {
+        let mut queue = FrameQueue::<3, 3>::new();
+        assert!(queue.send(&[1, 2, 3]));
+        assert_eq!(queue.pending_tx(), 1);
+
+        let ce_expectations = [PinTransaction::set(PinState::High)];
+
+        let mut tx_payload = [0u8; 33];
+        tx_payload[0] = commands::W_TX_PAYLOAD;
+        tx_payload[1..4].copy_from_slice(&[1, 2, 3]);
+
+        let mut rx_payload = [0u8; 33];
+        rx_payload[0] = commands::R_RX_PAYLOAD;
+        let mut rx_response = [0x55u8; 33];
+        rx_response[0] = 0xEu8;
+
+        let spi_expectations = spi_test_expects![
+            // get_fifo_state(true): TX FIFO occupied, not full
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 0u8]),
+            // write(): clear_status_flags() for tx_df/tx_ds
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0u8],
+            ),
+            // write() the queued frame, padded to the default static payload length
+            (tx_payload.to_vec(), vec![0u8; 33]),
+            // get_fifo_state(true): TX FIFO now full, stop loading
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 0x20]),
+            // available(): RX FIFO has a payload
+            (vec![registers::FIFO_STATUS, 0x20], vec![0xEu8, 0u8]),
+            // read() the payload
+            (rx_payload.to_vec(), rx_response.to_vec()),
+            // read(): clear_status_flags() for rx_dr
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_RX_DR,
+                ],
+                vec![0xEu8, 0u8],
+            ),
+            // available(): RX FIFO empty
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 1u8]),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.poll_queue(&mut queue).unwrap();
+        assert_eq!(queue.pending_tx(), 0);
+        assert_eq!(queue.pending_rx(), 1);
+        let (buf, len) = queue.receive().unwrap();
+        assert_eq!(len, 32);
+        assert_eq!(&buf[..len as usize], &[0x55; 32]);
+        spi.done();
+        ce_pin.done();
+    }
+}
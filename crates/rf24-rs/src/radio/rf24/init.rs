@@ -1,10 +1,10 @@
-use super::{commands, data_rate::set_tx_delay, registers, Feature, Nrf24Error, RF24};
+use super::{commands, data_rate::set_tx_delay, registers, Config, Feature, Nrf24Error, RF24};
 use crate::{
     radio::{
         prelude::{EsbChannel, EsbFifo, EsbInit, EsbPayloadLength, EsbPipe, EsbPower, EsbStatus},
         RadioConfig,
     },
-    StatusFlags,
+    DataRate, PaLevel, StatusFlags,
 };
 use embedded_hal::{
     delay::DelayNs,
@@ -18,6 +18,8 @@ where
     DO: OutputPin,
     DELAY: DelayNs,
 {
+    type ConfigErrorType = Nrf24Error<SPI::Error, DO::Error>;
+
     /// Initialize the radio's hardware using the [`SpiDevice`] and [`OutputPin`] given
     /// to [`RF24::new()`].
     fn init(&mut self) -> Result<(), Self::Error> {
@@ -61,6 +63,7 @@ where
         // PTX should use only 22uA of power in standby-I mode.
         self.config_reg = config.config_reg.with_power(true);
         self.ce_pin.set_low().map_err(|e| e.kind())?; // Guarantee CE is low on powerDown
+        self.ce_active = false;
         self.clear_status_flags(StatusFlags::new())?;
 
         // Flush buffers
@@ -70,6 +73,13 @@ where
         let addr_len = config.address_length();
         self.set_address_length(addr_len)?;
 
+        // NOTE: `SETUP_AW`, `SETUP_RETR`, `RF_CH`, and `RF_SETUP` occupy consecutive
+        // register addresses, but that doesn't make them batchable into a single SPI
+        // transaction. The 5-bit register address in a `W_REGISTER` command selects
+        // exactly one register; there's no auto-increment to the next address the way
+        // there is within a single multi-byte register (like the address registers
+        // written via `spi_write_buf()`/`spi_transfer()` below). Each register here
+        // genuinely needs its own command byte and CS cycle.
         self.spi_write_byte(registers::SETUP_RETR, config.auto_retries.into_bits())?;
         self.spi_write_byte(registers::EN_AA, config.auto_ack())?;
         self.feature = Feature::from_bits(
@@ -88,18 +98,19 @@ where
 
         // setup RX addresses
         if config.is_rx_pipe_enabled(0) {
-            self.pipe0_rx_addr = Some(config.pipes.pipe0);
+            self.pipe0_rx_addr = Some(config.ordered_address(config.pipes.pipe0));
         }
-        self.spi_write_buf(registers::RX_ADDR_P0 + 1, &config.pipes.pipe1)?;
+        let pipe1_addr = config.ordered_address(config.pipes.pipe1);
+        self.spi_write_buf(registers::RX_ADDR_P0 + 1, &pipe1_addr)?;
+        let mut prefix = [0u8; 1];
         for pipe in 2..6 {
-            self.spi_write_byte(
-                registers::RX_ADDR_P0 + pipe,
-                config.pipes.subsequent_pipe_prefixes[pipe as usize - 2],
-            )?;
+            config.pipes.get_rx_address(pipe, &mut prefix);
+            self.spi_write_byte(registers::RX_ADDR_P0 + pipe, prefix[0])?;
         }
 
         // setup TX address
         config.tx_address(&mut self.tx_address);
+        self.tx_address = config.ordered_address(self.tx_address);
         // use `spi_transfer()` to avoid multiple borrows of self (`spi_write_buf()` and `tx_address`)
         for reg in [registers::TX_ADDR, registers::RX_ADDR_P0] {
             self.buf[0] = reg | commands::W_REGISTER;
@@ -111,12 +122,95 @@ where
         // open all RX pipes; enable pipe 0 for TX mode
         self.spi_write_byte(registers::EN_RXADDR, config.pipes.rx_pipes_enabled | 1)?;
 
+        // `RX_PW_P0..RX_PW_P5` are likewise consecutive addresses that can't be folded
+        // into one burst write for the same reason noted above, so this only writes a
+        // pipe's length when it actually diverges from the base length just set.
         self.set_payload_length(config.payload_length())?;
+        for pipe in 0..6 {
+            let len = config.pipe_payload_length(pipe);
+            if len != config.payload_length() {
+                self.spi_write_byte(registers::RX_PW_P0 + pipe, len.clamp(1, 32))?;
+            }
+        }
 
         self.set_channel(config.channel())?;
 
         self.spi_write_byte(registers::CONFIG, self.config_reg.into_bits())
     }
+
+    fn read_config(&mut self) -> Result<RadioConfig, Self::ConfigErrorType> {
+        let mut config = RadioConfig::default();
+
+        self.spi_read(1, registers::CONFIG)?;
+        let config_reg = Config::from_bits(self.buf[1]);
+        config = config
+            .with_crc_length(config_reg.crc_length())
+            .with_rx_dr(config_reg.rx_dr())
+            .with_tx_ds(config_reg.tx_ds())
+            .with_tx_df(config_reg.tx_df());
+
+        self.spi_read(1, registers::RF_SETUP)?;
+        let rf_setup = self.buf[1];
+        config = config
+            .with_pa_level(PaLevel::from_bits(rf_setup & PaLevel::MASK))
+            .with_data_rate(DataRate::from_bits(rf_setup & DataRate::MASK))
+            .with_lna_enable(rf_setup & 1 == 1);
+
+        self.spi_read(1, registers::SETUP_AW)?;
+        config = config.with_address_length(self.buf[1].min(3) + 2);
+
+        self.spi_read(1, registers::SETUP_RETR)?;
+        config = config.with_auto_retries(self.buf[1] >> 4, self.buf[1] & 0xF);
+
+        self.spi_read(1, registers::RF_CH)?;
+        config = config.with_channel(self.buf[1]);
+
+        self.spi_read(1, registers::RX_PW_P0)?;
+        config = config.with_payload_length(self.buf[1]);
+
+        self.spi_read(1, registers::EN_AA)?;
+        config = config.with_auto_ack(self.buf[1] & 0x3F);
+
+        self.spi_read(1, registers::FEATURE)?;
+        let feature = Feature::from_bits(self.buf[1] & Feature::REG_MASK);
+        config = config
+            .with_dynamic_payloads(feature.dynamic_payloads())
+            .with_ack_payloads(feature.ack_payloads())
+            .with_ask_no_ack(feature.ask_no_ack());
+
+        self.spi_read(5, registers::TX_ADDR)?;
+        config = config.with_tx_address(&self.buf[1..6]);
+
+        self.spi_read(1, registers::EN_RXADDR)?;
+        let rx_pipes_enabled = self.buf[1];
+
+        self.spi_read(5, registers::RX_ADDR_P0)?;
+        config = config.with_rx_address(0, &self.buf[1..6]);
+        self.spi_read(5, registers::RX_ADDR_P0 + 1)?;
+        config = config.with_rx_address(1, &self.buf[1..6]);
+        for pipe in 2..6u8 {
+            self.spi_read(1, registers::RX_ADDR_P0 + pipe)?;
+            config = config.with_rx_address(pipe, &self.buf[1..2]);
+        }
+        for pipe in 0..6u8 {
+            if rx_pipes_enabled & (1 << pipe) == 0 {
+                config = config.close_rx_pipe(pipe);
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn is_chip_connected(&mut self) -> Result<bool, Self::ConfigErrorType> {
+        self.spi_read(1, registers::SETUP_AW)?;
+        let original = self.buf[1] & 0x3;
+        let probe = !original & 0x3;
+        self.spi_write_byte(registers::SETUP_AW, probe)?;
+        self.spi_read(1, registers::SETUP_AW)?;
+        let echoed = self.buf[1] & 0x3;
+        self.spi_write_byte(registers::SETUP_AW, original)?;
+        Ok(echoed == probe)
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////////
@@ -129,7 +223,7 @@ mod test {
         radio::{rf24::commands, RadioConfig},
         spi_test_expects,
         test::mk_radio,
-        DataRate, PaLevel,
+        ByteOrder, CrcLength, DataRate, PaLevel,
     };
     use embedded_hal_mock::eh1::{
         digital::{State as PinState, Transaction as PinTransaction},
@@ -360,4 +454,238 @@ mod test {
             ..Default::default()
         });
     }
+
+    #[test]
+    fn read_config() {
+        // NOTE: since `self.buf` is reused as-is between SPI transactions (for full
+        // duplex transfers), each MOSI byte (after the command byte) is whatever was
+        // left over from the previous transaction's response, not necessarily 0.
+        let spi_expectations = spi_test_expects![
+            // CONFIG: 16 bit CRC, all IRQ events enabled, powered down
+            (vec![registers::CONFIG, 0], vec![0xEu8, 0xC]),
+            // RF_SETUP: PaLevel::Max, DataRate::Mbps1, LNA enabled
+            (vec![registers::RF_SETUP, 0xC], vec![0xEu8, 7]),
+            // SETUP_AW: 5 byte addresses
+            (vec![registers::SETUP_AW, 7], vec![0xEu8, 3]),
+            // SETUP_RETR: delay 5, count 15
+            (vec![registers::SETUP_RETR, 3], vec![0xEu8, 0x5F]),
+            // RF_CH: channel 76
+            (vec![registers::RF_CH, 0x5F], vec![0xEu8, 76]),
+            // RX_PW_P0: payload length 32
+            (vec![registers::RX_PW_P0, 76], vec![0xEu8, 32]),
+            // EN_AA: auto-ack enabled on pipes 0 - 5
+            (vec![registers::EN_AA, 32], vec![0xEu8, 0x3F]),
+            // FEATURE: no dynamic payloads, no ack payloads, no ask-no-ack
+            (vec![registers::FEATURE, 0x3F], vec![0xEu8, 0]),
+            // TX_ADDR
+            (
+                vec![registers::TX_ADDR, 0, 0, 0, 0, 0],
+                vec![0xEu8, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+            ),
+            // EN_RXADDR: only pipe 1 enabled
+            // (the MOSI byte here is leftover from the TX_ADDR response above,
+            // since `self.buf` is reused as-is between SPI transactions)
+            (vec![registers::EN_RXADDR, 0xE7], vec![0xEu8, 2]),
+            // RX_ADDR_P0
+            (
+                vec![registers::RX_ADDR_P0, 2, 0xE7, 0xE7, 0xE7, 0xE7],
+                vec![0xEu8, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+            ),
+            // RX_ADDR_P1
+            (
+                vec![registers::RX_ADDR_P0 + 1, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+                vec![0xEu8, 0xC2, 0xC2, 0xC2, 0xC2, 0xC2],
+            ),
+            // RX_ADDR_P2 - RX_ADDR_P5
+            (vec![registers::RX_ADDR_P0 + 2, 0xC2], vec![0xEu8, 0xC3]),
+            (vec![registers::RX_ADDR_P0 + 3, 0xC3], vec![0xEu8, 0xC4]),
+            (vec![registers::RX_ADDR_P0 + 4, 0xC4], vec![0xEu8, 0xC5]),
+            (vec![registers::RX_ADDR_P0 + 5, 0xC5], vec![0xEu8, 0xC6]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let config = radio.read_config().unwrap();
+
+        assert_eq!(config.crc_length(), CrcLength::Bit16);
+        assert!(config.rx_dr() && config.tx_ds() && config.tx_df());
+        assert_eq!(config.pa_level(), PaLevel::Max);
+        assert_eq!(config.data_rate(), DataRate::Mbps1);
+        assert!(config.lna_enable());
+        assert_eq!(config.address_length(), 5);
+        assert_eq!(config.auto_retry_delay(), 5);
+        assert_eq!(config.auto_retry_count(), 15);
+        assert_eq!(config.channel(), 76);
+        assert_eq!(config.payload_length(), 32);
+        assert_eq!(config.auto_ack(), 0x3F);
+        assert!(!config.dynamic_payloads());
+        assert!(!config.ack_payloads());
+        assert!(!config.ask_no_ack());
+        assert!(!config.is_rx_pipe_enabled(0));
+        assert!(config.is_rx_pipe_enabled(1));
+        assert!(!config.is_rx_pipe_enabled(2));
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn is_chip_connected_true() {
+        let spi_expectations = spi_test_expects![
+            // read the address width currently configured (3 bytes)
+            (vec![registers::SETUP_AW, 0], vec![0xEu8, 1]),
+            // probe with the inverted 2-bit field (4 bytes)
+            (
+                vec![registers::SETUP_AW | commands::W_REGISTER, 2],
+                vec![0xEu8, 0],
+            ),
+            // the probe value echoes back correctly
+            (vec![registers::SETUP_AW, 0], vec![0xEu8, 2]),
+            // the original address width is restored
+            (
+                vec![registers::SETUP_AW | commands::W_REGISTER, 1],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert!(radio.is_chip_connected().unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn is_chip_connected_false() {
+        let spi_expectations = spi_test_expects![
+            // read the address width currently configured (3 bytes)
+            (vec![registers::SETUP_AW, 0], vec![0xEu8, 1]),
+            // probe with the inverted 2-bit field (4 bytes)
+            (
+                vec![registers::SETUP_AW | commands::W_REGISTER, 2],
+                vec![0xEu8, 0],
+            ),
+            // the module is unresponsive, so the register still reads the old value
+            (vec![registers::SETUP_AW, 0], vec![0xEu8, 1]),
+            // the original address width is restored anyway
+            (
+                vec![registers::SETUP_AW | commands::W_REGISTER, 1],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert!(!radio.is_chip_connected().unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn with_config_respects_msb_first_byte_order() {
+        // declared MSByte-first; `with_config()` must reverse each before it is shifted
+        // out, since the nRF24 always shifts an address out LSByte-first.
+        let pipe1 = [0x11, 0x22, 0x33, 0x44, 0x55];
+        let tx_and_pipe0 = [0x66, 0x77, 0x88, 0x99, 0xAA];
+        let config = RadioConfig::default()
+            .with_address_byte_order(ByteOrder::MsbFirst)
+            .with_rx_address(1, &pipe1)
+            .with_tx_address(&tx_and_pipe0);
+
+        let ce_expectations = [PinTransaction::set(PinState::Low)];
+        let mut spi_expectations = spi_test_expects![
+            // clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            // flush_rx()
+            (vec![commands::FLUSH_RX], vec![0xEu8]),
+            // flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // set_address_length()
+            (
+                vec![registers::SETUP_AW | commands::W_REGISTER, 3],
+                vec![0xEu8, 0],
+            ),
+            // set_auto_retries()
+            (
+                vec![registers::SETUP_RETR | commands::W_REGISTER, 0x5F],
+                vec![0xEu8, 0],
+            ),
+            // write auto-ack register
+            (
+                vec![registers::EN_AA | commands::W_REGISTER, 0x3F],
+                vec![0xEu8, 0],
+            ),
+            // write dynamic payloads register
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // write FEATURE register
+            (
+                vec![registers::FEATURE | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // write data rate && PA level register
+            (
+                vec![
+                    registers::RF_SETUP | commands::W_REGISTER,
+                    DataRate::Mbps1.into_bits() | PaLevel::Max.into_bits() | 1
+                ],
+                vec![0xEu8, 0],
+            ),
+            // set RX address for pipe 1: reversed to LSByte-first (0x55, 0x44, ...)
+            (
+                vec![
+                    (registers::RX_ADDR_P0 + 1) | commands::W_REGISTER,
+                    0x55,
+                    0x44,
+                    0x33,
+                    0x22,
+                    0x11
+                ],
+                vec![0xEu8, 0, 0, 0, 0, 0],
+            ),
+        ];
+        for (pipe, addr) in [0xC3, 0xC4, 0xC5, 0xC6].iter().enumerate() {
+            spi_expectations.extend(spi_test_expects![(
+                vec![
+                    (registers::RX_ADDR_P0 + 2 + pipe as u8) | commands::W_REGISTER,
+                    *addr,
+                ],
+                vec![0xEu8, 0],
+            ),]);
+        }
+        // set TX address and as RX address for pipe 0: reversed to LSByte-first
+        for reg in [registers::TX_ADDR, registers::RX_ADDR_P0] {
+            spi_expectations.extend(spi_test_expects![(
+                vec![reg | commands::W_REGISTER, 0xAA, 0x99, 0x88, 0x77, 0x66],
+                vec![0xEu8, 0, 0, 0, 0, 0],
+            ),]);
+        }
+        spi_expectations.extend(spi_test_expects![(
+            vec![registers::EN_RXADDR | commands::W_REGISTER, 3],
+            vec![0xEu8, 0],
+        ),]);
+        for pipe in 0..6 {
+            spi_expectations.extend(spi_test_expects![(
+                vec![(registers::RX_PW_P0 + pipe) | commands::W_REGISTER, 32],
+                vec![0xEu8, 0],
+            ),]);
+        }
+        spi_expectations.extend(spi_test_expects![
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xE],
+                vec![0xEu8, 0],
+            ),
+        ]);
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.with_config(&config).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
 }
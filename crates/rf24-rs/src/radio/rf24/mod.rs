@@ -1,17 +1,29 @@
 use embedded_hal::{
     delay::DelayNs,
-    digital::{Error as _, ErrorKind as OutputPinError, OutputPin},
+    digital::{Error as _, ErrorKind as OutputPinError, InputPin, OutputPin},
     spi::{Error as _, ErrorKind as SpiError, SpiDevice},
 };
+mod ack_queue;
+pub use ack_queue::AckPayloadQueue;
+mod adaptive_retry;
+mod framed_queue;
+pub use framed_queue::FrameQueue;
 mod auto_ack;
 pub(crate) mod bit_fields;
 mod channel;
+mod channel_scanner;
+mod verified_write;
+pub use channel_scanner::{CarrierSweep, ChannelScanner};
 mod init;
-use bit_fields::{ConfigReg, Feature};
+use bit_fields::{Config, Feature};
 mod constants;
 mod crc_length;
 mod data_rate;
+pub(crate) use data_rate::set_tx_delay;
+mod diagnostics;
+pub use diagnostics::DETAILS_SNAPSHOT_LEN;
 mod fifo;
+pub(crate) use fifo::decode_fifo_state;
 mod pa_level;
 mod payload_length;
 mod pipe;
@@ -19,14 +31,28 @@ mod power;
 mod radio;
 pub use constants::{commands, mnemonics, registers};
 mod details;
+mod ecosystem;
+mod snapshot;
 mod status;
+pub use snapshot::RADIO_CONFIG_BLOB_LEN;
+mod stream;
+pub use stream::{StreamError, STREAM_CHUNK_LEN, STREAM_MAX_CHUNKS, STREAM_MAX_MESSAGE_LEN};
+mod runtime;
+mod scanner;
+mod test_mode;
 use super::prelude::{
-    EsbAutoAck, EsbChannel, EsbCrcLength, EsbFifo, EsbPaLevel, EsbPower, EsbRadio, RadioErrorType,
+    EsbAutoAck, EsbChannel, EsbCrcLength, EsbDataRate, EsbFifo, EsbInit, EsbPaLevel,
+    EsbPayloadLength, EsbPipe, EsbPower, EsbRadio, EsbStatus, RadioErrorType,
 };
 use crate::{
-    types::{CrcLength, PaLevel},
-    StatusFlags,
+    radio::RadioConfig,
+    types::{CrcLength, DataRate, PaLevel},
+    RadioState, SendOutcome, StatusFlags,
 };
+#[cfg(feature = "radio-trait")]
+pub use ecosystem::{EcosystemState, Irq};
+pub use runtime::{Runtime, RxFrame};
+pub use scanner::Scanner;
 
 /// An collection of error types to describe hardware malfunctions.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -42,6 +68,29 @@ pub enum Nrf24Error<SPI, DO> {
     /// This only occurs when user code neglected to call [`RF24::as_tx()`] at least once
     /// before calling [`RF24::send()`].
     NotAsTxError,
+    /// The dynamic payload width read back from the radio (via `R_RX_PL_WID`) exceeds
+    /// the 32-byte maximum, which is not possible on working hardware. Carries the
+    /// offending byte.
+    InvalidPayloadWidth(u8),
+    /// Returned by [`RF24::verify_critical_writes`]-enabled writes when the register
+    /// does not hold the value just written to it.
+    RegisterMismatch {
+        /// The register (or command) byte that was written.
+        register: u8,
+        /// The value that was written.
+        expected: u8,
+        /// The value read back from the register.
+        actual: u8,
+    },
+    /// Returned by [`RF24::verify_critical_writes`]-enabled writes when the STATUS byte
+    /// returned with the write is all-ones or all-zeros, which (since the STATUS
+    /// register's most significant bit is always reserved-low on working hardware)
+    /// indicates the module is not responding (e.g. unwired SPI lines or no power).
+    ModuleUnreachable,
+    /// Returned by [`EsbDataRate::set_data_rate()`](fn@crate::radio::prelude::EsbDataRate::set_data_rate)
+    /// when [`DataRate::Kbps250`](crate::DataRate::Kbps250) is requested on a radio that is
+    /// not a nRF24L01+ variant (see [`RF24::is_plus_variant()`]).
+    UnsupportedDataRate,
 }
 
 impl From<SpiError> for Nrf24Error<SpiError, OutputPinError> {
@@ -102,7 +151,7 @@ pub struct RF24<SPI, DO, DELAY> {
     delay_impl: DELAY,
     buf: [u8; 33],
     status: StatusFlags,
-    config_reg: ConfigReg,
+    config_reg: Config,
     feature: Feature,
     pipe0_rx_addr: Option<[u8; 5]>,
     /// The TX address used on pipe 0 for outgoing transmissions.
@@ -114,6 +163,37 @@ pub struct RF24<SPI, DO, DELAY> {
     /// if pipe 0 is also used for RX with a different address.
     tx_address: [u8; 5],
     payload_length: u8,
+    /// Tracks whether [`RF24::ce_pin`] was last driven high, since [`OutputPin`] offers
+    /// no readback. This is used by [`RF24::current_state()`] to distinguish
+    /// [`RadioState::StandbyI`] from [`RadioState::TxMode`].
+    ce_active: bool,
+    /// The idle state that [`EsbRadio::send()`](fn@crate::radio::prelude::EsbRadio::send)
+    /// and [`EsbRadio::resend()`](fn@crate::radio::prelude::EsbRadio::resend) settle the
+    /// radio into once a (re)transmission completes.
+    ///
+    /// Controlled with
+    /// [`EsbPower::set_fallback_mode()`](fn@crate::radio::prelude::EsbPower::set_fallback_mode).
+    fallback_mode: FallbackMode,
+    /// When `true`, writes to critical registers (`FEATURE`, `EN_AA`, `DYNPD`) are
+    /// immediately read back and verified, returning
+    /// [`Nrf24Error::RegisterMismatch`] or [`Nrf24Error::ModuleUnreachable`] instead of
+    /// silently continuing with a mis-configured radio.
+    ///
+    /// This costs an extra SPI transaction per write, so it defaults to `false`.
+    pub verify_critical_writes: bool,
+    /// Microseconds remaining in the Tpd2stby settling window started by
+    /// [`EsbPower::begin_power_up()`](fn@crate::radio::prelude::EsbPower::begin_power_up),
+    /// as tracked by [`EsbPower::power_up_ready()`](fn@crate::radio::prelude::EsbPower::power_up_ready).
+    power_up_settle_us: u32,
+    /// How many times to retry a register write (read back via an extra SPI
+    /// transaction) before giving up, when set non-zero via
+    /// [`RF24::set_spi_verification()`].
+    ///
+    /// Unlike [`RF24::verify_critical_writes`], this applies to every register write
+    /// made through [`RF24::spi_write_byte()`]/[`RF24::spi_write_buf()`], not just a
+    /// handful of critical registers, and actively retries instead of just reporting
+    /// the mismatch. Defaults to `0` (disabled).
+    spi_verification_retries: u8,
 }
 
 impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
@@ -142,8 +222,13 @@ where
                 .with_address_length(5)
                 .with_is_plus_variant(true),
             // 16 bit CRC, enable all IRQ, and power down as TX
-            config_reg: ConfigReg::from_bits(0xC),
+            config_reg: Config::from_bits(0xC),
             payload_length: 32,
+            ce_active: false,
+            fallback_mode: FallbackMode::default(),
+            verify_critical_writes: false,
+            power_up_settle_us: 0,
+            spi_verification_retries: 0,
         }
     }
 
@@ -174,9 +259,8 @@ where
         command: u8,
         byte: u8,
     ) -> Result<(), Nrf24Error<SpiError, OutputPinError>> {
-        self.buf[0] = command | commands::W_REGISTER;
         self.buf[1] = byte;
-        self.spi_transfer(2)
+        self.spi_write_verified(command, 1)
     }
 
     fn spi_write_buf(
@@ -184,10 +268,110 @@ where
         command: u8,
         buf: &[u8],
     ) -> Result<(), Nrf24Error<SpiError, OutputPinError>> {
-        self.buf[0] = command | commands::W_REGISTER;
         let buf_len = buf.len();
         self.buf[1..(buf_len + 1)].copy_from_slice(&buf[..buf_len]);
-        self.spi_transfer(buf_len as u8 + 1)
+        self.spi_write_verified(command, buf_len as u8)
+    }
+
+    /// Write `self.buf[1..len + 1]` to `command` (a register), then (if
+    /// [`RF24::set_spi_verification()`] has set a non-zero retry budget) read it back
+    /// and re-issue the whole write until it matches or the retry budget is spent.
+    ///
+    /// This is the general-purpose counterpart to [`RF24::spi_write_byte_checked()`]'s
+    /// always-critical-registers check: it covers every register write made through
+    /// [`RF24::spi_write_byte()`]/[`RF24::spi_write_buf()`], gated by an explicit opt-in
+    /// instead of being hardcoded to a handful of registers.
+    fn spi_write_verified(
+        &mut self,
+        command: u8,
+        len: u8,
+    ) -> Result<(), Nrf24Error<SpiError, OutputPinError>> {
+        let write_command = command | commands::W_REGISTER;
+        let mut written = [0u8; 32];
+        written[..len as usize].copy_from_slice(&self.buf[1..len as usize + 1]);
+
+        self.buf[0] = write_command;
+        self.spi_transfer(len + 1)?;
+        if self.spi_verification_retries == 0 {
+            return Ok(());
+        }
+
+        let mut attempts_left = self.spi_verification_retries;
+        loop {
+            // an all-ones or all-zeros STATUS byte is not possible on working
+            // hardware (the register's most significant bit is reserved-low), so
+            // treat it as a sign the module isn't actually responding
+            let status_ok = self.buf[0] != 0xFF && self.buf[0] != 0;
+            let matches = status_ok && {
+                self.spi_read(len, command)?;
+                self.buf[1..len as usize + 1] == written[..len as usize]
+            };
+            if matches {
+                return Ok(());
+            }
+            if attempts_left == 0 {
+                return Err(if status_ok {
+                    Nrf24Error::RegisterMismatch {
+                        register: command,
+                        expected: written[0],
+                        actual: self.buf[1],
+                    }
+                } else {
+                    Nrf24Error::ModuleUnreachable
+                });
+            }
+            attempts_left -= 1;
+            self.buf[0] = write_command;
+            self.buf[1..len as usize + 1].copy_from_slice(&written[..len as usize]);
+            self.spi_transfer(len + 1)?;
+        }
+    }
+
+    /// Write `byte` to `command` (a single register), then (if
+    /// [`RF24::verify_critical_writes`] is enabled) read it back and confirm it stuck.
+    ///
+    /// Used for critical registers (`FEATURE`, `EN_AA`, `DYNPD`) whose silent
+    /// corruption would otherwise only surface as confusing downstream behavior (e.g.
+    /// auto-ack or dynamic payloads appearing to do nothing).
+    fn spi_write_byte_checked(
+        &mut self,
+        command: u8,
+        byte: u8,
+    ) -> Result<(), Nrf24Error<SpiError, OutputPinError>> {
+        self.spi_write_byte(command, byte)?;
+        if self.verify_critical_writes {
+            if self.buf[0] == 0xFF || self.buf[0] == 0 {
+                return Err(Nrf24Error::ModuleUnreachable);
+            }
+            self.spi_read(1, command)?;
+            if self.buf[1] != byte {
+                return Err(Nrf24Error::RegisterMismatch {
+                    register: command,
+                    expected: byte,
+                    actual: self.buf[1],
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Read `register`, apply `f` to its current value, then write the result back.
+    ///
+    /// Note that `R_REGISTER` and `W_REGISTER` are distinct SPI commands on the
+    /// nRF24L01 (the command byte is framed by CSN falling), so this still costs two
+    /// bus transactions; it cannot be folded into a single CS assertion the way a
+    /// multi-byte read or write of one register can. What this buys instead is a
+    /// single place for the read-modify-write pattern duplicated across
+    /// [`EsbCrcLength::set_crc_length()`](fn@crate::radio::prelude::EsbCrcLength::set_crc_length)
+    /// and [`EsbPipe`](crate::radio::prelude::EsbPipe)'s pipe enable/disable methods.
+    fn update_register(
+        &mut self,
+        register: u8,
+        f: impl FnOnce(u8) -> u8,
+    ) -> Result<(), Nrf24Error<SpiError, OutputPinError>> {
+        self.spi_read(1, register)?;
+        let new_value = f(self.buf[1]);
+        self.spi_write_byte(register, new_value)
     }
 
     /// A private function to write a special SPI command specific to older
@@ -206,6 +390,30 @@ where
         self.feature.is_plus_variant()
     }
 
+    /// The radio's coarse operating state, as tracked by this [`RF24`] instance.
+    ///
+    /// This reflects the local cache of the CONFIG register and the CE pin's last
+    /// known level; it does not perform any SPI transactions. Use
+    /// [`EsbInit::read_config()`](fn@crate::radio::prelude::EsbInit::read_config) to
+    /// verify the radio's actual hardware configuration instead.
+    pub fn current_state(&self) -> RadioState {
+        if !self.config_reg.power() {
+            RadioState::PowerDown
+        } else if self.config_reg.is_rx() {
+            RadioState::RxMode
+        } else if self.ce_active {
+            RadioState::TxMode
+        } else {
+            RadioState::StandbyI
+        }
+    }
+
+    /// An alias of [`RF24::current_state()`] matching the `get_*` naming convention used
+    /// elsewhere in this API (eg. [`EsbRadio::get_last_arc()`](fn@crate::radio::prelude::EsbRadio::get_last_arc)).
+    pub fn get_state(&self) -> RadioState {
+        self.current_state()
+    }
+
     /// Was the Received Power Detection (RPD) trigger?
     ///
     /// This flag is asserted during an RX session (after a mandatory 130 microseconds
@@ -225,10 +433,299 @@ where
         Ok(self.buf[1] & 1 == 1)
     }
 
+    /// Survey the given `channels` for activity using the Received Power Detector.
+    ///
+    /// For each channel (in order), the radio enters RX mode and samples
+    /// [`RF24::rpd()`] `samples_per_channel` times, waiting the mandatory 130
+    /// microsecond RPD assertion delay (see [`RF24::rpd()`]) between samples. The
+    /// returned array holds, per channel, how many of those samples had RPD
+    /// asserted.
+    ///
+    /// This gives a coarse, nRF24L01-appropriate equivalent of the RSSI/channel-activity
+    /// surveys offered by other radios; use the result to pick the quietest channel or
+    /// to detect interference before transmitting.
+    ///
+    /// The radio's CE pin and CONFIG register's PRIM_RX bit are restored to whatever they
+    /// were before this function was called.
+    pub fn scan_channels<const N: usize>(
+        &mut self,
+        channels: &[u8; N],
+        samples_per_channel: u8,
+    ) -> Result<[u8; N], Nrf24Error<SpiError, OutputPinError>> {
+        self.scan_channels_with(channels, samples_per_channel, |_, _| true)
+    }
+
+    /// Like [`RF24::scan_channels()`], but `per_channel` is invoked with the channel
+    /// number and its accumulated hit count immediately after each channel is sampled.
+    ///
+    /// This allows a caller to stream live histogram updates (e.g. to a display) while
+    /// the scan is in progress. Returning `false` from `per_channel` aborts the scan
+    /// early; any channels not yet sampled are left at `0` in the returned array.
+    ///
+    /// The scan is conducted at [`DataRate::Mbps2`] (restored to whatever
+    /// [`DataRate`] was configured beforehand once the scan ends), since the wider
+    /// receive bandwidth at 2 Mbps gives [`RF24::rpd()`] a coarser but faster read on
+    /// occupancy across the whole 1 MHz-spaced channel map.
+    pub fn scan_channels_with<const N: usize>(
+        &mut self,
+        channels: &[u8; N],
+        samples_per_channel: u8,
+        mut per_channel: impl FnMut(u8, u8) -> bool,
+    ) -> Result<[u8; N], Nrf24Error<SpiError, OutputPinError>> {
+        let was_rx = self.is_rx();
+        let was_ce_active = self.ce_active;
+        let prior_data_rate = self.get_data_rate()?;
+        if prior_data_rate != DataRate::Mbps2 {
+            self.set_data_rate(DataRate::Mbps2)?;
+        }
+
+        let mut hits = [0u8; N];
+        for (i, channel) in channels.iter().enumerate() {
+            self.set_channel(*channel)?;
+            self.as_rx()?;
+            for _ in 0..samples_per_channel {
+                self.delay_impl.delay_us(130);
+                if self.rpd()? {
+                    hits[i] += 1;
+                }
+            }
+            // Any payload that happened to arrive during this channel's dwell is noise,
+            // not data this caller asked for; discard it so it cannot linger in the RX
+            // FIFO once the scan hands control back to the caller.
+            self.flush_rx()?;
+            if !per_channel(*channel, hits[i]) {
+                break;
+            }
+        }
+
+        if prior_data_rate != DataRate::Mbps2 {
+            self.set_data_rate(prior_data_rate)?;
+        }
+        if !was_rx {
+            self.config_reg = self.config_reg.as_tx();
+            self.spi_write_byte(registers::CONFIG, self.config_reg.into_bits())?;
+        }
+        if was_ce_active != self.ce_active {
+            if was_ce_active {
+                self.ce_pin.set_high().map_err(|e| e.kind())?;
+            } else {
+                self.ce_pin.set_low().map_err(|e| e.kind())?;
+            }
+            self.ce_active = was_ce_active;
+        }
+        Ok(hits)
+    }
+
+    /// Survey a single `channel` for activity, sampling the Received Power Detector
+    /// `samples` times.
+    ///
+    /// This is a convenience wrapper around [`RF24::scan_channels()`] for callers that
+    /// only care about one channel's hit count.
+    pub fn scan_channel(
+        &mut self,
+        channel: u8,
+        samples: u8,
+    ) -> Result<u8, Nrf24Error<SpiError, OutputPinError>> {
+        Ok(self.scan_channels(&[channel], samples)?[0])
+    }
+
+    /// Survey all 126 channels (0..=125) for activity, sampling the Received Power
+    /// Detector `dwell` times per channel.
+    ///
+    /// This is a convenience wrapper around [`RF24::scan_channels()`] that returns a
+    /// full 126-entry histogram, suitable for picking the quietest channel available.
+    pub fn scan_all(
+        &mut self,
+        dwell: u8,
+    ) -> Result<[u8; 126], Nrf24Error<SpiError, OutputPinError>> {
+        let mut channels = [0u8; 126];
+        for (i, channel) in channels.iter_mut().enumerate() {
+            *channel = i as u8;
+        }
+        self.scan_channels(&channels, dwell)
+    }
+
+    /// Find the quietest channel among `channels`, i.e. whichever has the fewest RPD
+    /// hits over `samples_per_channel` samples.
+    ///
+    /// This is a convenience wrapper around [`RF24::scan_channels()`] for picking a
+    /// low-noise operating frequency at startup instead of hand-rolling the scan and
+    /// comparing its histogram. If multiple channels tie for the fewest hits, the
+    /// first (lowest-indexed) one is returned; an empty `channels` array returns `0`.
+    pub fn find_clear_channel<const N: usize>(
+        &mut self,
+        channels: &[u8; N],
+        samples_per_channel: u8,
+    ) -> Result<u8, Nrf24Error<SpiError, OutputPinError>> {
+        let hits = self.scan_channels(channels, samples_per_channel)?;
+        Ok(hits
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, hits)| **hits)
+            .map(|(i, _)| channels[i])
+            .unwrap_or(0))
+    }
+
+    /// Reconstruct a [`RadioConfig`] from the radio's current register state.
+    ///
+    /// This is a convenience alias for [`EsbInit::read_config()`], the inverse of
+    /// [`EsbInit::with_config()`], for callers who'd rather not import the [`EsbInit`]
+    /// trait just to snapshot the current configuration (e.g. before switching to a
+    /// different context and restoring it afterward).
+    pub fn get_config(&mut self) -> Result<RadioConfig, Nrf24Error<SpiError, OutputPinError>> {
+        EsbInit::read_config(self)
+    }
+
+    /// Block until the given `irq_pin` asserts, then read and decode the STATUS
+    /// register, clearing the latched events in the same SPI transaction (like
+    /// [`EsbStatus::what_happened()`](fn@crate::radio::prelude::EsbStatus::what_happened)).
+    ///
+    /// The nRF24L01's IRQ output is open-drain and active-low. Wire it to an
+    /// [`InputPin`] and pass that pin here (instead of polling the STATUS register over
+    /// SPI in a loop) to avoid unnecessary SPI traffic while waiting for an event. The
+    /// `async` feature's `AsyncRF24` type offers an awaitable equivalent for use under
+    /// an async executor.
+    pub fn wait_for_irq<IRQ: InputPin>(
+        &mut self,
+        irq_pin: &mut IRQ,
+    ) -> Result<StatusFlags, Nrf24Error<SpiError, OutputPinError>> {
+        while irq_pin.is_high().map_err(|e| e.kind())? {}
+        self.what_happened(StatusFlags::new())
+    }
+
+    /// Identical to [`RF24::wait_for_irq()`], but gives up (returning `None`) if
+    /// `irq_pin` has not asserted within `timeout_us` microseconds.
+    ///
+    /// This guards against the footgun of a busy loop that never returns because the
+    /// radio was misconfigured to never assert the IRQ pin for the awaited event(s)
+    /// (see [`EsbStatus::set_status_flags()`](fn@crate::radio::prelude::EsbStatus::set_status_flags)).
+    pub fn wait_for_irq_timeout<IRQ: InputPin>(
+        &mut self,
+        irq_pin: &mut IRQ,
+        timeout_us: u32,
+    ) -> Result<Option<StatusFlags>, Nrf24Error<SpiError, OutputPinError>> {
+        let mut elapsed_us = 0u32;
+        while irq_pin.is_high().map_err(|e| e.kind())? {
+            if elapsed_us >= timeout_us {
+                return Ok(None);
+            }
+            self.delay_impl.delay_us(1);
+            elapsed_us += 1;
+        }
+        self.what_happened(StatusFlags::new()).map(Some)
+    }
+
+    /// Identical to [`RF24::wait_for_irq()`], but when the latched flags indicate
+    /// `rx_dr`, this also fetches the length of the payload that is now sitting at
+    /// the front of the RX FIFO (via [`EsbPayloadLength::get_dynamic_payload_length()`]
+    /// if dynamic payloads are enabled, else [`RF24::payload_length`]).
+    ///
+    /// Returns `(flags, Some(len))` if `flags.rx_dr()` is set, otherwise `(flags, None)`.
+    /// Use [`StatusFlags::rx_pipe_number()`] on the returned flags to determine which
+    /// pipe received the payload.
+    pub fn wait_for_rx_event<IRQ: InputPin>(
+        &mut self,
+        irq_pin: &mut IRQ,
+    ) -> Result<(StatusFlags, Option<u8>), Nrf24Error<SpiError, OutputPinError>> {
+        let flags = self.wait_for_irq(irq_pin)?;
+        if !flags.rx_dr() {
+            return Ok((flags, None));
+        }
+        let len = if self.feature.dynamic_payloads() {
+            self.get_dynamic_payload_length()?
+        } else {
+            self.payload_length
+        };
+        Ok((flags, Some(len)))
+    }
+
+    /// Send a payload, blocking on the given `irq_pin` instead of polling STATUS
+    /// over SPI in a busy loop.
+    ///
+    /// Like [`EsbRadio::send()`], this calls [`RF24::flush_tx()`] upon entry; the wait
+    /// for a `tx_ds`/`tx_df` event uses [`RF24::wait_for_irq()`] instead. Unlike
+    /// [`EsbRadio::send()`], the CE pin is left active upon exit, whatever the
+    /// [`EsbPower::get_fallback_mode()`](fn@crate::radio::prelude::EsbPower::get_fallback_mode).
+    pub fn send_with_irq<IRQ: InputPin>(
+        &mut self,
+        buf: &[u8],
+        ask_no_ack: bool,
+        irq_pin: &mut IRQ,
+    ) -> Result<bool, Nrf24Error<SpiError, OutputPinError>> {
+        self.ce_pin.set_low().map_err(|e| e.kind())?;
+        self.ce_active = false;
+        self.flush_tx()?;
+        if !self.write(buf, ask_no_ack, true)? {
+            return Ok(false);
+        }
+        self.delay_impl.delay_us(10);
+        let flags = self.wait_for_irq(irq_pin)?;
+        Ok(flags.tx_ds())
+    }
+
+    /// Resend the last transmitted payload, blocking on the given `irq_pin` instead
+    /// of polling STATUS over SPI in a busy loop.
+    ///
+    /// Like [`EsbRadio::resend()`], this returns `false` while in RX mode; the wait for
+    /// a `tx_ds`/`tx_df` event uses [`RF24::wait_for_irq()`] instead. Unlike
+    /// [`EsbRadio::resend()`], it returns `false` without waiting if the TX FIFO is
+    /// empty, leaves the CE pin active upon exit whatever the fallback mode, and never
+    /// flushes the RX FIFO.
+    pub fn resend_with_irq<IRQ: InputPin>(
+        &mut self,
+        irq_pin: &mut IRQ,
+    ) -> Result<bool, Nrf24Error<SpiError, OutputPinError>> {
+        if self.is_rx() {
+            return Ok(false);
+        }
+        if !self.rewrite()? {
+            return Ok(false);
+        }
+        self.delay_impl.delay_us(10);
+        let flags = self.wait_for_irq(irq_pin)?;
+        Ok(flags.tx_ds())
+    }
+
+    /// Retry [`EsbRadio::send()`] (flushing the TX FIFO and re-arming the payload each
+    /// time) until it is acknowledged or `max_attempts` is reached.
+    ///
+    /// This is a software-level retry layered on top of (and independent of) the
+    /// hardware's own auto-retry mechanism (see [`EsbAutoAck::set_auto_retries()`]); it
+    /// does not change the `SETUP_RETR` configuration. Use this for resilience over
+    /// lossy links without open-coding the flush/retry dance.
+    ///
+    /// If `backoff_us` is non-zero, that many microseconds are blocked (via the radio's
+    /// [`DelayNs`] implementation) between failed attempts. `max_attempts` is clamped to
+    /// a minimum of `1`.
+    pub fn send_with_retries(
+        &mut self,
+        buf: &[u8],
+        ask_no_ack: bool,
+        max_attempts: u8,
+        backoff_us: u32,
+    ) -> Result<SendOutcome, Nrf24Error<SpiError, OutputPinError>> {
+        let max_attempts = max_attempts.max(1);
+        let mut attempts = 0u8;
+        loop {
+            attempts += 1;
+            let acked = self.send(buf, ask_no_ack)?;
+            if acked || attempts >= max_attempts {
+                return Ok(SendOutcome { acked, attempts });
+            }
+            if backoff_us > 0 {
+                self.delay_impl.delay_us(backoff_us);
+            }
+        }
+    }
+
     /// Start a constant carrier wave
     ///
     /// This functionality is meant for hardware tests (in conjunction with [`RF24::rpd()`]).
     /// Typically, this behavior is required by government agencies to enforce regional restrictions.
+    ///
+    /// For piggybacking BLE advertising beacons on this radio instead, see the `rf24ble`
+    /// crate's `FakeBle`, which handles the required 4-byte address width, CRC24, whitening,
+    /// and bit-reversal.
     pub fn start_carrier_wave(
         &mut self,
         level: PaLevel,
@@ -293,6 +790,21 @@ where
         Ok(())
     }
 
+    /// Alias of [`RF24::start_carrier_wave()`], named after the feature it exercises
+    /// (the `CONT_WAVE` bit) for callers coming from the reference C++ driver's API.
+    pub fn start_const_carrier(
+        &mut self,
+        level: PaLevel,
+        channel: u8,
+    ) -> Result<(), Nrf24Error<SpiError, OutputPinError>> {
+        self.start_carrier_wave(level, channel)
+    }
+
+    /// Alias of [`RF24::stop_carrier_wave()`].
+    pub fn stop_const_carrier(&mut self) -> Result<(), Nrf24Error<SpiError, OutputPinError>> {
+        self.stop_carrier_wave()
+    }
+
     /// Enable or disable the LNA feature.
     ///
     /// This is enabled by default (regardless of chip variant).
@@ -307,6 +819,78 @@ where
         let out = self.buf[1] & !1 | enable as u8;
         self.spi_write_byte(registers::RF_SETUP, out)
     }
+
+    /// Get the state of the LNA feature, as set by [`RF24::set_lna()`].
+    pub fn get_lna(&mut self) -> Result<bool, Nrf24Error<SpiError, OutputPinError>> {
+        self.spi_read(1, registers::RF_SETUP)?;
+        Ok(self.buf[1] & 1 == 1)
+    }
+
+    /// Read `buf.len()` bytes (up to 32) from `register`, bypassing the driver's cached
+    /// shadow state (e.g. [`RF24::is_plus_variant()`]'s cached [`Feature`] bits).
+    ///
+    /// This is a low-level diagnostic primitive mirroring the reference C++ driver's
+    /// register read, meant for dumping the full register map or probing undocumented
+    /// clone-chip behavior. Returns the STATUS byte latched by the same transaction.
+    pub fn read_register(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> Result<u8, Nrf24Error<SpiError, OutputPinError>> {
+        let len = buf.len().min(32);
+        self.spi_read(len as u8, register)?;
+        buf[..len].copy_from_slice(&self.buf[1..len + 1]);
+        Ok(self.buf[0])
+    }
+
+    /// Write `buf` to `register`, bypassing the driver's cached shadow state.
+    ///
+    /// This is a low-level diagnostic primitive; unlike the typed setters elsewhere in
+    /// this crate, it does not keep any cached register value (like [`RF24::config_reg`])
+    /// in sync. Returns the STATUS byte latched by the same transaction.
+    pub fn write_register(
+        &mut self,
+        register: u8,
+        buf: &[u8],
+    ) -> Result<u8, Nrf24Error<SpiError, OutputPinError>> {
+        self.spi_write_buf(register, buf)?;
+        Ok(self.buf[0])
+    }
+
+    /// Perform a single raw SPI transaction: assert CSN, write `command` followed by
+    /// `buf`, then replace `buf`'s contents with the bytes shifted back in over MISO.
+    ///
+    /// This is the most primitive diagnostic escape hatch this driver offers; `command`
+    /// is sent as-is (it is not combined with `W_REGISTER`/`R_REGISTER`, unlike
+    /// [`RF24::read_register()`]/[`RF24::write_register()`]), so it can drive any SPI
+    /// command the nRF24L01 (or a clone chip) supports, documented or not. Returns the
+    /// STATUS byte latched by the same transaction.
+    pub fn spi_command(
+        &mut self,
+        command: u8,
+        buf: &mut [u8],
+    ) -> Result<u8, Nrf24Error<SpiError, OutputPinError>> {
+        let len = buf.len().min(32);
+        self.buf[0] = command;
+        self.buf[1..len + 1].copy_from_slice(&buf[..len]);
+        self.spi_transfer(len as u8 + 1)?;
+        buf[..len].copy_from_slice(&self.buf[1..len + 1]);
+        Ok(self.buf[0])
+    }
+}
+
+/// Find the least congested channel in a histogram returned by
+/// [`RF24::scan_channels()`], [`RF24::scan_channel()`], or [`RF24::scan_all()`].
+///
+/// `channels` and `hits` are the same arrays passed to (and returned by) a scan; this
+/// just picks the channel with the fewest RPD hits, favoring the lowest channel number
+/// to break ties. Returns [`None`] if `channels` is empty.
+pub fn quietest_channel<const N: usize>(channels: &[u8; N], hits: &[u8; N]) -> Option<u8> {
+    channels
+        .iter()
+        .zip(hits.iter())
+        .min_by_key(|(_, hits)| **hits)
+        .map(|(channel, _)| *channel)
 }
 
 /////////////////////////////////////////////////////////////////////////////////
@@ -318,7 +902,7 @@ mod test {
     use crate::{radio::prelude::EsbRadio, spi_test_expects, test::mk_radio};
     use embedded_hal::{digital::ErrorKind as OutputPinError, spi::ErrorKind as SpiError};
     use embedded_hal_mock::eh1::{
-        digital::{State as PinState, Transaction as PinTransaction},
+        digital::{Mock as PinMock, State as PinState, Transaction as PinTransaction},
         spi::Transaction as SpiTransaction,
     };
     use std::vec;
@@ -336,6 +920,299 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    fn scan_channels() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            // get_data_rate(): already Mbps2, so the scan leaves it untouched
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 8u8]),
+            // set_channel(76)
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): assert PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): close_rx_pipe(0)
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // rpd() sampled twice: 1 hit, then 1 miss
+            (vec![registers::RPD, 0], vec![0xEu8, 1]),
+            (vec![registers::RPD, 0], vec![0xEu8, 0]),
+            // flush_rx(): discard any stray payload that arrived during the dwell
+            (vec![commands::FLUSH_RX], vec![0xEu8]),
+            // restore CONFIG's PRIM_RX bit (radio was not RX before scanning)
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(radio.scan_channels(&[76u8], 2).unwrap(), [1u8]);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn scan_channel() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            // get_data_rate(): already Mbps2, so the scan leaves it untouched
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 8u8]),
+            // set_channel(76)
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): assert PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): close_rx_pipe(0)
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // rpd() sampled once: a hit
+            (vec![registers::RPD, 0], vec![0xEu8, 1]),
+            // flush_rx(): discard any stray payload that arrived during the dwell
+            (vec![commands::FLUSH_RX], vec![0xEu8]),
+            // restore CONFIG's PRIM_RX bit (radio was not RX before scanning)
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(radio.scan_channel(76u8, 1).unwrap(), 1u8);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn find_clear_channel() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            // get_data_rate(): already Mbps2, so the scan leaves it untouched
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 8u8]),
+            // set_channel(76): a noisy channel
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): assert PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): close_rx_pipe(0)
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // rpd() sampled once: a hit
+            (vec![registers::RPD, 0], vec![0xEu8, 1]),
+            // flush_rx(): discard any stray payload that arrived during the dwell
+            (vec![commands::FLUSH_RX], vec![0xEu8]),
+            // set_channel(1): a clear channel
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 1],
+                vec![0xEu8, 0],
+            ),
+            // rpd() sampled once: a miss
+            (vec![registers::RPD, 0], vec![0xEu8, 0]),
+            // flush_rx(): discard any stray payload that arrived during the dwell
+            (vec![commands::FLUSH_RX], vec![0xEu8]),
+            // restore CONFIG's PRIM_RX bit (radio was not RX before scanning)
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(radio.find_clear_channel(&[76u8, 1u8], 1).unwrap(), 1u8);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn get_config() {
+        // NOTE: since `self.buf` is reused as-is between SPI transactions (for full
+        // duplex transfers), each MOSI byte (after the command byte) is whatever was
+        // left over from the previous transaction's response, not necessarily 0.
+        let spi_expectations = spi_test_expects![
+            (vec![registers::CONFIG, 0], vec![0xEu8, 0xC]),
+            (vec![registers::RF_SETUP, 0xC], vec![0xEu8, 7]),
+            (vec![registers::SETUP_AW, 7], vec![0xEu8, 3]),
+            (vec![registers::SETUP_RETR, 3], vec![0xEu8, 0x5F]),
+            (vec![registers::RF_CH, 0x5F], vec![0xEu8, 76]),
+            (vec![registers::RX_PW_P0, 76], vec![0xEu8, 32]),
+            (vec![registers::EN_AA, 32], vec![0xEu8, 0x3F]),
+            (vec![registers::FEATURE, 0x3F], vec![0xEu8, 0]),
+            (
+                vec![registers::TX_ADDR, 0, 0, 0, 0, 0],
+                vec![0xEu8, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+            ),
+            (vec![registers::EN_RXADDR, 0xE7], vec![0xEu8, 2]),
+            (
+                vec![registers::RX_ADDR_P0, 2, 0xE7, 0xE7, 0xE7, 0xE7],
+                vec![0xEu8, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+            ),
+            (
+                vec![registers::RX_ADDR_P0 + 1, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+                vec![0xEu8, 0xC2, 0xC2, 0xC2, 0xC2, 0xC2],
+            ),
+            (vec![registers::RX_ADDR_P0 + 2, 0xC2], vec![0xEu8, 0xC3]),
+            (vec![registers::RX_ADDR_P0 + 3, 0xC3], vec![0xEu8, 0xC4]),
+            (vec![registers::RX_ADDR_P0 + 4, 0xC4], vec![0xEu8, 0xC5]),
+            (vec![registers::RX_ADDR_P0 + 5, 0xC5], vec![0xEu8, 0xC6]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let config = radio.get_config().unwrap();
+        assert_eq!(config.channel(), 76);
+        assert_eq!(config.payload_length(), 32);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn quietest_channel() {
+        let channels = [1u8, 2, 3];
+        let hits = [3u8, 0, 1];
+        assert_eq!(super::quietest_channel(&channels, &hits), Some(2));
+        // ties favor the lowest channel number
+        let hits = [0u8, 0, 1];
+        assert_eq!(super::quietest_channel(&channels, &hits), Some(1));
+        let empty: [u8; 0] = [];
+        assert_eq!(super::quietest_channel(&empty, &empty), None);
+    }
+
+    #[test]
+    fn scan_channels_with_early_exit() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            // get_data_rate(): already Mbps2, so the scan leaves it untouched
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 8u8]),
+            // set_channel(76)
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): assert PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): close_rx_pipe(0)
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // rpd() sampled once: a hit
+            (vec![registers::RPD, 0], vec![0xEu8, 1]),
+            // flush_rx(): discard any stray payload that arrived during the dwell
+            // (this runs before `per_channel` is consulted, so it happens even though
+            // the callback aborts the scan on this very channel)
+            (vec![commands::FLUSH_RX], vec![0xEu8]),
+            // restore CONFIG's PRIM_RX bit (radio was not RX before scanning)
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut seen = vec![];
+        let hits = radio
+            .scan_channels_with(&[76u8, 1u8], 1, |channel, hits| {
+                seen.push((channel, hits));
+                false // abort after the first channel
+            })
+            .unwrap();
+        assert_eq!(seen, vec![(76u8, 1u8)]);
+        // channel 1 was never sampled
+        assert_eq!(hits, [1u8, 0u8]);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn current_state() {
+        use crate::RadioState;
+
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        // new() leaves the radio powered down (see `config_reg` default)
+        assert_eq!(radio.current_state(), RadioState::PowerDown);
+
+        radio.config_reg = radio.config_reg.with_power(true);
+        assert_eq!(radio.current_state(), RadioState::StandbyI);
+
+        radio.ce_active = true;
+        assert_eq!(radio.current_state(), RadioState::TxMode);
+
+        radio.config_reg = radio.config_reg.as_rx();
+        assert_eq!(radio.current_state(), RadioState::RxMode);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn get_state_matches_current_state() {
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.config_reg = radio.config_reg.with_power(true);
+        assert_eq!(radio.get_state(), radio.current_state());
+        spi.done();
+        ce_pin.done();
+    }
+
     fn start_carrier_wave_parametrized(is_plus_variant: bool) {
         let mut ce_expectations = [
             PinTransaction::set(PinState::Low),
@@ -519,6 +1396,85 @@ mod test {
         stop_carrier_wave_parametrized(false);
     }
 
+    #[test]
+    fn start_const_carrier_delegates_to_start_carrier_wave() {
+        // same exchange as `start_carrier_wave_non_plus_variant()`, driven through the
+        // `start_const_carrier()` alias instead
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+        ];
+        let spi_expectations = spi_test_expects![
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![
+                    registers::RX_ADDR_P0 | commands::W_REGISTER,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7
+                ],
+                vec![0xEu8, 0, 0, 0, 0, 0]
+            ),
+            (vec![registers::EN_RXADDR, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 1],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 0x90],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 0x91]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 0x97],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 125],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio
+            .start_const_carrier(crate::PaLevel::Max, 0xFF)
+            .unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn stop_const_carrier_delegates_to_stop_carrier_wave() {
+        // same exchange as `stop_carrier_wave_non_plus_variant()`, driven through the
+        // `stop_const_carrier()` alias instead
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 0x90]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.stop_const_carrier().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     fn set_lna() {
         let spi_expectations = spi_test_expects![
@@ -537,6 +1493,60 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    fn get_lna() {
+        let spi_expectations = spi_test_expects![
+            // RF_SETUP with the LNA_CUR flag cleared
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 0]),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert!(!radio.get_lna().unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn read_register() {
+        let spi_expectations =
+            spi_test_expects![(vec![registers::RF_CH, 0, 0], vec![0xEu8, 76, 0],)];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut buf = [0u8; 2];
+        let status = radio.read_register(registers::RF_CH, &mut buf).unwrap();
+        assert_eq!(status, 0xEu8);
+        assert_eq!(buf, [76, 0]);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn write_register() {
+        let spi_expectations = spi_test_expects![(
+            vec![registers::RF_CH | commands::W_REGISTER, 76],
+            vec![0xEu8, 0],
+        )];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let status = radio.write_register(registers::RF_CH, &[76]).unwrap();
+        assert_eq!(status, 0xEu8);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn spi_command() {
+        let spi_expectations = spi_test_expects![(vec![commands::NOP], vec![0xEu8])];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut buf = [];
+        let status = radio.spi_command(commands::NOP, &mut buf).unwrap();
+        assert_eq!(status, 0xEu8);
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     fn mock_hw_errors() {
         let ce_expectations =
@@ -550,4 +1560,212 @@ mod test {
         spi.done();
         ce_pin.done();
     }
+
+    #[test]
+    fn send_with_irq() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+        ];
+        let irq_expectations = [PinTransaction::get(PinState::Low)];
+
+        let mut buf = [0u8; 33];
+        buf[0] = commands::W_TX_PAYLOAD;
+        buf[1..9].copy_from_slice(&[0x55; 8]);
+
+        let spi_expectations = spi_test_expects![
+            // flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // clear_status_flags()
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            // write payload
+            (buf.to_vec(), vec![0u8; 33]),
+            // wait_for_irq(): update()
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_TX_DS]),
+            // wait_for_irq(): clear_status_flags(StatusFlags::new())
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70u8],
+                vec![0xEu8, 0],
+            ),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut irq_pin = PinMock::new(&irq_expectations);
+        let payload = [0x55; 8];
+        assert!(radio.send_with_irq(&payload, false, &mut irq_pin).unwrap());
+        spi.done();
+        ce_pin.done();
+        irq_pin.done();
+    }
+
+    #[test]
+    fn resend_with_irq() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+        ];
+        let irq_expectations = [PinTransaction::get(PinState::Low)];
+        let spi_expectations = spi_test_expects![
+            // the TX FIFO is occupied
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 0u8]),
+            // clear the tx_df and tx_ds events
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0u8],
+            ),
+            // assert the REUSE_TX_PL flag
+            (vec![commands::REUSE_TX_PL], vec![0xEu8]),
+            // wait_for_irq(): update()
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_TX_DS]),
+            // wait_for_irq(): clear_status_flags(StatusFlags::new())
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70u8],
+                vec![0xEu8, 0],
+            ),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut irq_pin = PinMock::new(&irq_expectations);
+        assert!(radio.resend_with_irq(&mut irq_pin).unwrap());
+        spi.done();
+        ce_pin.done();
+        irq_pin.done();
+    }
+
+    #[test]
+    fn wait_for_irq_timeout_expires() {
+        // the irq_pin never asserts (stays high), so this should give up and return `None`
+        // without touching the SPI bus beyond the initial polling.
+        let irq_expectations = [
+            PinTransaction::get(PinState::High),
+            PinTransaction::get(PinState::High),
+            PinTransaction::get(PinState::High),
+        ];
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut irq_pin = PinMock::new(&irq_expectations);
+        assert_eq!(radio.wait_for_irq_timeout(&mut irq_pin, 2).unwrap(), None);
+        spi.done();
+        ce_pin.done();
+        irq_pin.done();
+    }
+
+    #[test]
+    fn send_with_retries_succeeds_after_retry() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+        ];
+
+        let mut buf = [0u8; 33];
+        buf[0] = commands::W_TX_PAYLOAD;
+        buf[1..9].copy_from_slice(&[0x55; 8]);
+
+        let spi_expectations = spi_test_expects![
+            // attempt 1: flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // attempt 1: clear_status_flags()
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            // attempt 1: write payload
+            (buf.to_vec(), vec![0u8; 33]),
+            // attempt 1: spoof a tx_df event from a NOP write
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_MAX_RT]),
+            // attempt 2: flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // attempt 2: clear_status_flags()
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            // attempt 2: write payload
+            (buf.to_vec(), vec![0u8; 33]),
+            // attempt 2: spoof a tx_ds event from a NOP write
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_TX_DS]),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let payload = [0x55; 8];
+        let outcome = radio.send_with_retries(&payload, false, 3, 0).unwrap();
+        assert!(outcome.acked);
+        assert_eq!(outcome.attempts, 2);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn send_with_retries_exhausts_attempts() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+        ];
+
+        let mut buf = [0u8; 33];
+        buf[0] = commands::W_TX_PAYLOAD;
+        buf[1..9].copy_from_slice(&[0x55; 8]);
+
+        let spi_expectations = spi_test_expects![
+            // attempt 1: flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // attempt 1: clear_status_flags()
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            // attempt 1: write payload
+            (buf.to_vec(), vec![0u8; 33]),
+            // attempt 1: spoof a tx_df event from a NOP write
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_MAX_RT]),
+            // attempt 2: flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // attempt 2: clear_status_flags()
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            // attempt 2: write payload
+            (buf.to_vec(), vec![0u8; 33]),
+            // attempt 2: spoof a tx_df event from a NOP write
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_MAX_RT]),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let payload = [0x55; 8];
+        let outcome = radio.send_with_retries(&payload, false, 2, 0).unwrap();
+        assert!(!outcome.acked);
+        assert_eq!(outcome.attempts, 2);
+        spi.done();
+        ce_pin.done();
+    }
 }
@@ -12,14 +12,21 @@ where
 {
     type PaLevelErrorType = Nrf24Error<SPI::Error, DO::Error>;
 
-    fn get_pa_level(&mut self) -> Result<PaLevel, Self::PaLevelErrorType> {
+    fn get_pa_level_lna(&mut self) -> Result<(PaLevel, bool), Self::PaLevelErrorType> {
         self.spi_read(1, registers::RF_SETUP)?;
-        Ok(PaLevel::from_bits(self._buf[1] & PaLevel::MASK))
+        Ok((
+            PaLevel::from_bits(self.buf[1] & PaLevel::MASK),
+            self.buf[1] & 1 == 1,
+        ))
     }
 
-    fn set_pa_level(&mut self, pa_level: PaLevel) -> Result<(), Self::PaLevelErrorType> {
+    fn set_pa_level_lna(
+        &mut self,
+        pa_level: PaLevel,
+        lna_enable: bool,
+    ) -> Result<(), Self::PaLevelErrorType> {
         self.spi_read(1, registers::RF_SETUP)?;
-        let out = self._buf[1] & !PaLevel::MASK | pa_level.into_bits();
+        let out = self.buf[1] & !PaLevel::MASK & !1 | pa_level.into_bits() | lna_enable as u8;
         self.spi_write_byte(registers::RF_SETUP, out)
     }
 }
@@ -57,6 +64,7 @@ mod test {
     pub fn set_pa_level() {
         let spi_expectations = spi_test_expects![
             // set the RF_SETUP register value for each possible enumeration of CrcLength
+            // (the LNA gain bit is always asserted, since `set_pa_level()` defaults it on)
             (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 7u8]),
             (
                 vec![registers::RF_SETUP | commands::W_REGISTER, 1u8],
@@ -74,7 +82,7 @@ mod test {
             ),
             (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 0u8]),
             (
-                vec![registers::RF_SETUP | commands::W_REGISTER, 6u8],
+                vec![registers::RF_SETUP | commands::W_REGISTER, 7u8],
                 vec![0xEu8, 0u8],
             ),
         ];
@@ -87,4 +95,35 @@ mod test {
         spi.done();
         ce_pin.done();
     }
+
+    #[test]
+    pub fn get_pa_level_lna() {
+        let spi_expectations = spi_test_expects![
+            // RF_SETUP: PaLevel::Low bits with the LNA gain bit cleared (Si24R1 clone)
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 2u8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(radio.get_pa_level_lna(), Ok((PaLevel::Low, false)));
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn set_pa_level_lna() {
+        let spi_expectations = spi_test_expects![
+            // RF_SETUP currently has every bit set; disabling the LNA gain bit should
+            // only clear bit 0 (alongside the PA bits being overwritten)
+            (vec![registers::RF_SETUP, 0u8], vec![0xEu8, 0xFFu8]),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 0xFCu8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_pa_level_lna(PaLevel::High, false).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
 }
@@ -43,10 +43,51 @@ where
     fn get_dynamic_payload_length(&mut self) -> Result<u8, Self::Error> {
         self.spi_read(1, commands::R_RX_PL_WID)?;
         if self.buf[1] > 32 {
-            return Err(Nrf24Error::BinaryCorruption);
+            return Err(Nrf24Error::InvalidPayloadWidth(self.buf[1]));
         }
         Ok(self.buf[1])
     }
+
+    fn set_dynamic_payload_pipe(&mut self, enable: bool, pipe: u8) -> Result<(), Self::Error> {
+        if pipe > 5 {
+            return Ok(());
+        }
+        self.spi_read(1, registers::DYNPD)?;
+        let mask = 1 << pipe;
+        let dynpd = self.buf[1] & !mask | (mask * enable as u8);
+        self.spi_write_byte(registers::DYNPD, dynpd)?;
+
+        let any_enabled = dynpd != 0;
+        if self.feature.dynamic_payloads() != any_enabled {
+            self.spi_read(1, registers::FEATURE)?;
+            self.feature =
+                Feature::from_bits(self.feature.into_bits() & !Feature::REG_MASK | self.buf[1])
+                    .with_dynamic_payloads(any_enabled);
+            self.spi_write_byte(
+                registers::FEATURE,
+                self.feature.into_bits() & Feature::REG_MASK,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn set_dynamic_payloads_bin(&mut self, mask: u8) -> Result<(), Self::Error> {
+        let mask = mask & 0x3F;
+        self.spi_write_byte(registers::DYNPD, mask)?;
+
+        let any_enabled = mask != 0;
+        if self.feature.dynamic_payloads() != any_enabled {
+            self.spi_read(1, registers::FEATURE)?;
+            self.feature =
+                Feature::from_bits(self.feature.into_bits() & !Feature::REG_MASK | self.buf[1])
+                    .with_dynamic_payloads(any_enabled);
+            self.spi_write_byte(
+                registers::FEATURE,
+                self.feature.into_bits() & Feature::REG_MASK,
+            )?;
+        }
+        Ok(())
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////////
@@ -100,7 +141,7 @@ mod test {
         assert!(radio.get_dynamic_payloads());
         assert_eq!(
             radio.get_dynamic_payload_length(),
-            Err(Nrf24Error::BinaryCorruption)
+            Err(Nrf24Error::InvalidPayloadWidth(0xFF))
         );
         assert_eq!(radio.get_dynamic_payload_length().unwrap(), 32);
         radio.set_dynamic_payloads(false).unwrap();
@@ -110,6 +151,96 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    fn dynamic_payload_pipe() {
+        let spi_expectations = spi_test_expects![
+            // set_dynamic_payload_pipe(true, 1): DYNPD was 0, becomes bit 1 set
+            (vec![registers::DYNPD, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 1 << 1],
+                vec![0xEu8, 0],
+            ),
+            // any_enabled flips false -> true, so EN_DPL is asserted
+            (vec![registers::FEATURE, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::FEATURE | commands::W_REGISTER, EN_DPL],
+                vec![0xEu8, 0],
+            ),
+            // set_dynamic_payload_pipe(false, 1): DYNPD was only bit 1, now clears to 0
+            (vec![registers::DYNPD, 0], vec![0xEu8, 1 << 1]),
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // any_enabled flips true -> false, so EN_DPL is cleared
+            (vec![registers::FEATURE, 0], vec![0xEu8, EN_DPL]),
+            (
+                vec![registers::FEATURE | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // out-of-range pipe is a no-op (no further SPI transactions expected)
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_dynamic_payload_pipe(true, 1).unwrap();
+        assert!(radio.get_dynamic_payloads());
+        radio.set_dynamic_payload_pipe(false, 1).unwrap();
+        assert!(!radio.get_dynamic_payloads());
+        radio.set_dynamic_payload_pipe(true, 6).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn dynamic_payloads_bin() {
+        let spi_expectations = spi_test_expects![
+            // set_dynamic_payloads_bin(0x05): pipes 0 and 2 enabled in one write
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0x05],
+                vec![0xEu8, 0],
+            ),
+            // any_enabled flips false -> true, so EN_DPL is asserted
+            (vec![registers::FEATURE, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::FEATURE | commands::W_REGISTER, EN_DPL],
+                vec![0xEu8, 0],
+            ),
+            // set_dynamic_payloads_bin(0): all pipes disabled in one write
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // any_enabled flips true -> false, so EN_DPL is cleared
+            (vec![registers::FEATURE, 0], vec![0xEu8, EN_DPL]),
+            (
+                vec![registers::FEATURE | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // upper 2 bits of the mask are ignored
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0x3F],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::FEATURE, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::FEATURE | commands::W_REGISTER, EN_DPL],
+                vec![0xEu8, 0],
+            ),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_dynamic_payloads_bin(0x05).unwrap();
+        assert!(radio.get_dynamic_payloads());
+        radio.set_dynamic_payloads_bin(0).unwrap();
+        assert!(!radio.get_dynamic_payloads());
+        radio.set_dynamic_payloads_bin(0xFF).unwrap();
+        assert!(radio.get_dynamic_payloads());
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     pub fn set_payload_length() {
         let mut spi_expectations = vec![];
@@ -39,9 +39,15 @@ where
             self.spi_write_byte(registers::RX_ADDR_P0 + pipe, address[0])?;
         }
 
-        self.spi_read(1, registers::EN_RXADDR)?;
-        let out = self.buf[1] | (1 << pipe);
-        self.spi_write_byte(registers::EN_RXADDR, out)
+        self.update_register(registers::EN_RXADDR, |old| old | (1 << pipe))
+    }
+
+    fn open_tx_pipe(&mut self, address: &[u8]) -> Result<(), Self::Error> {
+        let width = address.len().min(self.feature.address_length() as usize);
+        // also set pipe 0's RX address (for auto-ack) since pipe 0 is the only
+        // pipe that can transmit; as_rx() restores any cached pipe 0 RX address.
+        self.spi_write_buf(registers::RX_ADDR_P0, &address[..width])?;
+        self.spi_write_buf(registers::TX_ADDR, &address[..width])
     }
 
     /// If the given `pipe` number is  not in range [0, 5], then this function does nothing.
@@ -49,9 +55,7 @@ where
         if pipe > 5 {
             return Ok(());
         }
-        self.spi_read(1, registers::EN_RXADDR)?;
-        let out = self.buf[1] & !(1 << pipe);
-        self.spi_write_byte(registers::EN_RXADDR, out)?;
+        self.update_register(registers::EN_RXADDR, |old| old & !(1 << pipe))?;
         if pipe == 0 {
             self.pipe0_rx_addr = None;
         }
@@ -153,6 +157,42 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    pub fn open_tx_pipe() {
+        let spi_expectations = spi_test_expects![
+            // open_tx_pipe(): set pipe 0's RX address
+            (
+                vec![
+                    registers::RX_ADDR_P0 | commands::W_REGISTER,
+                    0x55,
+                    0x55,
+                    0x55,
+                    0x55,
+                    0x55
+                ],
+                vec![0xEu8, 0, 0, 0, 0, 0],
+            ),
+            // open_tx_pipe(): set the TX_ADDR register
+            (
+                vec![
+                    registers::TX_ADDR | commands::W_REGISTER,
+                    0x55,
+                    0x55,
+                    0x55,
+                    0x55,
+                    0x55
+                ],
+                vec![0xEu8, 0, 0, 0, 0, 0],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let address = [0x55u8; 5];
+        radio.open_tx_pipe(&address).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     pub fn set_address_length() {
         let spi_expectations = spi_test_expects![
@@ -4,10 +4,17 @@ use embedded_hal::{
     spi::SpiDevice,
 };
 
-use crate::radio::{prelude::EsbPower, RF24};
+use crate::{
+    radio::{prelude::EsbPower, RF24},
+    types::FallbackMode,
+};
 
 use super::registers;
 
+/// The Tpd2stby settling delay (in microseconds) that the datasheet specifies between
+/// leaving power-down mode and the radio being ready for CE to go high.
+const TPD2STBY_US: u32 = 5000;
+
 impl<SPI, DO, DELAY> EsbPower for RF24<SPI, DO, DELAY>
 where
     SPI: SpiDevice,
@@ -25,6 +32,7 @@ where
     /// 900nA (.0009mA).
     fn power_down(&mut self) -> Result<(), Self::Error> {
         self.ce_pin.set_low().map_err(|e| e.kind())?; // Guarantee CE is low on powerDown
+        self.ce_active = false;
         self.config_reg = self.config_reg.with_power(false);
         self.spi_write_byte(registers::CONFIG, self.config_reg.into_bits())?;
         Ok(())
@@ -35,27 +43,61 @@ where
         if self.config_reg.power() {
             return Ok(());
         }
-        self.config_reg = self.config_reg.with_power(true);
-        self.spi_write_byte(registers::CONFIG, self.config_reg.into_bits())?;
+        self.begin_power_up()?;
 
         // For nRF24L01+ to go from power down mode to TX or RX mode it must first pass through stand-by mode.
         // There must be a delay of Tpd2standby (see Table 16.) after the nRF24L01+ leaves power down mode before
         // the CE is set high. Tpd2standby can be up to 5ms per the 1.0 datasheet
-        match delay {
-            Some(d) => {
-                if d > 0 {
-                    self.delay_impl.delay_us(d);
-                }
-            }
-            None => self.delay_impl.delay_us(5000),
+        let delay_us = match delay {
+            Some(d) => d,
+            None => TPD2STBY_US,
+        };
+        if delay_us > 0 {
+            self.delay_impl.delay_us(delay_us);
+        }
+        self.power_up_settle_us = 0;
+        Ok(())
+    }
+
+    fn begin_power_up(&mut self) -> Result<(), Self::Error> {
+        if self.config_reg.power() {
+            return Ok(());
         }
+        self.config_reg = self.config_reg.with_power(true);
+        self.spi_write_byte(registers::CONFIG, self.config_reg.into_bits())?;
+        self.power_up_settle_us = TPD2STBY_US;
         Ok(())
     }
 
+    fn power_up_ready(&mut self, elapsed_us: u32) -> bool {
+        self.power_up_settle_us = self.power_up_settle_us.saturating_sub(elapsed_us);
+        self.power_up_settle_us == 0
+    }
+
     /// Is the radio powered up?
     fn is_powered(&self) -> bool {
         self.config_reg.power()
     }
+
+    fn as_standby_i(&mut self) -> Result<(), Self::Error> {
+        self.ce_pin.set_low().map_err(|e| e.kind())?;
+        self.ce_active = false;
+        Ok(())
+    }
+
+    fn as_standby_ii(&mut self) -> Result<(), Self::Error> {
+        self.ce_pin.set_high().map_err(|e| e.kind())?;
+        self.ce_active = true;
+        Ok(())
+    }
+
+    fn set_fallback_mode(&mut self, mode: FallbackMode) {
+        self.fallback_mode = mode;
+    }
+
+    fn get_fallback_mode(&self) -> FallbackMode {
+        self.fallback_mode
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////////
@@ -64,8 +106,11 @@ where
 mod test {
     extern crate std;
     use super::{registers, EsbPower};
-    use crate::{radio::rf24::commands, spi_test_expects, test::mk_radio};
-    use embedded_hal_mock::eh1::spi::Transaction as SpiTransaction;
+    use crate::{radio::rf24::commands, spi_test_expects, test::mk_radio, types::FallbackMode};
+    use embedded_hal_mock::eh1::{
+        digital::{State as PinState, Transaction as PinTransaction},
+        spi::Transaction as SpiTransaction,
+    };
     use std::vec;
 
     #[test]
@@ -118,6 +163,29 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    pub fn begin_power_up_polled_ready() {
+        let spi_expectations = spi_test_expects![
+            // write the CONFIG register with the PWR_UP bit asserted
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xEu8],
+                vec![0xEu8, 0u8],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.begin_power_up().unwrap();
+        assert!(radio.is_powered());
+        assert!(!radio.power_up_ready(1000));
+        assert!(!radio.power_up_ready(3999));
+        assert!(radio.power_up_ready(1));
+        // a subsequent poll (or `begin_power_up()` on an already-powered radio)
+        // is a no-op and stays ready
+        assert!(radio.power_up_ready(0));
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     pub fn power_getter() {
         let mocks = mk_radio(&[], &[]);
@@ -127,4 +195,43 @@ mod test {
         spi.done();
         ce_pin.done();
     }
+
+    #[test]
+    pub fn standby_i() {
+        let ce_expectations = [PinTransaction::set(PinState::Low)];
+        let mocks = mk_radio(&ce_expectations, &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.as_standby_i().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn standby_ii() {
+        let ce_expectations = [PinTransaction::set(PinState::High)];
+        let mocks = mk_radio(&ce_expectations, &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.as_standby_ii().unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn fallback_mode_default_is_standby_i() {
+        let mocks = mk_radio(&[], &[]);
+        let (radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(radio.get_fallback_mode(), FallbackMode::StandbyI);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn fallback_mode_setter() {
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_fallback_mode(FallbackMode::StandbyII);
+        assert_eq!(radio.get_fallback_mode(), FallbackMode::StandbyII);
+        spi.done();
+        ce_pin.done();
+    }
 }
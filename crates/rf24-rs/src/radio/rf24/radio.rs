@@ -1,7 +1,9 @@
+use core::time::Duration;
+
 use super::{commands, mnemonics, registers, RF24};
 use crate::{
     radio::prelude::{EsbFifo, EsbPayloadLength, EsbPipe, EsbRadio, EsbStatus},
-    StatusFlags,
+    FallbackMode, FifoState, StatusFlags,
 };
 use embedded_hal::{
     delay::DelayNs,
@@ -20,6 +22,7 @@ where
         self.spi_write_byte(registers::CONFIG, self.config_reg.into_bits())?;
         self.clear_status_flags(StatusFlags::new())?;
         self.ce_pin.set_high().map_err(|e| e.kind())?;
+        self.ce_active = true;
 
         // Restore the pipe0 address, if exists
         if let Some(addr) = self.pipe0_rx_addr {
@@ -35,6 +38,7 @@ where
 
     fn as_tx(&mut self, tx_address: Option<&[u8]>) -> Result<(), Self::Error> {
         self.ce_pin.set_low().map_err(|e| e.kind())?;
+        self.ce_active = false;
 
         self.delay_impl.delay_us(self.tx_delay);
         if self.feature.ack_payloads() {
@@ -71,10 +75,12 @@ where
 
     /// See [`EsbRadio::send()`] for implementation-agnostic detail.
     ///
-    /// This function calls [`RF24::flush_tx()`] upon entry, but it does not
-    /// deactivate the radio's CE pin upon exit.
+    /// This function calls [`RF24::flush_tx()`] upon entry. Upon exit, the radio's
+    /// CE pin is settled according to [`EsbPower::get_fallback_mode()`](fn@crate::radio::prelude::EsbPower::get_fallback_mode)
+    /// (defaulting to [`FallbackMode::StandbyI`], which deactivates the CE pin).
     fn send(&mut self, buf: &[u8], ask_no_ack: bool) -> Result<bool, Self::Error> {
         self.ce_pin.set_low().map_err(|e| e.kind())?;
+        self.ce_active = false;
         // this function only handles 1 payload at a time
         self.flush_tx()?; // flush the TX FIFO to ensure we are sending the given buf
         if !self.write(buf, ask_no_ack, true)? {
@@ -86,6 +92,10 @@ where
         while self.status.into_bits() & (mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS) == 0 {
             self.spi_read(0, commands::NOP)?;
         }
+        if self.fallback_mode == FallbackMode::StandbyI {
+            self.ce_pin.set_low().map_err(|e| e.kind())?;
+            self.ce_active = false;
+        }
         Ok(self.status.tx_ds())
     }
 
@@ -125,6 +135,7 @@ where
         }
         if start_tx {
             self.ce_pin.set_high().map_err(|e| e.kind())?;
+            self.ce_active = true;
         }
         Ok(!self.status.tx_full())
     }
@@ -164,7 +175,56 @@ where
         Ok(buf_len)
     }
 
-    fn resend(&mut self) -> Result<bool, Self::Error> {
+    fn read_with_pipe(&mut self, buf: &mut [u8], len: Option<u8>) -> Result<(u8, u8), Self::Error> {
+        let buf_len =
+            (buf.len().min(32) as u8).min(len.unwrap_or(if self.feature.dynamic_payloads() {
+                self.get_dynamic_payload_length()?
+            } else {
+                self.payload_length
+            }));
+        if buf_len == 0 {
+            return Ok((0, self.status.pipe()));
+        }
+        self.spi_read(buf_len, commands::R_RX_PAYLOAD)?;
+        // capture the pipe number from this same transaction, before clear_status_flags()
+        // overwrites `self.status` with the STATUS byte from its own transaction
+        let pipe = self.status.pipe();
+        buf[0..buf_len as usize].copy_from_slice(&self.buf[1..buf_len as usize + 1]);
+        let flags = StatusFlags::from_bits(mnemonics::MASK_RX_DR);
+        self.clear_status_flags(flags)?;
+        Ok((buf_len, pipe))
+    }
+
+    fn read_all(&mut self, buf: &mut [u8], lengths: &mut [u8]) -> Result<usize, Self::Error> {
+        let mut drained = 0usize;
+        let mut offset = 0usize;
+        while self.available()? && drained < lengths.len() {
+            let len = if self.feature.dynamic_payloads() {
+                self.get_dynamic_payload_length()?
+            } else {
+                self.payload_length
+            };
+            let buf_len = (buf.len() - offset).min(32).min(len as usize);
+            if buf_len == 0 {
+                break;
+            }
+            self.spi_read(buf_len as u8, commands::R_RX_PAYLOAD)?;
+            buf[offset..offset + buf_len].copy_from_slice(&self.buf[1..buf_len + 1]);
+            lengths[drained] = buf_len as u8;
+            offset += buf_len;
+            drained += 1;
+        }
+        let flags = StatusFlags::from_bits(mnemonics::MASK_RX_DR);
+        self.clear_status_flags(flags)?;
+        Ok(drained)
+    }
+
+    /// See [`EsbRadio::resend()`] for implementation-agnostic detail.
+    ///
+    /// Like [`RF24::send()`](fn@super::RF24::send), this settles the radio's CE pin
+    /// according to [`EsbPower::get_fallback_mode()`](fn@crate::radio::prelude::EsbPower::get_fallback_mode)
+    /// once the retransmission completes.
+    fn resend(&mut self, send_only: bool) -> Result<bool, Self::Error> {
         if self.is_rx() {
             // if in RX  mode, prevent infinite loop below
             return Ok(false);
@@ -175,22 +235,93 @@ where
         while self.status.into_bits() & 0x30 == 0 {
             self.spi_read(0, commands::NOP)?;
         }
-        Ok(self.status.tx_ds())
+        if self.fallback_mode == FallbackMode::StandbyI {
+            self.ce_pin.set_low().map_err(|e| e.kind())?;
+            self.ce_active = false;
+        }
+        let acked = self.status.tx_ds();
+        if acked && !send_only {
+            // discard any ACK payload riding back on the reused packet instead of
+            // leaving it in the RX FIFO for the caller to stumble upon later
+            self.flush_rx()?;
+        }
+        Ok(acked)
     }
 
-    fn rewrite(&mut self) -> Result<(), Self::Error> {
+    fn rewrite(&mut self) -> Result<bool, Self::Error> {
+        if self.get_fifo_state(true)? == FifoState::Empty {
+            return Ok(false);
+        }
         self.ce_pin.set_low().map_err(|e| e.kind())?;
+        self.ce_active = false;
         let flags = StatusFlags::from_bits(mnemonics::MASK_TX_DS | mnemonics::MASK_MAX_RT);
         self.clear_status_flags(flags)?;
         self.spi_read(0, commands::REUSE_TX_PL)?;
         self.ce_pin.set_high().map_err(|e| e.kind())?;
-        Ok(())
+        self.ce_active = true;
+        Ok(true)
+    }
+
+    fn write_blocking(&mut self, buf: &[u8], timeout: Duration) -> Result<bool, Self::Error> {
+        const POLL_INTERVAL_US: u32 = 100;
+        let timeout_us = timeout.as_micros().min(u128::from(u32::MAX)) as u32;
+        let mut elapsed_us = 0u32;
+        loop {
+            if self.get_fifo_state(true)? != FifoState::Full {
+                return self.write(buf, false, true);
+            }
+            self.spi_read(0, commands::NOP)?;
+            if self.status.into_bits() & mnemonics::MASK_MAX_RT != 0 {
+                self.clear_status_flags(StatusFlags::from_bits(mnemonics::MASK_MAX_RT))?;
+                self.rewrite()?;
+            }
+            if elapsed_us >= timeout_us {
+                return Ok(false);
+            }
+            self.delay_impl.delay_us(POLL_INTERVAL_US);
+            elapsed_us = elapsed_us.saturating_add(POLL_INTERVAL_US);
+        }
     }
 
     fn get_last_arc(&mut self) -> Result<u8, Self::Error> {
         self.spi_read(1, registers::OBSERVE_TX)?;
         Ok(self.buf[1] & 0xF)
     }
+
+    fn get_lost_packets(&mut self) -> Result<u8, Self::Error> {
+        self.spi_read(1, registers::OBSERVE_TX)?;
+        Ok(self.buf[1] >> 4)
+    }
+
+    fn send_stream(&mut self, payloads: &[&[u8]], ask_no_ack: bool) -> Result<usize, Self::Error> {
+        if payloads.is_empty() {
+            return Ok(0);
+        }
+        self.flush_tx()?;
+        let mut queued = 0usize;
+        while queued < payloads.len() && queued < 3 {
+            if !self.write(payloads[queued], ask_no_ack, true)? {
+                break;
+            }
+            queued += 1;
+        }
+        let mut acked = 0usize;
+        while acked < queued {
+            self.spi_read(0, commands::NOP)?;
+            if self.status.into_bits() & mnemonics::MASK_MAX_RT != 0 {
+                self.clear_status_flags(StatusFlags::from_bits(mnemonics::MASK_MAX_RT))?;
+                return Ok(acked);
+            }
+            if self.status.tx_ds() {
+                self.clear_status_flags(StatusFlags::from_bits(mnemonics::MASK_TX_DS))?;
+                acked += 1;
+                if queued < payloads.len() && self.write(payloads[queued], ask_no_ack, true)? {
+                    queued += 1;
+                }
+            }
+        }
+        Ok(acked)
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////////
@@ -199,7 +330,7 @@ where
 mod test {
     extern crate std;
     use super::{commands, mnemonics, registers, EsbPipe, EsbRadio};
-    use crate::{spi_test_expects, test::mk_radio};
+    use crate::{radio::prelude::EsbPower, spi_test_expects, test::mk_radio, FallbackMode};
     use embedded_hal_mock::eh1::{
         digital::{State as PinState, Transaction as PinTransaction},
         spi::Transaction as SpiTransaction,
@@ -366,6 +497,9 @@ mod test {
         let ce_expectations = [
             PinTransaction::set(PinState::Low),
             PinTransaction::set(PinState::High),
+            // fallback mode defaults to `FallbackMode::StandbyI`, so CE is deactivated
+            // again once the transmission completes
+            PinTransaction::set(PinState::Low),
             PinTransaction::set(PinState::Low),
             PinTransaction::set(PinState::High),
             PinTransaction::set(PinState::Low),
@@ -419,6 +553,44 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    fn send_fallback_standby_ii() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+            // fallback mode is `FallbackMode::StandbyII`, so CE remains active
+        ];
+
+        let mut buf = [0u8; 33];
+        buf[0] = commands::W_TX_PAYLOAD;
+        buf[1..9].copy_from_slice(&[0x55; 8]);
+
+        let spi_expectations = spi_test_expects![
+            // flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // clear_status_flags()
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            // write payload
+            (buf.to_vec(), vec![0u8; 33]),
+            // spoof a tx_ds event from a NOP write
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_TX_DS]),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_fallback_mode(FallbackMode::StandbyII);
+        let payload = [0x55; 8];
+        assert!(radio.send(&payload, false).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     fn ask_no_ack() {
         let mut buf = [0u8; 33];
@@ -507,13 +679,88 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    fn read_with_pipe() {
+        let mut buf_payload = [0u8; 5];
+        buf_payload[0] = commands::R_RX_PAYLOAD;
+
+        let spi_expectations = spi_test_expects![
+            // read RX payload; the response's STATUS byte reports pipe 3
+            (buf_payload.to_vec(), vec![0x46u8, 0x11, 0x22, 0x33, 0x44]),
+            // clear the rx_dr event
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_RX_DR,
+                ],
+                vec![0xEu8, 0],
+            ),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut payload = [0u8; 4];
+        assert_eq!(radio.read_with_pipe(&mut payload, Some(4)).unwrap(), (4, 3));
+        assert_eq!(payload, [0x11, 0x22, 0x33, 0x44]);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn read_all() {
+        let mut buf_payload = [0u8; 33];
+        buf_payload[0] = commands::R_RX_PAYLOAD;
+        // `self.buf` is reused as-is, so the 2nd read shifts out the 1st payload's
+        // leftover bytes (except byte 1, which the FIFO_STATUS response overwrote)
+        let mut buf_payload2 = [0x55u8; 33];
+        buf_payload2[0] = commands::R_RX_PAYLOAD;
+        buf_payload2[1] = 0;
+
+        let spi_expectations = spi_test_expects![
+            // available(): RX FIFO occupied
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 0u8]),
+            // read 1st payload
+            (buf_payload.to_vec(), vec![0x55u8; 33]),
+            // available(): RX FIFO still occupied
+            (vec![registers::FIFO_STATUS, 0x55u8], vec![0xEu8, 0u8]),
+            // read 2nd payload
+            (buf_payload2.to_vec(), vec![0xAAu8; 33]),
+            // available(): RX FIFO now empty
+            (vec![registers::FIFO_STATUS, 0xAAu8], vec![0xEu8, 1u8]),
+            // clear the rx_dr event (once, for the whole drain)
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_RX_DR,
+                ],
+                vec![0xEu8, 0],
+            ),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut buf = [0u8; 64];
+        let mut lengths = [0u8; 2];
+        assert_eq!(radio.read_all(&mut buf, &mut lengths).unwrap(), 2);
+        assert_eq!(lengths, [32, 32]);
+        assert_eq!(&buf[0..32], &[0x55; 32]);
+        assert_eq!(&buf[32..64], &[0xAA; 32]);
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     fn resend() {
         let ce_expectations = [
             PinTransaction::set(PinState::Low),
             PinTransaction::set(PinState::High),
+            // fallback mode defaults to `FallbackMode::StandbyI`, so CE is deactivated
+            // again once the retransmission completes
+            PinTransaction::set(PinState::Low),
         ];
         let spi_expectations = spi_test_expects![
+            // the TX FIFO is occupied
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 0u8]),
             // clear the tx_df and tx_ds events
             (
                 vec![
@@ -530,9 +777,271 @@ mod test {
 
         let mocks = mk_radio(&ce_expectations, &spi_expectations);
         let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
-        assert!(radio.resend().unwrap());
+        assert!(radio.resend(true).unwrap());
         radio.config_reg = radio.config_reg.as_rx(); // simulate RX mode
-        assert!(!radio.resend().unwrap());
+        assert!(!radio.resend(true).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn resend_flushes_rx_fifo_unless_send_only() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+            // fallback mode defaults to `FallbackMode::StandbyI`, so CE is deactivated
+            // again once the retransmission completes
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            // the TX FIFO is occupied
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 0u8]),
+            // clear the tx_df and tx_ds events
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0u8],
+            ),
+            // assert the REUSE_TX_PL flag
+            (vec![commands::REUSE_TX_PL], vec![0xEu8]),
+            // spoof a tx_ds event from a NOP write
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_TX_DS]),
+            // send_only is false, so the RX FIFO is flushed after the successful resend
+            (vec![commands::FLUSH_RX], vec![0xEu8]),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert!(radio.resend(false).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn rewrite_empty_fifo() {
+        let spi_expectations = spi_test_expects![
+            // the TX FIFO is empty, so rewrite() should no-op
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 1u8]),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert!(!radio.rewrite().unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn send_stream() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::High),
+        ];
+
+        let mut buf0 = [0u8; 33];
+        buf0[0] = commands::W_TX_PAYLOAD;
+        buf0[1..5].copy_from_slice(&[0x11; 4]);
+        let mut buf1 = [0u8; 33];
+        buf1[0] = commands::W_TX_PAYLOAD;
+        buf1[1..5].copy_from_slice(&[0x22; 4]);
+
+        let spi_expectations = spi_test_expects![
+            // flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // write(payload0)
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            (buf0.to_vec(), vec![0u8; 33]),
+            // write(payload1)
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            (buf1.to_vec(), vec![0u8; 33]),
+            // payload0's tx_ds event
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_TX_DS]),
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_TX_DS
+                ],
+                vec![0xEu8, 0],
+            ),
+            // payload1's tx_ds event
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_TX_DS]),
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_TX_DS
+                ],
+                vec![0xEu8, 0],
+            ),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let payload0 = [0x11; 4];
+        let payload1 = [0x22; 4];
+        assert_eq!(
+            radio.send_stream(&[&payload0, &payload1], false).unwrap(),
+            2
+        );
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn send_stream_aborts_on_max_rt() {
+        let ce_expectations = [PinTransaction::set(PinState::High)];
+        let mut buf0 = [0u8; 33];
+        buf0[0] = commands::W_TX_PAYLOAD;
+        buf0[1..5].copy_from_slice(&[0x11; 4]);
+
+        let spi_expectations = spi_test_expects![
+            // flush_tx()
+            (vec![commands::FLUSH_TX], vec![0xEu8]),
+            // write(payload0)
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            (buf0.to_vec(), vec![0u8; 33]),
+            // the auto-retry limit was reached
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_MAX_RT]),
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT
+                ],
+                vec![0xEu8, 0],
+            ),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let payload0 = [0x11; 4];
+        assert_eq!(radio.send_stream(&[&payload0], false).unwrap(), 0);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn write_blocking_writes_immediately_when_fifo_not_full() {
+        let ce_expectations = [PinTransaction::set(PinState::High)];
+
+        let mut buf = [0u8; 33];
+        buf[0] = commands::W_TX_PAYLOAD;
+        buf[1..5].copy_from_slice(&[0x11; 4]);
+
+        let spi_expectations = spi_test_expects![
+            // the TX FIFO is not full, so write_blocking() writes immediately
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 0]),
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            (buf.to_vec(), vec![0u8; 33]),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let payload = [0x11; 4];
+        assert!(radio
+            .write_blocking(&payload, core::time::Duration::from_millis(10))
+            .unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn write_blocking_rewrites_on_max_rt_then_succeeds() {
+        let ce_expectations = [
+            // rewrite()'s reuse of the stuck payload
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+            // write()'s start_tx
+            PinTransaction::set(PinState::High),
+        ];
+
+        let mut buf = [0u8; 33];
+        buf[0] = commands::W_TX_PAYLOAD;
+        buf[1..5].copy_from_slice(&[0x22; 4]);
+
+        let spi_expectations = spi_test_expects![
+            // the TX FIFO is full
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 0x20]),
+            // the auto-retry limit was reached for the stuck payload
+            (vec![commands::NOP], vec![0xE | mnemonics::MASK_MAX_RT]),
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT
+                ],
+                vec![0xEu8, 0],
+            ),
+            // rewrite() reuses the stuck payload and keeps CE asserted
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 0x20]),
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_TX_DS | mnemonics::MASK_MAX_RT,
+                ],
+                vec![0xEu8, 0],
+            ),
+            (vec![commands::REUSE_TX_PL], vec![0xEu8]),
+            // the FIFO has a free slot now, so the new payload is written
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 0]),
+            (
+                vec![
+                    registers::STATUS | commands::W_REGISTER,
+                    mnemonics::MASK_MAX_RT | mnemonics::MASK_TX_DS,
+                ],
+                vec![0xEu8, 0],
+            ),
+            (buf.to_vec(), vec![0u8; 33]),
+        ];
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let payload = [0x22; 4];
+        assert!(radio
+            .write_blocking(&payload, core::time::Duration::from_millis(10))
+            .unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn write_blocking_times_out_while_fifo_stays_full() {
+        let spi_expectations = spi_test_expects![
+            // the TX FIFO is full and stays that way
+            (vec![registers::FIFO_STATUS, 0], vec![0xEu8, 0x20]),
+            // no MAX_RT event occurs, so there's nothing to rewrite
+            (vec![commands::NOP], vec![0xEu8]),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let payload = [0x33; 4];
+        assert!(!radio
+            .write_blocking(&payload, core::time::Duration::ZERO)
+            .unwrap());
         spi.done();
         ce_pin.done();
     }
@@ -550,4 +1059,18 @@ mod test {
         spi.done();
         ce_pin.done();
     }
+
+    #[test]
+    fn get_lost_packets() {
+        let spi_expectations = spi_test_expects![
+            // get the PLOS value from OBSERVE_TX register
+            (vec![registers::OBSERVE_TX, 0], vec![0xEu8, 0xF0]),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(radio.get_lost_packets().unwrap(), 15);
+        spi.done();
+        ce_pin.done();
+    }
 }
@@ -0,0 +1,367 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::RF24;
+use crate::radio::{
+    prelude::{EsbAutoAck, EsbFifo, EsbRadio, EsbStatus},
+    Nrf24Error,
+};
+use crate::StatusFlags;
+
+/// One payload queued for transmission, or received from the RX FIFO.
+struct Frame {
+    buf: [u8; 32],
+    len: u8,
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Self {
+            buf: [0u8; 32],
+            len: 0,
+        }
+    }
+}
+
+/// A fixed-capacity, single-producer/single-consumer ring of up to `N` [`Frame`]s.
+///
+/// This plays the role a `bbqueue` ring buffer plays in the `esb` crate's runtime,
+/// without pulling in an external dependency: a const-generic array is enough for the
+/// bounded, non-reallocating queue [`Runtime::on_irq()`] needs.
+struct FrameRing<const N: usize> {
+    frames: [Frame; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for FrameRing<N> {
+    fn default() -> Self {
+        Self {
+            frames: core::array::from_fn(|_| Frame::default()),
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> FrameRing<N> {
+    fn push(&mut self, data: &[u8]) -> bool {
+        if self.len >= N {
+            return false;
+        }
+        let tail = (self.head + self.len) % N;
+        let copy_len = data.len().min(32);
+        self.frames[tail].buf[..copy_len].copy_from_slice(&data[..copy_len]);
+        self.frames[tail].len = copy_len as u8;
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<([u8; 32], u8)> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = (self.frames[self.head].buf, self.frames[self.head].len);
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(item)
+    }
+}
+
+/// A received payload, tagged with the pipe it arrived on.
+pub struct RxFrame {
+    /// The pipe (0-5) the payload was received on.
+    pub pipe: u8,
+    /// The payload bytes.
+    pub buf: [u8; 32],
+    /// The number of valid leading bytes in [`RxFrame::buf`].
+    pub len: u8,
+}
+
+/// A fixed-capacity ring of up to `N` [`RxFrame`]s.
+struct RxFrameRing<const N: usize> {
+    frames: [(u8, Frame); N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for RxFrameRing<N> {
+    fn default() -> Self {
+        Self {
+            frames: core::array::from_fn(|_| (0u8, Frame::default())),
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> RxFrameRing<N> {
+    fn push(&mut self, pipe: u8, data: &[u8]) -> bool {
+        if self.len >= N {
+            return false;
+        }
+        let tail = (self.head + self.len) % N;
+        let copy_len = data.len().min(32);
+        self.frames[tail].0 = pipe;
+        self.frames[tail].1.buf[..copy_len].copy_from_slice(&data[..copy_len]);
+        self.frames[tail].1.len = copy_len as u8;
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<RxFrame> {
+        if self.len == 0 {
+            return None;
+        }
+        let (pipe, frame) = &self.frames[self.head];
+        let item = RxFrame {
+            pipe: *pipe,
+            buf: frame.buf,
+            len: frame.len,
+        };
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(item)
+    }
+}
+
+/// An ACK payload queued for [`EsbAutoAck::write_ack_payload()`], tagged with the pipe
+/// it should be attached to.
+struct AckFrameRing<const N: usize> {
+    frames: [(u8, Frame); N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for AckFrameRing<N> {
+    fn default() -> Self {
+        Self {
+            frames: core::array::from_fn(|_| (0u8, Frame::default())),
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> AckFrameRing<N> {
+    fn push(&mut self, pipe: u8, data: &[u8]) -> bool {
+        if self.len >= N {
+            return false;
+        }
+        let tail = (self.head + self.len) % N;
+        let copy_len = data.len().min(32);
+        self.frames[tail].0 = pipe;
+        self.frames[tail].1.buf[..copy_len].copy_from_slice(&data[..copy_len]);
+        self.frames[tail].1.len = copy_len as u8;
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<(u8, [u8; 32], u8)> {
+        if self.len == 0 {
+            return None;
+        }
+        let (pipe, frame) = &self.frames[self.head];
+        let item = (*pipe, frame.buf, frame.len);
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(item)
+    }
+}
+
+/// IRQ-driven, queue-backed state for turning the blocking [`EsbRadio`] API into an
+/// interrupt-serviced PTX/PRX runtime, driven one event at a time via
+/// [`RF24::on_irq()`].
+///
+/// The application pushes outgoing payloads with [`Runtime::enqueue_tx()`] and (when
+/// acting as a receiver that attaches ACK payloads) pre-stages replies with
+/// [`Runtime::enqueue_ack()`], then calls [`RF24::on_irq()`] from the top of its IRQ
+/// handler every time the radio's IRQ pin goes low. Received payloads accumulate in
+/// an internal queue, popped with [`Runtime::take_rx()`].
+///
+/// `TX_N`/`RX_N`/`ACK_N` bound the outgoing, incoming, and ACK-payload queues
+/// respectively; `retry_budget` bounds how many times [`RF24::on_irq()`] re-arms a
+/// failed transmission (via [`EsbRadio::rewrite()`]) before giving up on it and moving
+/// on to the next queued payload.
+pub struct Runtime<const TX_N: usize, const RX_N: usize, const ACK_N: usize> {
+    tx: FrameRing<TX_N>,
+    rx: RxFrameRing<RX_N>,
+    ack: AckFrameRing<ACK_N>,
+    retry_budget: u8,
+    retries_used: u8,
+    failed: usize,
+}
+
+impl<const TX_N: usize, const RX_N: usize, const ACK_N: usize> Runtime<TX_N, RX_N, ACK_N> {
+    /// Construct an empty runtime that gives up on a stuck payload (reporting it via
+    /// [`Runtime::failed_count()`]) after `retry_budget` re-arms of
+    /// [`EsbRadio::rewrite()`].
+    pub fn new(retry_budget: u8) -> Self {
+        Self {
+            tx: FrameRing::default(),
+            rx: RxFrameRing::default(),
+            ack: AckFrameRing::default(),
+            retry_budget,
+            retries_used: 0,
+            failed: 0,
+        }
+    }
+
+    /// Queue `data` (truncated to 32 bytes) as the next outgoing payload.
+    ///
+    /// Returns `false` (and queues nothing) if the outgoing queue already holds `TX_N`
+    /// payloads.
+    pub fn enqueue_tx(&mut self, data: &[u8]) -> bool {
+        self.tx.push(data)
+    }
+
+    /// Queue `data` (truncated to 32 bytes) as the next ACK payload to attach for
+    /// `pipe`, once [`RF24::on_irq()`] is ready to pre-load it.
+    ///
+    /// Returns `false` (and queues nothing) if the ACK queue already holds `ACK_N`
+    /// payloads.
+    pub fn enqueue_ack(&mut self, pipe: u8, data: &[u8]) -> bool {
+        self.ack.push(pipe, data)
+    }
+
+    /// Pop the oldest received payload (and the pipe it arrived on), if any.
+    pub fn take_rx(&mut self) -> Option<RxFrame> {
+        self.rx.pop()
+    }
+
+    /// The number of payloads currently queued for transmission.
+    pub fn pending_tx(&self) -> usize {
+        self.tx.len
+    }
+
+    /// The number of received payloads awaiting [`Runtime::take_rx()`].
+    pub fn pending_rx(&self) -> usize {
+        self.rx.len
+    }
+
+    /// The number of payloads that exhausted `retry_budget` re-arms and were given up
+    /// on, accumulated since this [`Runtime`] was constructed.
+    pub fn failed_count(&self) -> usize {
+        self.failed
+    }
+}
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Pre-load the next queued ACK payload (or a zero-length ACK if `runtime`'s ACK
+    /// queue is empty) so it is ready before the next inbound payload arrives.
+    ///
+    /// Call this once after [`RF24::as_rx()`] to arm the very first inbound packet's
+    /// reply; [`RF24::on_irq()`] calls it again after every `rx_dr` event to re-arm
+    /// for the following one.
+    pub fn prime_ack_payload<const TX_N: usize, const RX_N: usize, const ACK_N: usize>(
+        &mut self,
+        runtime: &mut Runtime<TX_N, RX_N, ACK_N>,
+    ) -> Result<bool, Nrf24Error<SPI::Error, DO::Error>> {
+        let (pipe, buf, len) = runtime.ack.pop().unwrap_or((0, [0u8; 32], 0));
+        self.write_ack_payload(pipe, &buf[..len as usize])
+    }
+
+    /// Service one interrupt event for `runtime`, to be called from the top of the
+    /// radio's IRQ handler.
+    ///
+    /// This reads the STATUS register once (via [`EsbStatus::what_happened()`]),
+    /// clearing whichever flags fired in the same SPI transaction before touching
+    /// [`EsbRadio::read_with_pipe()`], per the `rx_pipe` reliability warning on
+    /// [`EsbFifo::available_pipe()`]:
+    ///
+    /// - On `rx_dr`, drains the RX FIFO into `runtime`'s receive queue (via
+    ///   [`EsbRadio::read_with_pipe()`], stopping early if the queue fills up) and, if
+    ///   still in RX mode, re-arms the next queued ACK payload with
+    ///   [`RF24::prime_ack_payload()`].
+    /// - On `tx_ds`, resets the retry counter and loads the next queued outgoing
+    ///   payload (if any) via [`EsbRadio::write()`].
+    /// - On `tx_df`, re-arms the stuck payload with [`EsbRadio::rewrite()`] if
+    ///   `runtime`'s retry budget isn't spent yet; otherwise it counts the payload as
+    ///   failed (see [`Runtime::failed_count()`]), flushes the TX FIFO, and loads the
+    ///   next queued payload.
+    pub fn on_irq<const TX_N: usize, const RX_N: usize, const ACK_N: usize>(
+        &mut self,
+        runtime: &mut Runtime<TX_N, RX_N, ACK_N>,
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>> {
+        let flags = self.what_happened(StatusFlags::new())?;
+
+        if flags.rx_dr() {
+            let mut buf = [0u8; 32];
+            while self.available()? {
+                let (len, pipe) = self.read_with_pipe(&mut buf, None)?;
+                if !runtime.rx.push(pipe, &buf[..len as usize]) {
+                    break;
+                }
+            }
+            if self.is_rx() {
+                self.prime_ack_payload(runtime)?;
+            }
+        }
+
+        if flags.tx_ds() {
+            runtime.retries_used = 0;
+            if let Some((buf, len)) = runtime.tx.pop() {
+                self.write(&buf[..len as usize], false, true)?;
+            }
+        }
+
+        if flags.tx_df() {
+            if runtime.retries_used < runtime.retry_budget {
+                runtime.retries_used += 1;
+                self.rewrite()?;
+            } else {
+                runtime.failed += 1;
+                runtime.retries_used = 0;
+                self.flush_tx()?;
+                if let Some((buf, len)) = runtime.tx.pop() {
+                    self.write(&buf[..len as usize], false, true)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::Runtime;
+
+    #[test]
+    fn enqueue_respects_queue_capacity() {
+        let mut runtime = Runtime::<2, 2, 2>::new(3);
+        assert!(runtime.enqueue_tx(&[1, 2, 3]));
+        assert!(runtime.enqueue_tx(&[4, 5, 6]));
+        assert!(!runtime.enqueue_tx(&[7, 8, 9]));
+        assert_eq!(runtime.pending_tx(), 2);
+
+        assert!(runtime.enqueue_ack(0, &[0xAA]));
+        assert!(runtime.enqueue_ack(1, &[0xBB]));
+        assert!(!runtime.enqueue_ack(2, &[0xCC]));
+    }
+
+    #[test]
+    fn take_rx_returns_frames_in_order() {
+        let mut runtime = Runtime::<2, 2, 2>::new(0);
+        assert!(runtime.rx.push(1, &[0xAA, 0xBB]));
+        assert!(runtime.rx.push(2, &[0xCC]));
+        assert_eq!(runtime.pending_rx(), 2);
+
+        let first = runtime.take_rx().unwrap();
+        assert_eq!(first.pipe, 1);
+        assert_eq!(&first.buf[..first.len as usize], &[0xAA, 0xBB]);
+
+        let second = runtime.take_rx().unwrap();
+        assert_eq!(second.pipe, 2);
+        assert_eq!(&second.buf[..second.len as usize], &[0xCC]);
+
+        assert!(runtime.take_rx().is_none());
+    }
+}
@@ -0,0 +1,463 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::RF24;
+use crate::radio::{
+    prelude::{EsbChannel, EsbFifo, EsbPipe, EsbRadio},
+    Nrf24Error,
+};
+
+/// The worst possible RX pipe addresses, designed to confuse the radio into thinking
+/// an RF signal's preamble is part of the packet/payload.
+///
+/// This reverse-engineering tactic (borrowed from the scanner example) improves the
+/// reliability of a survey built on [`RF24::rpd()`] by making spurious packet matches
+/// (and thus [`Scanner`]'s FIFO-flush fallback) far more likely on real ambient noise
+/// than they would be with the radio's default addresses.
+const NOISE_ADDRESSES: [[u8; 2]; 6] = [
+    [0x55, 0x55],
+    [0xaa, 0xaa],
+    [0xa0, 0xaa],
+    [0x0a, 0xaa],
+    [0xa5, 0xaa],
+    [0x5a, 0xaa],
+];
+
+/// A first-class, `no_std`-friendly wrapper around [`RF24`] for surveying the 2.4 GHz
+/// band with the Received Power Detector (RPD).
+///
+/// This promotes the logic that used to be trapped in the `scanner` example's `App`
+/// into a reusable library type: [`Scanner::new()`] takes care of the reverse-engineered
+/// noise address setup, and [`Scanner::scan_channel()`]/[`Scanner::sweep()`] return raw
+/// per-channel hit counts instead of printing them, so callers can build waterfall
+/// displays, occupancy heatmaps, or channel-selection heuristics on top, on any target
+/// this crate supports (not just the std-based example).
+pub struct Scanner<SPI, DO, DELAY> {
+    radio: RF24<SPI, DO, DELAY>,
+    counts: [u8; 126],
+}
+
+impl<SPI, DO, DELAY> Scanner<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Wrap an already-[`RF24::init()`]ialized `radio`, configuring it for RPD surveys.
+    ///
+    /// This shortens the address length to 2 bytes and opens all 6 RX pipes on the
+    /// [`NOISE_ADDRESSES`] reverse-engineering tactic described on [`Scanner`]. Beyond
+    /// that, `radio` is used as-is, so set the desired [`CrcLength`](crate::CrcLength),
+    /// [`DataRate`](crate::DataRate), auto-ack, and dynamic-payloads settings
+    /// beforehand (the scanner example disables all of them).
+    pub fn new(mut radio: RF24<SPI, DO, DELAY>) -> Result<Self, Nrf24Error<SPI::Error, DO::Error>> {
+        radio.set_address_length(2)?;
+        for (pipe, address) in NOISE_ADDRESSES.iter().enumerate() {
+            radio.open_rx_pipe(pipe as u8, address)?;
+        }
+        Ok(Self {
+            radio,
+            counts: [0u8; 126],
+        })
+    }
+
+    /// Return the wrapped [`RF24`], undoing none of the setup [`Scanner::new()`] applied.
+    pub fn into_inner(self) -> RF24<SPI, DO, DELAY> {
+        self.radio
+    }
+
+    /// The per-channel hit counts accumulated across every [`Scanner::sweep()`] (and
+    /// [`Scanner::scan_channel()`]) call since construction or the last
+    /// [`Scanner::reset_counts()`].
+    pub fn counts(&self) -> &[u8; 126] {
+        &self.counts
+    }
+
+    /// Zero every channel's accumulated hit count.
+    pub fn reset_counts(&mut self) {
+        self.counts = [0u8; 126];
+    }
+
+    /// Survey a single `channel`, waiting `dwell_us` microseconds for the radio to
+    /// settle into RX mode before sampling [`RF24::rpd()`].
+    ///
+    /// As a fallback for signals too brief to latch RPD (e.g. a short BLE
+    /// advertisement), any payload that actually lands in the RX FIFO is treated as a
+    /// hit too, and the FIFO is flushed afterward so it doesn't linger into the next
+    /// channel's survey. A hit increments `channel`'s entry in
+    /// [`Scanner::counts()`] (saturating at `u8::MAX`) and is returned.
+    pub fn scan_channel(
+        &mut self,
+        channel: u8,
+        dwell_us: u32,
+    ) -> Result<bool, Nrf24Error<SPI::Error, DO::Error>> {
+        self.radio.set_channel(channel)?;
+        self.radio.delay_impl.delay_us(dwell_us);
+
+        self.radio.as_rx()?;
+        self.radio.delay_impl.delay_us(130);
+        let rpd_hit = self.radio.rpd()?;
+        self.radio.as_tx(None)?;
+
+        let hit = if self.radio.available()? {
+            self.radio.flush_rx()?;
+            true
+        } else {
+            rpd_hit
+        };
+        if hit {
+            let count = &mut self.counts[channel as usize];
+            *count = count.saturating_add(1);
+        }
+        Ok(hit)
+    }
+
+    /// Survey every channel in `channels` (in order) via [`Scanner::scan_channel()`],
+    /// then return the full, updated [`Scanner::counts()`] histogram.
+    ///
+    /// Counts accumulate across calls; use [`Scanner::reset_counts()`] between sweeps
+    /// to start a fresh histogram (e.g. after a fixed number of sweeps, as the scanner
+    /// example does to keep old noise from dominating the display).
+    pub fn sweep(
+        &mut self,
+        channels: impl IntoIterator<Item = u8>,
+        dwell_us: u32,
+    ) -> Result<[u8; 126], Nrf24Error<SPI::Error, DO::Error>> {
+        for channel in channels {
+            self.scan_channel(channel, dwell_us)?;
+        }
+        Ok(self.counts)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::{registers, Scanner, NOISE_ADDRESSES};
+    use crate::{radio::rf24::commands, spi_test_expects, test::mk_radio};
+    use embedded_hal_mock::eh1::{
+        delay::NoopDelay,
+        digital::{Mock as PinMock, State as PinState, Transaction as PinTransaction},
+        spi::{Mock as SpiMock, Transaction as SpiTransaction},
+    };
+    use std::vec;
+
+    #[test]
+    fn new() {
+        let spi_expectations = spi_test_expects![
+            // set_address_length(2)
+            (
+                vec![registers::SETUP_AW | commands::W_REGISTER, 0],
+                vec![0xEu8, 0],
+            ),
+            // open_rx_pipe(0, NOISE_ADDRESSES[0]): radio is not RX, so the address
+            // write is skipped; only the cached pipe0_rx_addr and EN_RXADDR change.
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 0]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 1],
+                vec![0xEu8, 0],
+            ),
+            // open_rx_pipe(1, NOISE_ADDRESSES[1])
+            (
+                vec![
+                    (registers::RX_ADDR_P0 + 1) | commands::W_REGISTER,
+                    0xaa,
+                    0xaa
+                ],
+                vec![0xEu8, 0, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 1]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 3],
+                vec![0xEu8, 0],
+            ),
+            // open_rx_pipe(2, NOISE_ADDRESSES[2]): only the MSB is written
+            (
+                vec![(registers::RX_ADDR_P0 + 2) | commands::W_REGISTER, 0xa0],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 3]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 7],
+                vec![0xEu8, 0],
+            ),
+            // open_rx_pipe(3, NOISE_ADDRESSES[3])
+            (
+                vec![(registers::RX_ADDR_P0 + 3) | commands::W_REGISTER, 0x0a],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 7]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 15],
+                vec![0xEu8, 0],
+            ),
+            // open_rx_pipe(4, NOISE_ADDRESSES[4])
+            (
+                vec![(registers::RX_ADDR_P0 + 4) | commands::W_REGISTER, 0xa5],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 15]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 31],
+                vec![0xEu8, 0],
+            ),
+            // open_rx_pipe(5, NOISE_ADDRESSES[5])
+            (
+                vec![(registers::RX_ADDR_P0 + 5) | commands::W_REGISTER, 0x5a],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 31]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 63],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let scanner = Scanner::new(radio).unwrap();
+        assert_eq!(scanner.counts(), &[0u8; 126]);
+        spi.done();
+        ce_pin.done();
+    }
+
+    /// Build a [`Scanner`] as if [`Scanner::new()`] had already run, without replaying
+    /// its SPI transactions, so each test below only mocks the method under test.
+    fn mk_scanner(
+        ce_expectations: &[PinTransaction],
+        spi_expectations: &[SpiTransaction<u8>],
+    ) -> (
+        Scanner<SpiMock<u8>, PinMock, NoopDelay>,
+        SpiMock<u8>,
+        PinMock,
+    ) {
+        let mocks = mk_radio(ce_expectations, spi_expectations);
+        let (mut radio, spi, ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.feature.set_address_length(2);
+        radio.pipe0_rx_addr = Some([NOISE_ADDRESSES[0][0], NOISE_ADDRESSES[0][1], 0, 0, 0]);
+        (
+            Scanner {
+                radio,
+                counts: [0u8; 126],
+            },
+            spi,
+            ce_pin,
+        )
+    }
+
+    #[test]
+    fn scan_channel_rpd_hit() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            // set_channel(76)
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): assert PRIM_RX flag
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): clear_status_flags()
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            // as_rx(): restore the cached pipe0 address
+            (
+                vec![registers::RX_ADDR_P0 | commands::W_REGISTER, 0x55, 0x55],
+                vec![0xEu8, 0, 0],
+            ),
+            // rpd() sampled: a hit
+            (vec![registers::RPD, 0], vec![0xEu8, 1]),
+            // as_tx(None): reassert CONFIG
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+            // as_tx(None): point pipe 0 back at the (unused) default TX address
+            (
+                vec![registers::RX_ADDR_P0 | commands::W_REGISTER, 0xE7, 0xE7],
+                vec![0xEu8, 0, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 0x3F]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0x3F],
+                vec![0xEu8, 0],
+            ),
+            // available(): RX FIFO reported empty, so the rpd() hit stands
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 1]),
+        ];
+        let (mut scanner, mut spi, mut ce_pin) = mk_scanner(&ce_expectations, &spi_expectations);
+        assert!(scanner.scan_channel(76, 130).unwrap());
+        assert_eq!(scanner.counts()[76], 1);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn scan_channel_fifo_fallback() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::RX_ADDR_P0 | commands::W_REGISTER, 0x55, 0x55],
+                vec![0xEu8, 0, 0],
+            ),
+            // rpd() sampled: no hit
+            (vec![registers::RPD, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::RX_ADDR_P0 | commands::W_REGISTER, 0xE7, 0xE7],
+                vec![0xEu8, 0, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 0x3F]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0x3F],
+                vec![0xEu8, 0],
+            ),
+            // available(): a payload landed in the RX FIFO despite no RPD hit
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 0]),
+            // flush_rx()
+            (vec![commands::FLUSH_RX], vec![0xEu8]),
+        ];
+        let (mut scanner, mut spi, mut ce_pin) = mk_scanner(&ce_expectations, &spi_expectations);
+        assert!(scanner.scan_channel(76, 130).unwrap());
+        assert_eq!(scanner.counts()[76], 1);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn scan_channel_miss() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let spi_expectations = spi_test_expects![
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::RX_ADDR_P0 | commands::W_REGISTER, 0x55, 0x55],
+                vec![0xEu8, 0, 0],
+            ),
+            // rpd() sampled: no hit
+            (vec![registers::RPD, 0], vec![0xEu8, 0]),
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::RX_ADDR_P0 | commands::W_REGISTER, 0xE7, 0xE7],
+                vec![0xEu8, 0, 0],
+            ),
+            (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 0x3F]),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 0x3F],
+                vec![0xEu8, 0],
+            ),
+            // available(): RX FIFO reported empty too
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 1]),
+        ];
+        let (mut scanner, mut spi, mut ce_pin) = mk_scanner(&ce_expectations, &spi_expectations);
+        assert!(!scanner.scan_channel(76, 130).unwrap());
+        assert_eq!(scanner.counts()[76], 0);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn sweep_surveys_every_channel() {
+        let ce_expectations = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+        let mut spi_expectations = vec![];
+        for channel in [1u8, 2u8] {
+            spi_expectations.extend(spi_test_expects![
+                (
+                    vec![registers::RF_CH | commands::W_REGISTER, channel],
+                    vec![0xEu8, 0],
+                ),
+                (
+                    vec![registers::CONFIG | commands::W_REGISTER, 0xD],
+                    vec![0xEu8, 0],
+                ),
+                (
+                    vec![registers::STATUS | commands::W_REGISTER, 0x70],
+                    vec![0xEu8, 0],
+                ),
+                (
+                    vec![registers::RX_ADDR_P0 | commands::W_REGISTER, 0x55, 0x55],
+                    vec![0xEu8, 0, 0],
+                ),
+                (vec![registers::RPD, 0], vec![0xEu8, 1]),
+                (
+                    vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                    vec![0xEu8, 0],
+                ),
+                (
+                    vec![registers::RX_ADDR_P0 | commands::W_REGISTER, 0xE7, 0xE7],
+                    vec![0xEu8, 0, 0],
+                ),
+                (vec![registers::EN_RXADDR, 0u8], vec![0xEu8, 0x3F]),
+                (
+                    vec![registers::EN_RXADDR | commands::W_REGISTER, 0x3F],
+                    vec![0xEu8, 0],
+                ),
+                (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 1]),
+            ]);
+        }
+        let (mut scanner, mut spi, mut ce_pin) = mk_scanner(&ce_expectations, &spi_expectations);
+        let counts = scanner.sweep([1, 2], 130).unwrap();
+        assert_eq!(counts[1], 1);
+        assert_eq!(counts[2], 1);
+        assert_eq!(scanner.counts()[1], 1);
+        assert_eq!(scanner.counts()[2], 1);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn reset_counts() {
+        let (mut scanner, mut spi, mut ce_pin) = mk_scanner(&[], &[]);
+        scanner.counts[3] = 5;
+        scanner.reset_counts();
+        assert_eq!(scanner.counts(), &[0u8; 126]);
+        spi.done();
+        ce_pin.done();
+    }
+}
@@ -0,0 +1,218 @@
+use crate::radio::RF24;
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::{commands, registers, Config, Feature, Nrf24Error};
+
+/// The number of bytes in a [`RF24::save_config()`]/[`RF24::load_config()`] blob.
+pub const RADIO_CONFIG_BLOB_LEN: usize = 21;
+
+/// The version byte stamped into every blob produced by [`RF24::save_config()`].
+///
+/// [`RF24::load_config()`] rejects a blob whose version byte does not match this,
+/// since the remaining byte layout is only meaningful for this specific version.
+const CONFIG_BLOB_VERSION: u8 = 1;
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Snapshot the radio's configuration registers into a compact, versioned byte blob.
+    ///
+    /// This reads `CONFIG`, `EN_AA`, `EN_RXADDR`, `SETUP_AW`, `SETUP_RETR`, `RF_CH`,
+    /// `RF_SETUP`, `FEATURE`, `DYNPD`, the static payload length (`RX_PW_P0`), the TX
+    /// address, and pipe 0's RX address. Pair this with [`RF24::load_config()`] to
+    /// persist a tuned radio profile to external flash/EEPROM and restore it later
+    /// without re-running the whole builder sequence.
+    pub fn save_config(
+        &mut self,
+        out: &mut [u8; RADIO_CONFIG_BLOB_LEN],
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>> {
+        out[0] = CONFIG_BLOB_VERSION;
+        for (i, register) in [
+            registers::CONFIG,
+            registers::EN_AA,
+            registers::EN_RXADDR,
+            registers::SETUP_AW,
+            registers::SETUP_RETR,
+            registers::RF_CH,
+            registers::RF_SETUP,
+            registers::FEATURE,
+            registers::DYNPD,
+            registers::RX_PW_P0,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            self.spi_read(1, register)?;
+            out[i + 1] = self.buf[1];
+        }
+        self.spi_read(5, registers::TX_ADDR)?;
+        out[11..16].copy_from_slice(&self.buf[1..6]);
+        self.spi_read(5, registers::RX_ADDR_P0)?;
+        out[16..21].copy_from_slice(&self.buf[1..6]);
+        Ok(())
+    }
+
+    /// Restore the radio's configuration registers from a blob produced by
+    /// [`RF24::save_config()`], refreshing the cached `config_reg`, `feature`,
+    /// `payload_length`, and `pipe0_rx_addr` so the in-memory mirrors stay consistent.
+    ///
+    /// Returns [`Nrf24Error::BinaryCorruption`] if `blob`'s version byte does not match
+    /// the version [`RF24::save_config()`] stamps.
+    pub fn load_config(
+        &mut self,
+        blob: &[u8; RADIO_CONFIG_BLOB_LEN],
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>> {
+        if blob[0] != CONFIG_BLOB_VERSION {
+            return Err(Nrf24Error::BinaryCorruption);
+        }
+        self.spi_write_byte(registers::CONFIG, blob[1])?;
+        self.spi_write_byte(registers::EN_AA, blob[2])?;
+        self.spi_write_byte(registers::EN_RXADDR, blob[3])?;
+        self.spi_write_byte(registers::SETUP_AW, blob[4])?;
+        self.spi_write_byte(registers::SETUP_RETR, blob[5])?;
+        self.spi_write_byte(registers::RF_CH, blob[6])?;
+        self.spi_write_byte(registers::RF_SETUP, blob[7])?;
+        self.spi_write_byte(registers::FEATURE, blob[8] & Feature::REG_MASK)?;
+        self.spi_write_byte(registers::DYNPD, blob[9])?;
+        self.spi_write_byte(registers::RX_PW_P0, blob[10])?;
+
+        self.buf[0] = registers::TX_ADDR | commands::W_REGISTER;
+        self.buf[1..6].copy_from_slice(&blob[11..16]);
+        self.spi_transfer(6)?;
+        self.tx_address.copy_from_slice(&blob[11..16]);
+
+        self.buf[0] = registers::RX_ADDR_P0 | commands::W_REGISTER;
+        self.buf[1..6].copy_from_slice(&blob[16..21]);
+        self.spi_transfer(6)?;
+        let mut pipe0_rx_addr = [0u8; 5];
+        pipe0_rx_addr.copy_from_slice(&blob[16..21]);
+        self.pipe0_rx_addr = Some(pipe0_rx_addr);
+
+        self.config_reg = Config::from_bits(blob[1]);
+        self.feature = Feature::from_bits(
+            self.feature.into_bits() & !Feature::REG_MASK | (blob[8] & Feature::REG_MASK),
+        );
+        self.payload_length = blob[10];
+        Ok(())
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::{commands, registers, RADIO_CONFIG_BLOB_LEN};
+    use crate::{spi_test_expects, test::mk_radio};
+    use std::vec;
+
+    #[test]
+    fn save_and_load_config() {
+        let spi_expectations = spi_test_expects![
+            // save_config()
+            (vec![registers::CONFIG, 0], vec![0xEu8, 0xC]),
+            (vec![registers::EN_AA, 0], vec![0xEu8, 0x3F]),
+            (vec![registers::EN_RXADDR, 0], vec![0xEu8, 3]),
+            (vec![registers::SETUP_AW, 0], vec![0xEu8, 3]),
+            (vec![registers::SETUP_RETR, 0], vec![0xEu8, 0x5F]),
+            (vec![registers::RF_CH, 0], vec![0xEu8, 76]),
+            (vec![registers::RF_SETUP, 0], vec![0xEu8, 6]),
+            (vec![registers::FEATURE, 0], vec![0xEu8, 6]),
+            (vec![registers::DYNPD, 0], vec![0xEu8, 0x3F]),
+            (vec![registers::RX_PW_P0, 0], vec![0xEu8, 32]),
+            (
+                vec![registers::TX_ADDR, 0, 0, 0, 0, 0],
+                vec![0xEu8, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+            ),
+            (
+                vec![registers::RX_ADDR_P0, 0, 0, 0, 0, 0],
+                vec![0xEu8, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+            ),
+            // load_config()
+            (
+                vec![registers::CONFIG | commands::W_REGISTER, 0xC],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::EN_AA | commands::W_REGISTER, 0x3F],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::EN_RXADDR | commands::W_REGISTER, 3],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::SETUP_AW | commands::W_REGISTER, 3],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::SETUP_RETR | commands::W_REGISTER, 0x5F],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::RF_SETUP | commands::W_REGISTER, 6],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::FEATURE | commands::W_REGISTER, 6],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::DYNPD | commands::W_REGISTER, 0x3F],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![registers::RX_PW_P0 | commands::W_REGISTER, 32],
+                vec![0xEu8, 0],
+            ),
+            (
+                vec![
+                    registers::TX_ADDR | commands::W_REGISTER,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                ],
+                vec![0u8; 6],
+            ),
+            (
+                vec![
+                    registers::RX_ADDR_P0 | commands::W_REGISTER,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                ],
+                vec![0u8; 6],
+            ),
+        ];
+
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut blob = [0u8; RADIO_CONFIG_BLOB_LEN];
+        radio.save_config(&mut blob).unwrap();
+        radio.load_config(&blob).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn load_config_rejects_bad_version() {
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut blob = [0u8; RADIO_CONFIG_BLOB_LEN];
+        blob[0] = 0xFF;
+        assert!(radio.load_config(&blob).is_err());
+        spi.done();
+        ce_pin.done();
+    }
+}
@@ -17,10 +17,10 @@ where
 
     fn set_status_flags(&mut self, flags: StatusFlags) -> Result<(), Self::StatusErrorType> {
         self.spi_read(1, registers::CONFIG)?;
-        self._config_reg = Config::from_bits(
-            self._buf[1] & !StatusFlags::IRQ_MASK | (!flags.into_bits() & StatusFlags::IRQ_MASK),
+        self.config_reg = Config::from_bits(
+            self.buf[1] & !StatusFlags::IRQ_MASK | (!flags.into_bits() & StatusFlags::IRQ_MASK),
         );
-        self.spi_write_byte(registers::CONFIG, self._config_reg.into_bits())
+        self.spi_write_byte(registers::CONFIG, self.config_reg.into_bits())
     }
 
     fn clear_status_flags(&mut self, flags: StatusFlags) -> Result<(), Self::StatusErrorType> {
@@ -32,7 +32,45 @@ where
     }
 
     fn get_status_flags(&self, flags: &mut StatusFlags) {
-        *flags = self._status;
+        *flags = self.status;
+    }
+
+    fn what_happened(&mut self, mask: StatusFlags) -> Result<StatusFlags, Self::StatusErrorType> {
+        self.update()?;
+        let flags = self.status;
+        self.clear_status_flags(mask)?;
+        Ok(flags)
+    }
+
+    fn get_masked_flags(&mut self) -> Result<StatusFlags, Self::StatusErrorType> {
+        self.spi_read(1, registers::CONFIG)?;
+        Ok(StatusFlags::from_bits(!self.buf[1] & StatusFlags::IRQ_MASK))
+    }
+
+    fn handle_interrupt<RX, TX, TXF>(
+        &mut self,
+        on_rx_dr: RX,
+        on_tx_ds: TX,
+        on_tx_df: TXF,
+    ) -> Result<StatusFlags, Self::StatusErrorType>
+    where
+        RX: FnOnce(),
+        TX: FnOnce(),
+        TXF: FnOnce(),
+    {
+        self.update()?;
+        let flags = self.status;
+        if flags.rx_dr() {
+            on_rx_dr();
+        }
+        if flags.tx_ds() {
+            on_tx_ds();
+        }
+        if flags.tx_df() {
+            on_tx_df();
+        }
+        self.clear_status_flags(flags)?;
+        Ok(flags)
     }
 }
 
@@ -64,6 +102,99 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    pub fn what_happened_isr() {
+        let spi_expectations = spi_test_expects![
+            // update()
+            (vec![commands::NOP], vec![0x70u8]),
+            // clear_status_flags(StatusFlags::new())
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70u8],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let flags = radio.what_happened(StatusFlags::new()).unwrap();
+        assert!(flags.rx_dr());
+        assert!(flags.tx_ds());
+        assert!(flags.tx_df());
+        assert_eq!(flags.pipe(), 0);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn get_masked_flags() {
+        let spi_expectations = spi_test_expects![
+            // all events unmasked (CONFIG's MASK_* bits are all 0)
+            (vec![registers::CONFIG, 0u8], vec![0xEu8, 0xFu8]),
+            // all events masked (CONFIG's MASK_* bits are all 1)
+            (vec![registers::CONFIG, 0u8], vec![0xEu8, 0x7Fu8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let flags = radio.get_masked_flags().unwrap();
+        assert!(flags.rx_dr());
+        assert!(flags.tx_ds());
+        assert!(flags.tx_df());
+        let flags = radio.get_masked_flags().unwrap();
+        assert!(!flags.rx_dr());
+        assert!(!flags.tx_ds());
+        assert!(!flags.tx_df());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn handle_interrupt() {
+        let spi_expectations = spi_test_expects![
+            // update()
+            (vec![commands::NOP], vec![0x70u8]),
+            // clear_status_flags(flags) (only the events that fired)
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x70u8],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut rx_dr = false;
+        let mut tx_ds = false;
+        let mut tx_df = false;
+        let flags = radio
+            .handle_interrupt(|| rx_dr = true, || tx_ds = true, || tx_df = true)
+            .unwrap();
+        assert!(rx_dr);
+        assert!(tx_ds);
+        assert!(tx_df);
+        assert!(flags.rx_dr());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    pub fn handle_interrupt_partial() {
+        let spi_expectations = spi_test_expects![
+            // update() with only rx_dr set
+            (vec![commands::NOP], vec![0x40u8]),
+            // clear_status_flags() only clears rx_dr
+            (
+                vec![registers::STATUS | commands::W_REGISTER, 0x40u8],
+                vec![0xEu8, 0],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut tx_ds_or_df = false;
+        radio
+            .handle_interrupt(|| {}, || tx_ds_or_df = true, || tx_ds_or_df = true)
+            .unwrap();
+        assert!(!tx_ds_or_df);
+        spi.done();
+        ce_pin.done();
+    }
+
     #[test]
     pub fn set_status_flags() {
         let spi_expectations = spi_test_expects![
@@ -0,0 +1,176 @@
+use core::time::Duration;
+
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::{registers, RF24};
+use crate::radio::Nrf24Error;
+use crate::transport::{Fragmenter, Reassembler, MAX_FRAME_DATA};
+
+/// The delay (in microseconds) between [`RF24::read_message()`]'s polls of the RX FIFO.
+const POLL_INTERVAL_US: u32 = 100;
+
+/// The number of payload bytes a single [`RF24::send_message()`] frame can carry. This
+/// is just [`MAX_FRAME_DATA`] under a name that reads naturally alongside
+/// [`STREAM_MAX_CHUNKS`]/[`STREAM_MAX_MESSAGE_LEN`].
+pub const STREAM_CHUNK_LEN: usize = MAX_FRAME_DATA;
+
+/// The largest number of frames a single message can be split into.
+pub const STREAM_MAX_CHUNKS: usize = 32;
+
+/// The largest message [`RF24::send_message()`]/[`RF24::read_message()`] can carry.
+pub const STREAM_MAX_MESSAGE_LEN: usize = STREAM_CHUNK_LEN * STREAM_MAX_CHUNKS;
+
+/// An error specific to [`RF24::send_message()`]/[`RF24::read_message()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StreamError<E> {
+    /// The underlying radio transaction failed.
+    Radio(E),
+    /// `data` is longer than [`STREAM_MAX_MESSAGE_LEN`] bytes.
+    MessageTooLong,
+    /// A chunk went unacknowledged. Since [`RF24::send_message()`] relies on
+    /// auto-ack to detect delivery, this aborts the whole message rather than
+    /// risking a gap the receiver can't recover from.
+    ChunkNotAcked,
+    /// `out` is smaller than [`STREAM_MAX_MESSAGE_LEN`] bytes, so it cannot safely
+    /// hold whatever a fully reassembled message turns out to be.
+    BufferTooSmall,
+    /// No frame arrived within the requested timeout while waiting on the RX FIFO.
+    Timeout,
+}
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Fragment `data` with a [`Fragmenter`] under the given `msg_id` and send every
+    /// frame, blocking on each one's acknowledgement before sending the next.
+    ///
+    /// This uses the same [`crate::transport`] wire format as every other binding's
+    /// `send_message()`/`read_message()` (or equivalent) helper, so a message sent
+    /// here reassembles correctly wherever it's received, and vice versa.
+    ///
+    /// Returns [`StreamError::MessageTooLong`] if `data` is longer than
+    /// [`STREAM_MAX_MESSAGE_LEN`] bytes, or [`StreamError::ChunkNotAcked`] as soon as
+    /// a chunk goes unacknowledged, leaving the rest of `data` unsent.
+    pub fn send_message(
+        &mut self,
+        msg_id: u8,
+        data: &[u8],
+    ) -> Result<(), StreamError<Nrf24Error<SPI::Error, DO::Error>>> {
+        let fragmenter = Fragmenter::<STREAM_MAX_CHUNKS>::new(msg_id, data)
+            .ok_or(StreamError::MessageTooLong)?;
+
+        for (frame, len) in fragmenter {
+            let acked = self
+                .send(&frame[..len], false)
+                .map_err(StreamError::Radio)?;
+            if !acked {
+                return Err(StreamError::ChunkNotAcked);
+            }
+        }
+        Ok(())
+    }
+
+    /// Block until a [`Reassembler`] collects a complete message, writing it into
+    /// `out`.
+    ///
+    /// `out` must be at least [`STREAM_MAX_MESSAGE_LEN`] bytes long, or this returns
+    /// [`StreamError::BufferTooSmall`] without touching the radio. `timeout` bounds
+    /// how long each individual fragment may take to arrive; it is restarted after
+    /// every fragment received. Returns [`StreamError::Timeout`] if `timeout` elapses
+    /// with no fragment available, instead of blocking forever on a sender that never
+    /// finishes.
+    pub fn read_message(
+        &mut self,
+        out: &mut [u8],
+        timeout: Duration,
+    ) -> Result<usize, StreamError<Nrf24Error<SPI::Error, DO::Error>>> {
+        if out.len() < STREAM_MAX_MESSAGE_LEN {
+            return Err(StreamError::BufferTooSmall);
+        }
+        let mut reassembler = Reassembler::<STREAM_MAX_CHUNKS>::new();
+        let timeout_us = timeout.as_micros().min(u128::from(u32::MAX)) as u32;
+
+        loop {
+            let mut elapsed_us = 0u32;
+            while !self.available().map_err(StreamError::Radio)? {
+                if elapsed_us >= timeout_us {
+                    return Err(StreamError::Timeout);
+                }
+                self.delay_impl.delay_us(POLL_INTERVAL_US);
+                elapsed_us = elapsed_us.saturating_add(POLL_INTERVAL_US);
+            }
+
+            let mut frame = [0u8; 32];
+            let len = self.read(&mut frame, None).map_err(StreamError::Radio)? as usize;
+            if len == 0 {
+                continue;
+            }
+            if let Some(written) = reassembler.receive_frame(&frame[..len], out) {
+                return Ok(written);
+            }
+        }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use core::time::Duration;
+
+    use super::{registers, StreamError, STREAM_MAX_MESSAGE_LEN};
+    use crate::{spi_test_expects, test::mk_radio};
+    use embedded_hal_mock::eh1::spi::Transaction as SpiTransaction;
+    use std::vec;
+
+    #[test]
+    fn send_message_rejects_oversized_message() {
+        let data = [0u8; STREAM_MAX_MESSAGE_LEN + 1];
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(
+            radio.send_message(0, &data),
+            Err(StreamError::MessageTooLong)
+        );
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn read_message_rejects_undersized_buffer() {
+        let mocks = mk_radio(&[], &[]);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut out = [0u8; STREAM_MAX_MESSAGE_LEN - 1];
+        assert_eq!(
+            radio.read_message(&mut out, Duration::from_micros(250)),
+            Err(StreamError::BufferTooSmall)
+        );
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn read_message_times_out() {
+        // the RX FIFO is polled 4 times (reporting empty every time) before a 250us
+        // timeout (with a 100us poll interval) gives up
+        let spi_expectations = spi_test_expects![
+            (vec![registers::FIFO_STATUS, 0u8], vec![0xEu8, 1u8]),
+            (vec![registers::FIFO_STATUS, 1u8], vec![0xEu8, 1u8]),
+            (vec![registers::FIFO_STATUS, 1u8], vec![0xEu8, 1u8]),
+            (vec![registers::FIFO_STATUS, 1u8], vec![0xEu8, 1u8]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        let mut out = [0u8; STREAM_MAX_MESSAGE_LEN];
+        assert_eq!(
+            radio.read_message(&mut out, Duration::from_micros(250)),
+            Err(StreamError::Timeout)
+        );
+        spi.done();
+        ce_pin.done();
+    }
+}
@@ -0,0 +1,59 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::RF24;
+use crate::{
+    radio::{prelude::EsbTestMode, Nrf24Error},
+    PaLevel,
+};
+
+impl<SPI, DO, DELAY> EsbTestMode for RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    type TestModeErrorType = Nrf24Error<SPI::Error, DO::Error>;
+
+    fn start_carrier_wave(
+        &mut self,
+        level: PaLevel,
+        channel: u8,
+    ) -> Result<(), Self::TestModeErrorType> {
+        RF24::start_carrier_wave(self, level, channel)
+    }
+
+    fn stop_carrier_wave(&mut self) -> Result<(), Self::TestModeErrorType> {
+        RF24::stop_carrier_wave(self)
+    }
+
+    fn test_rpd(&mut self) -> Result<bool, Self::TestModeErrorType> {
+        RF24::rpd(self)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use crate::{
+        radio::{
+            prelude::EsbTestMode,
+            rf24::{commands, registers},
+        },
+        spi_test_expects,
+        test::mk_radio,
+    };
+    use embedded_hal_mock::eh1::spi::Transaction as SpiTransaction;
+    use std::vec;
+
+    #[test]
+    fn test_rpd_trait_delegates_to_the_inherent_method() {
+        let spi_expectations = spi_test_expects![(vec![registers::RPD, 0], vec![0xEu8, 1]),];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert!(EsbTestMode::test_rpd(&mut radio).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+}
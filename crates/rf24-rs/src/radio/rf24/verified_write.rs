@@ -0,0 +1,155 @@
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+
+use super::{registers, RF24};
+use crate::radio::Nrf24Error;
+
+impl<SPI, DO, DELAY> RF24<SPI, DO, DELAY>
+where
+    SPI: SpiDevice,
+    DO: OutputPin,
+    DELAY: DelayNs,
+{
+    /// Opt in to "verified write" mode: every register write issued through
+    /// [`RF24::spi_write_byte()`]/[`RF24::spi_write_buf()`] (which together back nearly
+    /// every setter on this struct) is read back and, if it doesn't match what was
+    /// just written, the whole write is re-issued up to `retries` times before giving
+    /// up with [`Nrf24Error::RegisterMismatch`] or [`Nrf24Error::ModuleUnreachable`].
+    ///
+    /// This trades up to `2 * retries` extra SPI transactions per write for
+    /// resilience against noisy or long SPI wiring, where a write might only need a
+    /// couple of attempts to stick instead of silently leaving the radio
+    /// mis-configured. Pass `0` to disable (the default).
+    pub fn set_spi_verification(&mut self, retries: u8) {
+        self.spi_verification_retries = retries;
+    }
+
+    /// Write a known pattern to `TX_ADDR` and read it back, to check that the SPI
+    /// wiring (and the module itself) is actually responding, without needing
+    /// [`RF24::set_spi_verification()`] enabled first.
+    ///
+    /// Returns `Ok(false)` (instead of a hardware error) if the read-back doesn't
+    /// match, which usually means loose wiring, a missing power supply, or some other
+    /// transport fault corrupting the bus. The cached TX address's prior value is
+    /// restored afterward regardless of the outcome.
+    pub fn check_connection(&mut self) -> Result<bool, Nrf24Error<SPI::Error, DO::Error>> {
+        const PATTERN: [u8; 5] = [0xC6; 5];
+        let original = self.tx_address;
+        self.spi_write_buf(registers::TX_ADDR, &PATTERN)?;
+        self.spi_read(5, registers::TX_ADDR)?;
+        let matches = self.buf[1..6] == PATTERN;
+        self.spi_write_buf(registers::TX_ADDR, &original)?;
+        Ok(matches)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use crate::{
+        radio::{
+            prelude::EsbChannel,
+            rf24::{commands, registers},
+            Nrf24Error,
+        },
+        spi_test_expects,
+        test::mk_radio,
+    };
+    use embedded_hal_mock::eh1::spi::Transaction as SpiTransaction;
+    use std::vec;
+
+    #[test]
+    fn retries_a_mismatched_write_until_it_sticks() {
+        let spi_expectations = spi_test_expects![
+            // first attempt: write RF_CH, then the verification read-back reports a
+            // stale value (the write didn't stick)
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::RF_CH, 0], vec![0xEu8, 0]),
+            // second attempt: write RF_CH again, and this time it reads back correctly
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::RF_CH, 0], vec![0xEu8, 76]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_spi_verification(1);
+        radio.set_channel(76).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn gives_up_as_register_mismatch_once_retries_are_spent() {
+        let spi_expectations = spi_test_expects![
+            // first attempt: write RF_CH, then the verification read-back reports a
+            // stale value
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::RF_CH, 0], vec![0xEu8, 0]),
+            // the single retry also fails to stick
+            (
+                vec![registers::RF_CH | commands::W_REGISTER, 76],
+                vec![0xEu8, 0],
+            ),
+            (vec![registers::RF_CH, 0], vec![0xEu8, 0]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        radio.set_spi_verification(1);
+        assert_eq!(
+            radio.set_channel(76),
+            Err(Nrf24Error::RegisterMismatch {
+                register: registers::RF_CH,
+                expected: 76,
+                actual: 0,
+            })
+        );
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn check_connection_detects_a_mismatch() {
+        let spi_expectations = spi_test_expects![
+            (
+                vec![
+                    registers::TX_ADDR | commands::W_REGISTER,
+                    0xC6,
+                    0xC6,
+                    0xC6,
+                    0xC6,
+                    0xC6
+                ],
+                vec![0xEu8, 0, 0, 0, 0, 0],
+            ),
+            (
+                vec![registers::TX_ADDR, 0, 0, 0, 0, 0],
+                vec![0xEu8, 0xFF, 0xC6, 0xC6, 0xC6, 0xC6],
+            ),
+            (
+                vec![
+                    registers::TX_ADDR | commands::W_REGISTER,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7,
+                    0xE7
+                ],
+                vec![0xEu8, 0xFF, 0xC6, 0xC6, 0xC6, 0xC6],
+            ),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+        assert_eq!(radio.check_connection(), Ok(false));
+        spi.done();
+        ce_pin.done();
+    }
+}
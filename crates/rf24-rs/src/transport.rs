@@ -0,0 +1,268 @@
+//! A lightweight link-layer for transporting messages larger than one payload, built on
+//! top of [`crate::radio::RF24`]'s 32-byte FIFO.
+//!
+//! Each frame carries a 3-byte header (message id, sequence index, total frame count)
+//! ahead of up to [`MAX_FRAME_DATA`] bytes of payload. [`Fragmenter`] splits an
+//! arbitrarily sized buffer into frames; [`Reassembler`] collects frames (in any order)
+//! back into a complete message, restarting if a new message id arrives before the
+//! previous one finished.
+//!
+//! This is the one wire format every binding uses for its own `send_message()`/
+//! `read_message()` (or equivalent) helpers — [`RF24::send_message()`]/
+//! [`RF24::read_message()`] included. Bindings call [`Fragmenter`]/[`Reassembler`]
+//! directly rather than going through [`RF24::send_message()`] because they each
+//! drive the radio (and surface timeouts/errors) through their own host language's
+//! idioms, but they all speak the same frames, so a message fragmented by one binding
+//! reassembles correctly on any other.
+//!
+//! [`RF24::send_message()`]: fn@crate::radio::RF24::send_message
+//! [`RF24::read_message()`]: fn@crate::radio::RF24::read_message
+
+/// The number of header bytes prefixed to every frame (message id, sequence index,
+/// total frame count).
+pub const FRAME_HEADER_LEN: usize = 3;
+
+/// The maximum number of payload bytes a single frame can carry, leaving room for the
+/// [`FRAME_HEADER_LEN`]-byte header within the radio's 32-byte FIFO.
+pub const MAX_FRAME_DATA: usize = 32 - FRAME_HEADER_LEN;
+
+/// Splits a message into frames no larger than the radio's payload, prefixing each with
+/// a header of `(message id, sequence index, total frame count)` so [`Reassembler`] can
+/// reorder frames and detect completion on the receiving end.
+///
+/// `N` bounds the number of frames a single message can be split into; messages longer
+/// than `N * `[`MAX_FRAME_DATA`] bytes are rejected by [`Fragmenter::new`].
+pub struct Fragmenter<'a, const N: usize> {
+    msg_id: u8,
+    data: &'a [u8],
+    total: u8,
+    next_seq: u8,
+}
+
+impl<'a, const N: usize> Fragmenter<'a, N> {
+    /// Begin fragmenting `data` under the given `msg_id`.
+    ///
+    /// Returns `None` if `data` would not fit in `N` frames (including an empty `data`,
+    /// which still occupies one frame).
+    pub fn new(msg_id: u8, data: &'a [u8]) -> Option<Self> {
+        let total = data.len().div_ceil(MAX_FRAME_DATA).max(1);
+        if total > N || total > u8::MAX as usize {
+            return None;
+        }
+        Some(Self {
+            msg_id,
+            data,
+            total: total as u8,
+            next_seq: 0,
+        })
+    }
+
+    /// The total number of frames this message was split into.
+    pub fn frame_count(&self) -> u8 {
+        self.total
+    }
+}
+
+impl<const N: usize> Iterator for Fragmenter<'_, N> {
+    /// A frame buffer (ready to pass to [`crate::radio::RF24::send`]) and the number of
+    /// its leading bytes that are valid.
+    type Item = ([u8; 32], usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_seq >= self.total {
+            return None;
+        }
+        let start = self.next_seq as usize * MAX_FRAME_DATA;
+        let end = (start + MAX_FRAME_DATA).min(self.data.len());
+        let chunk = &self.data[start..end];
+
+        let mut frame = [0u8; 32];
+        frame[0] = self.msg_id;
+        frame[1] = self.next_seq;
+        frame[2] = self.total;
+        frame[FRAME_HEADER_LEN..FRAME_HEADER_LEN + chunk.len()].copy_from_slice(chunk);
+
+        self.next_seq += 1;
+        Some((frame, FRAME_HEADER_LEN + chunk.len()))
+    }
+}
+
+/// Reassembles frames produced by a [`Fragmenter`] back into a complete message.
+///
+/// `N` bounds the number of frames (and therefore the maximum message size, `N * `
+/// [`MAX_FRAME_DATA`] bytes) a single [`Reassembler`] can hold.
+pub struct Reassembler<const N: usize> {
+    msg_id: Option<u8>,
+    total: u8,
+    received: [bool; N],
+    buf: [[u8; MAX_FRAME_DATA]; N],
+    frame_len: [u8; N],
+}
+
+impl<const N: usize> Default for Reassembler<N> {
+    fn default() -> Self {
+        Self {
+            msg_id: None,
+            total: 0,
+            received: [false; N],
+            buf: [[0u8; MAX_FRAME_DATA]; N],
+            frame_len: [0u8; N],
+        }
+    }
+}
+
+impl<const N: usize> Reassembler<N> {
+    /// Construct an empty reassembler, ready to receive frames for any message id.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Is there no message currently in progress?
+    ///
+    /// Useful for callers that want to bound how long a partially received message may
+    /// linger (e.g. discarding it after some elapsed time) without [`Reassembler`] itself
+    /// needing to know about wall-clock time.
+    pub fn is_empty(&self) -> bool {
+        self.msg_id.is_none()
+    }
+
+    /// Feed one received `frame` (as read from the radio's RX FIFO) into the
+    /// reassembler.
+    ///
+    /// Returns the number of bytes written to `out` once every frame of the message in
+    /// progress has arrived; `out` must be at least `N * `[`MAX_FRAME_DATA`] bytes long.
+    /// Returns `None` while the message is still incomplete, or if `frame` is malformed.
+    /// A `frame` whose message id differs from the one in progress restarts
+    /// reassembly, discarding whatever frames had already arrived for the old id.
+    pub fn receive_frame(&mut self, frame: &[u8], out: &mut [u8]) -> Option<usize> {
+        if frame.len() < FRAME_HEADER_LEN {
+            return None;
+        }
+        let (msg_id, seq, total) = (frame[0], frame[1], frame[2]);
+        if total == 0 || seq >= total || seq as usize >= N {
+            return None;
+        }
+        if self.msg_id != Some(msg_id) {
+            *self = Self::default();
+            self.msg_id = Some(msg_id);
+            self.total = total;
+        }
+
+        let seq = seq as usize;
+        let data = &frame[FRAME_HEADER_LEN..];
+        let len = data.len().min(MAX_FRAME_DATA);
+        self.buf[seq][..len].copy_from_slice(&data[..len]);
+        self.frame_len[seq] = len as u8;
+        self.received[seq] = true;
+
+        if self.received[..self.total as usize].iter().all(|r| *r) {
+            let mut written = 0;
+            for frame_data in self.buf[..self.total as usize]
+                .iter()
+                .zip(self.frame_len[..self.total as usize].iter())
+            {
+                let (data, len) = frame_data;
+                let len = *len as usize;
+                out[written..written + len].copy_from_slice(&data[..len]);
+                written += len;
+            }
+            let result = Some(written);
+            *self = Self::default();
+            result
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fragment_and_reassemble_multi_frame() {
+        let data: [u8; 70] = core::array::from_fn(|i| i as u8);
+        let fragmenter = Fragmenter::<4>::new(7, &data).unwrap();
+        assert_eq!(fragmenter.frame_count(), 3);
+
+        let mut reassembler = Reassembler::<4>::new();
+        let mut out = [0u8; 4 * MAX_FRAME_DATA];
+        let mut result = None;
+        for (frame, len) in fragmenter {
+            result = reassembler.receive_frame(&frame[..len], &mut out);
+        }
+        let len = result.expect("message should be complete after the last frame");
+        assert_eq!(len, data.len());
+        assert_eq!(&out[..len], &data[..]);
+    }
+
+    #[test]
+    fn fragment_single_frame_message() {
+        let data = [1u8, 2, 3];
+        let fragmenter = Fragmenter::<4>::new(1, &data).unwrap();
+        assert_eq!(fragmenter.frame_count(), 1);
+    }
+
+    #[test]
+    fn fragmenter_rejects_oversized_message() {
+        let data = [0u8; MAX_FRAME_DATA * 3];
+        assert!(Fragmenter::<2>::new(1, &data).is_none());
+    }
+
+    #[test]
+    fn reassembler_is_empty_until_a_frame_arrives() {
+        let mut reassembler = Reassembler::<4>::new();
+        let mut out = [0u8; 4 * MAX_FRAME_DATA];
+        assert!(reassembler.is_empty());
+        reassembler.receive_frame(&[1, 0, 2, 0xAA], &mut out);
+        assert!(!reassembler.is_empty());
+    }
+
+    #[test]
+    fn reassembler_restarts_on_new_message_id() {
+        let mut reassembler = Reassembler::<4>::new();
+        let mut out = [0u8; 4 * MAX_FRAME_DATA];
+
+        // first frame of message 1 never completes
+        assert!(reassembler
+            .receive_frame(&[1, 0, 2, 0xAA], &mut out)
+            .is_none());
+
+        // a frame for a different message id discards the stale progress and the new
+        // message (a single frame) completes immediately
+        let len = reassembler
+            .receive_frame(&[2, 0, 1, 0xBB], &mut out)
+            .expect("single-frame message should complete immediately");
+        assert_eq!(&out[..len], &[0xBB]);
+    }
+
+    #[test]
+    fn reassembler_rejects_malformed_frames() {
+        let mut reassembler = Reassembler::<4>::new();
+        let mut out = [0u8; 4 * MAX_FRAME_DATA];
+
+        // total of 0 is never satisfiable
+        assert!(reassembler.receive_frame(&[1, 0, 0], &mut out).is_none());
+        // sequence index out of range for its own total
+        assert!(reassembler.receive_frame(&[1, 5, 2], &mut out).is_none());
+    }
+
+    #[test]
+    fn frames_can_arrive_out_of_order() {
+        let data: [u8; 50] = core::array::from_fn(|i| i as u8);
+        let mut fragmenter = Fragmenter::<4>::new(9, &data).unwrap();
+        let first = fragmenter.next().unwrap();
+        let second = fragmenter.next().unwrap();
+        assert!(fragmenter.next().is_none());
+
+        let mut reassembler = Reassembler::<4>::new();
+        let mut out = [0u8; 4 * MAX_FRAME_DATA];
+        assert!(reassembler
+            .receive_frame(&second.0[..second.1], &mut out)
+            .is_none());
+        let len = reassembler
+            .receive_frame(&first.0[..first.1], &mut out)
+            .expect("message should complete once both frames arrive");
+        assert_eq!(&out[..len], &data[..]);
+    }
+}
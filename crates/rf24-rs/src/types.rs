@@ -10,6 +10,14 @@ use bitfield_struct::bitfield;
 
 /// Power Amplifier level. The units dBm (decibel-milliwatts or dB<sub>mW</sub>)
 /// represents a logarithmic signal loss.
+///
+/// Each variant documents a distinct dBm rating for the nRF24L01 versus the Si24R1 clone
+/// with its LNA (Low Noise Amplifier) enabled or disabled. To select which of the Si24R1
+/// columns applies, toggle the LNA gain bit with
+/// [`RF24::set_lna()`](crate::radio::RF24::set_lna) (or
+/// [`RadioConfig::with_lna_enable()`](crate::radio::RadioConfig::with_lna_enable) when
+/// building a [`RadioConfig`](crate::radio::RadioConfig)); it has no effect on genuine
+/// nRF24L01 silicon.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PaLevel {
     /// | nRF24L01 | Si24R1 with<br>LNA Enabled | Si24R1 with<br>LNA Disabled |
@@ -213,6 +221,123 @@ impl Display for FifoState {
     }
 }
 
+/// The coarse operating state of the radio, as reported by [`RF24::current_state()`](crate::RF24::current_state).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RadioState {
+    /// The radio is powered down (asleep). This is the lowest power consumption state.
+    PowerDown,
+    /// The radio is powered up but neither transmitting nor receiving.
+    StandbyI,
+    /// The radio is powered up and actively transmitting (or about to) payloads.
+    TxMode,
+    /// The radio is powered up and actively listening for incoming payloads.
+    RxMode,
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(target_os = "none")]
+impl defmt::Format for RadioState {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            RadioState::PowerDown => defmt::write!(fmt, "PowerDown"),
+            RadioState::StandbyI => defmt::write!(fmt, "StandbyI"),
+            RadioState::TxMode => defmt::write!(fmt, "TxMode"),
+            RadioState::RxMode => defmt::write!(fmt, "RxMode"),
+        }
+    }
+}
+
+impl Display for RadioState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            RadioState::PowerDown => write!(f, "PowerDown"),
+            RadioState::StandbyI => write!(f, "StandbyI"),
+            RadioState::TxMode => write!(f, "TxMode"),
+            RadioState::RxMode => write!(f, "RxMode"),
+        }
+    }
+}
+
+/// Where the radio settles after completing an active transmission
+/// (see [`EsbPower::set_fallback_mode()`](crate::radio::prelude::EsbPower::set_fallback_mode)).
+///
+/// Rather than always settling into the same idle state after every transmission, this
+/// lets battery-sensitive designs trade the lower standby current of Standby-I against the
+/// faster re-transmit latency of Standby-II.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FallbackMode {
+    /// Settle in Standby-I (CE low) after a transmission completes.
+    ///
+    /// This is the lowest standby current (~26uA), but re-entering TX mode pays the
+    /// CE-high settling time again for the next transmission.
+    #[default]
+    StandbyI,
+    /// Settle in Standby-II (CE held high, TX FIFO empty) after a transmission completes.
+    ///
+    /// This allows sub-millisecond re-transmit latency, at a slightly higher standby
+    /// current than Standby-I.
+    StandbyII,
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(target_os = "none")]
+impl defmt::Format for FallbackMode {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            FallbackMode::StandbyI => defmt::write!(fmt, "StandbyI"),
+            FallbackMode::StandbyII => defmt::write!(fmt, "StandbyII"),
+        }
+    }
+}
+
+impl Display for FallbackMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            FallbackMode::StandbyI => write!(f, "StandbyI"),
+            FallbackMode::StandbyII => write!(f, "StandbyII"),
+        }
+    }
+}
+
+/// The byte order that [`RadioConfig`](crate::radio::RadioConfig) addresses are
+/// declared in, before being shifted out to the chip.
+///
+/// The nRF24 always shifts an address out LSByte-first over the air. If a user is
+/// porting address constants from a protocol peer (or documentation) that lists
+/// addresses MSByte-first, declaring them verbatim silently produces a mismatched
+/// pipe. This only affects the multi-byte pipe 0/pipe 1/TX addresses; the single-byte
+/// pipe 2 - 5 prefixes have no byte order to speak of.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ByteOrder {
+    /// Addresses are declared least-significant byte first, matching how the nRF24
+    /// shifts them out over the air. This is the default, preserving prior behavior.
+    #[default]
+    LsbFirst,
+    /// Addresses are declared most-significant byte first; each address is reversed
+    /// before being written to the chip.
+    MsbFirst,
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(target_os = "none")]
+impl defmt::Format for ByteOrder {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            ByteOrder::LsbFirst => defmt::write!(fmt, "LsbFirst"),
+            ByteOrder::MsbFirst => defmt::write!(fmt, "MsbFirst"),
+        }
+    }
+}
+
+impl Display for ByteOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ByteOrder::LsbFirst => write!(f, "LsbFirst"),
+            ByteOrder::MsbFirst => write!(f, "MsbFirst"),
+        }
+    }
+}
+
 /// A struct used to describe the different interrupt events.
 ///
 /// To instantiate an object with flags that have different values:
@@ -298,6 +423,25 @@ impl StatusFlags {
             Self::from_bits(new_val)
         }
     }
+
+    /// The RX pipe number (0-5) that received the available payload.
+    ///
+    /// This is only meaningful when [`StatusFlags::rx_dr()`] is `true`; a value of `7`
+    /// means the RX FIFO is empty.
+    pub fn pipe(&self) -> u8 {
+        self.rx_pipe()
+    }
+
+    /// Like [`StatusFlags::pipe()`], but `None` instead of `7` when the RX FIFO is empty.
+    pub fn rx_pipe_number(&self) -> Option<u8> {
+        let pipe = self.rx_pipe();
+        (pipe < 7).then_some(pipe)
+    }
+
+    /// Was the TX FIFO full at the time this [`StatusFlags`] was read?
+    pub fn tx_fifo_full(&self) -> bool {
+        self.tx_full()
+    }
 }
 
 impl Display for StatusFlags {
@@ -312,11 +456,193 @@ impl Display for StatusFlags {
     }
 }
 
+/// A snapshot of the radio's decoded register state.
+///
+/// This is the structured counterpart to
+/// [`RF24::print_details()`](crate::radio::RF24::print_details), returned by
+/// [`RF24::get_details()`](crate::radio::RF24::get_details) for use in logging,
+/// GUIs, automated diagnostics, or test assertions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RadioDetails {
+    /// Is the radio module a nRF24L01+ (as opposed to a non-plus variant)?
+    pub is_plus_variant: bool,
+    /// The radio's current RF channel, in range `[0, 125]`.
+    pub channel: u8,
+    /// The radio's current over-the-air data rate.
+    pub data_rate: DataRate,
+    /// The radio's current Power Amplifier level.
+    pub pa_level: PaLevel,
+    /// Is the radio's Low Noise Amplifier (LNA) feature currently enabled?
+    ///
+    /// See [`RF24::set_lna()`](crate::radio::RF24::set_lna).
+    pub lna_enabled: bool,
+    /// The radio's current CRC encoding scheme.
+    pub crc_length: CrcLength,
+    /// The number of bytes used for on-air addresses, in range `[2, 5]`.
+    pub address_length: u8,
+    /// The number of bytes used for statically sized payloads.
+    pub payload_length: u8,
+    /// A bit mask (pipes `0` - `5`) of which pipes have dynamic payloads enabled.
+    pub dynamic_payloads: u8,
+    /// A bit mask (pipes `0` - `5`) of which pipes have auto-ack enabled.
+    pub auto_ack: u8,
+    /// Are ACK payloads currently enabled (see
+    /// [`EsbAutoAck::set_ack_payloads()`](crate::radio::prelude::EsbAutoAck::set_ack_payloads))?
+    pub ack_payloads_enabled: bool,
+    /// Is the `NO_ACK` flag (see
+    /// [`EsbAutoAck::allow_ask_no_ack()`](crate::radio::prelude::EsbAutoAck::allow_ask_no_ack))
+    /// honored for payloads that request it?
+    pub ask_no_ack_enabled: bool,
+    /// A bit mask (pipes `0` - `5`) of which RX pipes are currently open.
+    pub open_rx_pipes: u8,
+    /// Is the radio currently powered up?
+    pub is_powered: bool,
+    /// Is the radio currently configured for RX mode (as opposed to TX mode)?
+    pub is_rx: bool,
+    /// The address used for transmissions (see [`RF24::open_tx_pipe()`](crate::radio::RF24::open_tx_pipe)).
+    pub tx_address: [u8; 5],
+    /// The addresses bound to RX pipes `0` - `5`.
+    ///
+    /// Per the radio's hardware, pipes `2` - `5` only store their own least
+    /// significant byte; the remaining 4 bytes are shared with pipe `1`
+    /// (already reflected in these addresses).
+    pub rx_addresses: [[u8; 5]; 6],
+    /// The most recently cached IRQ status flags (the latched bits from the `STATUS`
+    /// register).
+    pub status_flags: StatusFlags,
+    /// Is the "RX Data Ready" IRQ event currently unmasked (enabled)?
+    pub irq_rx_dr_enabled: bool,
+    /// Is the "TX Data Sent" IRQ event currently unmasked (enabled)?
+    pub irq_tx_ds_enabled: bool,
+    /// Is the "TX Data Fail" IRQ event currently unmasked (enabled)?
+    pub irq_tx_df_enabled: bool,
+    /// The current state of the TX FIFO.
+    pub tx_fifo: FifoState,
+    /// The current state of the RX FIFO.
+    pub rx_fifo: FifoState,
+    /// Will the radio re-transmit the last TX FIFO payload the next time it enters TX mode
+    /// (see the `REUSE_TX_PL` command)?
+    pub reuse_tx: bool,
+    /// The delay (in microseconds) awaited after transmitting, allowing time for the
+    /// radio to receive (and this driver to wait for) an ACK packet.
+    ///
+    /// See [`RF24::tx_delay`](crate::radio::RF24::tx_delay).
+    pub tx_delay: u32,
+    /// The count of lost packets (PLOS) since the last time the radio's channel was set.
+    ///
+    /// See [`EsbRadio::get_lost_packets()`](crate::radio::prelude::EsbRadio::get_lost_packets).
+    pub packets_lost: u8,
+    /// The Auto-Retry Count (ARC) about the previous transmission.
+    ///
+    /// See [`EsbRadio::get_last_arc()`](crate::radio::prelude::EsbRadio::get_last_arc).
+    pub retry_count: u8,
+}
+
+/// The result of a software-level retried transmission.
+///
+/// Returned by [`RF24::send_with_retries()`](crate::radio::RF24::send_with_retries).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SendOutcome {
+    /// Was the payload eventually acknowledged (or was auto-ack disabled for it)?
+    pub acked: bool,
+    /// How many calls to [`EsbRadio::send()`](crate::radio::prelude::EsbRadio::send) were made,
+    /// including the final (successful or not) attempt.
+    pub attempts: u8,
+}
+
+/// A cumulative accumulator of runtime radio activity, for budgeting battery life
+/// without external instrumentation.
+///
+/// This driver has no notion of wall-clock time (it is `no_std` and blocking), so this
+/// does not sample the clock on its own. Instead, feed it from your application's own
+/// timer: call [`RadioStats::record_state()`] with the duration actually spent in a
+/// [`RadioState`] each time the radio transitions, and
+/// [`RadioStats::record_send()`]/[`RadioStats::record_retransmit()`] around each
+/// [`EsbRadio::send()`](crate::radio::prelude::EsbRadio::send)/
+/// [`EsbRadio::resend()`](crate::radio::prelude::EsbRadio::resend) attempt. Then use
+/// [`RadioStats::estimated_charge_uah()`] to convert the accumulated dwell times into an
+/// estimated charge consumption, using the current draw figures documented on
+/// [`EsbPower::power_down()`](crate::radio::prelude::EsbPower::power_down).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RadioStats {
+    power_down_us: u64,
+    standby_us: u64,
+    tx_us: u64,
+    rx_us: u64,
+    /// The number of completed [`EsbRadio::send()`](crate::radio::prelude::EsbRadio::send)
+    /// attempts recorded via [`RadioStats::record_send()`].
+    pub packets_sent: u32,
+    /// The number of [`RadioStats::record_send()`] calls reporting an unacknowledged
+    /// (failed) attempt.
+    pub packets_failed: u32,
+    /// The number of retransmissions recorded via [`RadioStats::record_retransmit()`].
+    pub retransmits: u32,
+}
+
+impl RadioStats {
+    /// Add `duration_us` microseconds of dwell time to the bucket for `state`.
+    pub fn record_state(&mut self, state: RadioState, duration_us: u32) {
+        let bucket = match state {
+            RadioState::PowerDown => &mut self.power_down_us,
+            RadioState::StandbyI => &mut self.standby_us,
+            RadioState::TxMode => &mut self.tx_us,
+            RadioState::RxMode => &mut self.rx_us,
+        };
+        *bucket += duration_us as u64;
+    }
+
+    /// Record the outcome of an [`EsbRadio::send()`](crate::radio::prelude::EsbRadio::send)
+    /// attempt, incrementing [`RadioStats::packets_sent`] (and
+    /// [`RadioStats::packets_failed`] if `acked` is `false`).
+    pub fn record_send(&mut self, acked: bool) {
+        self.packets_sent += 1;
+        if !acked {
+            self.packets_failed += 1;
+        }
+    }
+
+    /// Record a single hardware or software-level retransmission, incrementing
+    /// [`RadioStats::retransmits`].
+    pub fn record_retransmit(&mut self) {
+        self.retransmits += 1;
+    }
+
+    /// Estimate the charge (in microamp-hours) consumed by the recorded dwell times,
+    /// using the current draw figures documented on
+    /// [`EsbPower::power_down()`](crate::radio::prelude::EsbPower::power_down) and scaling
+    /// the TX figure for the given `pa_level`.
+    ///
+    /// This is a coarse estimate: it assumes a non-PA/LNA module and constant current
+    /// draw throughout each state's dwell time.
+    pub fn estimated_charge_uah(&self, pa_level: PaLevel) -> u64 {
+        const POWER_DOWN_NA: u64 = 900;
+        const STANDBY_NA: u64 = 26_000;
+        const RX_NA: u64 = 13_500_000;
+        let tx_na: u64 = match pa_level {
+            PaLevel::Min => 7_000_000,
+            PaLevel::Low => 7_500_000,
+            PaLevel::High => 9_000_000,
+            PaLevel::Max => 11_500_000,
+        };
+
+        let total_na_us = POWER_DOWN_NA * self.power_down_us
+            + STANDBY_NA * self.standby_us
+            + RX_NA * self.rx_us
+            + tx_na * self.tx_us;
+
+        // 1 uAh = 1 uA for 3_600_000_000 us, and 1 nA-us = 0.001 uA-us.
+        total_na_us / 3_600_000_000_000
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::StatusFlags;
 
-    use super::{CrcLength, DataRate, FifoState, PaLevel};
+    use super::{CrcLength, DataRate, FifoState, PaLevel, RadioStats, RadioState};
     extern crate std;
     use std::{format, string::String};
 
@@ -434,4 +760,50 @@ mod test {
     fn flags_0x20() {
         set_flags(false, true, false);
     }
+
+    #[test]
+    fn rx_pipe_number_empty() {
+        let flags = StatusFlags::from_bits(0x0E);
+        assert_eq!(flags.pipe(), 7);
+        assert_eq!(flags.rx_pipe_number(), None);
+    }
+
+    #[test]
+    fn rx_pipe_number_some() {
+        let flags = StatusFlags::from_bits(0x02);
+        assert_eq!(flags.pipe(), 1);
+        assert_eq!(flags.rx_pipe_number(), Some(1));
+    }
+
+    #[test]
+    fn tx_fifo_full() {
+        assert!(StatusFlags::from_bits(1).tx_fifo_full());
+        assert!(!StatusFlags::from_bits(0).tx_fifo_full());
+    }
+
+    #[test]
+    fn radio_stats_packet_counters() {
+        let mut stats = RadioStats::default();
+        stats.record_send(true);
+        stats.record_send(false);
+        stats.record_retransmit();
+        stats.record_retransmit();
+        assert_eq!(stats.packets_sent, 2);
+        assert_eq!(stats.packets_failed, 1);
+        assert_eq!(stats.retransmits, 2);
+    }
+
+    #[test]
+    fn radio_stats_estimated_charge() {
+        let mut stats = RadioStats::default();
+        // 1 hour (in microseconds) spent receiving
+        stats.record_state(RadioState::RxMode, 3_600_000_000);
+        assert_eq!(stats.estimated_charge_uah(PaLevel::Max), 13_500);
+    }
+
+    #[test]
+    fn radio_stats_default_is_zero() {
+        let stats = RadioStats::default();
+        assert_eq!(stats.estimated_charge_uah(PaLevel::Max), 0);
+    }
 }
@@ -0,0 +1,230 @@
+//! A composable builder for packing multiple GAP AD (Advertising Data) structures into a
+//! single advertisement payload, for use with [`FakeBle::send()`](crate::radio::FakeBle::send).
+//!
+//! This mirrors the structured advertise-data model used by full BLE stacks, so callers
+//! don't have to hand-format the length/type/data layout documented on
+//! [`FakeBle::send()`](crate::radio::FakeBle::send) themselves.
+
+use crate::radio::FakeBle;
+
+/// The number of bytes available in an advertisement payload, when
+/// [`FakeBle::show_pa_level`](crate::radio::FakeBle::show_pa_level) is disabled and no
+/// device name is set (see [`FakeBle::len_available()`](crate::radio::FakeBle::len_available)).
+const MAX_LEN: usize = 18;
+
+/// An error returned by [`AdvertisementBuilder`] when an AD structure would not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvertisementError {
+    /// Appending this AD structure would exceed the 18-byte budget shared by all AD
+    /// structures in the payload.
+    BufferFull,
+    /// The built payload, combined with the given [`FakeBle`]'s configured device name and
+    /// [`FakeBle::show_pa_level`](crate::radio::FakeBle::show_pa_level) setting, would exceed
+    /// the advertisement's 18-byte budget (see
+    /// [`FakeBle::len_available()`](crate::radio::FakeBle::len_available)).
+    ExceedsDeviceBudget,
+}
+
+/// Builds a single advertisement payload out of one or more typed AD structures.
+///
+/// Append structures with [`Self::add_flags()`], [`Self::add_name()`],
+/// [`Self::add_manufacturer_data()`], [`Self::add_service_data()`],
+/// [`Self::add_service_uuids16()`], or [`Self::add_raw()`] (for a buffer already produced by
+/// one of [`crate::services`]'s built-in service types), then pass [`Self::build()`]'s output
+/// to [`FakeBle::send()`].
+#[derive(Debug, Clone, Copy)]
+pub struct AdvertisementBuilder {
+    buf: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl Default for AdvertisementBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdvertisementBuilder {
+    /// Create an empty [`AdvertisementBuilder`].
+    pub fn new() -> Self {
+        Self {
+            buf: [0u8; MAX_LEN],
+            len: 0,
+        }
+    }
+
+    /// How many bytes are still free for additional AD structures.
+    pub fn remaining(&self) -> usize {
+        MAX_LEN - self.len
+    }
+
+    /// Append a raw length-prefixed AD structure (as produced by, e.g.,
+    /// [`AsBuffer::buffer()`](crate::services::prelude::AsBuffer::buffer) on one of
+    /// [`crate::services`]'s built-in service types).
+    pub fn add_raw(&mut self, ad_structure: &[u8]) -> Result<&mut Self, AdvertisementError> {
+        if ad_structure.len() > self.remaining() {
+            return Err(AdvertisementError::BufferFull);
+        }
+        self.buf[self.len..self.len + ad_structure.len()].copy_from_slice(ad_structure);
+        self.len += ad_structure.len();
+        Ok(self)
+    }
+
+    /// Append an AD structure of the given `ad_type`, built from the length byte, type byte,
+    /// and `data`.
+    fn append(&mut self, ad_type: u8, data: &[u8]) -> Result<&mut Self, AdvertisementError> {
+        let total = data.len() + 2;
+        if total > self.remaining() {
+            return Err(AdvertisementError::BufferFull);
+        }
+        let start = self.len;
+        self.buf[start] = (data.len() + 1) as u8;
+        self.buf[start + 1] = ad_type;
+        self.buf[start + 2..start + 2 + data.len()].copy_from_slice(data);
+        self.len += total;
+        Ok(self)
+    }
+
+    /// Append a Flags AD structure (`0x01`).
+    pub fn add_flags(&mut self, flags: u8) -> Result<&mut Self, AdvertisementError> {
+        self.append(0x01, &[flags])
+    }
+
+    /// Append a Local Name AD structure: Complete (`0x09`) if `complete` is `true`,
+    /// otherwise Shortened (`0x08`).
+    pub fn add_name(
+        &mut self,
+        name: &str,
+        complete: bool,
+    ) -> Result<&mut Self, AdvertisementError> {
+        let ad_type = if complete { 0x09 } else { 0x08 };
+        self.append(ad_type, name.as_bytes())
+    }
+
+    /// Append a Manufacturer Specific Data AD structure (`0xFF`): a 2-byte little-endian
+    /// company identifier followed by `data`.
+    pub fn add_manufacturer_data(
+        &mut self,
+        company_id: u16,
+        data: &[u8],
+    ) -> Result<&mut Self, AdvertisementError> {
+        let mut payload = [0u8; MAX_LEN];
+        let len = data.len().min(MAX_LEN - 2);
+        payload[0..2].copy_from_slice(&company_id.to_le_bytes());
+        payload[2..2 + len].copy_from_slice(&data[0..len]);
+        self.append(0xFF, &payload[0..2 + len])
+    }
+
+    /// Append a Service Data AD structure (`0x16`): a 16-bit little-endian service UUID
+    /// followed by arbitrary service-specific `data`.
+    pub fn add_service_data(
+        &mut self,
+        uuid: u16,
+        data: &[u8],
+    ) -> Result<&mut Self, AdvertisementError> {
+        let mut payload = [0u8; MAX_LEN];
+        let len = data.len().min(MAX_LEN - 2);
+        payload[0..2].copy_from_slice(&uuid.to_le_bytes());
+        payload[2..2 + len].copy_from_slice(&data[0..len]);
+        self.append(0x16, &payload[0..2 + len])
+    }
+
+    /// Append an Incomplete (`0x02`) or Complete (`0x03`) List of 16-bit Service UUIDs.
+    pub fn add_service_uuids16(
+        &mut self,
+        uuids: &[u16],
+        complete: bool,
+    ) -> Result<&mut Self, AdvertisementError> {
+        let ad_type = if complete { 0x03 } else { 0x02 };
+        let mut payload = [0u8; MAX_LEN];
+        let count = uuids.len().min(payload.len() / 2);
+        for (i, uuid) in uuids[0..count].iter().enumerate() {
+            payload[i * 2..i * 2 + 2].copy_from_slice(&uuid.to_le_bytes());
+        }
+        self.append(ad_type, &payload[0..count * 2])
+    }
+
+    /// Emit the packed payload built so far, ready for [`FakeBle::send()`].
+    pub fn build(&self) -> &[u8] {
+        &self.buf[0..self.len]
+    }
+
+    /// Like [`Self::build()`], but also validates that the payload fits within `ble`'s
+    /// 18-byte advertisement budget once its device name and
+    /// [`FakeBle::show_pa_level`](crate::radio::FakeBle::show_pa_level) setting are
+    /// accounted for (see [`FakeBle::len_available()`](crate::radio::FakeBle::len_available)).
+    pub fn build_for(&self, ble: &FakeBle) -> Result<&[u8], AdvertisementError> {
+        let payload = self.build();
+        if ble.len_available(payload) < 0 {
+            return Err(AdvertisementError::ExceedsDeviceBudget);
+        }
+        Ok(payload)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AdvertisementBuilder, AdvertisementError};
+    use crate::radio::FakeBle;
+
+    #[test]
+    fn flags_and_name() {
+        let mut builder = AdvertisementBuilder::new();
+        builder.add_flags(0x06).unwrap();
+        builder.add_name("nRF24", true).unwrap();
+        assert_eq!(
+            builder.build(),
+            &[0x02, 0x01, 0x06, 0x06, 0x09, b'n', b'R', b'F', b'2', b'4']
+        );
+    }
+
+    #[test]
+    fn manufacturer_data() {
+        let mut builder = AdvertisementBuilder::new();
+        builder
+            .add_manufacturer_data(0x1234, &[0xAA, 0xBB])
+            .unwrap();
+        assert_eq!(builder.build(), &[0x05, 0xFF, 0x34, 0x12, 0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn service_data() {
+        let mut builder = AdvertisementBuilder::new();
+        builder.add_service_data(0x180F, &[0x55]).unwrap();
+        assert_eq!(builder.build(), &[0x04, 0x16, 0x0F, 0x18, 0x55]);
+    }
+
+    #[test]
+    fn service_uuids16() {
+        let mut builder = AdvertisementBuilder::new();
+        builder
+            .add_service_uuids16(&[0x180F, 0x1809], false)
+            .unwrap();
+        assert_eq!(
+            builder.build(),
+            &[0x05, 0x02, 0x0F, 0x18, 0x09, 0x18]
+        );
+    }
+
+    #[test]
+    fn buffer_full() {
+        let mut builder = AdvertisementBuilder::new();
+        assert_eq!(
+            builder.add_name("this name is definitely too long to fit", true),
+            Err(AdvertisementError::BufferFull)
+        );
+    }
+
+    #[test]
+    fn exceeds_device_budget() {
+        let mut ble = FakeBle::default();
+        ble.set_name("0123456789");
+
+        let mut builder = AdvertisementBuilder::new();
+        builder.add_flags(0x06).unwrap();
+        assert_eq!(
+            builder.build_for(&ble),
+            Err(AdvertisementError::ExceedsDeviceBudget)
+        );
+    }
+}
@@ -39,7 +39,16 @@
 //!    triggered when the auto-ack feature is disabled.
 
 mod radio;
-pub use radio::{ble_config, BleChannels, FakeBle, BLE_CHANNEL};
+pub use radio::{
+    ble_config, crc24, sniffer_config, whiten, BleChannels, FakeBle, ADV_ACCESS_ADDRESS,
+    BLE_CHANNEL,
+};
+
+pub mod advertisement;
+pub use advertisement::{AdvertisementBuilder, AdvertisementError};
+
+pub mod scheduler;
+pub use scheduler::{AdvertiseMode, AdvertiseScheduler};
 
 pub mod data_manipulation;
 
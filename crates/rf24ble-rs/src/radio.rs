@@ -1,14 +1,20 @@
 use crate::{
-    data_manipulation::{crc24_ble, reverse_bits, whiten},
+    data_manipulation::{crc24_ble, reverse_bits, whiten as whiten_buf},
     services::BlePayload,
 };
+#[cfg(not(feature = "std"))]
+use crate::services::{RawAdStructure, MAX_AD_STRUCTURES};
+#[cfg(feature = "std")]
+use crate::services::RawAdStructure;
+#[cfg(feature = "std")]
+extern crate std;
 use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
 use rf24::{
     radio::{
-        prelude::{EsbChannel, EsbPaLevel, EsbRadio},
+        prelude::{EsbChannel, EsbFifo, EsbInit, EsbPaLevel, EsbRadio},
         Nrf24Error, RadioConfig, RF24,
     },
-    CrcLength, PaLevel,
+    CrcLength, DataRate, PaLevel,
 };
 
 /// The supported channels used amongst BLE devices.
@@ -48,6 +54,61 @@ impl BleChannels {
 /// The only address usable in BLE context.
 const BLE_ADDRESS: [u8; 4] = [0x71, 0x91, 0x7d, 0x6b];
 
+/// The real BLE advertising channels' Access Address (`0x8E89BED6`), as it is
+/// transmitted over the air: least-significant byte first.
+///
+/// Unlike [`BLE_ADDRESS`] (a proprietary address only other [`FakeBle`] devices use),
+/// this is the address every BLE peripheral's advertisements are sent to, so listening
+/// on it (see [`sniffer_config()`]) picks up advertisements from any nearby BLE device,
+/// not just other [`FakeBle`] devices.
+pub const ADV_ACCESS_ADDRESS: [u8; 4] = [0xD6, 0xBE, 0x89, 0x8E];
+
+/// Returns a [`RadioConfig`] object tailored for passively sniffing real BLE
+/// advertising packets.
+///
+/// Unlike [`ble_config()`] (which only interoperates with other [`FakeBle`] devices via
+/// [`BLE_ADDRESS`]), this listens on [`ADV_ACCESS_ADDRESS`], the address every BLE
+/// peripheral actually advertises on. The nRF24L01's own CRC is still disabled (BLE's
+/// CRC24 is verified separately, after de-whitening, by [`FakeBle::read()`]).
+///
+/// This configuration complies with inherent [Limitations](index.html#limitations).
+/// Use [`FakeBle::as_rx()`] to apply it and put the radio into RX mode in one call.
+pub fn sniffer_config() -> RadioConfig {
+    RadioConfig::default()
+        .with_channel(BLE_CHANNEL[0])
+        .with_crc_length(CrcLength::Disabled)
+        .with_auto_ack(0)
+        .with_auto_retries(0, 0)
+        .with_address_length(4)
+        .with_data_rate(DataRate::Mbps1)
+        .with_rx_address(1, &ADV_ACCESS_ADDRESS)
+}
+
+/// Whiten (or de-whiten) `data` as it would be transmitted/received on the given BLE `channel`.
+///
+/// This is a convenience wrapper around
+/// [`data_manipulation::whiten()`](crate::data_manipulation::whiten) for users building
+/// custom/proprietary PDUs (eg. the `0xFF` manufacturer-specific path documented on
+/// [`FakeBle::send()`]) who need to validate or construct packets outside the services
+/// already provided by this crate. The whitening LFSR's register is seeded the same way
+/// [`FakeBle::make_payload()`] does it: from `channel`'s index into [`BLE_CHANNEL`] (offset
+/// by 37), with bit 6 forced to 1.
+pub fn whiten(data: &mut [u8], channel: u8) {
+    let coefficient = (BleChannels::index_of(channel).unwrap_or_default() + 37) | 0x40;
+    whiten_buf(data, coefficient as u8);
+}
+
+/// Calculate a 24 bit CRC checksum for `data`, as used by the BLE specification.
+///
+/// This is a convenience re-export of
+/// [`data_manipulation::crc24_ble()`](crate::data_manipulation::crc24_ble) for users building
+/// custom/proprietary PDUs (eg. the `0xFF` manufacturer-specific path documented on
+/// [`FakeBle::send()`]). The returned bytes shall be appended to the transmitted payload
+/// *before* applying [`whiten()`].
+pub fn crc24(data: &[u8]) -> [u8; 3] {
+    crc24_ble(data)
+}
+
 /// Returns a [`RadioConfig`] object tailored for OTA compatibility with
 /// BLE specifications.
 ///
@@ -63,6 +124,26 @@ pub fn ble_config() -> RadioConfig {
         .with_tx_address(&BLE_ADDRESS)
 }
 
+/// The PDU type advertised in byte 0 of a BLE advertising-channel PDU header.
+///
+/// This occupies the low 4 bits of the header's first byte; the remaining bits are
+/// reserved (and, for [`FakeBle`], used for the TxAdd flag in bit 6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvType {
+    /// `ADV_IND`: connectable and scannable undirected advertising.
+    ConnectableUndirected = 0x0,
+    /// `ADV_NONCONN_IND`: non-connectable and non-scannable undirected advertising.
+    NonConnectableUndirected = 0x2,
+    /// `ADV_SCAN_IND`: scannable but non-connectable undirected advertising.
+    ScannableUndirected = 0x6,
+}
+
+impl Default for AdvType {
+    fn default() -> Self {
+        AdvType::NonConnectableUndirected
+    }
+}
+
 /// A struct that implements BLE functionality.
 ///
 /// This implementation is subject to [Limitations](index.html#limitations).
@@ -92,6 +173,12 @@ pub struct FakeBle {
     /// A MAC address is required by BLE specifications.
     /// Use this attribute to uniquely identify the BLE device.
     pub mac_address: [u8; 6],
+    /// The PDU type advertised in the advertising-channel PDU header.
+    pub adv_type: AdvType,
+    /// Whether [`FakeBle::mac_address`] is a random address (as opposed to a public one).
+    ///
+    /// This sets the TxAdd bit (bit 6) of the advertising-channel PDU header.
+    pub random_address: bool,
 }
 
 impl Default for FakeBle {
@@ -101,26 +188,50 @@ impl Default for FakeBle {
 }
 
 impl FakeBle {
-    const DEVICE_FLAGS: u8 = 0x42;
     const PROFILE_FLAGS: [u8; 3] = [2, 1, 5];
 
     /// Instantiate a BLE device using a given instance of [`RF24`].
     ///
     /// The `radio` object is consumed because altering the radio's setting will
     /// instigate unexpected behavior.
+    ///
+    /// This defaults [`FakeBle::mac_address`] to a fixed, non-random value. Use
+    /// [`FakeBle::with_rng()`] (or [`FakeBle::randomize_mac()`]) instead if advertisers
+    /// need to avoid colliding on the same identifier.
     pub fn new() -> Self {
         let mut mac_address = [0u8; 6];
-
-        // TODO: randomize this default MAC address.
         mac_address.copy_from_slice(b"nRF24L");
 
         Self {
             name: [0u8; 12],
             show_pa_level: false,
             mac_address,
+            adv_type: AdvType::default(),
+            random_address: true,
         }
     }
 
+    /// Instantiate a BLE device with a random [`FakeBle::mac_address`], generated via
+    /// [`FakeBle::randomize_mac()`].
+    #[cfg(feature = "rand")]
+    pub fn with_rng<R: rand_core::RngCore>(rng: &mut R) -> Self {
+        let mut result = Self::new();
+        result.randomize_mac(rng);
+        result
+    }
+
+    /// Fill [`FakeBle::mac_address`] with random bytes from `rng`, then force it into a
+    /// valid BLE *static* random address by setting the two most-significant bits of the
+    /// most-significant byte (`mac_address[5]`) to `0b11`, per the BLE Core
+    /// Specification's device-address-generation rules. This also sets
+    /// [`FakeBle::random_address`] so the TxAdd bit is reported correctly.
+    #[cfg(feature = "rand")]
+    pub fn randomize_mac<R: rand_core::RngCore>(&mut self, rng: &mut R) {
+        rng.fill_bytes(&mut self.mac_address);
+        self.mac_address[5] |= 0xC0;
+        self.random_address = true;
+    }
+
     /// Set the BLE device's name for inclusion in advertisements.
     ///
     /// Setting a BLE device name will occupy more bytes from the
@@ -180,6 +291,8 @@ impl FakeBle {
     ///
     /// Use this function after [`FakeBle::send()`] to comply with BLE specifications.
     /// This is not required, but it is recommended to avoid bandwidth pollution.
+    ///
+    /// See [`FakeBle::hop_channel_async()`] for the `.await`-based counterpart.
     pub fn hop_channel<SPI, DO, DELAY>(
         &self,
         radio: &mut RF24<SPI, DO, DELAY>,
@@ -213,7 +326,7 @@ impl FakeBle {
 
         let mut tx_queue = [0; 32];
         // tx_queue[11..29] is available for user data.
-        tx_queue[0] = Self::DEVICE_FLAGS;
+        tx_queue[0] = self.adv_type as u8 | if self.random_address { 0x40 } else { 0 };
         // tx_queue[1] is for the total payload size excluding the following data:
         // - GATT profile flags (tx_queue[0]) at beginning
         // - payload size at tx_queue[1]
@@ -256,16 +369,128 @@ impl FakeBle {
         offset += 3;
 
         let coefficient = (BleChannels::index_of(channel).unwrap_or_default() + 37) | 0x40;
-        whiten(&mut tx_queue[0..offset], coefficient as u8);
+        whiten_buf(&mut tx_queue[0..offset], coefficient as u8);
 
         reverse_bits(&mut tx_queue[0..offset]);
         Some(tx_queue)
     }
 
+    /// Build whitened payloads for all three of [`BLE_CHANNEL`] at once.
+    ///
+    /// Real BLE advertisers broadcast the same PDU on all three primary advertising
+    /// channels (since a scanner may only be listening on one of them at any given
+    /// moment), re-whitening the PDU for each channel because the whitening LFSR is
+    /// seeded from the channel index. This is a helper for callers that want to
+    /// replicate that behavior: given a `buf` already formatted per [`FakeBle::send()`],
+    /// it returns the three channel-specific whitened buffers paired with the RF channel
+    /// number ([`BLE_CHANNEL`]) each one must be transmitted on.
+    ///
+    /// Returns [`None`] under the same conditions as [`FakeBle::make_payload()`] (i.e. the
+    /// resulting payload would exceed 32 bytes).
+    ///
+    /// See also [`FakeBle::send_all()`], which drives the radio through all three
+    /// channels in one call.
+    pub fn make_payloads(
+        &self,
+        buf: &[u8],
+        pa_level: Option<PaLevel>,
+    ) -> Option<[([u8; 32], u8); 3]> {
+        let mut result = [([0u8; 32], 0u8); 3];
+        for (index, channel) in BLE_CHANNEL.into_iter().enumerate() {
+            result[index] = (self.make_payload(buf, pa_level, channel)?, channel);
+        }
+        Some(result)
+    }
+
+    /// Broadcast a BLE advertisement on all three of [`BLE_CHANNEL`], in order.
+    ///
+    /// This is a convenience wrapper around [`FakeBle::make_payloads()`] that also sets
+    /// the radio's channel and transmits for each one, significantly improving the odds
+    /// that a scanner catches the broadcast (since real BLE scanners themselves hop
+    /// between the primary advertising channels). The radio is left tuned to the last
+    /// channel in [`BLE_CHANNEL`] afterward.
+    ///
+    /// Returns `true` only if every channel's transmission succeeded.
+    pub fn send_all<SPI, DO, DELAY>(
+        &self,
+        radio: &mut RF24<SPI, DO, DELAY>,
+        buf: &[u8],
+    ) -> Result<bool, Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+    {
+        let pa_level = if self.show_pa_level {
+            Some(radio.get_pa_level()?)
+        } else {
+            None
+        };
+        let Some(payloads) = self.make_payloads(buf, pa_level) else {
+            return Ok(false);
+        };
+        let mut all_sent = true;
+        for (payload, channel) in payloads {
+            radio.set_channel(channel)?;
+            // Disregarding any hardware error, `RF24::send()` should
+            // always return `Ok(true)` because auto-ack is off.
+            all_sent &= radio.send(&payload, false)?;
+        }
+        Ok(all_sent)
+    }
+
+    /// Broadcast a complete advertising event: [`FakeBle::send_all()`], but with a
+    /// settling delay after every channel hop and the radio's original channel restored
+    /// afterward.
+    ///
+    /// Real BLE advertisers pause briefly after hopping channels (the radio's PLL needs
+    /// to re-lock) and return to their prior operating channel once an advertising
+    /// event (all three of [`BLE_CHANNEL`]) completes, instead of staying parked on the
+    /// last channel transmitted on. `settle_delay_us` (applied via `delay`) is used for
+    /// both: after each of the three hops, and after the final restore.
+    ///
+    /// Returns `true` only if every channel's transmission succeeded.
+    pub fn broadcast_all<SPI, DO, DELAY, WAIT>(
+        &self,
+        radio: &mut RF24<SPI, DO, DELAY>,
+        delay: &mut WAIT,
+        buf: &[u8],
+        settle_delay_us: u32,
+    ) -> Result<bool, Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+        WAIT: DelayNs,
+    {
+        let original_channel = radio.get_channel()?;
+        let pa_level = if self.show_pa_level {
+            Some(radio.get_pa_level()?)
+        } else {
+            None
+        };
+        let Some(payloads) = self.make_payloads(buf, pa_level) else {
+            return Ok(false);
+        };
+        let mut all_sent = true;
+        for (payload, channel) in payloads {
+            radio.set_channel(channel)?;
+            delay.delay_us(settle_delay_us);
+            // Disregarding any hardware error, `RF24::send()` should
+            // always return `Ok(true)` because auto-ack is off.
+            all_sent &= radio.send(&payload, false)?;
+        }
+        radio.set_channel(original_channel)?;
+        delay.delay_us(settle_delay_us);
+        Ok(all_sent)
+    }
+
     /// Send a BLE advertisement
     ///
     /// The `buf` parameter takes a buffer that has been already formatted for
-    /// BLE specifications.
+    /// BLE specifications. Use [`AdvertisementBuilder`](crate::AdvertisementBuilder) to
+    /// compose `buf` out of multiple AD structures instead of hand-formatting the layout
+    /// described below.
     ///
     /// See our convenient API to
     /// - advertise a Battery's remaining change level: [`BatteryService`](struct@crate::services::BatteryService)
@@ -280,6 +505,8 @@ impl FakeBle {
     /// | `0` | `n - 1` |
     /// | `1` | `0xFF`  |
     /// | `2 ... n - 1` | custom data |
+    ///
+    /// See [`FakeBle::send_async()`] for the `.await`-based counterpart.
     pub fn send<SPI, DO, DELAY>(
         &self,
         radio: &mut RF24<SPI, DO, DELAY>,
@@ -306,6 +533,55 @@ impl FakeBle {
         Ok(false)
     }
 
+    /// Advertise `data` under the given `name` in one call, then hop to the next
+    /// [`BLE_CHANNEL`].
+    ///
+    /// This is a convenience wrapper combining [`FakeBle::set_name()`],
+    /// [`FakeBle::send()`], and [`FakeBle::hop_channel()`] for callers who just want to
+    /// broadcast a single named beacon packet without managing those steps by hand. `name`
+    /// replaces whatever name was previously set (an empty `name` clears it). Repeated
+    /// calls naturally round-robin across all of [`BLE_CHANNEL`], one per call.
+    pub fn advertise<SPI, DO, DELAY>(
+        &mut self,
+        radio: &mut RF24<SPI, DO, DELAY>,
+        name: &str,
+        data: &[u8],
+    ) -> Result<bool, Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+    {
+        self.name = [0u8; 12];
+        if !name.is_empty() {
+            self.set_name(name);
+        }
+        let sent = self.send(radio, data)?;
+        self.hop_channel(radio)?;
+        Ok(sent)
+    }
+
+    /// Configure `radio` for passively sniffing real BLE advertising packets (applying
+    /// [`sniffer_config()`]), then put it into RX mode.
+    ///
+    /// Afterward, use [`EsbFifo::available()`](fn@rf24::radio::prelude::EsbFifo::available)
+    /// and [`FakeBle::read()`] (or [`FakeBle::read_all()`]) to pick up advertisements from
+    /// any nearby BLE device, not just other [`FakeBle`] devices. Pair this with
+    /// [`FakeBle::hop_channel()`] to cycle across all of [`BLE_CHANNEL`], the same way a
+    /// real BLE scanner does.
+    pub fn as_rx<SPI, DO, DELAY>(
+        &self,
+        radio: &mut RF24<SPI, DO, DELAY>,
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+    {
+        radio.with_config(&sniffer_config())?;
+        radio.as_rx()
+    }
+
     /// Read the first available payload from the radio's RX FIFO
     /// and decode it into a [`BlePayload`].
     ///
@@ -322,6 +598,8 @@ impl FakeBle {
     ///
     /// If the payload was somehow malformed or incomplete,
     /// then this function returns a [`None`] value.
+    ///
+    /// See [`FakeBle::read_async()`] for the `.await`-based counterpart.
     pub fn read<SPI, DO, DELAY>(
         &self,
         radio: &mut RF24<SPI, DO, DELAY>,
@@ -336,6 +614,225 @@ impl FakeBle {
         let channel = radio.get_channel()?;
         Ok(BlePayload::from_bytes(&mut buf, channel))
     }
+
+    /// Read the first available payload from the radio's RX FIFO and decode it into the
+    /// advertiser's MAC address plus its raw sequence of GAP AD structures, via
+    /// [`BlePayload::decode_ad_structures()`](crate::services::BlePayload::decode_ad_structures).
+    ///
+    /// Unlike [`FakeBle::read()`] (which only recognizes this crate's own built-in
+    /// services), this reports every AD structure in the payload verbatim, so third-party
+    /// advertisers aren't silently dropped.
+    ///
+    /// See [`FakeBle::read()`] for the same channel/malformed-payload caveats.
+    #[cfg(feature = "std")]
+    pub fn read_all<SPI, DO, DELAY>(
+        &self,
+        radio: &mut RF24<SPI, DO, DELAY>,
+    ) -> Result<Option<std::vec::Vec<RawAdStructure>>, Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+    {
+        let mut buf = [0u8; 32];
+        radio.read(&mut buf, Some(32))?;
+        let channel = radio.get_channel()?;
+        Ok(BlePayload::decode_ad_structures(&mut buf, channel))
+    }
+
+    /// Read the first available payload from the radio's RX FIFO and decode it into the
+    /// advertiser's MAC address plus its raw sequence of GAP AD structures, via
+    /// [`BlePayload::decode_ad_structures()`](crate::services::BlePayload::decode_ad_structures).
+    ///
+    /// Unlike [`FakeBle::read()`] (which only recognizes this crate's own built-in
+    /// services), this reports every AD structure in the payload verbatim, so third-party
+    /// advertisers aren't silently dropped.
+    ///
+    /// See [`FakeBle::read()`] for the same channel/malformed-payload caveats.
+    #[cfg(not(feature = "std"))]
+    pub fn read_all<SPI, DO, DELAY>(
+        &self,
+        radio: &mut RF24<SPI, DO, DELAY>,
+    ) -> Result<Option<[Option<RawAdStructure>; MAX_AD_STRUCTURES]>, Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+    {
+        let mut buf = [0u8; 32];
+        radio.read(&mut buf, Some(32))?;
+        let channel = radio.get_channel()?;
+        Ok(BlePayload::decode_ad_structures(&mut buf, channel))
+    }
+
+    /// Passively scan for BLE advertisements, cycling the radio across all of [`BLE_CHANNEL`].
+    ///
+    /// For each channel (in order), the radio dwells for `samples_per_channel` polls of
+    /// [`EsbFifo::available()`], waiting `poll_delay_ms` (via `delay`) between polls, and
+    /// decodes (via [`FakeBle::read()`]) any payload that arrives while parked there. This
+    /// mirrors [`RF24::scan_channels()`]'s count-based dwell, since the nRF24L01 has no way
+    /// to time a dwell against a wall-clock duration.
+    ///
+    /// `on_payload` is invoked with the channel a decoded payload arrived on and the payload
+    /// itself; returning `false` aborts the scan early. This whole process repeats for
+    /// `cycles` iterations of all [`BLE_CHANNEL`]s, giving a passive observer role analogous
+    /// to a BLE central scanning for advertisers.
+    ///
+    /// The radio must already be in RX mode (see [`EsbRadio::as_rx()`]).
+    #[allow(clippy::too_many_arguments)]
+    pub fn observe<SPI, DO, DELAY, WAIT>(
+        &self,
+        radio: &mut RF24<SPI, DO, DELAY>,
+        delay: &mut WAIT,
+        samples_per_channel: u8,
+        poll_delay_ms: u32,
+        cycles: u32,
+        mut on_payload: impl FnMut(u8, BlePayload) -> bool,
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+        WAIT: DelayNs,
+    {
+        for _ in 0..cycles {
+            for channel in BLE_CHANNEL {
+                radio.set_channel(channel)?;
+                for _ in 0..samples_per_channel {
+                    if radio.available()? {
+                        if let Some(payload) = self.read(radio)? {
+                            if !on_payload(channel, payload) {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    delay.delay_ms(poll_delay_ms);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Actively scan for BLE advertisers, cycling the radio across all of [`BLE_CHANNEL`]
+    /// and accumulating unique advertisers into `out`.
+    ///
+    /// For each channel (in order), the radio is set to that channel and given
+    /// `window_us` microseconds (via `delay`) to receive before every payload already
+    /// latched in the RX FIFO is drained and decoded via [`FakeBle::read()`]. This must
+    /// happen before hopping to the next channel, since decoding (the whitening
+    /// coefficient) depends on the channel the packet actually arrived on.
+    ///
+    /// Decoded payloads are deduplicated by [`BlePayload::mac_address`] against the
+    /// entries already written into `out`, then appended there; entries at or beyond the
+    /// returned count are left untouched, so reuse `out` across calls (without clearing
+    /// it) to keep deduplicating against advertisers found by earlier scans.
+    ///
+    /// Returns the number of unique advertisers written into `out`, capped at
+    /// `out.len()`. Unlike [`FakeBle::observe()`] (which is passive and open-ended), this
+    /// is meant for one-shot presence detection: "who is out there right now?"
+    ///
+    /// The radio must already be in RX mode (see [`FakeBle::as_rx()`]).
+    pub fn scan<SPI, DO, DELAY, WAIT>(
+        &self,
+        radio: &mut RF24<SPI, DO, DELAY>,
+        delay: &mut WAIT,
+        window_us: u32,
+        out: &mut [Option<BlePayload>],
+    ) -> Result<usize, Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+        WAIT: DelayNs,
+    {
+        let mut found = out.iter().filter(|entry| entry.is_some()).count();
+        for channel in BLE_CHANNEL {
+            radio.set_channel(channel)?;
+            delay.delay_us(window_us);
+            while radio.available()? {
+                let Some(payload) = self.read(radio)? else {
+                    continue;
+                };
+                if found >= out.len() {
+                    continue;
+                }
+                let is_dup = out[..found].iter().any(|seen| {
+                    seen.as_ref()
+                        .is_some_and(|seen| seen.mac_address == payload.mac_address)
+                });
+                if !is_dup {
+                    out[found] = Some(payload);
+                    found += 1;
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    /// `.await`-based counterpart to [`FakeBle::hop_channel()`], built on [`rf24::radio::AsyncRF24`].
+    #[cfg(feature = "async")]
+    pub async fn hop_channel_async<SPI, DO, IRQ, DELAY>(
+        &self,
+        radio: &mut rf24::radio::AsyncRF24<SPI, DO, IRQ, DELAY>,
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: embedded_hal_async::spi::SpiDevice,
+        DO: OutputPin,
+        IRQ: embedded_hal_async::digital::Wait,
+        DELAY: embedded_hal_async::delay::DelayNs,
+    {
+        let channel = radio.get_channel().await?;
+        if let Some(channel) = BleChannels::increment(channel) {
+            radio.set_channel(channel).await?;
+        }
+        // if the current channel is not a BLE_CHANNEL, then do nothing
+        Ok(())
+    }
+
+    /// `.await`-based counterpart to [`FakeBle::send()`], built on [`rf24::radio::AsyncRF24`].
+    #[cfg(feature = "async")]
+    pub async fn send_async<SPI, DO, IRQ, DELAY>(
+        &self,
+        radio: &mut rf24::radio::AsyncRF24<SPI, DO, IRQ, DELAY>,
+        buf: &[u8],
+    ) -> Result<bool, Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: embedded_hal_async::spi::SpiDevice,
+        DO: OutputPin,
+        IRQ: embedded_hal_async::digital::Wait,
+        DELAY: embedded_hal_async::delay::DelayNs,
+    {
+        let pa_level = if self.show_pa_level {
+            Some(radio.get_pa_level().await?)
+        } else {
+            None
+        };
+        let channel = radio.get_channel().await?;
+        if let Some(tx_queue) = self.make_payload(buf, pa_level, channel) {
+            // Disregarding any hardware error, `AsyncRF24::send()` should
+            // always return `Ok(true)` because auto-ack is off.
+            return radio.send(&tx_queue, false).await;
+        }
+        Ok(false)
+    }
+
+    /// `.await`-based counterpart to [`FakeBle::read()`], built on [`rf24::radio::AsyncRF24`].
+    #[cfg(feature = "async")]
+    pub async fn read_async<SPI, DO, IRQ, DELAY>(
+        &self,
+        radio: &mut rf24::radio::AsyncRF24<SPI, DO, IRQ, DELAY>,
+    ) -> Result<Option<BlePayload>, Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: embedded_hal_async::spi::SpiDevice,
+        DO: OutputPin,
+        IRQ: embedded_hal_async::digital::Wait,
+        DELAY: embedded_hal_async::delay::DelayNs,
+    {
+        let mut buf = [0u8; 32];
+        radio.read(&mut buf, Some(32)).await?;
+        let channel = radio.get_channel().await?;
+        Ok(BlePayload::from_bytes(&mut buf, channel))
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////////
@@ -343,13 +840,19 @@ impl FakeBle {
 #[cfg(test)]
 mod test {
     extern crate std;
-    use super::{ble_config, FakeBle, BLE_ADDRESS, BLE_CHANNEL};
+    use super::{
+        ble_config, crc24, sniffer_config, whiten, AdvType, FakeBle, ADV_ACCESS_ADDRESS,
+        BLE_ADDRESS, BLE_CHANNEL,
+    };
+    use crate::data_manipulation::reverse_bits;
+    use crate::services::BlePayload;
     use crate::{spi_test_expects, test::mk_radio};
     use embedded_hal_mock::eh1::{
+        delay::NoopDelay,
         digital::{State, Transaction as PinTransaction},
         spi::Transaction as SpiTransaction,
     };
-    use rf24::{CrcLength, PaLevel};
+    use rf24::{CrcLength, DataRate, PaLevel};
     use std::vec;
 
     #[test]
@@ -372,6 +875,52 @@ mod test {
         assert_eq!(ble.len_available(b""), 18);
     }
 
+    #[cfg(feature = "rand")]
+    struct StepRng(u8);
+
+    #[cfg(feature = "rand")]
+    impl rand_core::RngCore for StepRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let mut buf = [0u8; 8];
+            self.fill_bytes(&mut buf);
+            u64::from_le_bytes(buf)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                *byte = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn randomize_mac() {
+        let mut ble = FakeBle::new();
+        let mut rng = StepRng(0);
+        ble.randomize_mac(&mut rng);
+        assert_eq!(ble.mac_address, [0, 1, 2, 3, 4, 0xC5]);
+        assert!(ble.random_address);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn with_rng() {
+        let mut rng = StepRng(0);
+        let ble = FakeBle::with_rng(&mut rng);
+        assert_eq!(ble.mac_address, [0, 1, 2, 3, 4, 0xC5]);
+    }
+
     #[test]
     fn pa_level() {
         let mut ble = FakeBle::default();
@@ -380,6 +929,43 @@ mod test {
         assert_eq!(ble.len_available(b""), 15);
     }
 
+    /// Undo the whitening and bit-reversal applied to byte 0 of a payload built by
+    /// [`FakeBle::make_payload()`], so the raw advertising-channel PDU header can be
+    /// inspected.
+    fn pdu_header(payload: &[u8; 32], channel: u8) -> u8 {
+        let mut header = [payload[0]];
+        reverse_bits(&mut header);
+        whiten(&mut header, channel);
+        header[0]
+    }
+
+    #[test]
+    fn adv_type_default() {
+        // the default FakeBle must reproduce the original hard-coded 0x42 PDU header
+        // (NonConnectableUndirected with a random TxAdd) for backward compatibility.
+        let ble = FakeBle::default();
+        let channel = BLE_CHANNEL[0];
+        let payload = ble.make_payload(&[], None, channel).unwrap();
+        assert_eq!(pdu_header(&payload, channel), 0x42);
+    }
+
+    #[test]
+    fn adv_type_variants() {
+        let mut ble = FakeBle::default();
+        let channel = BLE_CHANNEL[0];
+        for (adv_type, random_address, expected) in [
+            (AdvType::ConnectableUndirected, false, 0x00),
+            (AdvType::ConnectableUndirected, true, 0x40),
+            (AdvType::NonConnectableUndirected, false, 0x02),
+            (AdvType::ScannableUndirected, true, 0x46),
+        ] {
+            ble.adv_type = adv_type;
+            ble.random_address = random_address;
+            let payload = ble.make_payload(&[], None, channel).unwrap();
+            assert_eq!(pdu_header(&payload, channel), expected);
+        }
+    }
+
     #[test]
     fn config() {
         let config = ble_config();
@@ -400,6 +986,51 @@ mod test {
         }
     }
 
+    #[test]
+    fn sniffer() {
+        let config = sniffer_config();
+        assert_eq!(config.channel(), BLE_CHANNEL[0]);
+        assert_eq!(config.crc_length(), CrcLength::Disabled);
+        assert_eq!(config.auto_ack(), 0);
+        assert_eq!(config.auto_retry_count(), 0);
+        assert_eq!(config.auto_retry_delay(), 0);
+        assert_eq!(config.address_length(), 4);
+        assert_eq!(config.data_rate(), DataRate::Mbps1);
+        let mut address = [0u8; 4];
+        config.rx_address(1, &mut address);
+        assert_eq!(address, ADV_ACCESS_ADDRESS);
+        for pipe in 0..5 {
+            let enabled = config.is_rx_pipe_enabled(pipe);
+            assert_eq!(enabled, pipe == 1);
+        }
+    }
+
+    #[test]
+    fn whiten_by_channel() {
+        let mut buf = [0u8; 11];
+        buf.copy_from_slice(b"Hello World");
+        whiten(&mut buf, BLE_CHANNEL[0]);
+
+        let expected: [u8; 11] = [
+            0x57, 0x52, 0x26, 0x33, 0xEA, 0xD6, 0xCB, 0xF5, 0xB3, 0xBA, 0xA1,
+        ];
+        assert_eq!(buf, expected);
+
+        // de-whiten (w/ same channel) should restore the buffer to original content
+        whiten(&mut buf, BLE_CHANNEL[0]);
+        let mut expected = [0u8; 11];
+        expected.copy_from_slice(b"Hello World");
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn crc24_of_data() {
+        let buffer = b"Hello World";
+        let checksum = crc24(buffer);
+        let expected = [0xB6u8, 0x8C, 0xB0];
+        assert_eq!(expected, checksum);
+    }
+
     /// radio's register to control the channel
     const RF_CH: u8 = 5;
     /// mnemonic to write to a register
@@ -455,6 +1086,201 @@ mod test {
         ce_pin.done();
     }
 
+    #[test]
+    fn read_all() {
+        let ble = FakeBle::default();
+        let channel = BLE_CHANNEL[0];
+        let payload = ble.make_payload(&[], None, channel).unwrap();
+        let mut buf = [0; 33];
+        buf[1..].copy_from_slice(&payload);
+        buf[0] = 0xE;
+        let mut expected = [0; 33];
+        expected[0] = R_RX_PAYLOAD;
+
+        let spi_expectations = spi_test_expects![
+            (expected.to_vec(), buf.to_vec()),
+            (vec![STATUS | W_REGISTER, MASK_RX_DR], vec![0xEu8, 0]),
+            (vec![RF_CH, 0], vec![0xEu8, BLE_CHANNEL[0]]),
+        ];
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        let structures = ble.read_all(&mut radio).unwrap().unwrap();
+        // the profile flags AD structure is always present, even with an empty user buf
+        assert_eq!(structures.len(), 1);
+        assert_eq!(structures[0].ad_type, 0x01);
+        spi.done();
+        ce_pin.done();
+    }
+
+    const FIFO_STATUS: u8 = 0x17;
+
+    #[test]
+    fn observe() {
+        let ble = FakeBle::default();
+        let payload = ble.make_payload(&[], None, BLE_CHANNEL[0]).unwrap();
+        let mut rx_buf = [0; 33];
+        rx_buf[1..].copy_from_slice(&payload);
+        rx_buf[0] = 0xE;
+        let mut rx_expected = [0; 33];
+        rx_expected[0] = R_RX_PAYLOAD;
+
+        let mut spi_expectations = vec::Vec::new();
+        for (index, channel) in BLE_CHANNEL.into_iter().enumerate() {
+            spi_expectations.append(
+                &mut spi_test_expects![(
+                    vec![RF_CH | W_REGISTER, channel],
+                    vec![0xEu8, 0]
+                ),]
+                .to_vec(),
+            );
+            if index == 0 {
+                spi_expectations.append(
+                    &mut spi_test_expects![
+                        (vec![FIFO_STATUS, 0], vec![0xEu8, 0]),
+                        (rx_expected.to_vec(), rx_buf.to_vec()),
+                        (vec![STATUS | W_REGISTER, MASK_RX_DR], vec![0xEu8, 0]),
+                        (vec![RF_CH, 0], vec![0xEu8, BLE_CHANNEL[0]]),
+                    ]
+                    .to_vec(),
+                );
+            } else {
+                spi_expectations.append(
+                    &mut spi_test_expects![(vec![FIFO_STATUS, 0], vec![0xEu8, 1]),].to_vec(),
+                );
+            }
+        }
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        let mut found = vec::Vec::new();
+        ble.observe(
+            &mut radio,
+            &mut NoopDelay,
+            1,
+            0,
+            1,
+            |channel, payload| {
+                found.push((channel, payload.mac_address));
+                true
+            },
+        )
+        .unwrap();
+
+        assert_eq!(found, vec![(BLE_CHANNEL[0], ble.mac_address)]);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn scan() {
+        let ble = FakeBle::default();
+        let payload = ble.make_payload(&[], None, BLE_CHANNEL[0]).unwrap();
+        let mut rx_buf = [0; 33];
+        rx_buf[1..].copy_from_slice(&payload);
+        rx_buf[0] = 0xE;
+        let mut rx_expected = [0; 33];
+        rx_expected[0] = R_RX_PAYLOAD;
+
+        let mut spi_expectations = vec::Vec::new();
+        for (index, channel) in BLE_CHANNEL.into_iter().enumerate() {
+            spi_expectations.append(
+                &mut spi_test_expects![(vec![RF_CH | W_REGISTER, channel], vec![0xEu8, 0]),]
+                    .to_vec(),
+            );
+            if index == 0 {
+                spi_expectations.append(
+                    &mut spi_test_expects![
+                        (vec![FIFO_STATUS, 0], vec![0xEu8, 0]),
+                        (rx_expected.to_vec(), rx_buf.to_vec()),
+                        (vec![STATUS | W_REGISTER, MASK_RX_DR], vec![0xEu8, 0]),
+                        (vec![RF_CH, 0], vec![0xEu8, BLE_CHANNEL[0]]),
+                        (vec![FIFO_STATUS, 0], vec![0xEu8, 1]),
+                    ]
+                    .to_vec(),
+                );
+            } else {
+                spi_expectations.append(
+                    &mut spi_test_expects![(vec![FIFO_STATUS, 0], vec![0xEu8, 1]),].to_vec(),
+                );
+            }
+        }
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        let mut out: [Option<BlePayload>; 2] = [None, None];
+        let found = ble.scan(&mut radio, &mut NoopDelay, 0, &mut out).unwrap();
+
+        assert_eq!(found, 1);
+        assert_eq!(out[0].as_ref().unwrap().mac_address, ble.mac_address);
+        assert!(out[1].is_none());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn scan_dedups_against_existing_entries() {
+        let ble = FakeBle::default();
+        let payload = ble.make_payload(&[], None, BLE_CHANNEL[0]).unwrap();
+        let mut rx_buf = [0; 33];
+        rx_buf[1..].copy_from_slice(&payload);
+        rx_buf[0] = 0xE;
+        let mut rx_expected = [0; 33];
+        rx_expected[0] = R_RX_PAYLOAD;
+
+        let mut spi_expectations = vec::Vec::new();
+        for (index, channel) in BLE_CHANNEL.into_iter().enumerate() {
+            spi_expectations.append(
+                &mut spi_test_expects![(vec![RF_CH | W_REGISTER, channel], vec![0xEu8, 0]),]
+                    .to_vec(),
+            );
+            if index == 0 {
+                spi_expectations.append(
+                    &mut spi_test_expects![
+                        (vec![FIFO_STATUS, 0], vec![0xEu8, 0]),
+                        (rx_expected.to_vec(), rx_buf.to_vec()),
+                        (vec![STATUS | W_REGISTER, MASK_RX_DR], vec![0xEu8, 0]),
+                        (vec![RF_CH, 0], vec![0xEu8, BLE_CHANNEL[0]]),
+                        (vec![FIFO_STATUS, 0], vec![0xEu8, 1]),
+                    ]
+                    .to_vec(),
+                );
+            } else {
+                spi_expectations.append(
+                    &mut spi_test_expects![(vec![FIFO_STATUS, 0], vec![0xEu8, 1]),].to_vec(),
+                );
+            }
+        }
+        let mocks = mk_radio(&[], &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        // pretend a prior scan already found this same advertiser
+        let mut out: [Option<BlePayload>; 2] = [
+            Some(BlePayload {
+                mac_address: ble.mac_address,
+                short_name: None,
+                tx_power: None,
+                battery_charge: None,
+                url: None,
+                temperature: None,
+                eddystone_uid: None,
+                eddystone_tlm: None,
+                ibeacon: None,
+                #[cfg(feature = "std")]
+                unsupported: vec::Vec::new(),
+                #[cfg(not(feature = "std"))]
+                unsupported: [None; 4],
+            }),
+            None,
+        ];
+        let found = ble.scan(&mut radio, &mut NoopDelay, 0, &mut out).unwrap();
+
+        assert_eq!(found, 1);
+        assert!(out[1].is_none());
+        spi.done();
+        ce_pin.done();
+    }
+
     const MASK_TX_DS: u8 = 1 << 5;
     const MASK_MAX_RT: u8 = 1 << 4;
     const W_TX_PAYLOAD: u8 = 0xA0;
@@ -561,4 +1387,120 @@ mod test {
         spi.done();
         ce_pin.done();
     }
+
+    #[test]
+    fn advertise() {
+        let mut ble = FakeBle::default();
+
+        let mut spi_expectations = send_spi_expects(&ble, None, false);
+        spi_expectations.append(
+            &mut spi_test_expects![
+                (vec![RF_CH, 0], vec![0xEu8, BLE_CHANNEL[0]]),
+                (vec![RF_CH | W_REGISTER, BLE_CHANNEL[1]], vec![0xEu8, 0]),
+            ]
+            .to_vec(),
+        );
+        let ce_expectations = send_ce_expects();
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        assert!(ble.advertise(&mut radio, "", &[]).unwrap());
+        assert_eq!(ble.get_name(&mut [0u8; 10]), 0);
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn make_payloads_channels() {
+        let ble = FakeBle::default();
+        let payloads = ble.make_payloads(&[], None).unwrap();
+        for (index, (payload, channel)) in payloads.into_iter().enumerate() {
+            assert_eq!(channel, BLE_CHANNEL[index]);
+            assert_eq!(payload, ble.make_payload(&[], None, channel).unwrap());
+        }
+    }
+
+    #[test]
+    fn send_all_channels() {
+        let ble = FakeBle::default();
+
+        let mut spi_expectations = vec::Vec::new();
+        for channel in BLE_CHANNEL {
+            let payload = ble.make_payload(&[], None, channel).unwrap();
+            let mut buf = [0; 33];
+            buf[0] = 0xE;
+            let mut expected = [0; 33];
+            expected[0] = W_TX_PAYLOAD;
+            expected[1..].copy_from_slice(&payload);
+
+            spi_expectations.append(
+                &mut spi_test_expects![
+                    (vec![RF_CH | W_REGISTER, channel], vec![0xEu8, 0]),
+                    (vec![FLUSH_TX], vec![0xEu8]),
+                    (
+                        vec![STATUS | W_REGISTER, MASK_TX_DS | MASK_MAX_RT],
+                        vec![0xEu8, 0]
+                    ),
+                    (expected.to_vec(), buf.to_vec()),
+                    (vec![NOP], vec![0xE | MASK_TX_DS]),
+                ]
+                .to_vec(),
+            );
+        }
+
+        let ce_expectations: vec::Vec<PinTransaction> =
+            BLE_CHANNEL.iter().flat_map(|_| send_ce_expects()).collect();
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        assert!(ble.send_all(&mut radio, &[]).unwrap());
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn broadcast_all_channels() {
+        let ble = FakeBle::default();
+        let original_channel = 10u8;
+
+        let mut spi_expectations =
+            spi_test_expects![(vec![RF_CH, 0], vec![0xEu8, original_channel]),].to_vec();
+        for channel in BLE_CHANNEL {
+            let payload = ble.make_payload(&[], None, channel).unwrap();
+            let mut buf = [0; 33];
+            buf[0] = 0xE;
+            let mut expected = [0; 33];
+            expected[0] = W_TX_PAYLOAD;
+            expected[1..].copy_from_slice(&payload);
+
+            spi_expectations.append(
+                &mut spi_test_expects![
+                    (vec![RF_CH | W_REGISTER, channel], vec![0xEu8, 0]),
+                    (vec![FLUSH_TX], vec![0xEu8]),
+                    (
+                        vec![STATUS | W_REGISTER, MASK_TX_DS | MASK_MAX_RT],
+                        vec![0xEu8, 0]
+                    ),
+                    (expected.to_vec(), buf.to_vec()),
+                    (vec![NOP], vec![0xE | MASK_TX_DS]),
+                ]
+                .to_vec(),
+            );
+        }
+        spi_expectations.append(
+            &mut spi_test_expects![(vec![RF_CH | W_REGISTER, original_channel], vec![0xEu8, 0]),]
+                .to_vec(),
+        );
+
+        let ce_expectations: vec::Vec<PinTransaction> =
+            BLE_CHANNEL.iter().flat_map(|_| send_ce_expects()).collect();
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        assert!(ble
+            .broadcast_all(&mut radio, &mut NoopDelay, &[], 150)
+            .unwrap());
+        spi.done();
+        ce_pin.done();
+    }
 }
@@ -0,0 +1,325 @@
+//! An automatic advertising scheduler for [`FakeBle`].
+//!
+//! Without this, callers must manually interleave [`FakeBle::send()`] and
+//! [`FakeBle::hop_channel()`] with their own timing. [`AdvertiseScheduler`] wraps that
+//! pattern into a single call that cycles through all of [`BLE_CHANNEL`] on a fixed
+//! cadence, mirroring how real BLE advertisers rotate channels.
+
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+use rf24::{
+    radio::{prelude::EsbPaLevel, Nrf24Error, RF24},
+    PaLevel,
+};
+
+use crate::radio::{FakeBle, BLE_CHANNEL};
+
+/// Advertising cadence presets, modeled on the advertise-interval settings offered by
+/// common BLE beacon SDKs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvertiseMode {
+    /// Advertise about once every 1000 ms. Favors battery life over discoverability.
+    LowPower,
+    /// Advertise about once every 250 ms. A reasonable default for most beacons.
+    Balanced,
+    /// Advertise about once every 100 ms. Favors fast discovery over battery life.
+    LowLatency,
+}
+
+impl AdvertiseMode {
+    /// The delay (in milliseconds) to wait between advertising cycles.
+    pub fn interval_ms(&self) -> u32 {
+        match self {
+            AdvertiseMode::LowPower => 1000,
+            AdvertiseMode::Balanced => 250,
+            AdvertiseMode::LowLatency => 100,
+        }
+    }
+}
+
+/// The TX power levels cycled through by [`AdvertiseScheduler::sweep_tx_power`], in
+/// ascending order.
+const TX_POWER_SWEEP: [PaLevel; 4] = [PaLevel::Min, PaLevel::Low, PaLevel::High, PaLevel::Max];
+
+/// Repeatedly advertises a payload, rotating through all of [`BLE_CHANNEL`] on each
+/// cycle and waiting [`AdvertiseMode::interval_ms()`] between cycles.
+///
+/// This gives users a one-call "keep advertising this beacon" loop instead of
+/// interleaving [`FakeBle::send()`] and [`FakeBle::hop_channel()`] by hand.
+#[derive(Debug, Clone, Copy)]
+pub struct AdvertiseScheduler {
+    /// The cadence used to space out advertising cycles.
+    pub mode: AdvertiseMode,
+    /// Whether the beacon should advertise as connectable.
+    ///
+    /// This is stored for API parity with real BLE advertisers; [`FakeBle`]'s simplified
+    /// over-the-air format does not distinguish connectable from non-connectable
+    /// advertisements.
+    pub connectable: bool,
+    /// The radio's TX power, applied via [`EsbPaLevel::set_pa_level()`] before each cycle.
+    ///
+    /// Ignored by [`Self::run()`] when [`Self::sweep_tx_power`] is enabled.
+    pub tx_power: PaLevel,
+    /// When `true`, [`Self::run()`] ignores [`Self::tx_power`] and instead cycles through
+    /// [`PaLevel::Min`], [`PaLevel::Low`], [`PaLevel::High`], and [`PaLevel::Max`] (one
+    /// level per cycle, wrapping around), mirroring the TX-power sweep offered by some
+    /// BLE beacon SDKs for range testing.
+    pub sweep_tx_power: bool,
+}
+
+impl AdvertiseScheduler {
+    /// Create a new scheduler with the given `mode`, `connectable` flag, and `tx_power`.
+    ///
+    /// [`Self::sweep_tx_power`] defaults to `false`; toggle it directly to enable a
+    /// TX-power sweep in [`Self::run()`].
+    pub fn new(mode: AdvertiseMode, connectable: bool, tx_power: PaLevel) -> Self {
+        Self {
+            mode,
+            connectable,
+            tx_power,
+            sweep_tx_power: false,
+        }
+    }
+
+    /// Broadcast `buf` once on each of the [`BLE_CHANNEL`]s, in sequence.
+    ///
+    /// [`Self::tx_power`] is applied to the radio before the first transmission.
+    pub fn advertise_cycle<SPI, DO, DELAY>(
+        &self,
+        ble: &FakeBle,
+        radio: &mut RF24<SPI, DO, DELAY>,
+        buf: &[u8],
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+    {
+        self.advertise_cycle_at_power(ble, radio, buf, self.tx_power)
+    }
+
+    /// Same as [`Self::advertise_cycle()`], but applies the given `tx_power` instead of
+    /// [`Self::tx_power`]. This is what lets [`Self::run()`] sweep the TX power across
+    /// cycles without mutating `self`.
+    fn advertise_cycle_at_power<SPI, DO, DELAY>(
+        &self,
+        ble: &FakeBle,
+        radio: &mut RF24<SPI, DO, DELAY>,
+        buf: &[u8],
+        tx_power: PaLevel,
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+    {
+        radio.set_pa_level(tx_power)?;
+        for _ in 0..BLE_CHANNEL.len() {
+            ble.send(radio, buf)?;
+            ble.hop_channel(radio)?;
+        }
+        Ok(())
+    }
+
+    /// Repeat [`Self::advertise_cycle()`] for `cycles` iterations, waiting
+    /// [`AdvertiseMode::interval_ms()`] (per [`Self::mode`]) between each cycle.
+    ///
+    /// If [`Self::sweep_tx_power`] is enabled, each cycle uses the next level in
+    /// [`TX_POWER_SWEEP`] (wrapping around) instead of the fixed [`Self::tx_power`].
+    pub fn run<SPI, DO, DELAY, WAIT>(
+        &self,
+        ble: &FakeBle,
+        radio: &mut RF24<SPI, DO, DELAY>,
+        buf: &[u8],
+        delay: &mut WAIT,
+        cycles: u32,
+    ) -> Result<(), Nrf24Error<SPI::Error, DO::Error>>
+    where
+        SPI: SpiDevice,
+        DO: OutputPin,
+        DELAY: DelayNs,
+        WAIT: DelayNs,
+    {
+        for cycle in 0..cycles {
+            let tx_power = if self.sweep_tx_power {
+                TX_POWER_SWEEP[cycle as usize % TX_POWER_SWEEP.len()]
+            } else {
+                self.tx_power
+            };
+            self.advertise_cycle_at_power(ble, radio, buf, tx_power)?;
+            delay.delay_ms(self.mode.interval_ms());
+        }
+        Ok(())
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+/// unit tests
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::{AdvertiseMode, AdvertiseScheduler};
+    use crate::{
+        radio::{BleChannels, FakeBle, BLE_CHANNEL},
+        spi_test_expects,
+        test::mk_radio,
+    };
+    use embedded_hal_mock::eh1::{
+        digital::{State, Transaction as PinTransaction},
+        spi::Transaction as SpiTransaction,
+    };
+    use rf24::PaLevel;
+    use std::vec;
+
+    #[test]
+    fn interval_ms() {
+        assert_eq!(AdvertiseMode::LowPower.interval_ms(), 1000);
+        assert_eq!(AdvertiseMode::Balanced.interval_ms(), 250);
+        assert_eq!(AdvertiseMode::LowLatency.interval_ms(), 100);
+    }
+
+    #[test]
+    fn new_stores_fields() {
+        let scheduler = AdvertiseScheduler::new(AdvertiseMode::LowLatency, true, PaLevel::Max);
+        assert_eq!(scheduler.mode, AdvertiseMode::LowLatency);
+        assert!(scheduler.connectable);
+        assert_eq!(scheduler.tx_power, PaLevel::Max);
+        assert!(!scheduler.sweep_tx_power);
+    }
+
+    const RF_SETUP: u8 = 0x06;
+    const RF_CH: u8 = 5;
+    const W_REGISTER: u8 = 0x20;
+    const STATUS: u8 = 7;
+    const MASK_TX_DS: u8 = 1 << 5;
+    const MASK_MAX_RT: u8 = 1 << 4;
+    const W_TX_PAYLOAD: u8 = 0xA0;
+    const FLUSH_TX: u8 = 0xE1;
+    const NOP: u8 = 0xFF;
+
+    #[test]
+    fn advertise_cycle() {
+        let ble = FakeBle::default();
+        let scheduler = AdvertiseScheduler::new(AdvertiseMode::Balanced, true, PaLevel::High);
+
+        let mut ce_expectations = vec::Vec::new();
+        // `set_pa_level()` first reads the existing register, then writes the masked value.
+        let mut spi_expectations = spi_test_expects![
+            (vec![RF_SETUP, 0], vec![0xEu8, 0]),
+            (vec![RF_SETUP | W_REGISTER, 4], vec![0xEu8, 0]),
+        ]
+        .to_vec();
+
+        for channel in BLE_CHANNEL {
+            ce_expectations.append(&mut vec![
+                PinTransaction::set(State::Low),
+                PinTransaction::set(State::High),
+            ]);
+
+            let payload = ble.make_payload(&[], None, channel).unwrap();
+            let mut buf = [0; 33];
+            buf[0] = 0xE;
+            let mut expected = [0; 33];
+            expected[0] = W_TX_PAYLOAD;
+            expected[1..].copy_from_slice(&payload);
+
+            spi_expectations.append(
+                &mut spi_test_expects![
+                    (vec![RF_CH, 0], vec![0xEu8, channel]),
+                    (vec![FLUSH_TX], vec![0xEu8]),
+                    (
+                        vec![STATUS | W_REGISTER, MASK_TX_DS | MASK_MAX_RT],
+                        vec![0xEu8, 0]
+                    ),
+                    (expected.to_vec(), buf.to_vec()),
+                    (vec![NOP], vec![0xE | MASK_TX_DS]),
+                ]
+                .to_vec(),
+            );
+
+            let next = BleChannels::increment(channel).unwrap();
+            spi_expectations.append(
+                &mut spi_test_expects![
+                    (vec![RF_CH, 0], vec![0xEu8, channel]),
+                    (vec![RF_CH | W_REGISTER, next], vec![0xEu8, 0]),
+                ]
+                .to_vec(),
+            );
+        }
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        scheduler.advertise_cycle(&ble, &mut radio, &[]).unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+
+    #[test]
+    fn run_sweeps_tx_power() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+
+        let ble = FakeBle::default();
+        let mut scheduler = AdvertiseScheduler::new(AdvertiseMode::LowLatency, false, PaLevel::Max);
+        scheduler.sweep_tx_power = true;
+
+        let mut ce_expectations = vec::Vec::new();
+        let mut spi_expectations = vec::Vec::new();
+
+        // `PaLevel::Min`, then `PaLevel::Low`, one per cycle (see `TX_POWER_SWEEP`).
+        for masked_power in [0u8, 2u8] {
+            spi_expectations.append(
+                &mut spi_test_expects![
+                    (vec![RF_SETUP, 0], vec![0xEu8, 0]),
+                    (vec![RF_SETUP | W_REGISTER, masked_power], vec![0xEu8, 0]),
+                ]
+                .to_vec(),
+            );
+
+            for channel in BLE_CHANNEL {
+                ce_expectations.append(&mut vec![
+                    PinTransaction::set(State::Low),
+                    PinTransaction::set(State::High),
+                ]);
+
+                let payload = ble.make_payload(&[], None, channel).unwrap();
+                let mut buf = [0; 33];
+                buf[0] = 0xE;
+                let mut expected = [0; 33];
+                expected[0] = W_TX_PAYLOAD;
+                expected[1..].copy_from_slice(&payload);
+
+                spi_expectations.append(
+                    &mut spi_test_expects![
+                        (vec![RF_CH, 0], vec![0xEu8, channel]),
+                        (vec![FLUSH_TX], vec![0xEu8]),
+                        (
+                            vec![STATUS | W_REGISTER, MASK_TX_DS | MASK_MAX_RT],
+                            vec![0xEu8, 0]
+                        ),
+                        (expected.to_vec(), buf.to_vec()),
+                        (vec![NOP], vec![0xE | MASK_TX_DS]),
+                    ]
+                    .to_vec(),
+                );
+
+                let next = BleChannels::increment(channel).unwrap();
+                spi_expectations.append(
+                    &mut spi_test_expects![
+                        (vec![RF_CH, 0], vec![0xEu8, channel]),
+                        (vec![RF_CH | W_REGISTER, next], vec![0xEu8, 0]),
+                    ]
+                    .to_vec(),
+                );
+            }
+        }
+
+        let mocks = mk_radio(&ce_expectations, &spi_expectations);
+        let (mut radio, mut spi, mut ce_pin) = (mocks.0, mocks.1, mocks.2);
+
+        scheduler
+            .run(&ble, &mut radio, &[], &mut NoopDelay, 2)
+            .unwrap();
+        spi.done();
+        ce_pin.done();
+    }
+}
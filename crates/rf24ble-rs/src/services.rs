@@ -227,6 +227,26 @@ impl UrlService {
         self.buf[0] = index as u8 - 1;
     }
 
+    /// Like [`UrlService::set_data()`], but returns an error instead of silently
+    /// broadcasting a URL that would exceed `ble`'s 18-byte advertisement budget (see
+    /// [`FakeBle::len_available()`](crate::radio::FakeBle::len_available)).
+    pub fn set_data_for(
+        &mut self,
+        ble: &crate::radio::FakeBle,
+        value: &str,
+    ) -> Result<(), crate::advertisement::AdvertisementError> {
+        use crate::advertisement::AdvertisementError;
+        use prelude::AsBuffer;
+
+        let mut candidate = *self;
+        candidate.set_data(value);
+        if ble.len_available(candidate.buffer()) < 0 {
+            return Err(AdvertisementError::ExceedsDeviceBudget);
+        }
+        *self = candidate;
+        Ok(())
+    }
+
     /// Get the URL to be broadcasted.
     #[cfg(feature = "std")]
     pub fn data(&self) -> std::string::String {
@@ -278,6 +298,463 @@ impl prelude::FromBuffer for UrlService {
     }
 }
 
+/// A data service for broadcasting an Eddystone-UID beacon identity.
+///
+/// Conforms to the UID frame specified by [Google's EddyStone][eddystone] data format.
+/// [`BlePayload::from_bytes()`] demultiplexes this from the sibling [`UrlService`] and
+/// [`EddystoneTlmService`] frames (which share the same `0xFEAA` UUID) by inspecting the
+/// frame-type byte.
+///
+/// <div class="warning">
+///
+/// This service's buffer is 24 bytes, which exceeds the 18 bytes of payload that
+/// [`FakeBle`](crate::radio::FakeBle) has available (see the
+/// [Limitations](index.html#limitations)). [`FakeBle::make_payload()`](crate::radio::FakeBle::make_payload)
+/// will return [`None`] for any `buf` built from this service.
+///
+/// </div>
+///
+/// [eddystone]: https://github.com/google/eddystone
+#[derive(Debug, Clone, Copy)]
+pub struct EddystoneUidService {
+    buf: [u8; 24],
+}
+
+impl Default for EddystoneUidService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EddystoneUidService {
+    /// Create an instance of [`EddystoneUidService`].
+    pub fn new() -> Self {
+        let mut data = [0u8; 24];
+        data[0] = 23; // chunk length (including type)
+        data[1] = 0x16; // chunk type. 0x16 means format is defined in BLE specs.
+        data[2..4].copy_from_slice(&EDDYSTONE_UUID.to_le_bytes());
+        data[4] = 0x00; // frame type for the UID frame (0x10 is used by UrlService's URL frame)
+        data[5] = -25i8 as u8;
+        Self { buf: data }
+    }
+
+    /// Set the predicted PA (Power Amplitude) level at 1 meter radius.
+    pub fn set_pa_level(&mut self, level: i8) {
+        self.buf[5] = level as u8;
+    }
+
+    /// Get the predicted PA (Power Amplitude) level at 1 meter radius.
+    pub fn pa_level(&self) -> i8 {
+        self.buf[5] as i8
+    }
+
+    /// Set the 10-byte namespace ID.
+    pub fn set_namespace(&mut self, namespace: [u8; 10]) {
+        self.buf[6..16].copy_from_slice(&namespace);
+    }
+
+    /// Get the 10-byte namespace ID.
+    pub fn namespace(&self) -> [u8; 10] {
+        let mut namespace = [0u8; 10];
+        namespace.copy_from_slice(&self.buf[6..16]);
+        namespace
+    }
+
+    /// Set the 6-byte instance ID.
+    pub fn set_instance(&mut self, instance: [u8; 6]) {
+        self.buf[16..22].copy_from_slice(&instance);
+    }
+
+    /// Get the 6-byte instance ID.
+    pub fn instance(&self) -> [u8; 6] {
+        let mut instance = [0u8; 6];
+        instance.copy_from_slice(&self.buf[16..22]);
+        instance
+    }
+}
+
+impl prelude::AsBuffer for EddystoneUidService {
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl prelude::FromBuffer for EddystoneUidService {
+    fn from_buffer(buf: &[u8]) -> Self {
+        let max_len = buf.len().min(24);
+        let mut self_buf = [0u8; 24];
+        self_buf[0..max_len].copy_from_slice(&buf[0..max_len]);
+        Self { buf: self_buf }
+    }
+}
+
+/// A data service for broadcasting an Eddystone-TLM telemetry frame.
+///
+/// Conforms to the unencrypted TLM frame specified by [Google's EddyStone][eddystone] data
+/// format. Unlike [`UrlService`] and [`EddystoneUidService`], the multi-byte fields in this
+/// frame are **big-endian**.
+///
+/// [eddystone]: https://github.com/google/eddystone
+#[derive(Debug, Clone, Copy)]
+pub struct EddystoneTlmService {
+    buf: [u8; 18],
+}
+
+impl Default for EddystoneTlmService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EddystoneTlmService {
+    /// Create an instance of [`EddystoneTlmService`].
+    pub fn new() -> Self {
+        let mut data = [0u8; 18];
+        data[0] = 17; // chunk length (including type)
+        data[1] = 0x16; // chunk type. 0x16 means format is defined in BLE specs.
+        data[2..4].copy_from_slice(&EDDYSTONE_UUID.to_le_bytes());
+        data[4] = 0x20; // frame type for the TLM frame
+        data[5] = 0x00; // TLM version
+        data[6..8].copy_from_slice(&0x0000u16.to_be_bytes()); // battery voltage unsupported
+        data[8..10].copy_from_slice(&0x8000u16.to_be_bytes()); // temperature unsupported
+        Self { buf: data }
+    }
+
+    /// Get the TLM frame version. [`EddystoneTlmService::new()`] always starts this at
+    /// `0`, the only version currently defined by the Eddystone-TLM spec.
+    pub fn version(&self) -> u8 {
+        self.buf[5]
+    }
+
+    /// Set the battery voltage (in mV). `0` means "unsupported".
+    pub fn set_battery_voltage(&mut self, millivolts: u16) {
+        self.buf[6..8].copy_from_slice(&millivolts.to_be_bytes());
+    }
+
+    /// Get the battery voltage (in mV). `0` means "unsupported".
+    pub fn battery_voltage(&self) -> u16 {
+        u16::from_be_bytes([self.buf[6], self.buf[7]])
+    }
+
+    /// Does this frame report a battery voltage, or is it the `0` "unsupported" sentinel
+    /// (left over from [`EddystoneTlmService::new()`] because the beacon has no battery
+    /// monitoring, e.g. a mains-powered device)?
+    pub fn has_battery_voltage(&self) -> bool {
+        self.battery_voltage() != 0
+    }
+
+    /// Set the beacon's temperature (in Celsius) as a signed 8.8 fixed-point value.
+    /// `-128.0` (`0x8000`) means "unsupported".
+    pub fn set_temperature(&mut self, celsius: f32) {
+        let fixed = (celsius * 256.0) as i16;
+        self.buf[8..10].copy_from_slice(&fixed.to_be_bytes());
+    }
+
+    /// Get the beacon's temperature (in Celsius) from its signed 8.8 fixed-point value.
+    pub fn temperature(&self) -> f32 {
+        let fixed = i16::from_be_bytes([self.buf[8], self.buf[9]]);
+        fixed as f32 / 256.0
+    }
+
+    /// Does this frame report a temperature, or is it the `0x8000` "unsupported" sentinel
+    /// (left over from [`EddystoneTlmService::new()`] because the beacon has no
+    /// temperature sensor)?
+    pub fn has_temperature(&self) -> bool {
+        i16::from_be_bytes([self.buf[8], self.buf[9]]) != -0x8000i16
+    }
+
+    /// Set the count of advertising PDUs sent since power-up (or reboot).
+    pub fn set_pdu_count(&mut self, count: u32) {
+        self.buf[10..14].copy_from_slice(&count.to_be_bytes());
+    }
+
+    /// Get the count of advertising PDUs sent since power-up (or reboot).
+    pub fn pdu_count(&self) -> u32 {
+        u32::from_be_bytes(self.buf[10..14].try_into().unwrap())
+    }
+
+    /// Set the time since power-up (or reboot), in 0.1 second units.
+    pub fn set_uptime(&mut self, deciseconds: u32) {
+        self.buf[14..18].copy_from_slice(&deciseconds.to_be_bytes());
+    }
+
+    /// Get the time since power-up (or reboot), in 0.1 second units.
+    pub fn uptime(&self) -> u32 {
+        u32::from_be_bytes(self.buf[14..18].try_into().unwrap())
+    }
+}
+
+impl prelude::AsBuffer for EddystoneTlmService {
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl prelude::FromBuffer for EddystoneTlmService {
+    fn from_buffer(buf: &[u8]) -> Self {
+        let max_len = buf.len().min(18);
+        let mut self_buf = [0u8; 18];
+        self_buf[0..max_len].copy_from_slice(&buf[0..max_len]);
+        Self { buf: self_buf }
+    }
+}
+
+/// A data service for broadcasting an Apple iBeacon.
+///
+/// Conforms to the iBeacon advertising format, which (unlike the other services in this
+/// module) is carried in a Manufacturer Specific Data AD structure (type `0xFF`) rather
+/// than the GATT Service Data format (type `0x16`).
+///
+/// <div class="warning">
+///
+/// An iBeacon frame is 27 bytes, which exceeds the 18 bytes of payload that
+/// [`FakeBle`](crate::radio::FakeBle) has available (see the
+/// [Limitations](index.html#limitations)). [`FakeBle::make_payload()`](crate::radio::FakeBle::make_payload)
+/// will return [`None`] for any `buf` built from this service.
+///
+/// </div>
+#[derive(Debug, Clone, Copy)]
+pub struct IBeaconService {
+    buf: [u8; 27],
+}
+
+impl Default for IBeaconService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IBeaconService {
+    /// Apple's Bluetooth SIG company identifier (used by all iBeacon frames).
+    const APPLE_COMPANY_ID: [u8; 2] = [0x4C, 0x00];
+    /// The iBeacon sub-type and sub-length bytes.
+    const BEACON_TYPE: [u8; 2] = [0x02, 0x15];
+
+    /// Create an instance of [`IBeaconService`].
+    pub fn new() -> Self {
+        let mut data = [0u8; 27];
+        data[0] = 0x1A; // chunk length (including type)
+        data[1] = 0xFF; // chunk type. 0xFF means manufacturer specific data.
+        data[2..4].copy_from_slice(&Self::APPLE_COMPANY_ID);
+        data[4..6].copy_from_slice(&Self::BEACON_TYPE);
+        Self { buf: data }
+    }
+
+    /// Set the 16-byte proximity UUID.
+    pub fn set_uuid(&mut self, uuid: [u8; 16]) {
+        self.buf[6..22].copy_from_slice(&uuid);
+    }
+
+    /// Get the 16-byte proximity UUID.
+    pub fn uuid(&self) -> [u8; 16] {
+        let mut uuid = [0u8; 16];
+        uuid.copy_from_slice(&self.buf[6..22]);
+        uuid
+    }
+
+    /// Set the major value.
+    pub fn set_major(&mut self, major: u16) {
+        self.buf[22..24].copy_from_slice(&major.to_be_bytes());
+    }
+
+    /// Get the major value.
+    pub fn major(&self) -> u16 {
+        u16::from_be_bytes([self.buf[22], self.buf[23]])
+    }
+
+    /// Set the minor value.
+    pub fn set_minor(&mut self, minor: u16) {
+        self.buf[24..26].copy_from_slice(&minor.to_be_bytes());
+    }
+
+    /// Get the minor value.
+    pub fn minor(&self) -> u16 {
+        u16::from_be_bytes([self.buf[24], self.buf[25]])
+    }
+
+    /// Set the measured power (in dBm) at 1 meter.
+    pub fn set_measured_power(&mut self, power: i8) {
+        self.buf[26] = power as u8;
+    }
+
+    /// Get the measured power (in dBm) at 1 meter.
+    pub fn measured_power(&self) -> i8 {
+        self.buf[26] as i8
+    }
+}
+
+impl prelude::AsBuffer for IBeaconService {
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl prelude::FromBuffer for IBeaconService {
+    fn from_buffer(buf: &[u8]) -> Self {
+        let max_len = buf.len().min(27);
+        let mut self_buf = [0u8; 27];
+        self_buf[0..max_len].copy_from_slice(&buf[0..max_len]);
+        Self { buf: self_buf }
+    }
+}
+
+/// A data service for broadcasting arbitrary manufacturer-specific data.
+///
+/// Conforms to the Manufacturer Specific Data AD structure (type `0xFF`): a 2-byte
+/// little-endian company identifier followed by an arbitrary payload. Unlike
+/// [`IBeaconService`] (a fixed Apple-defined layout under the same AD type), this service
+/// lets callers transmit and decode their own custom beacon formats.
+#[derive(Debug, Clone, Copy)]
+pub struct ManufacturerDataService {
+    buf: [u8; 18],
+}
+
+impl Default for ManufacturerDataService {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl ManufacturerDataService {
+    /// Create an instance of [`ManufacturerDataService`] for the given company identifier.
+    pub fn new(company_id: u16) -> Self {
+        let mut data = [0u8; 18];
+        data[0] = 3; // chunk length (including type and company ID, excluding payload)
+        data[1] = 0xFF; // chunk type. 0xFF means manufacturer specific data.
+        data[2..4].copy_from_slice(&company_id.to_le_bytes());
+        Self { buf: data }
+    }
+
+    /// Get the company identifier.
+    pub fn company_id(&self) -> u16 {
+        u16::from_le_bytes([self.buf[2], self.buf[3]])
+    }
+
+    /// Set the payload that follows the company identifier.
+    pub fn set_data(&mut self, payload: &[u8]) {
+        let max_len = self.buf.len() - 4;
+        let len = payload.len().min(max_len);
+        self.buf[4..4 + len].copy_from_slice(&payload[0..len]);
+        self.buf[0] = 3 + len as u8;
+    }
+
+    /// Get the payload that follows the company identifier.
+    pub fn data(&self) -> &[u8] {
+        let len = (self.buf[0] as usize).saturating_sub(3);
+        &self.buf[4..4 + len]
+    }
+
+    /// Like [`ManufacturerDataService::set_data()`], but rejects `payload` instead of
+    /// silently truncating it if it wouldn't fit within `ble`'s 18-byte advertisement
+    /// budget (see [`FakeBle::len_available()`](crate::radio::FakeBle::len_available)).
+    pub fn set_data_for(
+        &mut self,
+        ble: &crate::radio::FakeBle,
+        payload: &[u8],
+    ) -> Result<(), crate::advertisement::AdvertisementError> {
+        use crate::advertisement::AdvertisementError;
+        use prelude::AsBuffer;
+
+        let max_len = self.buf.len() - 4;
+        if payload.len() > max_len {
+            return Err(AdvertisementError::BufferFull);
+        }
+        let mut candidate = *self;
+        candidate.set_data(payload);
+        if ble.len_available(candidate.buffer()) < 0 {
+            return Err(AdvertisementError::ExceedsDeviceBudget);
+        }
+        *self = candidate;
+        Ok(())
+    }
+}
+
+impl prelude::AsBuffer for ManufacturerDataService {
+    /// Transform the service data into a BLE compliant buffer that is ready for broadcasting.
+    fn buffer(&self) -> &[u8] {
+        let len = self.buf[0] + 1;
+        &self.buf[0..len as usize]
+    }
+}
+
+impl prelude::FromBuffer for ManufacturerDataService {
+    fn from_buffer(buf: &[u8]) -> Self {
+        let max_len = buf.len().min(18);
+        let mut self_buf = [0u8; 18];
+        self_buf[0..max_len].copy_from_slice(&buf[0..max_len]);
+        Self { buf: self_buf }
+    }
+}
+
+/// An unrecognized Advertising Data structure, captured verbatim.
+///
+/// The captured bytes (length byte, type byte, and payload) are exactly what a
+/// recognized service's `FromBuffer::from_buffer()` would expect, e.g.
+/// [`ManufacturerDataService::from_buffer()`] for AD type `0xFF`.
+#[derive(Debug, Clone, Copy)]
+pub struct RawAdStructure {
+    /// The AD structure's type byte (e.g. `0xFF` for manufacturer specific data).
+    pub ad_type: u8,
+    len: u8,
+    raw: [u8; 27],
+}
+
+impl RawAdStructure {
+    fn capture(chunk: &[u8]) -> Self {
+        let mut raw = [0u8; 27];
+        let len = chunk.len().min(27);
+        raw[0..len].copy_from_slice(&chunk[0..len]);
+        Self {
+            ad_type: chunk[1],
+            len: len as u8,
+            raw,
+        }
+    }
+
+    /// Get the raw AD structure bytes (length byte, type byte, and payload).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.raw[0..self.len as usize]
+    }
+
+    /// Decode the 2-byte service-data UUID (for [`RawAdStructure::ad_type`] `0x16`) or
+    /// manufacturer company identifier (for `0xFF`) leading this structure's payload, if
+    /// the payload is at least that long.
+    pub fn service_id(&self) -> Option<u16> {
+        let bytes = self.as_bytes();
+        if bytes.len() < 4 {
+            return None;
+        }
+        Some(u16::from_le_bytes([bytes[2], bytes[3]]))
+    }
+
+    /// The raw payload following [`RawAdStructure::service_id()`], if any.
+    pub fn payload(&self) -> &[u8] {
+        let bytes = self.as_bytes();
+        if bytes.len() < 4 {
+            return &[];
+        }
+        &bytes[4..]
+    }
+
+    /// The raw data following [`RawAdStructure::ad_type`], with no further interpretation.
+    ///
+    /// Unlike [`RawAdStructure::payload()`], this doesn't assume a leading 2-byte service
+    /// UUID or company identifier, so it applies to any AD type (e.g. Flags, Local Name,
+    /// Service UUID lists).
+    pub fn data(&self) -> &[u8] {
+        &self.as_bytes()[2..]
+    }
+}
+
+/// The maximum number of AD structures [`BlePayload::decode_ad_structures()`] can return
+/// without the `std` feature, bounded by the 18-byte advertisement budget divided by the
+/// smallest possible 2-byte AD structure.
+#[cfg(not(feature = "std"))]
+pub const MAX_AD_STRUCTURES: usize = 9;
+
 /// A structure to represent received BLE data.
 pub struct BlePayload {
     pub mac_address: [u8; 6],
@@ -286,28 +763,50 @@ pub struct BlePayload {
     pub battery_charge: Option<BatteryService>,
     pub url: Option<UrlService>,
     pub temperature: Option<TemperatureService>,
+    pub eddystone_uid: Option<EddystoneUidService>,
+    pub eddystone_tlm: Option<EddystoneTlmService>,
+    pub ibeacon: Option<IBeaconService>,
+    /// Any AD structures that aren't one of the built-in services above, captured verbatim.
+    #[cfg(feature = "std")]
+    pub unsupported: std::vec::Vec<RawAdStructure>,
+    /// Any AD structures that aren't one of the built-in services above, captured verbatim.
+    ///
+    /// Limited to 4 entries when built without the `std` feature; additional unrecognized
+    /// AD structures beyond that are silently dropped.
+    #[cfg(not(feature = "std"))]
+    pub unsupported: [Option<RawAdStructure>; 4],
+}
+
+/// De-whiten `buf` (received over `channel`) and verify its CRC, returning the length of
+/// the payload (header plus GAP AD structures, excluding the trailing CRC) on success.
+///
+/// Shared by [`BlePayload::from_bytes()`] and [`BlePayload::decode_ad_structures()`], since
+/// both need the same de-whitening/CRC preamble before they can walk the payload.
+fn dewhiten_and_verify_crc(buf: &mut [u8], channel: u8) -> Option<usize> {
+    reverse_bits(buf);
+    let coefficient = (BleChannels::index_of(channel).unwrap_or_default() as u8 + 37) | 0x40;
+    whiten(buf, coefficient);
+
+    let len = buf[1] as usize;
+    if len > 27 {
+        return None;
+    }
+    let len = len + 2;
+
+    let mut crc = [0u8; 3];
+    crc.copy_from_slice(&buf[len..len + 3]);
+    let expected = crc24_ble(&buf[0..len]);
+    if crc != expected {
+        return None;
+    }
+    Some(len)
 }
 
 impl BlePayload {
     pub fn from_bytes(buf: &mut [u8], channel: u8) -> Option<Self> {
         use prelude::FromBuffer;
 
-        reverse_bits(buf);
-        let coefficient = (BleChannels::index_of(channel).unwrap_or_default() as u8 + 37) | 0x40;
-        whiten(buf, coefficient);
-
-        let len = buf[1] as usize;
-        if len > 27 {
-            return None;
-        }
-        let len = len + 2;
-
-        let mut crc = [0u8; 3];
-        crc.copy_from_slice(&buf[len..len + 3]);
-        let expected = crc24_ble(&buf[0..len]);
-        if crc != expected {
-            return None;
-        }
+        let len = dewhiten_and_verify_crc(buf, channel)?;
 
         let mut mac_address = [0u8; 6];
         mac_address.copy_from_slice(&buf[2..8]);
@@ -317,13 +816,41 @@ impl BlePayload {
         let mut battery_charge = None;
         let mut temperature = None;
         let mut url = None;
+        let mut eddystone_uid = None;
+        let mut eddystone_tlm = None;
+        let mut ibeacon = None;
+        #[cfg(feature = "std")]
+        let mut unsupported: std::vec::Vec<RawAdStructure> = std::vec::Vec::new();
+        #[cfg(not(feature = "std"))]
+        let mut unsupported: [Option<RawAdStructure>; 4] = [None; 4];
+        #[cfg(not(feature = "std"))]
+        let mut unsupported_len = 0usize;
+
+        macro_rules! capture_unsupported {
+            ($chunk:expr) => {{
+                #[cfg(feature = "std")]
+                unsupported.push(RawAdStructure::capture($chunk));
+                #[cfg(not(feature = "std"))]
+                {
+                    if unsupported_len < unsupported.len() {
+                        unsupported[unsupported_len] = Some(RawAdStructure::capture($chunk));
+                        unsupported_len += 1;
+                    }
+                }
+            }};
+        }
 
         let mut index = 8_usize;
-        while index < len {
+        while index + 1 < len && buf[index] > 0 {
             let chunk_len = (buf[index] - 1) as usize;
             let chunk_type = buf[index + 1];
             let start = index + 2;
             let end = index + chunk_len + 2;
+            if end > len {
+                // a malformed AD structure claims more data than is available;
+                // stop parsing rather than index out of bounds.
+                break;
+            }
             match chunk_type {
                 0x08 | 0x09 => {
                     let mut name = [0u8; 10];
@@ -348,15 +875,38 @@ impl BlePayload {
                             temperature = Some(temp);
                         }
                         EDDYSTONE_UUID => {
-                            let eddystone = UrlService::from_buffer(&buf[index..end]);
-                            url = Some(eddystone);
+                            // byte following the UUID is the Eddystone frame type:
+                            // 0x10 is the URL frame, 0x00 is the UID frame, 0x20 is the
+                            // TLM (telemetry) frame.
+                            match buf[start + 2] {
+                                0x00 => {
+                                    eddystone_uid =
+                                        Some(EddystoneUidService::from_buffer(&buf[index..end]));
+                                }
+                                0x20 => {
+                                    eddystone_tlm =
+                                        Some(EddystoneTlmService::from_buffer(&buf[index..end]));
+                                }
+                                _ => {
+                                    url = Some(UrlService::from_buffer(&buf[index..end]));
+                                }
+                            }
                         }
-                        _ => {}
+                        _ => {
+                            capture_unsupported!(&buf[index..end]);
+                        }
+                    }
+                }
+                0xFF => {
+                    if end - start >= 4 && buf[start..start + 2] == IBeaconService::APPLE_COMPANY_ID
+                    {
+                        ibeacon = Some(IBeaconService::from_buffer(&buf[index..end]));
+                    } else {
+                        capture_unsupported!(&buf[index..end]);
                     }
                 }
                 _ => {
-                    // unsupported chunk type
-                    // TODO: save arbitrary data from chunk as a buffer
+                    capture_unsupported!(&buf[index..end]);
                 }
             }
             index = end;
@@ -368,8 +918,113 @@ impl BlePayload {
             battery_charge,
             url,
             temperature,
+            eddystone_uid,
+            eddystone_tlm,
+            ibeacon,
+            unsupported,
         })
     }
+
+    /// Get the number of captured unrecognized AD structures (see [`BlePayload::unsupported`]).
+    pub fn unsupported_len(&self) -> usize {
+        #[cfg(feature = "std")]
+        return self.unsupported.len();
+        #[cfg(not(feature = "std"))]
+        return self.unsupported.iter().filter(|e| e.is_some()).count();
+    }
+
+    /// Get a captured unrecognized AD structure by index (see [`BlePayload::unsupported`]).
+    pub fn nth_unsupported(&self, index: usize) -> Option<&RawAdStructure> {
+        #[cfg(feature = "std")]
+        return self.unsupported.get(index);
+        #[cfg(not(feature = "std"))]
+        return self.unsupported.get(index).and_then(|e| e.as_ref());
+    }
+
+    /// Decode `buf` (a received advertisement, same preconditions as
+    /// [`BlePayload::from_bytes()`]) into its raw sequence of GAP AD structures, without
+    /// interpreting any of them as one of this crate's built-in services.
+    ///
+    /// Unlike [`BlePayload::from_bytes()`] (which only captures AD structures it doesn't
+    /// recognize into [`BlePayload::unsupported`]), this returns *every* AD structure found
+    /// in the payload verbatim. This is useful for sniffing third-party advertisers (flags,
+    /// appearance, service UUID lists, manufacturer data, etc.) that this crate has no
+    /// built-in decoder for.
+    ///
+    /// A malformed AD structure (one claiming more data than remains in the payload) stops
+    /// parsing and returns whatever was parsed up to that point, the same as
+    /// [`BlePayload::from_bytes()`].
+    ///
+    /// Returns [`None`] if the CRC check fails.
+    ///
+    /// Also returns the advertiser's 6-byte MAC address alongside the AD structures, the
+    /// same as [`BlePayload::from_bytes()`]'s [`BlePayload::mac_address`].
+    #[cfg(feature = "std")]
+    pub fn decode_ad_structures(
+        buf: &mut [u8],
+        channel: u8,
+    ) -> Option<([u8; 6], std::vec::Vec<RawAdStructure>)> {
+        let len = dewhiten_and_verify_crc(buf, channel)?;
+        let mut mac_address = [0u8; 6];
+        mac_address.copy_from_slice(&buf[2..8]);
+        let mut result = std::vec::Vec::new();
+        let mut index = 8_usize;
+        while index + 1 < len && buf[index] > 0 {
+            let chunk_len = (buf[index] - 1) as usize;
+            let end = index + chunk_len + 2;
+            if end > len {
+                break;
+            }
+            result.push(RawAdStructure::capture(&buf[index..end]));
+            index = end;
+        }
+        Some((mac_address, result))
+    }
+
+    /// Decode `buf` (a received advertisement, same preconditions as
+    /// [`BlePayload::from_bytes()`]) into its raw sequence of GAP AD structures, without
+    /// interpreting any of them as one of this crate's built-in services.
+    ///
+    /// Unlike [`BlePayload::from_bytes()`] (which only captures AD structures it doesn't
+    /// recognize into [`BlePayload::unsupported`]), this returns *every* AD structure found
+    /// in the payload verbatim. This is useful for sniffing third-party advertisers (flags,
+    /// appearance, service UUID lists, manufacturer data, etc.) that this crate has no
+    /// built-in decoder for.
+    ///
+    /// A malformed AD structure (one claiming more data than remains in the payload) stops
+    /// parsing and returns whatever was parsed up to that point, the same as
+    /// [`BlePayload::from_bytes()`]. Limited to [`MAX_AD_STRUCTURES`] entries; additional AD
+    /// structures beyond that are silently dropped.
+    ///
+    /// Returns [`None`] if the CRC check fails.
+    ///
+    /// Also returns the advertiser's 6-byte MAC address alongside the AD structures, the
+    /// same as [`BlePayload::from_bytes()`]'s [`BlePayload::mac_address`].
+    #[cfg(not(feature = "std"))]
+    pub fn decode_ad_structures(
+        buf: &mut [u8],
+        channel: u8,
+    ) -> Option<([u8; 6], [Option<RawAdStructure>; MAX_AD_STRUCTURES])> {
+        let len = dewhiten_and_verify_crc(buf, channel)?;
+        let mut mac_address = [0u8; 6];
+        mac_address.copy_from_slice(&buf[2..8]);
+        let mut result: [Option<RawAdStructure>; MAX_AD_STRUCTURES] = [None; MAX_AD_STRUCTURES];
+        let mut result_len = 0usize;
+        let mut index = 8_usize;
+        while index + 1 < len && buf[index] > 0 {
+            let chunk_len = (buf[index] - 1) as usize;
+            let end = index + chunk_len + 2;
+            if end > len {
+                break;
+            }
+            if result_len < result.len() {
+                result[result_len] = Some(RawAdStructure::capture(&buf[index..end]));
+                result_len += 1;
+            }
+            index = end;
+        }
+        Some((mac_address, result))
+    }
 }
 
 #[cfg(test)]
@@ -377,8 +1032,9 @@ mod test {
     use rf24::PaLevel;
 
     use super::{
-        prelude::{AsBuffer, ServiceData},
-        BatteryService, BlePayload, TemperatureService, UrlService,
+        prelude::{AsBuffer, FromBuffer, ServiceData},
+        BatteryService, BlePayload, EddystoneTlmService, EddystoneUidService, IBeaconService,
+        ManufacturerDataService, TemperatureService, UrlService,
     };
     use crate::data_manipulation::{reverse_bits, whiten};
     use crate::{BleChannels, FakeBle, BLE_CHANNEL};
@@ -417,6 +1073,91 @@ mod test {
         );
     }
 
+    #[test]
+    fn url_service_set_data_for() {
+        use crate::advertisement::AdvertisementError;
+
+        let mut ble = FakeBle::default();
+        ble.set_name("01234");
+
+        let mut url = UrlService::default();
+        // the device's name already occupies most of the 18-byte budget
+        assert_eq!(
+            url.set_data_for(&ble, "https://www.foo.com/bar/bazz"),
+            Err(AdvertisementError::ExceedsDeviceBudget)
+        );
+        assert_eq!(url.pa_level(), -25);
+
+        assert_eq!(url.set_data_for(&ble, "https://foo.com"), Ok(()));
+        assert_eq!(
+            [0x0A, 0x16, 0xAA, 0xFE, 0x10, 0xE7, 0x03, 0x66, 0x6F, 0x6F, 0x07],
+            url.buffer()
+        );
+    }
+
+    #[test]
+    fn eddystone_uid_service() {
+        let mut uid = EddystoneUidService::default();
+        uid.set_pa_level(-20);
+        uid.set_namespace([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        uid.set_instance([0xA, 0xB, 0xC, 0xD, 0xE, 0xF]);
+        assert_eq!(uid.pa_level(), -20);
+        assert_eq!(uid.namespace(), [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(uid.instance(), [0xA, 0xB, 0xC, 0xD, 0xE, 0xF]);
+        assert_eq!(
+            [
+                0x17, 0x16, 0xAA, 0xFE, 0x00, 0xEC, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0xA, 0xB, 0xC,
+                0xD, 0xE, 0xF, 0, 0
+            ],
+            *uid.buffer()
+        );
+    }
+
+    #[test]
+    fn eddystone_tlm_service() {
+        let mut tlm = EddystoneTlmService::default();
+        assert!(!tlm.has_battery_voltage());
+        assert!(!tlm.has_temperature());
+        tlm.set_battery_voltage(3000);
+        tlm.set_temperature(22.5);
+        tlm.set_pdu_count(12345);
+        tlm.set_uptime(600);
+        assert_eq!(tlm.battery_voltage(), 3000);
+        assert_eq!(tlm.temperature(), 22.5);
+        assert_eq!(tlm.pdu_count(), 12345);
+        assert_eq!(tlm.uptime(), 600);
+        assert!(tlm.has_battery_voltage());
+        assert!(tlm.has_temperature());
+        assert_eq!(
+            [
+                0x11, 0x16, 0xAA, 0xFE, 0x20, 0x00, 0x0B, 0xB8, 0x16, 0x80, 0x00, 0x00, 0x30,
+                0x39, 0x00, 0x00, 0x02, 0x58
+            ],
+            *tlm.buffer()
+        );
+    }
+
+    #[test]
+    fn ibeacon_service() {
+        let mut beacon = IBeaconService::default();
+        let uuid = [0x11; 16];
+        beacon.set_uuid(uuid);
+        beacon.set_major(1);
+        beacon.set_minor(2);
+        beacon.set_measured_power(-59);
+        assert_eq!(beacon.uuid(), uuid);
+        assert_eq!(beacon.major(), 1);
+        assert_eq!(beacon.minor(), 2);
+        assert_eq!(beacon.measured_power(), -59);
+        assert_eq!(
+            [
+                0x1A, 0xFF, 0x4C, 0x00, 0x02, 0x15, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+                0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0, 1, 0, 2, -59i8 as u8
+            ],
+            *beacon.buffer()
+        );
+    }
+
     #[test]
     fn rx_battery() {
         let mut service = BatteryService::default();
@@ -470,6 +1211,109 @@ mod test {
         }
     }
 
+    #[test]
+    fn eddystone_uid_exceeds_ble_budget() {
+        let service = EddystoneUidService::default();
+        let ble = FakeBle::default();
+        assert!(ble
+            .make_payload(service.buffer(), None, BLE_CHANNEL[0])
+            .is_none());
+    }
+
+    #[test]
+    fn ibeacon_exceeds_ble_budget() {
+        let service = IBeaconService::default();
+        let ble = FakeBle::default();
+        assert!(ble
+            .make_payload(service.buffer(), None, BLE_CHANNEL[0])
+            .is_none());
+    }
+
+    #[test]
+    fn rx_eddystone_uid() {
+        let mut service = EddystoneUidService::default();
+        service.set_namespace([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        service.set_instance([0xA, 0xB, 0xC, 0xD, 0xE, 0xF]);
+        let buffer = service.buffer();
+
+        // hand-assemble a raw OTA frame, bypassing FakeBle::make_payload()'s budget
+        // check, to exercise BlePayload::from_bytes()'s Eddystone-UID frame-type
+        // disambiguation in isolation.
+        let channel = BLE_CHANNEL[0];
+        let coefficient = (BleChannels::index_of(channel).unwrap() as u8 + 37) | 0x40;
+        let mut payload = [0u8; 32];
+        payload[0] = 0x42;
+        payload[1] = (buffer.len() + 9) as u8;
+        payload[2..8].copy_from_slice(&[0x11; 6]);
+        payload[8..11].copy_from_slice(&[2, 1, 5]);
+        payload[11..11 + buffer.len()].copy_from_slice(buffer);
+        let len = 2 + payload[1] as usize;
+        let crc = super::crc24_ble(&payload[0..len]);
+        payload[len..len + 3].copy_from_slice(&crc);
+        whiten(&mut payload, coefficient);
+        reverse_bits(&mut payload);
+
+        let ble_payload = BlePayload::from_bytes(&mut payload, channel).unwrap();
+        let uid = ble_payload.eddystone_uid.unwrap();
+        assert_eq!(uid.namespace(), service.namespace());
+        assert_eq!(uid.instance(), service.instance());
+    }
+
+    #[test]
+    fn rx_eddystone_tlm() {
+        let mut service = EddystoneTlmService::default();
+        service.set_battery_voltage(3000);
+        service.set_temperature(22.5);
+        service.set_pdu_count(12345);
+        service.set_uptime(600);
+        let buffer = service.buffer();
+
+        let ble = FakeBle::default();
+        let channel = BLE_CHANNEL[0];
+        let mut payload = ble.make_payload(buffer, None, channel).unwrap();
+
+        let ble_payload = BlePayload::from_bytes(&mut payload, channel).unwrap();
+        let tlm = ble_payload.eddystone_tlm.unwrap();
+        assert_eq!(tlm.battery_voltage(), service.battery_voltage());
+        assert_eq!(tlm.temperature(), service.temperature());
+        assert_eq!(tlm.pdu_count(), service.pdu_count());
+        assert_eq!(tlm.uptime(), service.uptime());
+    }
+
+    #[test]
+    fn rx_ibeacon() {
+        let mut service = IBeaconService::default();
+        service.set_uuid([0x22; 16]);
+        service.set_major(1);
+        service.set_minor(2);
+        service.set_measured_power(-59);
+        let buffer = service.buffer();
+
+        // hand-assemble a raw OTA frame, bypassing FakeBle::make_payload()'s budget
+        // check, to exercise BlePayload::from_bytes()'s manufacturer-data (0xFF)
+        // parsing in isolation.
+        let channel = BLE_CHANNEL[0];
+        let coefficient = (BleChannels::index_of(channel).unwrap() as u8 + 37) | 0x40;
+        let mut payload = [0u8; 32];
+        payload[0] = 0x42;
+        payload[1] = (buffer.len() + 9) as u8;
+        payload[2..8].copy_from_slice(&[0x11; 6]);
+        payload[8..11].copy_from_slice(&[2, 1, 5]);
+        payload[11..11 + buffer.len()].copy_from_slice(buffer);
+        let len = 2 + payload[1] as usize;
+        let crc = super::crc24_ble(&payload[0..len]);
+        payload[len..len + 3].copy_from_slice(&crc);
+        whiten(&mut payload, coefficient);
+        reverse_bits(&mut payload);
+
+        let ble_payload = BlePayload::from_bytes(&mut payload, channel).unwrap();
+        let beacon = ble_payload.ibeacon.unwrap();
+        assert_eq!(beacon.uuid(), service.uuid());
+        assert_eq!(beacon.major(), service.major());
+        assert_eq!(beacon.minor(), service.minor());
+        assert_eq!(beacon.measured_power(), service.measured_power());
+    }
+
     #[test]
     fn rx_too_big() {
         let channel = BLE_CHANNEL[0];
@@ -493,6 +1337,27 @@ mod test {
         assert!(BlePayload::from_bytes(&mut payload, coefficient).is_none());
     }
 
+    #[test]
+    fn custom_service_decode() {
+        let buffer = [4u8, 0x16, 0x0F, 0xFF, 0xAB];
+
+        let ble = FakeBle::default();
+        let channel = BLE_CHANNEL[0];
+        let mut payload = ble
+            .make_payload(&buffer, Some(PaLevel::Min), channel)
+            .unwrap();
+
+        let ble_payload = BlePayload::from_bytes(
+            &mut payload,
+            (BleChannels::index_of(channel).unwrap() as u8 + 37) | 0x40,
+        )
+        .unwrap();
+        let custom = ble_payload.nth_unsupported(0).unwrap();
+        assert_eq!(custom.ad_type, 0x16);
+        assert_eq!(custom.service_id(), Some(0xFF0F));
+        assert_eq!(custom.payload(), &[0xAB]);
+    }
+
     #[test]
     fn rx_unsupported_service() {
         let buffer = [4u8, 0x16, 0xFF, 0x0F, 0xFF];
@@ -509,6 +1374,125 @@ mod test {
         )
         .unwrap();
         assert_eq!(&ble.mac_address, &ble_payload.mac_address);
-        // TODO decode custom data
+        assert_eq!(ble_payload.unsupported_len(), 1);
+        assert_eq!(
+            ble_payload.nth_unsupported(0).unwrap().as_bytes(),
+            &buffer[..]
+        );
+    }
+
+    #[test]
+    fn manufacturer_data_service() {
+        let mut service = ManufacturerDataService::new(0x1234);
+        service.set_data(&[0xAA, 0xBB, 0xCC]);
+        assert_eq!(service.company_id(), 0x1234);
+        assert_eq!(service.data(), [0xAA, 0xBB, 0xCC]);
+        assert_eq!(
+            [0x06, 0xFF, 0x34, 0x12, 0xAA, 0xBB, 0xCC],
+            *service.buffer()
+        );
+    }
+
+    #[test]
+    fn manufacturer_data_service_set_data_for() {
+        use crate::advertisement::AdvertisementError;
+
+        let mut ble = FakeBle::default();
+        ble.set_name("0123456789");
+
+        let mut service = ManufacturerDataService::new(0x1234);
+        // the device's name already occupies most of the 18-byte budget
+        assert_eq!(
+            service.set_data_for(&ble, &[0xAAu8; 10]),
+            Err(AdvertisementError::ExceedsDeviceBudget)
+        );
+        assert_eq!(service.data(), &[] as &[u8]);
+
+        assert_eq!(service.set_data_for(&ble, &[0xAA, 0xBB]), Ok(()));
+        assert_eq!(service.data(), [0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn rx_manufacturer_data() {
+        let mut service = ManufacturerDataService::new(0x1234);
+        service.set_data(&[0xAA, 0xBB, 0xCC]);
+        let buffer = service.buffer();
+
+        // hand-assemble a raw OTA frame, bypassing FakeBle::make_payload()'s budget
+        // check, to exercise BlePayload::from_bytes()'s manufacturer-data capture in
+        // isolation (since it isn't Apple's iBeacon company ID).
+        let channel = BLE_CHANNEL[0];
+        let coefficient = (BleChannels::index_of(channel).unwrap() as u8 + 37) | 0x40;
+        let mut payload = [0u8; 32];
+        payload[0] = 0x42;
+        payload[1] = (buffer.len() + 9) as u8;
+        payload[2..8].copy_from_slice(&[0x11; 6]);
+        payload[8..11].copy_from_slice(&[2, 1, 5]);
+        payload[11..11 + buffer.len()].copy_from_slice(buffer);
+        let len = 2 + payload[1] as usize;
+        let crc = super::crc24_ble(&payload[0..len]);
+        payload[len..len + 3].copy_from_slice(&crc);
+        whiten(&mut payload, coefficient);
+        reverse_bits(&mut payload);
+
+        let ble_payload = BlePayload::from_bytes(&mut payload, channel).unwrap();
+        assert_eq!(ble_payload.unsupported_len(), 1);
+        let captured = ble_payload.nth_unsupported(0).unwrap();
+        assert_eq!(captured.ad_type, 0xFF);
+        assert_eq!(captured.as_bytes(), buffer);
+        let decoded = ManufacturerDataService::from_buffer(captured.as_bytes());
+        assert_eq!(decoded.company_id(), service.company_id());
+        assert_eq!(decoded.data(), service.data());
+    }
+
+    #[test]
+    fn rx_malformed_chunk_len() {
+        // a 0-length AD structure chunk is bogus per BLE specs; ensure decoding
+        // stops gracefully instead of underflowing/panicking while parsing it.
+        let buffer = [0u8, 0xFFu8];
+
+        let ble = FakeBle::default();
+        let channel = BLE_CHANNEL[0];
+        let mut payload = ble.make_payload(&buffer, None, channel).unwrap();
+
+        let ble_payload = BlePayload::from_bytes(
+            &mut payload,
+            (BleChannels::index_of(channel).unwrap() as u8 + 37) | 0x40,
+        )
+        .unwrap();
+        assert_eq!(&ble.mac_address, &ble_payload.mac_address);
+    }
+
+    #[test]
+    fn decode_ad_structures() {
+        let mut service = BatteryService::default();
+        service.set_data(85);
+
+        let ble = FakeBle::default();
+        let channel = BLE_CHANNEL[0];
+        let mut payload = ble
+            .make_payload(service.buffer(), Some(PaLevel::Low), channel)
+            .unwrap();
+
+        let (mac_address, structures) =
+            BlePayload::decode_ad_structures(&mut payload, channel).unwrap();
+        assert_eq!(&mac_address, &ble.mac_address);
+        assert_eq!(structures.len(), 3);
+        assert_eq!(structures[0].ad_type, 0x01); // profile flags, always present
+        assert_eq!(structures[1].ad_type, 0x0A); // tx_power
+        assert_eq!(structures[1].data(), &[-12i8 as u8]);
+        assert_eq!(structures[2].ad_type, 0x16); // service data
+        assert_eq!(structures[2].as_bytes(), service.buffer());
+    }
+
+    #[test]
+    fn decode_ad_structures_bad_crc() {
+        let ble = FakeBle::default();
+        let channel = BLE_CHANNEL[0];
+        let coefficient = (BleChannels::index_of(channel).unwrap() as u8 + 37) | 0x40;
+
+        let mut payload = ble.make_payload(&[17u8; 18], None, channel).unwrap();
+        reverse_bits(&mut payload[29..32]);
+        assert!(BlePayload::decode_ad_structures(&mut payload, coefficient).is_none());
     }
 }
@@ -11,32 +11,61 @@ use embassy_rp::Peripherals;
 use embassy_sync::blocking_mutex::raw::NoopRawMutex;
 use embassy_sync::blocking_mutex::Mutex;
 
+/// RP2040's reference clock feeding the SPI peripheral, in Hz. This is the default
+/// `clk_peri` when `embassy_rp::init()` is given no custom clock configuration.
+const CLK_PERI_HZ: u32 = 125_000_000;
+
+/// Compute the SPI clock (in Hz) RP2040 can actually achieve for `requested_hz`, given
+/// its `clk_peri / presc / postdiv` clock chain (`presc` an even value in `2..=254`,
+/// `postdiv` in `1..=256`).
+///
+/// `presc` is chosen as the smallest even value such that `clk_peri / presc <=
+/// requested_hz * 256` (the largest `postdiv` can divide down further), then `postdiv`
+/// is rounded up to the smallest value that doesn't exceed `requested_hz`. Requests
+/// that don't divide evenly therefore round down to the nearest attainable frequency
+/// rather than erroring.
+fn achievable_spi_speed(clk_peri_hz: u32, requested_hz: u32) -> u32 {
+    let requested_hz = requested_hz.max(1);
+    let mut presc = 2u32;
+    while presc < 254 && clk_peri_hz / presc > requested_hz.saturating_mul(256) {
+        presc += 2;
+    }
+    let postdiv = clk_peri_hz.div_ceil(presc * requested_hz).clamp(1, 256);
+    clk_peri_hz / (presc * postdiv)
+}
+
 pub struct BoardHardware<'b> {
     peri: Peripherals,
     spi_bus_mutex: Mutex<NoopRawMutex, RefCell<Spi<'b, SPI1, Blocking>>>,
 }
 
 impl BoardHardware<'_> {
-    pub fn new() -> Self {
+    /// Build the board with a non-default SPI clock frequency.
+    ///
+    /// `spi_speed_hz` is clamped to the nearest frequency RP2040's SPI peripheral can
+    /// actually produce; see [`achievable_spi_speed`].
+    pub fn with_spi_speed(spi_speed_hz: u32) -> Result<Self> {
         let peri = embassy_rp::init(Default::default());
 
         let clk = peri.PIN_10;
         let mosi = peri.PIN_11;
         let miso = peri.PIN_12;
         let mut spi_config = Config::default();
-        spi_config.frequency = 10_000_000;
+        spi_config.frequency = achievable_spi_speed(CLK_PERI_HZ, spi_speed_hz);
         let spi = Spi::new_blocking(peri.SPI1, clk, mosi, miso, spi_config);
         let spi_bus_mutex: Mutex<NoopRawMutex, RefCell<_>> = Mutex::new(RefCell::new(spi));
 
-        BoardHardware {
+        Ok(BoardHardware {
             peri,
             spi_bus_mutex,
-        }
+        })
     }
 }
 
 impl HardwareImpl for BoardHardware {
-    fn new() -> Result<Self>;
+    fn new() -> Result<Self> {
+        Self::with_spi_speed(10_000_000)
+    }
 
     fn default_ce_pin(&self) -> Result<impl OutputPin> {
         let ce = self.peri.PIN_9;
@@ -1,16 +1,33 @@
+//! A generic fallback hardware backend for any target that is neither Linux nor RP2040.
+//!
+//! Unlike [`crate::linux`] and [`crate::rp2040`], which wire up one specific board, this
+//! backend is generic over any user-supplied `embedded-hal` 1.0 [`SpiDevice`], [`OutputPin`],
+//! [`InputPin`], and [`DelayNs`] implementation. Construct a [`BoardHardware`] with
+//! [`BoardHardware::new_with()`] (or, with the `embassy` feature enabled,
+//! [`BoardHardware::from_embassy()`]) and wrap it around whatever peripherals your target
+//! exposes.
 #![cfg(not(target_os = "linux"))]
 
-use anyhow::Result;
-use embedded_hal::{delay::DelayNs, digital::{InputPin, OutputPin}, spi::SpiDevice};
+use anyhow::{anyhow, Result};
+use core::cell::RefCell;
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin},
+    spi::SpiDevice,
+};
+
 use crate::hal_impl_trait::HardwareImpl;
 
 extern crate std;
 pub use std::{print, println};
 
-pub struct DelayImpl;
-impl DelayNs for DelayImpl {
-    fn delay_ns(&mut self, _ns: u32) {
-        todo!()
+/// Wraps any user-supplied [`DelayNs`] implementation.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DelayImpl<D>(pub D);
+
+impl<D: DelayNs> DelayNs for DelayImpl<D> {
+    fn delay_ns(&mut self, ns: u32) {
+        self.0.delay_ns(ns)
     }
 }
 
@@ -19,8 +36,11 @@ pub mod digital {
         use anyhow::Error;
         use embedded_hal::digital::{Error as DigitalOutError, ErrorKind};
 
+        /// Wraps any GPIO error raised by a wrapped [`super::output::DigitalOutImpl`] or
+        /// [`super::input::DigitalInImpl`], preserving the wrapped HAL's [`ErrorKind`].
         #[derive(Debug)]
         pub struct DigitalInOutErrorImpl {
+            kind: ErrorKind,
             err: Error,
         }
 
@@ -29,11 +49,19 @@ pub mod digital {
             pub fn inner(&self) -> &Error {
                 &self.err
             }
+
+            /// The [`ErrorKind`] reported by the wrapped HAL implementation.
+            pub fn kind(&self) -> ErrorKind {
+                self.kind
+            }
         }
 
-        impl From<Error> for DigitalInOutErrorImpl {
-            fn from(err: Error) -> Self {
-                Self { err }
+        impl<E: DigitalOutError> From<E> for DigitalInOutErrorImpl {
+            fn from(err: E) -> Self {
+                Self {
+                    kind: err.kind(),
+                    err: Error::msg(std::format!("{err:?}")),
+                }
             }
         }
 
@@ -45,7 +73,7 @@ pub mod digital {
 
         impl DigitalOutError for DigitalInOutErrorImpl {
             fn kind(&self) -> ErrorKind {
-                ErrorKind::Other
+                self.kind
             }
         }
     }
@@ -53,52 +81,57 @@ pub mod digital {
     pub mod output {
         use embedded_hal::digital::{ErrorType, OutputPin};
 
-        #[derive(Default)]
-        pub struct DigitalOutImpl;
+        /// Wraps any user-supplied [`OutputPin`] so [`super::super::BoardHardware`] can stay
+        /// generic over the concrete pin type.
+        #[derive(Debug, Default)]
+        pub struct DigitalOutImpl<P>(pub P);
 
-        impl DigitalOutImpl {
-            pub fn new() -> Self {
-                Self {}
+        impl<P> DigitalOutImpl<P> {
+            pub fn new(pin: P) -> Self {
+                Self(pin)
             }
         }
 
-        impl ErrorType for DigitalOutImpl {
+        impl<P: OutputPin> ErrorType for DigitalOutImpl<P> {
             type Error = super::error::DigitalInOutErrorImpl;
         }
 
-        impl OutputPin for DigitalOutImpl {
+        impl<P: OutputPin> OutputPin for DigitalOutImpl<P> {
             fn set_low(&mut self) -> core::result::Result<(), Self::Error> {
-                todo!()
+                self.0.set_low().map_err(Into::into)
             }
 
             fn set_high(&mut self) -> core::result::Result<(), Self::Error> {
-                todo!()
+                self.0.set_high().map_err(Into::into)
             }
         }
     }
 
     pub mod input {
         use embedded_hal::digital::{ErrorType, InputPin};
-        #[derive(Default)]
-        pub struct DigitalInImpl;
 
-        impl DigitalInImpl {
-            pub fn new() -> Self {
-                Self {}
+        /// Wraps any user-supplied [`InputPin`] so [`super::super::BoardHardware`] can stay
+        /// generic over the concrete pin type.
+        #[derive(Debug, Default)]
+        pub struct DigitalInImpl<P>(pub P);
+
+        impl<P> DigitalInImpl<P> {
+            pub fn new(pin: P) -> Self {
+                Self(pin)
             }
         }
 
-        impl ErrorType for DigitalInImpl {
+        impl<P: InputPin> ErrorType for DigitalInImpl<P> {
             type Error = super::error::DigitalInOutErrorImpl;
         }
 
-        impl InputPin for DigitalInImpl {
+        impl<P: InputPin> InputPin for DigitalInImpl<P> {
             fn is_high(&mut self) -> Result<bool, Self::Error> {
-                todo!()
+                self.0.is_high().map_err(Into::into)
             }
 
             fn is_low(&mut self) -> Result<bool, Self::Error> {
-                todo!()
+                self.0.is_low().map_err(Into::into)
             }
         }
     }
@@ -113,8 +146,11 @@ pub mod spi {
         use anyhow::Error;
         use embedded_hal::spi::{Error as SpiError, ErrorKind};
 
+        /// Wraps any SPI error raised by a wrapped [`super::SpiImpl`], preserving the
+        /// wrapped HAL's [`ErrorKind`].
         #[derive(Debug)]
         pub struct SpiErrorImpl {
+            kind: ErrorKind,
             err: Error,
         }
 
@@ -122,11 +158,25 @@ pub mod spi {
             pub fn inner(&self) -> &Error {
                 &self.err
             }
+
+            /// The [`ErrorKind`] reported by the wrapped HAL implementation.
+            pub fn kind(&self) -> ErrorKind {
+                self.kind
+            }
+        }
+
+        impl<E: SpiError> From<E> for SpiErrorImpl {
+            fn from(err: E) -> Self {
+                Self {
+                    kind: err.kind(),
+                    err: Error::msg(std::format!("{err:?}")),
+                }
+            }
         }
 
         impl SpiError for SpiErrorImpl {
             fn kind(&self) -> ErrorKind {
-                ErrorKind::Other
+                self.kind
             }
         }
 
@@ -137,41 +187,149 @@ pub mod spi {
         }
     }
 
-    #[derive(Debug)]
-    pub struct SpiImpl;
+    /// Wraps any user-supplied [`SpiDevice`] so [`super::BoardHardware`] can stay generic
+    /// over the concrete SPI type.
+    #[derive(Debug, Default)]
+    pub struct SpiImpl<S>(pub S);
+
+    impl<S> SpiImpl<S> {
+        pub fn new(spi: S) -> Self {
+            Self(spi)
+        }
+    }
 
-    impl ErrorType for SpiImpl {
+    impl<S: SpiDevice> ErrorType for SpiImpl<S> {
         type Error = error::SpiErrorImpl;
     }
 
-    impl SpiDevice for SpiImpl {
+    impl<S: SpiDevice> SpiDevice for SpiImpl<S> {
         fn transaction(
             &mut self,
-            _operations: &mut [Operation<'_, u8>],
+            operations: &mut [Operation<'_, u8>],
         ) -> Result<(), Self::Error> {
-            todo!()
+            self.0.transaction(operations).map_err(Into::into)
         }
     }
 }
 
 pub use spi::SpiImpl;
 
+/// A generic board backend that wraps already-constructed `embedded-hal` 1.0 peripherals.
+///
+/// Each peripheral is held behind a [`RefCell`] so [`HardwareImpl`]'s `&self` accessors can
+/// hand out ownership of it exactly once, mirroring how [`crate::linux::BoardHardware`] and
+/// [`crate::rp2040::BoardHardware`] each own their peripherals outright.
 #[derive(Debug)]
-pub struct BoardHardware;
-impl HardwareImpl for BoardHardware {
+pub struct BoardHardware<S, O, I, D> {
+    spi: RefCell<Option<S>>,
+    ce: RefCell<Option<O>>,
+    irq: RefCell<Option<I>>,
+    delay: RefCell<Option<D>>,
+}
+
+impl<S, O, I, D> BoardHardware<S, O, I, D> {
+    /// Wrap already-constructed peripherals, rather than relying on [`HardwareImpl::new()`]'s
+    /// `Default`-based construction.
+    pub fn new_with(spi: S, ce: O, irq: I, delay: D) -> Self {
+        Self {
+            spi: RefCell::new(Some(spi)),
+            ce: RefCell::new(Some(ce)),
+            irq: RefCell::new(Some(irq)),
+            delay: RefCell::new(Some(delay)),
+        }
+    }
+
+    /// Take ownership of the delay implementation passed to [`Self::new_with()`].
+    ///
+    /// [`HardwareImpl`] has no delay accessor of its own, so callers fetch it from here
+    /// instead, alongside the CE pin and SPI device, when assembling an [`rf24::radio::RF24`].
+    pub fn take_delay(&self) -> Result<D> {
+        self.delay
+            .borrow_mut()
+            .take()
+            .ok_or_else(|| anyhow!("delay implementation was already taken"))
+    }
+}
+
+impl<S, O, I, D> HardwareImpl for BoardHardware<S, O, I, D>
+where
+    S: SpiDevice + Default,
+    O: OutputPin + Default,
+    I: InputPin + Default,
+    D: DelayNs + Default,
+{
     fn new() -> Result<Self> {
-        Ok(Self {})
+        Ok(Self::new_with(
+            S::default(),
+            O::default(),
+            I::default(),
+            D::default(),
+        ))
+    }
+
+    fn default_ce_pin(&self) -> Result<impl OutputPin> {
+        self.ce
+            .borrow_mut()
+            .take()
+            .ok_or_else(|| anyhow!("CE pin was already taken"))
     }
 
-    fn default_ce_pin(&mut self) -> Result<impl OutputPin> {
-        Ok(DigitalOutImpl)
+    fn default_spi_device(&self) -> Result<impl SpiDevice> {
+        self.spi
+            .borrow_mut()
+            .take()
+            .ok_or_else(|| anyhow!("SPI device was already taken"))
     }
 
-    fn default_spi_device() -> Result<impl SpiDevice> {
-        Ok(SpiImpl)
+    fn default_irq_pin(&self) -> Result<impl InputPin> {
+        self.irq
+            .borrow_mut()
+            .take()
+            .ok_or_else(|| anyhow!("IRQ pin was already taken"))
     }
+}
 
-    fn default_irq_pin(&mut self) -> Result<impl InputPin> {
-        Ok(DigitalInImpl)
+/// An embassy-flavored constructor for [`BoardHardware`], gated behind the `embassy` feature
+/// so this module compiles without pulling in embassy's HAL crates by default.
+#[cfg(feature = "embassy")]
+mod embassy_ctor {
+    use super::{BoardHardware, DelayImpl, DigitalInImpl, DigitalOutImpl, SpiImpl};
+    use core::cell::RefCell;
+    use embassy_embedded_hal::shared_bus::blocking::spi::SpiDevice as EmbassySpiDevice;
+    use embassy_rp::gpio::{Input, Output};
+    use embassy_rp::peripherals::SPI1;
+    use embassy_rp::spi::{Blocking, Spi};
+    use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+    use embassy_sync::blocking_mutex::Mutex;
+    use embassy_time::Delay;
+
+    type EmbassySpi<'b> = Spi<'b, SPI1, Blocking>;
+    type EmbassyBoardHardware<'b> = BoardHardware<
+        SpiImpl<EmbassySpiDevice<'b, NoopRawMutex, EmbassySpi<'b>, Output<'b>>>,
+        DigitalOutImpl<Output<'b>>,
+        DigitalInImpl<Input<'b>>,
+        DelayImpl<Delay>,
+    >;
+
+    impl<'b> EmbassyBoardHardware<'b> {
+        /// Build a [`BoardHardware`] from a shared SPI bus and a CS pin, plus separate CE
+        /// and IRQ pins, the way [`crate::rp2040::BoardHardware`] wires up its own fixed
+        /// pin assignments. Use this when the host application brings up embassy's
+        /// peripherals itself (e.g. for a board other than the RP2040 Pico this crate
+        /// targets out of the box) and only needs this crate to wrap the result.
+        pub fn from_embassy(
+            spi_bus: &'b Mutex<NoopRawMutex, RefCell<EmbassySpi<'b>>>,
+            cs_pin: Output<'b>,
+            ce_pin: Output<'b>,
+            irq_pin: Input<'b>,
+        ) -> Self {
+            let spi_device = EmbassySpiDevice::new(spi_bus, cs_pin);
+            Self::new_with(
+                SpiImpl::new(spi_device),
+                DigitalOutImpl::new(ce_pin),
+                DigitalInImpl::new(irq_pin),
+                DelayImpl(Delay),
+            )
+        }
     }
 }
@@ -0,0 +1,130 @@
+//! A host-side CLI for bring-up, link testing, and register inspection of a radio
+//! wired to a Linux SBC, without writing a one-off Rust program.
+//!
+//! ```text
+//! util set-channel <0-125>
+//! util get-channel
+//! util set-crc <off|8|16>
+//! util open-pipe <0-5> <address>
+//! util set-address-length <2-5>
+//! util carrier <channel> <seconds>
+//! util ping <tx|rx> <count>
+//! ```
+mod options;
+
+use std::{thread, time::Duration};
+
+use anyhow::{anyhow, Result};
+use options::{Command, Options, Role};
+use rf24_rs::{
+    radio::{prelude::*, RF24},
+    PaLevel,
+};
+use rf24_rs_examples::linux::BoardHardware;
+
+fn main() -> Result<()> {
+    let options = Options::parse()?;
+
+    let board = BoardHardware::default()?;
+    let mut radio = RF24::new(board.ce_pin, board.spi, board.delay);
+    radio.init().map_err(|e| anyhow!("{e:?}"))?;
+
+    match options.command {
+        Command::SetChannel(channel) => {
+            radio.set_channel(channel).map_err(|e| anyhow!("{e:?}"))?;
+            println!("channel set to {channel}");
+        }
+        Command::GetChannel => {
+            let channel = radio.get_channel().map_err(|e| anyhow!("{e:?}"))?;
+            println!("channel: {channel}");
+        }
+        Command::SetCrc(crc_length) => {
+            radio
+                .set_crc_length(crc_length)
+                .map_err(|e| anyhow!("{e:?}"))?;
+            println!("CRC length set to {crc_length:?}");
+        }
+        Command::OpenPipe(pipe, address) => {
+            radio
+                .open_rx_pipe(pipe, &address)
+                .map_err(|e| anyhow!("{e:?}"))?;
+            println!("pipe {pipe} opened for RX with address {address:02X?}");
+        }
+        Command::SetAddressLength(length) => {
+            radio
+                .set_address_length(length)
+                .map_err(|e| anyhow!("{e:?}"))?;
+            println!("address length set to {length}");
+        }
+        Command::Carrier(channel, seconds) => carrier(&mut radio, channel, seconds)?,
+        Command::Ping(role, count) => ping(&mut radio, role, count)?,
+    }
+    Ok(())
+}
+
+/// Transmit a constant, unmodulated carrier wave for `seconds`, then stop.
+///
+/// Pair with a spectrum analyzer (or a second radio's [`RF24::rpd()`]) to verify
+/// regional transmission requirements.
+fn carrier(
+    radio: &mut RF24<impl embedded_hal::spi::SpiDevice, impl embedded_hal::digital::OutputPin, impl embedded_hal::delay::DelayNs>,
+    channel: u8,
+    seconds: u64,
+) -> Result<()> {
+    radio
+        .start_carrier_wave(PaLevel::Max, channel)
+        .map_err(|e| anyhow!("{e:?}"))?;
+    println!("transmitting a carrier wave on channel {channel} for {seconds}s...");
+    thread::sleep(Duration::from_secs(seconds));
+    radio.stop_carrier_wave().map_err(|e| anyhow!("{e:?}"))?;
+    println!("carrier wave stopped");
+    Ok(())
+}
+
+const PING_ADDRESSES: [&[u8; 5]; 2] = [b"1Node", b"2Node"];
+
+/// A simple single-sided link-quality test: the `tx` role sends `count` payloads
+/// and reports how many were acknowledged; the `rx` role listens and reports how
+/// many distinct payloads arrived.
+fn ping(
+    radio: &mut RF24<impl embedded_hal::spi::SpiDevice, impl embedded_hal::digital::OutputPin, impl embedded_hal::delay::DelayNs>,
+    role: Role,
+    count: u8,
+) -> Result<()> {
+    radio
+        .open_rx_pipe(1, PING_ADDRESSES[0])
+        .map_err(|e| anyhow!("{e:?}"))?;
+    radio
+        .open_tx_pipe(PING_ADDRESSES[1])
+        .map_err(|e| anyhow!("{e:?}"))?;
+
+    match role {
+        Role::Tx => {
+            let mut acked = 0u8;
+            for payload in 0..count {
+                let ok = radio
+                    .send(&[payload], false)
+                    .map_err(|e| anyhow!("{e:?}"))?;
+                if ok {
+                    acked += 1;
+                }
+            }
+            println!("{acked}/{count} payloads acknowledged");
+        }
+        Role::Rx => {
+            radio.as_rx().map_err(|e| anyhow!("{e:?}"))?;
+            let mut received = 0u8;
+            let timeout = Duration::from_secs(count as u64);
+            let start = std::time::Instant::now();
+            while received < count && start.elapsed() < timeout {
+                if radio.available().map_err(|e| anyhow!("{e:?}"))? {
+                    let mut buf = [0u8; 1];
+                    radio.read(&mut buf, None).map_err(|e| anyhow!("{e:?}"))?;
+                    received += 1;
+                }
+            }
+            println!("{received}/{count} payloads received within {count}s");
+        }
+    }
+    Ok(())
+}
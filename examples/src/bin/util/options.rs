@@ -0,0 +1,112 @@
+use anyhow::{anyhow, Result};
+use rf24_rs::CrcLength;
+
+/// The operation requested on the command line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// `set-channel <0-125>`
+    SetChannel(u8),
+    /// `get-channel`
+    GetChannel,
+    /// `set-crc <off|8|16>`
+    SetCrc(CrcLength),
+    /// `open-pipe <0-5> <address>`
+    OpenPipe(u8, [u8; 5]),
+    /// `set-address-length <2-5>`
+    SetAddressLength(u8),
+    /// `carrier <channel> <seconds>`
+    Carrier(u8, u64),
+    /// `ping tx|rx <count>`
+    Ping(Role, u8),
+}
+
+/// Which side of the ping/pong link-quality test to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Tx,
+    Rx,
+}
+
+/// Parsed command-line options for the `util` binary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub command: Command,
+}
+
+impl Options {
+    /// Parse options from the process's command-line arguments (skipping `argv[0]`).
+    pub fn parse() -> Result<Self> {
+        Self::parse_from(std::env::args().skip(1))
+    }
+
+    /// Parse options from an arbitrary iterator of arguments.
+    pub fn parse_from(args: impl Iterator<Item = String>) -> Result<Self> {
+        let args: Vec<String> = args.collect();
+        let (name, rest) = args
+            .split_first()
+            .ok_or(anyhow!("expected a subcommand (see USAGE)"))?;
+
+        let command = match name.as_str() {
+            "set-channel" => Command::SetChannel(parse_arg(rest, 0, "channel")?),
+            "get-channel" => Command::GetChannel,
+            "set-crc" => Command::SetCrc(parse_crc_length(arg(rest, 0, "crc length")?)?),
+            "open-pipe" => Command::OpenPipe(
+                parse_arg(rest, 0, "pipe number")?,
+                parse_address(arg(rest, 1, "address")?)?,
+            ),
+            "set-address-length" => Command::SetAddressLength(parse_arg(rest, 0, "length")?),
+            "carrier" => Command::Carrier(
+                parse_arg(rest, 0, "channel")?,
+                parse_arg(rest, 1, "seconds")?,
+            ),
+            "ping" => Command::Ping(
+                parse_role(arg(rest, 0, "role")?)?,
+                parse_arg(rest, 1, "count")?,
+            ),
+            other => return Err(anyhow!("unrecognized subcommand `{other}` (see USAGE)")),
+        };
+        Ok(Self { command })
+    }
+}
+
+fn arg<'a>(args: &'a [String], index: usize, what: &str) -> Result<&'a str> {
+    args.get(index)
+        .map(String::as_str)
+        .ok_or(anyhow!("missing {what} argument"))
+}
+
+fn parse_arg<T: std::str::FromStr>(args: &[String], index: usize, what: &str) -> Result<T> {
+    arg(args, index, what)?
+        .parse()
+        .map_err(|_| anyhow!("invalid {what} argument"))
+}
+
+fn parse_crc_length(value: &str) -> Result<CrcLength> {
+    match value {
+        "off" | "disabled" => Ok(CrcLength::Disabled),
+        "8" => Ok(CrcLength::Bit8),
+        "16" => Ok(CrcLength::Bit16),
+        other => Err(anyhow!(
+            "invalid crc length `{other}` (expected `off`, `8`, or `16`)"
+        )),
+    }
+}
+
+fn parse_role(value: &str) -> Result<Role> {
+    match value {
+        "tx" => Ok(Role::Tx),
+        "rx" => Ok(Role::Rx),
+        other => Err(anyhow!("invalid role `{other}` (expected `tx` or `rx`)")),
+    }
+}
+
+/// Parse a 5-byte radio address from its ASCII representation, right-padded with `0`.
+fn parse_address(value: &str) -> Result<[u8; 5]> {
+    let bytes = value.as_bytes();
+    if bytes.len() > 5 {
+        return Err(anyhow!("address must be at most 5 bytes, got {}", bytes.len()));
+    }
+    let mut address = [0u8; 5];
+    address[..bytes.len()].copy_from_slice(bytes);
+    Ok(address)
+}
@@ -1,40 +1,155 @@
+use core::time::Duration;
+use std::os::unix::io::AsRawFd;
+
 use anyhow::{anyhow, Error, Result};
+use embedded_hal::{delay::DelayNs, digital::OutputPin, spi::SpiDevice};
+use embedded_hal_bus::spi::ExclusiveDevice;
 use linux_embedded_hal::{
-    gpio_cdev::{chips, Chip, LineRequestFlags},
+    gpio_cdev::{chips, Chip, EventRequestFlags, LineEventHandle, LineRequestFlags},
     spidev::{SpiModeFlags, SpidevOptions},
-    CdevPin, Delay, SpidevDevice,
+    CdevPin, Delay, SpidevBus, SpidevDevice,
+};
+use nix::poll::{poll, PollFd, PollFlags};
+use rf24_rs::{
+    radio::{prelude::EsbStatus, RF24},
+    StatusFlags,
 };
 
-pub struct BoardHardware {
-    pub spi: SpidevDevice,
+/// Open the desired `/dev/gpiochip{dev_gpio_chip}` for this system.
+fn open_gpio_chip(dev_gpio_chip: u8) -> Result<Chip> {
+    chips()?
+        .find(|chip| {
+            if let Ok(chip) = chip {
+                if chip.path().ends_with(dev_gpio_chip.to_string()) {
+                    return true;
+                }
+            }
+            false
+        })
+        .ok_or(anyhow!(
+            "Could not find specified dev/gpiochip{dev_gpio_chip} for this system."
+        ))?
+        .map_err(Error::from)
+}
+
+/// Request a GPIO `line` as an output (initialized to `default_value`) and wrap it as a [`CdevPin`].
+fn request_output_pin(gpio: &mut Chip, line: u32, default_value: u8) -> Result<CdevPin> {
+    let gpio_line = gpio
+        .get_line(line)
+        .map_err(|_| anyhow!("GPIO{line} is unavailable"))?;
+    let gpio_line_handle = gpio_line
+        .request(LineRequestFlags::OUTPUT, default_value, "rf24-rs")
+        .map_err(Error::from)?;
+    CdevPin::new(gpio_line_handle).map_err(Error::from)
+}
+
+/// Tunable SPI bus parameters, part of [`BoardHardwareConfig`].
+#[derive(Debug, Clone, Copy)]
+pub struct SpiConfig {
+    pub max_speed_hz: u32,
+    pub mode: SpiModeFlags,
+    pub bits_per_word: u8,
+}
+
+impl Default for SpiConfig {
+    fn default() -> Self {
+        Self {
+            max_speed_hz: 10_000_000,
+            mode: SpiModeFlags::SPI_MODE_0,
+            bits_per_word: 8,
+        }
+    }
+}
+
+/// Tunable hardware bring-up parameters for [`BoardHardware::new()`] and
+/// [`BoardHardware::with_gpio_cs()`], so non-default wiring (a different gpiochip, a
+/// slower SPI clock for long wiring, or a peripheral that needs extra chip-select
+/// settle time) doesn't require forking this module.
+///
+/// The defaults match what these constructors used to hard-code, so
+/// [`BoardHardware::default()`] keeps behaving the same.
+#[derive(Debug, Clone, Copy)]
+pub struct BoardHardwareConfig {
+    /// Which `/dev/gpiochip{N}` to search for [`BoardHardware::ce_pin`] (and any IRQ
+    /// or chip-select GPIO) on.
+    pub dev_gpio_chip: u8,
+    pub spi: SpiConfig,
+    /// How long to wait, after configuring the SPI bus, before the first transfer.
+    ///
+    /// Some peripherals (the ENC424J600 driver's experience with certain boosters,
+    /// for instance) need extra settle time to meet their chip-select (NSS) timing
+    /// beyond what the SPI clock alone provides. Defaults to `0` (no extra delay).
+    pub cs_settle_delay_us: u32,
+}
+
+impl Default for BoardHardwareConfig {
+    fn default() -> Self {
+        Self {
+            dev_gpio_chip: option_env!("RF24_EXAMPLE_GPIO_CHIP")
+                .unwrap_or("0")
+                .parse()
+                .unwrap_or(0),
+            spi: SpiConfig::default(),
+            cs_settle_delay_us: 0,
+        }
+    }
+}
+
+/// Block until `irq_line` reports a falling edge (the radio's IRQ pin is active-low)
+/// or `timeout` elapses, then decode why the radio interrupted via
+/// [`EsbStatus::what_happened()`] instead of leaving that extra SPI round-trip to the
+/// caller.
+///
+/// Unlike busy-polling the pin's level, this parks the thread (via `poll()`) until the
+/// GPIO subsystem actually reports the edge, so it costs no CPU time while waiting.
+/// Returns `Ok(None)` if `timeout` elapses with no edge detected.
+///
+/// A `Future`-based equivalent for use with [`rf24::radio::AsyncRF24`] is not provided
+/// here (its IRQ pin already implements `embedded-hal-async`'s `Wait`, so no polling
+/// helper is needed); use [`AsyncRF24::wait_for_irq()`](fn@rf24::radio::AsyncRF24::wait_for_irq)
+/// directly instead.
+pub fn wait_for_irq<SPI: SpiDevice, DO: OutputPin, DELAY: DelayNs>(
+    irq_line: &mut LineEventHandle,
+    timeout: Duration,
+    radio: &mut RF24<SPI, DO, DELAY>,
+) -> Result<Option<StatusFlags>> {
+    let mut fds = [PollFd::new(irq_line.as_raw_fd(), PollFlags::POLLIN)];
+    let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+    let events =
+        poll(&mut fds, timeout_ms).map_err(|e| anyhow!("poll() on the IRQ line failed: {e}"))?;
+    if events == 0 {
+        return Ok(None);
+    }
+    // consume the event so the next call doesn't immediately return again
+    irq_line.get_event().map_err(Error::from)?;
+    radio
+        .what_happened(StatusFlags::new())
+        .map(Some)
+        .map_err(|e| anyhow!("{e:?}"))
+}
+
+/// A set of hardware peripherals for the radio, generic over the SPI device
+/// implementation used.
+///
+/// Defaults to [`SpidevDevice`] (a kernel-owned, devfs chip-select). Use
+/// [`BoardHardware::with_gpio_cs()`] instead for a software-driven (GPIO) chip-select.
+pub struct BoardHardware<SPI = SpidevDevice> {
+    pub spi: SPI,
     pub ce_pin: CdevPin,
     #[allow(dead_code)]
     gpio: Chip,
     pub delay: Delay,
 }
 
-impl BoardHardware {
-    pub fn new(dev_gpio_chip: u8, ce_pin: u32, dev_spi_bus: u8, cs_pin: u8) -> Result<Self> {
-        // get the desired "dev/gpiochip{dev_gpio_chip}"
-        let mut dev_gpio = chips()?
-            .find(|chip| {
-                if let Ok(chip) = chip {
-                    if chip.path().ends_with(dev_gpio_chip.to_string()) {
-                        return true;
-                    }
-                }
-                false
-            })
-            .ok_or(anyhow!(
-                "Could not find specified dev/gpiochip{dev_gpio_chip} for this system."
-            ))??;
-        let ce_line = dev_gpio
-            .get_line(ce_pin)
-            .map_err(|_| anyhow!("GPIO{ce_pin} is unavailable"))?;
-        let ce_line_handle = ce_line
-            .request(LineRequestFlags::OUTPUT, 0, "rf24-rs")
-            .map_err(Error::from)?;
-        let ce_pin = CdevPin::new(ce_line_handle).map_err(Error::from)?;
+impl BoardHardware<SpidevDevice> {
+    pub fn new(
+        ce_pin: u32,
+        dev_spi_bus: u8,
+        cs_pin: u8,
+        config: BoardHardwareConfig,
+    ) -> Result<Self> {
+        let mut dev_gpio = open_gpio_chip(config.dev_gpio_chip)?;
+        let ce_pin = request_output_pin(&mut dev_gpio, ce_pin, 0)?;
 
         let mut spi =
             SpidevDevice::open(format!("/dev/spidev{dev_spi_bus}.{cs_pin}")).map_err(|_| {
@@ -42,12 +157,15 @@ impl BoardHardware {
                 "SPI bus {dev_spi_bus} with CS pin option {cs_pin} is not available in this system"
             )
             })?;
-        let config = SpidevOptions::new()
-            .max_speed_hz(10000000)
-            .mode(SpiModeFlags::SPI_MODE_0)
-            .bits_per_word(8)
+        let spi_config = SpidevOptions::new()
+            .max_speed_hz(config.spi.max_speed_hz)
+            .mode(config.spi.mode)
+            .bits_per_word(config.spi.bits_per_word)
             .build();
-        spi.configure(&config).map_err(Error::from)?;
+        spi.configure(&spi_config).map_err(Error::from)?;
+        if config.cs_settle_delay_us > 0 {
+            Delay.delay_us(config.cs_settle_delay_us);
+        }
 
         Ok(BoardHardware {
             spi,
@@ -59,13 +177,67 @@ impl BoardHardware {
 
     #[allow(clippy::should_implement_trait)]
     pub fn default() -> Result<Self> {
-        Self::new(
-            option_env!("RF24_EXAMPLE_GPIO_CHIP")
-                .unwrap_or("0")
-                .parse()?,
-            22,
-            0,
-            0,
-        )
+        Self::new(22, 0, 0, BoardHardwareConfig::default())
+    }
+}
+
+impl<SPI> BoardHardware<SPI> {
+    /// Request `irq_pin` as a falling-edge event line, for use with [`wait_for_irq()`]
+    /// instead of busy-polling a plain `INPUT` line's level.
+    pub fn get_irq_event_pin(&mut self, irq_pin: u32) -> Result<LineEventHandle> {
+        let irq_line = self
+            .gpio
+            .get_line(irq_pin)
+            .map_err(|_| anyhow!("GPIO{irq_pin} is unavailable"))?;
+        irq_line
+            .events(
+                LineRequestFlags::INPUT,
+                EventRequestFlags::FALLING_EDGE,
+                "rf24-rs",
+            )
+            .map_err(Error::from)
+    }
+}
+
+impl BoardHardware<ExclusiveDevice<SpidevBus, CdevPin, Delay>> {
+    /// Build the SPI device from a raw [`SpidevBus`] plus an arbitrary GPIO pin used
+    /// for chip-select, instead of a kernel-owned `/dev/spidevX.Y` CS line.
+    ///
+    /// This lets any free GPIO serve as chip-select (e.g. to drive multiple radios on
+    /// one bus with GPIO-selected CS), rather than being limited to the CS pins the
+    /// SoC's SPI controller exposes via devfs.
+    pub fn with_gpio_cs(
+        ce_pin: u32,
+        cs_pin: u32,
+        dev_spi_bus: u8,
+        config: BoardHardwareConfig,
+    ) -> Result<Self> {
+        let mut dev_gpio = open_gpio_chip(config.dev_gpio_chip)?;
+        let ce_pin = request_output_pin(&mut dev_gpio, ce_pin, 0)?;
+        let cs_pin = request_output_pin(&mut dev_gpio, cs_pin, 1)?;
+
+        // bus-only device; SPI_NO_CS tells the kernel driver not to toggle its own
+        // (devfs-owned) CS line, since `cs_pin` handles chip-select instead.
+        let mut bus = SpidevBus::open(format!("/dev/spidev{dev_spi_bus}.0"))
+            .map_err(|_| anyhow!("SPI bus {dev_spi_bus} is not available in this system"))?;
+        let spi_config = SpidevOptions::new()
+            .max_speed_hz(config.spi.max_speed_hz)
+            .mode(config.spi.mode | SpiModeFlags::SPI_NO_CS)
+            .bits_per_word(config.spi.bits_per_word)
+            .build();
+        bus.configure(&spi_config).map_err(Error::from)?;
+        if config.cs_settle_delay_us > 0 {
+            Delay.delay_us(config.cs_settle_delay_us);
+        }
+
+        let spi = ExclusiveDevice::new(bus, cs_pin, Delay)
+            .map_err(|e| anyhow!("Could not build the GPIO-CS SPI device: {e:?}"))?;
+
+        Ok(BoardHardware {
+            spi,
+            ce_pin,
+            gpio: dev_gpio,
+            delay: Delay,
+        })
     }
 }